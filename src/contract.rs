@@ -1,7 +1,7 @@
 use cosmwasm_std::{
-    log, to_binary, Api, Binary, BlockInfo, CanonicalAddr, CosmosMsg, Env, Extern, HandleResponse,
-    HandleResult, HumanAddr, InitResponse, InitResult, Querier, QueryResult, ReadonlyStorage,
-    StdError, StdResult, Storage, WasmMsg,
+    log, to_binary, Api, BankMsg, Binary, BlockInfo, CanonicalAddr, Coin, CosmosMsg, Env, Extern,
+    HandleResponse, HandleResult, HumanAddr, InitResponse, InitResult, LogAttribute, Querier,
+    QueryResult, ReadonlyStorage, StdError, StdResult, Storage, Uint128, WasmMsg,
 };
 use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
 use primitive_types::U256;
@@ -18,22 +18,31 @@ use crate::expiration::Expiration;
 use crate::inventory::{Inventory, InventoryIter};
 use crate::mint_run::{SerialNumber, StoredMintRunInfo};
 use crate::msg::{
-    AccessLevel, Burn, ContractStatus, Cw721Approval, Cw721OwnerOfResponse, HandleAnswer,
-    HandleMsg, InitMsg, Mint, QueryAnswer, QueryMsg, QueryWithPermit, ReceiverInfo,
-    ResponseStatus::Success, Send, Snip721Approval, Transfer, ViewerInfo,
+    AccessLevel, Burn, ContractStatus, Cw721Approval, Cw721OwnerOfResponse, ExportedToken,
+    HandleAnswer, HandleMsg, InitMsg, MediaHash, MetadataType, Mint, NumericTrait, PrefixApproval,
+    PublicDossier, QueryAnswer, QueryMsg, QueryWithPermit, ReceiverInfo, ResponseStatus::Success,
+    Send, Snip721Approval, Transfer, TxType, UnwrappedStatus, ViewerInfo, WeightedTrait,
+    DEFAULT_PRNG_ALGORITHM,
 };
-use crate::rand::sha_256;
+use crate::rand::{sha_256, Prng};
 use crate::receiver::{batch_receive_nft_msg, receive_nft_msg};
-use crate::royalties::{RoyaltyInfo, StoredRoyaltyInfo};
+use crate::royalties::{RoyaltyInfo, StoredRoyalty, StoredRoyaltyInfo};
 use crate::state::{
-    get_txs, json_may_load, json_save, load, may_load, remove, save, store_burn, store_mint,
-    store_transfer, AuthList, Config, Permission, PermissionType, ReceiveRegistration, BLOCK_KEY,
-    CONFIG_KEY, CREATOR_KEY, DEFAULT_ROYALTY_KEY, MINTERS_KEY, MY_ADDRESS_KEY,
-    PREFIX_ALL_PERMISSIONS, PREFIX_AUTHLIST, PREFIX_INFOS, PREFIX_MAP_TO_ID, PREFIX_MAP_TO_INDEX,
-    PREFIX_MINT_RUN, PREFIX_MINT_RUN_NUM, PREFIX_OWNER_PRIV, PREFIX_PRIV_META, PREFIX_PUB_META,
-    PREFIX_RECEIVERS, PREFIX_REVOKED_PERMITS, PREFIX_ROYALTY_INFO, PREFIX_VIEW_KEY, PRNG_SEED_KEY,
+    get_trait_count, get_trait_tokens, get_txs, get_txs_of_type, json_may_load, json_save, load,
+    may_load, remove, save, store_burn, store_mint, store_transfer, unprotected_attributes_of,
+    update_trait_index, AuthList, Config, Permission, PermissionType, ReceiveRegistration,
+    StoredPrefixApproval, BLOCK_KEY, CONFIG_KEY, CREATOR_KEY, DEFAULT_EXTENSION_KEY,
+    DEFAULT_ROYALTY_KEY, METADATA_EDITORS_KEY, MINTERS_KEY, MY_ADDRESS_KEY, PREFIX_ALL_PERMISSIONS,
+    PREFIX_AUTHLIST, PREFIX_CLONE_ID_NEXT, PREFIX_FROZEN, PREFIX_INFOS, PREFIX_MAP_TO_ID,
+    PREFIX_MAP_TO_INDEX, PREFIX_MINT_RUN, PREFIX_MINT_RUN_NUM, PREFIX_OWNER_PRIV,
+    PREFIX_PREFIX_APPROVALS, PREFIX_PRIV_META, PREFIX_PUB_META, PREFIX_RECEIVERS, PREFIX_RESERVED,
+    PREFIX_REVEAL_COMMIT, PREFIX_REVOKED_NAMES, PREFIX_REVOKED_PERMITS, PREFIX_ROYALTY_INFO,
+    PREFIX_VIEW_CNT, PREFIX_VIEW_KEY, PRNG_SEED_KEY, RESERVED_CNT_KEY,
+};
+use crate::token::{
+    check_ipfs_gateway, check_locale, Extension, Metadata, MetadataPatch, Token, Trait,
+    DEFAULT_MAX_IMAGE_DATA_SIZE,
 };
-use crate::token::{Metadata, Token};
 use crate::viewing_key::{ViewingKey, VIEWING_KEY_SIZE};
 
 /// pad handle responses and log attributes to blocks of 256 bytes to prevent leaking info based on
@@ -41,6 +50,13 @@ use crate::viewing_key::{ViewingKey, VIEWING_KEY_SIZE};
 pub const BLOCK_SIZE: usize = 256;
 /// max number of token ids to keep in id list block
 pub const ID_BLOCK_SIZE: u32 = 64;
+/// max length in bytes of a transfer memo
+pub const MAX_MEMO_LEN: usize = 256;
+/// fixed-point scale of rarity scores
+pub const RARITY_SCALE: u64 = 1_000_000;
+/// width every generated clone id index is zero-padded to.  This is the number of digits in
+/// u32::MAX, so the ids generated from one prefix always sort in mint order
+pub const CLONE_ID_WIDTH: usize = 10;
 
 ////////////////////////////////////// Init ///////////////////////////////////////
 /// Returns InitResult
@@ -87,6 +103,14 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
         minter_may_update_metadata: init_config.minter_may_update_metadata.unwrap_or(true),
         owner_may_update_metadata: init_config.owner_may_update_metadata.unwrap_or(false),
         burn_is_enabled: init_config.enable_burn.unwrap_or(false),
+        max_attributes: init_config.max_attributes,
+        mutable_trait_types: None,
+        track_views: init_config.track_views.unwrap_or(false),
+        max_image_data_size: init_config
+            .max_image_data_size
+            .unwrap_or(DEFAULT_MAX_IMAGE_DATA_SIZE),
+        max_royalty_bps: init_config.max_royalty_bps,
+        prng_algorithm: init_config.prng_algorithm.unwrap_or(DEFAULT_PRNG_ALGORITHM),
     };
 
     let minters = vec![admin_raw];
@@ -103,6 +127,7 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
             msg.royalty_info.as_ref(),
             None,
             DEFAULT_ROYALTY_KEY,
+            config.max_royalty_bps,
         )?;
     }
 
@@ -149,6 +174,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             private_metadata,
             serial_number,
             royalty_info,
+            reveal_commitment,
             memo,
             ..
         } => mint(
@@ -162,6 +188,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             private_metadata,
             serial_number,
             royalty_info,
+            reveal_commitment,
             memo,
         ),
         HandleMsg::BatchMintNft { mut mints, .. } => batch_mint(
@@ -174,8 +201,13 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
         HandleMsg::MintNftClones {
             mint_run_id,
             quantity,
+            id_prefix,
             owner,
             public_metadata,
+            use_default_extension,
+            trait_pool,
+            rarity_tiers,
+            numeric_traits,
             private_metadata,
             royalty_info,
             memo,
@@ -187,12 +219,24 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             ContractStatus::Normal.to_u8(),
             mint_run_id.as_ref(),
             quantity,
+            id_prefix.as_deref(),
             owner,
             public_metadata,
+            use_default_extension.unwrap_or(false),
+            trait_pool,
+            rarity_tiers,
+            numeric_traits,
             private_metadata,
             royalty_info,
             memo,
         ),
+        HandleMsg::ReserveTokenIds { token_ids, .. } => reserve_token_ids(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_ids,
+        ),
         HandleMsg::SetMetadata {
             token_id,
             public_metadata,
@@ -202,7 +246,21 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             deps,
             env,
             &config,
-            ContractStatus::StopTransactions.to_u8(),
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            public_metadata,
+            private_metadata,
+        ),
+        HandleMsg::PatchMetadata {
+            token_id,
+            public_metadata,
+            private_metadata,
+            ..
+        } => patch_metadata(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
             &token_id,
             public_metadata,
             private_metadata,
@@ -215,11 +273,65 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             deps,
             env,
             &config,
-            ContractStatus::StopTransactions.to_u8(),
+            ContractStatus::Normal.to_u8(),
             token_id.as_deref(),
             royalty_info.as_ref(),
         ),
+        HandleMsg::SetDefaultExtension { extension, .. } => set_default_extension(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            extension,
+        ),
         HandleMsg::Reveal { token_id, .. } => reveal(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            None,
+        ),
+        HandleMsg::RevealWithProof {
+            token_id,
+            private_metadata,
+            ..
+        } => reveal(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            Some(private_metadata),
+        ),
+        HandleMsg::BatchReveal { token_ids, .. } => batch_reveal(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_ids,
+        ),
+        HandleMsg::SetTransferLock {
+            token_id, locked, ..
+        } => set_transfer_lock(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &token_id,
+            locked,
+        ),
+        HandleMsg::BatchSetTransferLock {
+            token_ids, locked, ..
+        } => batch_set_transfer_lock(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &token_ids,
+            locked,
+        ),
+        HandleMsg::RecordView { token_id, .. } => record_view(
             deps,
             env,
             &config,
@@ -264,12 +376,14 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             view_private_metadata,
             transfer,
             expires,
+            None,
             SetAppResp::SetWhitelistedApproval,
         ),
         HandleMsg::Approve {
             spender,
             token_id,
             expires,
+            max_uses,
             ..
         } => approve_revoke(
             deps,
@@ -279,6 +393,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             &spender,
             &token_id,
             expires,
+            max_uses,
             true,
         ),
         HandleMsg::Revoke {
@@ -291,10 +406,14 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             &spender,
             &token_id,
             None,
+            None,
             false,
         ),
         HandleMsg::ApproveAll {
-            operator, expires, ..
+            operator,
+            expires,
+            max_uses,
+            ..
         } => set_whitelisted_approval(
             deps,
             env,
@@ -306,6 +425,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             None,
             Some(AccessLevel::All),
             expires,
+            max_uses,
             SetAppResp::ApproveAll,
         ),
         HandleMsg::RevokeAll { operator, .. } => set_whitelisted_approval(
@@ -319,8 +439,34 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             None,
             Some(AccessLevel::None),
             None,
+            None,
             SetAppResp::RevokeAll,
         ),
+        HandleMsg::ApproveAllWithPrefix {
+            operator,
+            prefix,
+            expires,
+            ..
+        } => set_prefix_approval(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &operator,
+            prefix,
+            Some(expires.unwrap_or(Expiration::Never)),
+        ),
+        HandleMsg::RevokeAllWithPrefix {
+            operator, prefix, ..
+        } => set_prefix_approval(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &operator,
+            prefix,
+            None,
+        ),
         HandleMsg::TransferNft {
             recipient,
             token_id,
@@ -330,7 +476,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             deps,
             env,
             &mut config,
-            ContractStatus::Normal.to_u8(),
+            ContractStatus::StopMutations.to_u8(),
             recipient,
             token_id,
             memo,
@@ -339,7 +485,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             deps,
             env,
             &mut config,
-            ContractStatus::Normal.to_u8(),
+            ContractStatus::StopMutations.to_u8(),
             transfers,
         ),
         HandleMsg::SendNft {
@@ -353,7 +499,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             deps,
             env,
             &mut config,
-            ContractStatus::Normal.to_u8(),
+            ContractStatus::StopMutations.to_u8(),
             contract,
             receiver_info,
             token_id,
@@ -364,7 +510,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             deps,
             env,
             &mut config,
-            ContractStatus::Normal.to_u8(),
+            ContractStatus::StopMutations.to_u8(),
             sends,
         ),
         HandleMsg::RegisterReceiveNft {
@@ -383,7 +529,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             deps,
             env,
             &mut config,
-            ContractStatus::Normal.to_u8(),
+            ContractStatus::StopMutations.to_u8(),
             token_id,
             memo,
         ),
@@ -391,7 +537,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             deps,
             env,
             &mut config,
-            ContractStatus::Normal.to_u8(),
+            ContractStatus::StopMutations.to_u8(),
             &mut burns,
         ),
         HandleMsg::CreateViewingKey { entropy, .. } => create_key(
@@ -429,6 +575,27 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             ContractStatus::StopTransactions.to_u8(),
             &minters,
         ),
+        HandleMsg::AddMetadataEditors { editors, .. } => add_metadata_editors(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &editors,
+        ),
+        HandleMsg::RemoveMetadataEditors { editors, .. } => remove_metadata_editors(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &editors,
+        ),
+        HandleMsg::SetMetadataEditors { editors, .. } => set_metadata_editors(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &editors,
+        ),
         HandleMsg::ChangeAdmin { address, .. } => change_admin(
             deps,
             env,
@@ -439,8 +606,47 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
         HandleMsg::SetContractStatus { level, .. } => {
             set_contract_status(deps, env, &mut config, level)
         }
+        HandleMsg::SetMaxAttributes { max_attributes, .. } => set_max_attributes(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::StopTransactions.to_u8(),
+            max_attributes,
+        ),
+        HandleMsg::SetMaxRoyalty {
+            max_royalty_bps, ..
+        } => set_max_royalty(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            max_royalty_bps,
+        ),
+        HandleMsg::FreezeToken { token_id, .. } => set_frozen(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &token_id,
+            true,
+        ),
+        HandleMsg::UnfreezeToken { token_id, .. } => set_frozen(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &token_id,
+            false,
+        ),
+        HandleMsg::SetMutableTraitTypes { trait_types, .. } => set_mutable_trait_types(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::StopTransactions.to_u8(),
+            trait_types,
+        ),
         HandleMsg::RevokePermit { permit_name, .. } => {
-            revoke_permit(&mut deps.storage, &env.message.sender, &permit_name)
+            revoke_permit(deps, &env.message.sender, &permit_name)
         }
     };
     pad_handle_result(response, BLOCK_SIZE)
@@ -462,6 +668,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
 /// * `private_metadata` - optional private metadata viewable only by owner and whitelist
 /// * `serial_number` - optional serial number information for this token
 /// * `royalty_info` - optional royalties information for this token
+/// * `reveal_commitment` - optional hex sha256 hash of the private metadata to reveal
 /// * `memo` - optional memo for the mint tx
 #[allow(clippy::too_many_arguments)]
 pub fn mint<S: Storage, A: Api, Q: Querier>(
@@ -475,6 +682,7 @@ pub fn mint<S: Storage, A: Api, Q: Querier>(
     private_metadata: Option<Metadata>,
     serial_number: Option<SerialNumber>,
     royalty_info: Option<RoyaltyInfo>,
+    reveal_commitment: Option<String>,
     memo: Option<String>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
@@ -493,6 +701,7 @@ pub fn mint<S: Storage, A: Api, Q: Querier>(
         private_metadata,
         serial_number,
         royalty_info,
+        reveal_commitment,
         memo,
     }];
     let mut minted = mint_list(deps, &env, config, &sender_raw, &mut mints)?;
@@ -555,8 +764,13 @@ pub fn batch_mint<S: Storage, A: Api, Q: Querier>(
 /// * `priority` - u8 representation of highest status level this action is permitted at
 /// * `mint_run_id` - optional id used to track subsequent mint runs
 /// * `quantity` - number of clones to mint
+/// * `id_prefix` - optional prefix used to generate the clones' token ids
 /// * `owner` - optional owner of this token, if not specified, use the minter's address
 /// * `public_metadata` - optional public metadata viewable by everyone
+/// * `use_default_extension` - true if the public extension overlays the default template
+/// * `trait_pool` - optional traits to shuffle and distribute, one per clone
+/// * `rarity_tiers` - optional weighted traits, one of which is drawn for each clone
+/// * `numeric_traits` - optional numeric traits given a random value for each clone
 /// * `private_metadata` - optional private metadata viewable only by owner and whitelist
 /// * `royalty_info` - optional royalties information for these clones
 /// * `memo` - optional memo for the mint txs
@@ -568,8 +782,13 @@ pub fn mint_clones<S: Storage, A: Api, Q: Querier>(
     priority: u8,
     mint_run_id: Option<&String>,
     quantity: u32,
+    id_prefix: Option<&str>,
     owner: Option<HumanAddr>,
     public_metadata: Option<Metadata>,
+    use_default_extension: bool,
+    trait_pool: Option<Vec<Trait>>,
+    rarity_tiers: Option<Vec<WeightedTrait>>,
+    numeric_traits: Option<Vec<NumericTrait>>,
     private_metadata: Option<Metadata>,
     royalty_info: Option<RoyaltyInfo>,
     memo: Option<String>,
@@ -586,6 +805,93 @@ pub fn mint_clones<S: Storage, A: Api, Q: Querier>(
     if quantity == 0 {
         return Err(StdError::generic_err("Quantity can not be zero"));
     }
+    // continue the numbering where the last clone mint with this prefix left off
+    let clone_ids = id_prefix
+        .map(|p| {
+            let key = p.as_bytes();
+            let mut next_store = PrefixedStorage::new(PREFIX_CLONE_ID_NEXT, &mut deps.storage);
+            let start: u32 = may_load(&next_store, key)?.unwrap_or(1);
+            let next: u32 = start.checked_add(quantity).ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "Token ID prefix {} has already reached its maximum possible index",
+                    p
+                ))
+            })?;
+            save(&mut next_store, key, &next)?;
+            Ok(generate_clone_ids(p, start, quantity))
+        })
+        .transpose()?;
+    // check every generated id before anything is minted
+    if let Some(ids) = clone_ids.as_ref() {
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        for id in ids.iter() {
+            if may_load::<u32, _>(&map2idx, id.as_bytes())?.is_some() {
+                return Err(StdError::generic_err(format!(
+                    "Generated token ID {} is already in use",
+                    id
+                )));
+            }
+        }
+    }
+    // overlay the supplied extension fields onto the default template
+    let public_metadata = if use_default_extension {
+        let template: Extension =
+            may_load(&deps.storage, DEFAULT_EXTENSION_KEY)?.unwrap_or_default();
+        let (token_uri, overrides) = public_metadata
+            .map(|m| (m.token_uri, m.extension))
+            .unwrap_or((None, None));
+        let mut merged = Metadata {
+            token_uri,
+            extension: Some(template),
+        };
+        if let Some(ext) = overrides {
+            merged.apply_patch(ext.into());
+        }
+        Some(merged)
+    } else {
+        public_metadata
+    };
+    if matches!(trait_pool.as_ref(), Some(pool) if pool.len() != quantity as usize) {
+        return Err(StdError::generic_err(
+            "The trait pool must contain exactly one trait per clone",
+        ));
+    }
+    // each clone draws seed-dependent traits from the pool, the rarity tiers, and the numeric traits
+    let mut trait_rng =
+        if trait_pool.is_some() || rarity_tiers.is_some() || numeric_traits.is_some() {
+            let prng_seed: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY)?;
+            let mut entropy = env.block.height.to_be_bytes().to_vec();
+            entropy.extend_from_slice(&env.block.time.to_be_bytes());
+            entropy.extend_from_slice(sender_raw.as_slice());
+            Some(Prng::with_algorithm(
+                &config.prng_algorithm,
+                &prng_seed,
+                &entropy,
+            ))
+        } else {
+            None
+        };
+    // the id each clone will be minted with
+    let clone_token_id = |i: usize| {
+        clone_ids.as_ref().map_or_else(
+            || format!("{}", config.mint_cnt as u64 + i as u64),
+            |ids| ids[i].clone(),
+        )
+    };
+    let mut pool_iter = trait_pool.map(|mut pool| {
+        if let Some(rng) = trait_rng.as_mut() {
+            // mix in every clone's id before shuffling, so the assignment of pool traits
+            // depends on the ids of the whole run
+            for i in 0..quantity as usize {
+                rng.reseed(clone_token_id(i).as_bytes());
+            }
+            rng.shuffle(&mut pool);
+        }
+        pool.into_iter()
+    });
+    let tier_weights: Option<Vec<u32>> = rarity_tiers
+        .as_ref()
+        .map(|tiers| tiers.iter().map(|t| t.weight).collect());
     let mint_run = mint_run_id
         .map(|i| {
             let key = i.as_bytes();
@@ -607,14 +913,43 @@ pub fn mint_clones<S: Storage, A: Api, Q: Querier>(
         quantity_minted_this_run: Some(quantity),
     };
     let mut mints: Vec<Mint> = Vec::new();
-    for _ in 0..quantity {
+    for i in 0..quantity as usize {
+        let mut clone_pub = public_metadata.clone();
+        if let Some(rng) = trait_rng.as_mut() {
+            let mut drawn: Vec<Trait> = Vec::new();
+            if let Some(trt) = pool_iter.as_mut().and_then(|p| p.next()) {
+                drawn.push(trt);
+            }
+            if rarity_tiers.is_some() || numeric_traits.is_some() {
+                // reseed with this clone's id before its draws
+                rng.reseed(clone_token_id(i).as_bytes());
+            }
+            if let (Some(tiers), Some(weights)) = (rarity_tiers.as_ref(), tier_weights.as_ref()) {
+                drawn.push(tiers[rng.weighted_index(weights)?].attribute.clone());
+            }
+            if let Some(numerics) = numeric_traits.as_ref() {
+                drawn.extend(
+                    numerics
+                        .iter()
+                        .map(|n| Trait::new_numeric(rng, &n.trait_type, n.max)),
+                );
+            }
+            clone_pub
+                .get_or_insert_with(Metadata::default)
+                .extension
+                .get_or_insert_with(Extension::default)
+                .attributes
+                .get_or_insert_with(Vec::new)
+                .extend(drawn);
+        }
         mints.push(Mint {
-            token_id: None,
+            token_id: clone_ids.as_ref().map(|ids| ids[i].clone()),
             owner: owner.clone(),
-            public_metadata: public_metadata.clone(),
+            public_metadata: clone_pub,
             private_metadata: private_metadata.clone(),
             serial_number: Some(serial_number.clone()),
             royalty_info: royalty_info.clone(),
+            reveal_commitment: None,
             memo: memo.clone(),
         });
         serial_number.serial_number += 1;
@@ -642,6 +977,82 @@ pub fn mint_clones<S: Storage, A: Api, Q: Querier>(
     })
 }
 
+/// Returns Vec<String> of the token ids `<prefix>-<index>` for `count` consecutive indices
+/// beginning at `start`.  Every index is zero-padded to CLONE_ID_WIDTH digits, so all the ids
+/// generated from one prefix sort lexicographically in mint order
+///
+/// # Arguments
+///
+/// * `prefix` - the prefix of every generated id
+/// * `start` - the first index
+/// * `count` - the number of ids to generate
+pub fn generate_clone_ids(prefix: &str, start: u32, count: u32) -> Vec<String> {
+    (0..count as u64)
+        .map(|i| {
+            format!(
+                "{}-{:0width$}",
+                prefix,
+                start as u64 + i,
+                width = CLONE_ID_WIDTH
+            )
+        })
+        .collect()
+}
+
+/// Returns HandleResult
+///
+/// reserves token ids so that only the reserving minter may mint them
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_ids` - list of token ids to reserve
+pub fn reserve_token_ids<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_ids: &[String],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let minters: Vec<CanonicalAddr> =
+        may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_else(Vec::new);
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to reserve token ids",
+        ));
+    }
+    let mut reserved_cnt: u32 = may_load(&deps.storage, RESERVED_CNT_KEY)?.unwrap_or(0);
+    for id in token_ids.iter() {
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let may_exist: Option<u32> = may_load(&map2idx, id.as_bytes())?;
+        let mut res_store = PrefixedStorage::new(PREFIX_RESERVED, &mut deps.storage);
+        let may_reserved: Option<CanonicalAddr> = may_load(&res_store, id.as_bytes())?;
+        if may_exist.is_some() || may_reserved.is_some() {
+            return Err(StdError::generic_err(format!(
+                "Token ID {} is already in use",
+                id
+            )));
+        }
+        save(&mut res_store, id.as_bytes(), &sender_raw)?;
+        reserved_cnt = reserved_cnt.checked_add(1).ok_or_else(|| {
+            StdError::generic_err("Attempting to reserve more tokens than the implementation limit")
+        })?;
+    }
+    save(&mut deps.storage, RESERVED_CNT_KEY, &reserved_cnt)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::ReserveTokenIds {
+            status: Success,
+        })?),
+    })
+}
+
 /// Returns HandleResult
 ///
 /// sets new public and/or private metadata
@@ -665,28 +1076,26 @@ pub fn set_metadata<S: Storage, A: Api, Q: Querier>(
     private_metadata: Option<Metadata>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let custom_err = format!("Not authorized to update metadata of token {}", token_id);
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they are not authorized for that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
-    };
-    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    if !(token.owner == sender_raw && config.owner_may_update_metadata) {
-        let minters: Vec<CanonicalAddr> =
-            may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_else(Vec::new);
-        if !(minters.contains(&sender_raw) && config.minter_may_update_metadata) {
-            return Err(StdError::generic_err(custom_err));
-        }
-    }
+    let (token, idx) = get_token_for_meta_update(deps, &env, config, token_id)?;
     if let Some(public) = public_metadata {
-        set_metadata_impl(&mut deps.storage, &token, idx, PREFIX_PUB_META, &public)?;
+        set_metadata_impl(
+            &mut deps.storage,
+            &token,
+            idx,
+            PREFIX_PUB_META,
+            &public,
+            config,
+        )?;
     }
     if let Some(private) = private_metadata {
-        set_metadata_impl(&mut deps.storage, &token, idx, PREFIX_PRIV_META, &private)?;
+        set_metadata_impl(
+            &mut deps.storage,
+            &token,
+            idx,
+            PREFIX_PRIV_META,
+            &private,
+            config,
+        )?;
     }
     Ok(HandleResponse {
         messages: vec![],
@@ -697,8 +1106,7 @@ pub fn set_metadata<S: Storage, A: Api, Q: Querier>(
 
 /// Returns HandleResult
 ///
-/// sets new royalty information for a specified token or if no token ID is provided, sets new
-/// royalty information as the contract's default
+/// merges updates into the existing public and/or private metadata
 ///
 /// # Arguments
 ///
@@ -706,35 +1114,87 @@ pub fn set_metadata<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - optional token id String slice of token whose royalty info should be updated
-/// * `royalty_info` - a optional reference to the new RoyaltyInfo
-pub fn set_royalty_info<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - token id String slice of token whose metadata should be updated
+/// * `public_metadata` - the optional update to the public metadata
+/// * `private_metadata` - the optional update to the private metadata
+pub fn patch_metadata<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    token_id: Option<&str>,
-    royalty_info: Option<&RoyaltyInfo>,
+    token_id: &str,
+    public_metadata: Option<MetadataPatch>,
+    private_metadata: Option<MetadataPatch>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    // set a token's royalties
-    if let Some(id) = token_id {
-        let custom_err = "A token's RoyaltyInfo may only be set by the token creator when they are also the token owner";
-        // if token supply is private, don't leak that the token id does not exist
-        // instead just say they are not authorized for that token
-        let opt_err = if config.token_supply_is_public {
-            None
-        } else {
-            Some(custom_err)
-        };
-        let (token, idx) = get_token(&deps.storage, id, opt_err)?;
-        let token_key = idx.to_le_bytes();
-        let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, &deps.storage);
-        let mint_run: StoredMintRunInfo = load(&run_store, &token_key)?;
-        if sender_raw != mint_run.token_creator || sender_raw != token.owner {
-            return Err(StdError::generic_err(custom_err));
-        }
+    let (token, idx) = get_token_for_meta_update(deps, &env, config, token_id)?;
+    if let Some(public) = public_metadata {
+        patch_metadata_impl(
+            &mut deps.storage,
+            &token,
+            idx,
+            PREFIX_PUB_META,
+            public,
+            config,
+        )?;
+    }
+    if let Some(private) = private_metadata {
+        patch_metadata_impl(
+            &mut deps.storage,
+            &token,
+            idx,
+            PREFIX_PRIV_META,
+            private,
+            config,
+        )?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::PatchMetadata { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets new royalty information for a specified token or if no token ID is provided, sets new
+/// royalty information as the contract's default
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - optional token id String slice of token whose royalty info should be updated
+/// * `royalty_info` - a optional reference to the new RoyaltyInfo
+pub fn set_royalty_info<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_id: Option<&str>,
+    royalty_info: Option<&RoyaltyInfo>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    // set a token's royalties
+    if let Some(id) = token_id {
+        let custom_err = "A token's RoyaltyInfo may only be set by the token creator when they are also the token owner";
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they are not authorized for that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(custom_err)
+        };
+        let (token, idx) = get_token(&deps.storage, id, opt_err)?;
+        let token_key = idx.to_le_bytes();
+        let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, &deps.storage);
+        let mint_run: StoredMintRunInfo = load(&run_store, &token_key)?;
+        if sender_raw != mint_run.token_creator || sender_raw != token.owner {
+            return Err(StdError::generic_err(custom_err));
+        }
         let default_roy = royalty_info.as_ref().map_or_else(
             || may_load::<StoredRoyaltyInfo, _>(&deps.storage, DEFAULT_ROYALTY_KEY),
             |_r| Ok(None),
@@ -746,6 +1206,7 @@ pub fn set_royalty_info<S: Storage, A: Api, Q: Querier>(
             royalty_info,
             default_roy.as_ref(),
             &token_key,
+            config.max_royalty_bps,
         )?;
     // set default royalty
     } else {
@@ -762,6 +1223,7 @@ pub fn set_royalty_info<S: Storage, A: Api, Q: Querier>(
             royalty_info,
             None,
             DEFAULT_ROYALTY_KEY,
+            config.max_royalty_bps,
         )?;
     };
 
@@ -776,7 +1238,57 @@ pub fn set_royalty_info<S: Storage, A: Api, Q: Querier>(
 
 /// Returns HandleResult
 ///
-/// makes the sealed private metadata public
+/// sets or deletes the default extension template used when minting clones
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `extension` - the optional new template.  If None, the template is deleted
+pub fn set_default_extension<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    extension: Option<Extension>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let minters: Vec<CanonicalAddr> =
+        may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_else(Vec::new);
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters can set the default extension",
+        ));
+    }
+    if let Some(ext) = extension {
+        // check the template's urls now so a bad template is not found at mint time
+        let template = Metadata {
+            token_uri: None,
+            extension: Some(ext),
+        };
+        template.validate(config)?;
+        if let Some(ext) = template.extension {
+            save(&mut deps.storage, DEFAULT_EXTENSION_KEY, &ext)?;
+        }
+    } else {
+        remove(&mut deps.storage, DEFAULT_EXTENSION_KEY);
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetDefaultExtension {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// makes the sealed private metadata public.  If the token was minted with a reveal commitment,
+/// the private metadata must be provided as proof
 ///
 /// # Arguments
 ///
@@ -785,12 +1297,14 @@ pub fn set_royalty_info<S: Storage, A: Api, Q: Querier>(
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest status level this action is permitted at
 /// * `token_id` - token id String slice of token whose metadata should be updated
+/// * `proof` - optional private metadata that must match the token's reveal commitment
 pub fn reveal<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
     token_id: &str,
+    proof: Option<Metadata>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     if !config.sealed_metadata_is_enabled {
@@ -807,7 +1321,7 @@ pub fn reveal<S: Storage, A: Api, Q: Querier>(
     } else {
         Some(&*custom_err)
     };
-    let (mut token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
     if token.unwrapped {
         return Err(StdError::generic_err(
             "This token has already been unwrapped",
@@ -816,29 +1330,24 @@ pub fn reveal<S: Storage, A: Api, Q: Querier>(
     if token.owner != sender_raw {
         return Err(StdError::generic_err(custom_err));
     }
-    token.unwrapped = true;
-    let token_key = idx.to_le_bytes();
-    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
-    json_save(&mut info_store, &token_key, &token)?;
-    if !config.unwrap_to_private {
-        let mut priv_store = PrefixedStorage::new(PREFIX_PRIV_META, &mut deps.storage);
-        let may_priv: Option<Metadata> = may_load(&priv_store, &token_key)?;
-        if let Some(metadata) = may_priv {
-            remove(&mut priv_store, &token_key);
-            let mut pub_store = PrefixedStorage::new(PREFIX_PUB_META, &mut deps.storage);
-            save(&mut pub_store, &token_key, &metadata)?;
-        }
-    }
+    let is_proof = proof.is_some();
+    let hash = unwrap_token(deps, &env, config, token, idx, proof)?;
     Ok(HandleResponse {
         messages: vec![],
-        log: vec![],
-        data: Some(to_binary(&HandleAnswer::Reveal { status: Success })?),
+        log: reveal_logs(vec![(token_id, hash)]),
+        data: Some(if is_proof {
+            to_binary(&HandleAnswer::RevealWithProof { status: Success })?
+        } else {
+            to_binary(&HandleAnswer::Reveal { status: Success })?
+        }),
     })
 }
 
 /// Returns HandleResult
 ///
-/// grants/revokes trasfer permission on a token
+/// makes the sealed private metadata of a list of tokens public.  The sender must own every
+/// listed token, and tokens that have already been unwrapped or that have a reveal commitment
+/// are skipped
 ///
 /// # Arguments
 ///
@@ -846,99 +1355,164 @@ pub fn reveal<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `spender` - a reference to the address being granted permission
-/// * `token_id` - string slice of the token id to grant permission to
-/// * `expires` - optional Expiration for this approval
-/// * `is_approve` - true if this is an Approve call
-#[allow(clippy::too_many_arguments)]
-pub fn approve_revoke<S: Storage, A: Api, Q: Querier>(
+/// * `token_ids` - list of token ids to unwrap
+pub fn batch_reveal<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    spender: &HumanAddr,
-    token_id: &str,
-    expires: Option<Expiration>,
-    is_approve: bool,
+    token_ids: &[String],
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let address_raw = deps.api.canonical_address(spender)?;
+    if !config.sealed_metadata_is_enabled {
+        return Err(StdError::generic_err(
+            "Sealed metadata functionality is not enabled for this contract",
+        ));
+    }
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let custom_err = format!(
-        "Not authorized to grant/revoke transfer permission for token {}",
-        token_id
-    );
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they are not authorized for that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
-    };
-    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
-    let mut all_perm: Option<Vec<Permission>> = None;
-    let mut from_oper = false;
-    let transfer_idx = PermissionType::Transfer.to_usize();
-    // if not called by the owner, check if message sender has operator status
-    if token.owner != sender_raw {
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let may_list: Option<Vec<Permission>> = json_may_load(&all_store, token.owner.as_slice())?;
-        if let Some(list) = may_list.clone() {
-            if let Some(perm) = list.iter().find(|&p| p.address == sender_raw) {
-                if let Some(exp) = perm.expirations[transfer_idx] {
-                    if exp.is_expired(&env.block) {
-                        return Err(StdError::generic_err(format!(
-                            "Transfer authority for all tokens of {} has expired",
-                            &deps.api.human_address(&token.owner)?
-                        )));
-                    } else {
-                        from_oper = true;
-                    }
-                }
-            }
-        }
-        if !from_oper {
+    let mut sealed: Vec<(&str, u32, Token)> = Vec::new();
+    let mut needs_proof: Vec<String> = Vec::new();
+    for token_id in token_ids.iter() {
+        let custom_err = format!("You do not own token {}", token_id);
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they do not own that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*custom_err)
+        };
+        let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+        if token.owner != sender_raw {
             return Err(StdError::generic_err(custom_err));
         }
-        all_perm = may_list;
+        // skip tokens that are already unwrapped and duplicate ids
+        if token.unwrapped || sealed.iter().any(|(_, i, _)| *i == idx) {
+            continue;
+        }
+        // tokens with a reveal commitment can only be unwrapped with RevealWithProof
+        let commit_store = ReadonlyPrefixedStorage::new(PREFIX_REVEAL_COMMIT, &deps.storage);
+        if may_load::<Vec<u8>, _>(&commit_store, &idx.to_le_bytes())?.is_some() {
+            if !needs_proof.contains(token_id) {
+                needs_proof.push(token_id.clone());
+            }
+            continue;
+        }
+        sealed.push((token_id, idx, token));
     }
-    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
-    let response: HandleAnswer;
-    if is_approve {
-        accesses[transfer_idx] = Some(AccessLevel::ApproveToken);
-        response = HandleAnswer::Approve { status: Success };
-    } else {
-        accesses[transfer_idx] = Some(AccessLevel::RevokeToken);
-        response = HandleAnswer::Revoke { status: Success };
+    let revealed = sealed.len() as u32;
+    let mut hashes: Vec<(&str, Option<String>)> = Vec::new();
+    for (token_id, idx, token) in sealed.into_iter() {
+        hashes.push((
+            token_id,
+            unwrap_token(deps, &env, config, token, idx, None)?,
+        ));
     }
-    let owner = token.owner.clone();
-    let mut proc_info = ProcessAccInfo {
-        token,
-        idx,
-        token_given: true,
-        accesses,
-        expires,
-        from_oper,
-    };
-    process_accesses(
-        &mut deps.storage,
-        &env,
-        &address_raw,
-        &owner,
-        &mut proc_info,
-        all_perm,
-    )?;
-    let res = HandleResponse {
+    Ok(HandleResponse {
         messages: vec![],
-        log: vec![],
-        data: Some(to_binary(&response)?),
-    };
-    Ok(res)
+        log: reveal_logs(hashes),
+        data: Some(to_binary(&HandleAnswer::BatchReveal {
+            revealed,
+            needs_proof,
+        })?),
+    })
+}
+
+/// Returns Vec<LogAttribute>
+///
+/// creates the log attributes listing the sha256 hash of each revealed token's metadata
+///
+/// # Arguments
+///
+/// * `hashes` - list of revealed token ids and the hex-encoded hashes of their metadata
+fn reveal_logs(hashes: Vec<(&str, Option<String>)>) -> Vec<LogAttribute> {
+    hashes
+        .into_iter()
+        .filter_map(|(token_id, hash)| {
+            hash.map(|h| {
+                vec![
+                    log("revealed_token_id", token_id),
+                    log("revealed_metadata_hash", h),
+                ]
+            })
+        })
+        .flatten()
+        .collect()
+}
+
+/// Returns StdResult<Option<String>>
+///
+/// verifies the reveal commitment if there is one, marks the token as unwrapped, and makes
+/// its private metadata public if the contract does not unwrap to private.  Returns the
+/// hex-encoded sha256 hash of the token's revealed metadata, if it has any
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `token` - the sealed Token to unwrap
+/// * `idx` - index of the token
+/// * `proof` - optional private metadata that must match the token's reveal commitment
+fn unwrap_token<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: &Env,
+    config: &Config,
+    mut token: Token,
+    idx: u32,
+    proof: Option<Metadata>,
+) -> StdResult<Option<String>> {
+    let token_key = idx.to_le_bytes();
+    let mut commit_store = PrefixedStorage::new(PREFIX_REVEAL_COMMIT, &mut deps.storage);
+    let commitment: Option<Vec<u8>> = may_load(&commit_store, &token_key)?;
+    match (commitment, proof) {
+        (Some(commit), Some(private)) => {
+            if sha_256(&private.canonical_bytes()?).to_vec() != commit {
+                return Err(StdError::generic_err(
+                    "The provided private metadata does not match the reveal commitment",
+                ));
+            }
+            remove(&mut commit_store, &token_key);
+            private.validate(config)?;
+            let mut priv_store = PrefixedStorage::new(PREFIX_PRIV_META, &mut deps.storage);
+            save(&mut priv_store, &token_key, &private)?;
+        }
+        (Some(_), None) => {
+            return Err(StdError::generic_err(
+                "This token has a reveal commitment and must be unwrapped with RevealWithProof",
+            ));
+        }
+        (None, Some(_)) => {
+            return Err(StdError::generic_err(
+                "This token does not have a reveal commitment",
+            ));
+        }
+        (None, None) => (),
+    }
+    token.unwrapped = true;
+    token.prune_expired(&env.block);
+    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
+    json_save(&mut info_store, &token_key, &token)?;
+    let mut priv_store = PrefixedStorage::new(PREFIX_PRIV_META, &mut deps.storage);
+    let may_priv: Option<Metadata> = may_load(&priv_store, &token_key)?;
+    let hash = may_priv
+        .as_ref()
+        .map(|m| m.canonical_bytes().map(|v| hex::encode(sha_256(&v))))
+        .transpose()?;
+    if !config.unwrap_to_private {
+        if let Some(metadata) = may_priv {
+            remove(&mut priv_store, &token_key);
+            let mut pub_store = PrefixedStorage::new(PREFIX_PUB_META, &mut deps.storage);
+            let may_pub: Option<Metadata> = may_load(&pub_store, &token_key)?;
+            save(&mut pub_store, &token_key, &metadata)?;
+            update_trait_index(&mut deps.storage, idx, may_pub.as_ref(), Some(&metadata))?;
+        }
+    }
+    Ok(hash)
 }
 
 /// Returns HandleResult
 ///
-/// makes an address' token ownership private
+/// locks/unlocks a token against transfers, sends, and burns
 ///
 /// # Arguments
 ///
@@ -946,23 +1520,292 @@ pub fn approve_revoke<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest status level this action is permitted at
-pub fn make_owner_private<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - token id String slice of token to lock/unlock
+/// * `locked` - true if the token should be locked
+pub fn set_transfer_lock<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
+    token_id: &str,
+    locked: bool,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    // only need to do this if the contract has public ownership
-    if config.owner_is_public {
-        let mut priv_store = PrefixedStorage::new(PREFIX_OWNER_PRIV, &mut deps.storage);
-        save(&mut priv_store, sender_raw.as_slice(), &false)?
-    }
+    set_transfer_lock_impl(deps, &env, config, &[token_id.to_string()], locked)?;
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::MakeOwnershipPrivate {
+        data: Some(to_binary(&HandleAnswer::SetTransferLock {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// locks/unlocks a list of tokens against transfers, sends, and burns.  If the sender does
+/// not own every token, none of them are changed
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_ids` - list of token ids to lock/unlock
+/// * `locked` - true if the tokens should be locked
+pub fn batch_set_transfer_lock<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_ids: &[String],
+    locked: bool,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let updated = set_transfer_lock_impl(deps, &env, config, token_ids, locked)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::BatchSetTransferLock { updated })?),
+    })
+}
+
+/// Returns StdResult<u32>
+///
+/// verifies the sender owns every listed token before locking/unlocking any of them, and
+/// returns the number of tokens whose lock status changed
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `token_ids` - list of token ids to lock/unlock
+/// * `locked` - true if the tokens should be locked
+fn set_transfer_lock_impl<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: &Env,
+    config: &Config,
+    token_ids: &[String],
+    locked: bool,
+) -> StdResult<u32> {
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let mut changes: Vec<(u32, Token)> = Vec::new();
+    let mut updated = 0u32;
+    for token_id in token_ids.iter() {
+        let custom_err = format!("You do not own token {}", token_id);
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they do not own that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*custom_err)
+        };
+        let (mut token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+        if token.owner != sender_raw {
+            return Err(StdError::generic_err(custom_err));
+        }
+        // skip duplicate ids
+        if changes.iter().any(|(i, _)| *i == idx) {
+            continue;
+        }
+        let was_locked = token.transfer_locked;
+        if token.prune_expired(&env.block) || was_locked != locked {
+            token.transfer_locked = locked;
+            if was_locked != locked {
+                updated += 1;
+            }
+            changes.push((idx, token));
+        }
+    }
+    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
+    for (idx, token) in changes.iter() {
+        json_save(&mut info_store, &idx.to_le_bytes(), token)?;
+    }
+    Ok(updated)
+}
+
+/// Returns HandleResult
+///
+/// records a view of a token's private metadata by an address permitted to view it
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - token id String slice of the viewed token
+pub fn record_view<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    if !config.track_views {
+        return Err(StdError::generic_err(
+            "View tracking is not enabled for this contract",
+        ));
+    }
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let (token, idx) = get_token_if_permitted(
+        deps,
+        &env.block,
+        token_id,
+        Some(&sender_raw),
+        PermissionType::ViewMetadata,
+        &mut Vec::new(),
+        config,
+    )?;
+    // sealed metadata can not be viewed, so there is nothing to record
+    if !token.unwrapped {
+        return Err(StdError::generic_err(
+            "Sealed metadata must be unwrapped by calling Reveal before it can be viewed",
+        ));
+    }
+    let mut cnt_store = PrefixedStorage::new(PREFIX_VIEW_CNT, &mut deps.storage);
+    let count: u32 = may_load(&cnt_store, &idx.to_le_bytes())?.unwrap_or(0);
+    save(&mut cnt_store, &idx.to_le_bytes(), &count.saturating_add(1))?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::RecordView { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// grants/revokes trasfer permission on a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `spender` - a reference to the address being granted permission
+/// * `token_id` - string slice of the token id to grant permission to
+/// * `expires` - optional Expiration for this approval
+/// * `max_uses` - optional number of transfers this approval may authorize
+/// * `is_approve` - true if this is an Approve call
+#[allow(clippy::too_many_arguments)]
+pub fn approve_revoke<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    spender: &HumanAddr,
+    token_id: &str,
+    expires: Option<Expiration>,
+    max_uses: Option<u32>,
+    is_approve: bool,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let address_raw = deps.api.canonical_address(spender)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let custom_err = format!(
+        "Not authorized to grant/revoke transfer permission for token {}",
+        token_id
+    );
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+    let mut all_perm: Option<Vec<Permission>> = None;
+    let mut from_oper = false;
+    let transfer_idx = PermissionType::Transfer.to_usize();
+    // if not called by the owner, check if message sender has operator status
+    if token.owner != sender_raw {
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let may_list: Option<Vec<Permission>> = json_may_load(&all_store, token.owner.as_slice())?;
+        if let Some(list) = may_list.clone() {
+            if let Some(perm) = list.iter().find(|&p| p.address == sender_raw) {
+                if let Some(exp) = perm.expirations[transfer_idx] {
+                    if exp.is_expired(&env.block) {
+                        return Err(StdError::generic_err(format!(
+                            "Transfer authority for all tokens of {} has expired",
+                            &deps.api.human_address(&token.owner)?
+                        )));
+                    } else {
+                        from_oper = true;
+                    }
+                }
+            }
+        }
+        if !from_oper {
+            return Err(StdError::generic_err(custom_err));
+        }
+        all_perm = may_list;
+    }
+    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
+    let response: HandleAnswer;
+    if is_approve {
+        accesses[transfer_idx] = Some(AccessLevel::ApproveToken);
+        response = HandleAnswer::Approve { status: Success };
+    } else {
+        accesses[transfer_idx] = Some(AccessLevel::RevokeToken);
+        response = HandleAnswer::Revoke { status: Success };
+    }
+    let owner = token.owner.clone();
+    let mut proc_info = ProcessAccInfo {
+        token,
+        idx,
+        token_given: true,
+        accesses,
+        expires,
+        max_uses,
+        from_oper,
+    };
+    process_accesses(
+        &mut deps.storage,
+        &env,
+        &address_raw,
+        &owner,
+        &mut proc_info,
+        all_perm,
+    )?;
+    let res = HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&response)?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// makes an address' token ownership private
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+pub fn make_owner_private<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    // only need to do this if the contract has public ownership
+    if config.owner_is_public {
+        let mut priv_store = PrefixedStorage::new(PREFIX_OWNER_PRIV, &mut deps.storage);
+        save(&mut priv_store, sender_raw.as_slice(), &false)?
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::MakeOwnershipPrivate {
             status: Success,
         })?),
     })
@@ -1017,6 +1860,7 @@ pub fn set_global_approval<S: Storage, A: Api, Q: Querier>(
                 owner: sender_raw.clone(),
                 permissions: Vec::new(),
                 unwrapped: false,
+                transfer_locked: false,
             },
             0,
         )
@@ -1034,6 +1878,7 @@ pub fn set_global_approval<S: Storage, A: Api, Q: Querier>(
         token_given,
         accesses,
         expires,
+        max_uses: None,
         from_oper: false,
     };
     process_accesses(
@@ -1069,6 +1914,7 @@ pub fn set_global_approval<S: Storage, A: Api, Q: Querier>(
 /// * `view_private_metadata` - optional access level for viewing private metadata
 /// * `transfer` - optional access level for transferring tokens
 /// * `expires` - optional Expiration for this approval
+/// * `max_uses` - optional number of transfers a transfer approval may authorize
 /// * `response_type` - which response to return for SetWhitelistedApproval, ApproveAll, or RevokeAll
 #[allow(clippy::too_many_arguments)]
 pub fn set_whitelisted_approval<S: Storage, A: Api, Q: Querier>(
@@ -1082,6 +1928,7 @@ pub fn set_whitelisted_approval<S: Storage, A: Api, Q: Querier>(
     view_private_metadata: Option<AccessLevel>,
     transfer: Option<AccessLevel>,
     expires: Option<Expiration>,
+    max_uses: Option<u32>,
     response_type: SetAppResp,
 ) -> HandleResult {
     check_status(config.status, priority)?;
@@ -1107,6 +1954,7 @@ pub fn set_whitelisted_approval<S: Storage, A: Api, Q: Querier>(
                 owner: sender_raw.clone(),
                 permissions: Vec::new(),
                 unwrapped: false,
+                transfer_locked: false,
             },
             0,
         )
@@ -1125,6 +1973,7 @@ pub fn set_whitelisted_approval<S: Storage, A: Api, Q: Querier>(
         token_given,
         accesses,
         expires,
+        max_uses,
         from_oper: false,
     };
     process_accesses(
@@ -1150,6 +1999,72 @@ pub fn set_whitelisted_approval<S: Storage, A: Api, Q: Querier>(
     Ok(res)
 }
 
+/// Returns HandleResult
+///
+/// grants or revokes an operator's approval to transfer all of the message sender's tokens
+/// whose ids start with a prefix
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `operator` - a reference to the address being granted or revoked approval
+/// * `prefix` - prefix of the ids of the tokens the approval applies to
+/// * `expires` - Expiration of the approval being granted, or None if revoking the approval
+pub fn set_prefix_approval<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    operator: &HumanAddr,
+    prefix: String,
+    expires: Option<Expiration>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    if prefix.is_empty() {
+        return Err(StdError::generic_err(
+            "The prefix can not be empty.  Use ApproveAll or RevokeAll for approvals of all tokens",
+        ));
+    }
+    let operator_raw = deps.api.canonical_address(operator)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let mut prefix_store = PrefixedStorage::new(PREFIX_PREFIX_APPROVALS, &mut deps.storage);
+    let mut approvals: Vec<StoredPrefixApproval> =
+        may_load(&prefix_store, sender_raw.as_slice())?.unwrap_or_else(Vec::new);
+    // remove any existing approval for this operator and prefix, as well as expired approvals
+    approvals.retain(|a| {
+        let is_replaced = a.operator == operator_raw && a.prefix == prefix;
+        !(is_replaced || a.expires.is_expired(&env.block))
+    });
+    let response = if let Some(exp) = expires {
+        if exp.is_expired(&env.block) {
+            return Err(StdError::generic_err(
+                "The expiration of the approval has already passed",
+            ));
+        }
+        approvals.push(StoredPrefixApproval {
+            operator: operator_raw,
+            prefix,
+            expires: exp,
+        });
+        HandleAnswer::ApproveAllWithPrefix { status: Success }
+    } else {
+        HandleAnswer::RevokeAllWithPrefix { status: Success }
+    };
+    if approvals.is_empty() {
+        remove(&mut prefix_store, sender_raw.as_slice());
+    } else {
+        save(&mut prefix_store, sender_raw.as_slice(), &approvals)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&response)?),
+    })
+}
+
 /// Returns HandleResult
 ///
 /// burns many tokens
@@ -1244,11 +2159,11 @@ pub fn batch_transfer_nft<S: Storage, A: Api, Q: Querier>(
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let _m = send_list(deps, &env, config, &sender_raw, Some(transfers), None)?;
+    let (messages, logs) = send_list(deps, &env, config, &sender_raw, Some(transfers), None)?;
 
     let res = HandleResponse {
-        messages: vec![],
-        log: vec![],
+        messages,
+        log: logs,
         data: Some(to_binary(&HandleAnswer::BatchTransferNft {
             status: Success,
         })?),
@@ -1285,11 +2200,11 @@ pub fn transfer_nft<S: Storage, A: Api, Q: Querier>(
         token_ids: vec![token_id],
         memo,
     }]);
-    let _m = send_list(deps, &env, config, &sender_raw, transfers, None)?;
+    let (messages, logs) = send_list(deps, &env, config, &sender_raw, transfers, None)?;
 
     let res = HandleResponse {
-        messages: vec![],
-        log: vec![],
+        messages,
+        log: logs,
         data: Some(to_binary(&HandleAnswer::TransferNft { status: Success })?),
     };
     Ok(res)
@@ -1316,11 +2231,11 @@ fn batch_send_nft<S: Storage, A: Api, Q: Querier>(
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let messages = send_list(deps, &env, config, &sender_raw, None, Some(sends))?;
+    let (messages, logs) = send_list(deps, &env, config, &sender_raw, None, Some(sends))?;
 
     let res = HandleResponse {
         messages,
-        log: vec![],
+        log: logs,
         data: Some(to_binary(&HandleAnswer::BatchSendNft { status: Success })?),
     };
     Ok(res)
@@ -1364,11 +2279,11 @@ fn send_nft<S: Storage, A: Api, Q: Querier>(
         msg,
         memo,
     }]);
-    let messages = send_list(deps, &env, config, &sender_raw, None, sends)?;
+    let (messages, logs) = send_list(deps, &env, config, &sender_raw, None, sends)?;
 
     let res = HandleResponse {
         messages,
-        log: vec![],
+        log: logs,
         data: Some(to_binary(&HandleAnswer::SendNft { status: Success })?),
     };
     Ok(res)
@@ -1464,79 +2379,399 @@ pub fn set_key<S: Storage, A: Api, Q: Querier>(
     key: String,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let vk = ViewingKey(key.clone());
-    let message_sender = deps.api.canonical_address(&env.message.sender)?;
-    let mut key_store = PrefixedStorage::new(PREFIX_VIEW_KEY, &mut deps.storage);
-    save(&mut key_store, message_sender.as_slice(), &vk.to_hashed())?;
+    let vk = ViewingKey(key.clone());
+    let message_sender = deps.api.canonical_address(&env.message.sender)?;
+    let mut key_store = PrefixedStorage::new(PREFIX_VIEW_KEY, &mut deps.storage);
+    save(&mut key_store, message_sender.as_slice(), &vk.to_hashed())?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::ViewingKey { key })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// add a list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `new_minters` - list of minter addresses to add
+pub fn add_minters<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    new_minters: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut minters: Vec<CanonicalAddr> =
+        may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_else(Vec::new);
+    let old_len = minters.len();
+    for minter in new_minters {
+        let minter_raw = deps.api.canonical_address(minter)?;
+        if !minters.contains(&minter_raw) {
+            minters.push(minter_raw);
+        }
+    }
+    // only save if the list changed
+    if old_len != minters.len() {
+        save(&mut deps.storage, MINTERS_KEY, &minters)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::AddMinters { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// remove a list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `no_minters` - list of minter addresses to remove
+pub fn remove_minters<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    no_minters: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let may_minters: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, MINTERS_KEY)?;
+    if let Some(mut minters) = may_minters {
+        let old_len = minters.len();
+        let no_raw: Vec<CanonicalAddr> = no_minters
+            .iter()
+            .map(|x| deps.api.canonical_address(x))
+            .collect::<StdResult<Vec<CanonicalAddr>>>()?;
+        minters.retain(|m| !no_raw.contains(m));
+        let new_len = minters.len();
+        if new_len > 0 {
+            if old_len != new_len {
+                save(&mut deps.storage, MINTERS_KEY, &minters)?;
+            }
+        } else {
+            remove(&mut deps.storage, MINTERS_KEY);
+        }
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::RemoveMinters { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// define the exact list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `human_minters` - exact list of minter addresses
+pub fn set_minters<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    human_minters: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    // remove duplicates from the minters list
+    let minters_raw: Vec<CanonicalAddr> = human_minters
+        .iter()
+        .map(|x| deps.api.canonical_address(x))
+        .collect::<StdResult<Vec<CanonicalAddr>>>()?;
+    let mut sortable: Vec<&[u8]> = minters_raw.iter().map(|x| x.as_slice()).collect();
+    sortable.sort_unstable();
+    sortable.dedup();
+    let minters: Vec<CanonicalAddr> = sortable
+        .iter()
+        .map(|x| CanonicalAddr(Binary(x.to_vec())))
+        .collect();
+    if minters.is_empty() {
+        remove(&mut deps.storage, MINTERS_KEY);
+    } else {
+        save(&mut deps.storage, MINTERS_KEY, &minters)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetMinters { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// add a list of metadata editors
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `new_editors` - list of editor addresses to add
+pub fn add_metadata_editors<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    new_editors: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut editors: Vec<CanonicalAddr> =
+        may_load(&deps.storage, METADATA_EDITORS_KEY)?.unwrap_or_else(Vec::new);
+    let old_len = editors.len();
+    for editor in new_editors {
+        let editor_raw = deps.api.canonical_address(editor)?;
+        if !editors.contains(&editor_raw) {
+            editors.push(editor_raw);
+        }
+    }
+    // only save if the list changed
+    if old_len != editors.len() {
+        save(&mut deps.storage, METADATA_EDITORS_KEY, &editors)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::AddMetadataEditors {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// remove a list of metadata editors
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `no_editors` - list of editor addresses to remove
+pub fn remove_metadata_editors<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    no_editors: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let may_editors: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, METADATA_EDITORS_KEY)?;
+    if let Some(mut editors) = may_editors {
+        let old_len = editors.len();
+        let no_raw: Vec<CanonicalAddr> = no_editors
+            .iter()
+            .map(|x| deps.api.canonical_address(x))
+            .collect::<StdResult<Vec<CanonicalAddr>>>()?;
+        editors.retain(|e| !no_raw.contains(e));
+        let new_len = editors.len();
+        if new_len > 0 {
+            if old_len != new_len {
+                save(&mut deps.storage, METADATA_EDITORS_KEY, &editors)?;
+            }
+        } else {
+            remove(&mut deps.storage, METADATA_EDITORS_KEY);
+        }
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::RemoveMetadataEditors {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// define the exact list of metadata editors
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `human_editors` - exact list of editor addresses
+pub fn set_metadata_editors<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    human_editors: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    // remove duplicates from the editors list
+    let editors_raw: Vec<CanonicalAddr> = human_editors
+        .iter()
+        .map(|x| deps.api.canonical_address(x))
+        .collect::<StdResult<Vec<CanonicalAddr>>>()?;
+    let mut sortable: Vec<&[u8]> = editors_raw.iter().map(|x| x.as_slice()).collect();
+    sortable.sort_unstable();
+    sortable.dedup();
+    let editors: Vec<CanonicalAddr> = sortable
+        .iter()
+        .map(|x| CanonicalAddr(Binary(x.to_vec())))
+        .collect();
+    if editors.is_empty() {
+        remove(&mut deps.storage, METADATA_EDITORS_KEY);
+    } else {
+        save(&mut deps.storage, METADATA_EDITORS_KEY, &editors)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetMetadataEditors {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// change the admin address
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `address` - new admin address
+pub fn change_admin<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    address: &HumanAddr,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let new_admin = deps.api.canonical_address(address)?;
+    if new_admin != config.admin {
+        config.admin = new_admin;
+        save(&mut deps.storage, CONFIG_KEY, &config)?;
+    }
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::ViewingKey { key })?),
+        data: Some(to_binary(&HandleAnswer::ChangeAdmin { status: Success })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// add a list of minters
+/// set the contract status level
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `new_minters` - list of minter addresses to add
-pub fn add_minters<S: Storage, A: Api, Q: Querier>(
+/// * `config` - a mutable reference to the Config
+/// * `level` - new ContractStatus
+pub fn set_contract_status<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &Config,
-    priority: u8,
-    new_minters: &[HumanAddr],
+    config: &mut Config,
+    level: ContractStatus,
 ) -> HandleResult {
-    check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
     if config.admin != sender_raw {
         return Err(StdError::generic_err(
             "This is an admin command and can only be run from the admin address",
         ));
     }
-    let mut minters: Vec<CanonicalAddr> =
-        may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_else(Vec::new);
-    let old_len = minters.len();
-    for minter in new_minters {
-        let minter_raw = deps.api.canonical_address(minter)?;
-        if !minters.contains(&minter_raw) {
-            minters.push(minter_raw);
-        }
-    }
-    // only save if the list changed
-    if old_len != minters.len() {
-        save(&mut deps.storage, MINTERS_KEY, &minters)?;
+    let new_status = level.to_u8();
+    if config.status != new_status {
+        config.status = new_status;
+        save(&mut deps.storage, CONFIG_KEY, &config)?;
     }
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::AddMinters { status: Success })?),
+        data: Some(to_binary(&HandleAnswer::SetContractStatus {
+            status: Success,
+        })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// remove a list of minters
+/// set the maximum number of attributes a token's metadata may have
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `no_minters` - list of minter addresses to remove
-pub fn remove_minters<S: Storage, A: Api, Q: Querier>(
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `max_attributes` - optional maximum number of attributes.  None means unlimited
+pub fn set_max_attributes<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &Config,
+    config: &mut Config,
     priority: u8,
-    no_minters: &[HumanAddr],
+    max_attributes: Option<u16>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
@@ -1545,47 +2780,36 @@ pub fn remove_minters<S: Storage, A: Api, Q: Querier>(
             "This is an admin command and can only be run from the admin address",
         ));
     }
-    let may_minters: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, MINTERS_KEY)?;
-    if let Some(mut minters) = may_minters {
-        let old_len = minters.len();
-        let no_raw: Vec<CanonicalAddr> = no_minters
-            .iter()
-            .map(|x| deps.api.canonical_address(x))
-            .collect::<StdResult<Vec<CanonicalAddr>>>()?;
-        minters.retain(|m| !no_raw.contains(m));
-        let new_len = minters.len();
-        if new_len > 0 {
-            if old_len != new_len {
-                save(&mut deps.storage, MINTERS_KEY, &minters)?;
-            }
-        } else {
-            remove(&mut deps.storage, MINTERS_KEY);
-        }
+    if config.max_attributes != max_attributes {
+        config.max_attributes = max_attributes;
+        save(&mut deps.storage, CONFIG_KEY, &config)?;
     }
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::RemoveMinters { status: Success })?),
+        data: Some(to_binary(&HandleAnswer::SetMaxAttributes {
+            status: Success,
+        })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// define the exact list of minters
+/// set the maximum sum of royalty rates, in basis points, that new RoyaltyInfo may have
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `human_minters` - exact list of minter addresses
-pub fn set_minters<S: Storage, A: Api, Q: Querier>(
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `max_royalty_bps` - optional maximum sum of royalty rates.  None means only the 100% limit
+pub fn set_max_royalty<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &Config,
+    config: &mut Config,
     priority: u8,
-    human_minters: &[HumanAddr],
+    max_royalty_bps: Option<u16>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
@@ -1594,47 +2818,36 @@ pub fn set_minters<S: Storage, A: Api, Q: Querier>(
             "This is an admin command and can only be run from the admin address",
         ));
     }
-    // remove duplicates from the minters list
-    let minters_raw: Vec<CanonicalAddr> = human_minters
-        .iter()
-        .map(|x| deps.api.canonical_address(x))
-        .collect::<StdResult<Vec<CanonicalAddr>>>()?;
-    let mut sortable: Vec<&[u8]> = minters_raw.iter().map(|x| x.as_slice()).collect();
-    sortable.sort_unstable();
-    sortable.dedup();
-    let minters: Vec<CanonicalAddr> = sortable
-        .iter()
-        .map(|x| CanonicalAddr(Binary(x.to_vec())))
-        .collect();
-    if minters.is_empty() {
-        remove(&mut deps.storage, MINTERS_KEY);
-    } else {
-        save(&mut deps.storage, MINTERS_KEY, &minters)?;
+    if config.max_royalty_bps != max_royalty_bps {
+        config.max_royalty_bps = max_royalty_bps;
+        save(&mut deps.storage, CONFIG_KEY, &config)?;
     }
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::SetMinters { status: Success })?),
+        data: Some(to_binary(&HandleAnswer::SetMaxRoyalty { status: Success })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// change the admin address
+/// freezes or unfreezes a token against transfers, sends, and burns
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a mutable reference to the Config
-/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `address` - new admin address
-pub fn change_admin<S: Storage, A: Api, Q: Querier>(
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - token id String slice of token to freeze/unfreeze
+/// * `frozen` - true if the token should be frozen
+pub fn set_frozen<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &mut Config,
+    config: &Config,
     priority: u8,
-    address: &HumanAddr,
+    token_id: &str,
+    frozen: bool,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
@@ -1643,49 +2856,57 @@ pub fn change_admin<S: Storage, A: Api, Q: Querier>(
             "This is an admin command and can only be run from the admin address",
         ));
     }
-    let new_admin = deps.api.canonical_address(address)?;
-    if new_admin != config.admin {
-        config.admin = new_admin;
-        save(&mut deps.storage, CONFIG_KEY, &config)?;
-    }
+    let (_token, idx) = get_token(&deps.storage, token_id, None)?;
+    let mut frozen_store = PrefixedStorage::new(PREFIX_FROZEN, &mut deps.storage);
+    let response = if frozen {
+        save(&mut frozen_store, &idx.to_le_bytes(), &true)?;
+        HandleAnswer::FreezeToken { status: Success }
+    } else {
+        remove(&mut frozen_store, &idx.to_le_bytes());
+        HandleAnswer::UnfreezeToken { status: Success }
+    };
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::ChangeAdmin { status: Success })?),
+        data: Some(to_binary(&response)?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// set the contract status level
+/// set the trait_types whose attributes may be modified after minting
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
 /// * `config` - a mutable reference to the Config
-/// * `level` - new ContractStatus
-pub fn set_contract_status<S: Storage, A: Api, Q: Querier>(
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `trait_types` - optional list of mutable trait_types.  None or empty means all are mutable
+pub fn set_mutable_trait_types<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &mut Config,
-    level: ContractStatus,
+    priority: u8,
+    trait_types: Option<Vec<String>>,
 ) -> HandleResult {
+    check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
     if config.admin != sender_raw {
         return Err(StdError::generic_err(
             "This is an admin command and can only be run from the admin address",
         ));
     }
-    let new_status = level.to_u8();
-    if config.status != new_status {
-        config.status = new_status;
+    // an empty list is the same as no restriction
+    let trait_types = trait_types.filter(|t| !t.is_empty());
+    if config.mutable_trait_types != trait_types {
+        config.mutable_trait_types = trait_types;
         save(&mut deps.storage, CONFIG_KEY, &config)?;
     }
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::SetContractStatus {
+        data: Some(to_binary(&HandleAnswer::SetMutableTraitTypes {
             status: Success,
         })?),
     })
@@ -1697,15 +2918,29 @@ pub fn set_contract_status<S: Storage, A: Api, Q: Querier>(
 ///
 /// # Arguments
 ///
-/// * `storage` - mutable reference to the contract's storage
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `sender` - a reference to the message sender
 /// * `permit_name` - string slice of the name of the permit to revoke
-fn revoke_permit<S: Storage>(
-    storage: &mut S,
+fn revoke_permit<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
     sender: &HumanAddr,
     permit_name: &str,
 ) -> HandleResult {
-    RevokedPermits::revoke_permit(storage, PREFIX_REVOKED_PERMITS, sender, permit_name);
+    RevokedPermits::revoke_permit(
+        &mut deps.storage,
+        PREFIX_REVOKED_PERMITS,
+        sender,
+        permit_name,
+    );
+    // keep a list of the names so the revocations can be audited
+    let sender_raw = deps.api.canonical_address(sender)?;
+    let mut name_store = PrefixedStorage::new(PREFIX_REVOKED_NAMES, &mut deps.storage);
+    let mut names: Vec<String> =
+        may_load(&name_store, sender_raw.as_slice())?.unwrap_or_else(Vec::new);
+    if !names.iter().any(|n| n == permit_name) {
+        names.push(permit_name.to_string());
+        save(&mut name_store, sender_raw.as_slice(), &names)?;
+    }
 
     Ok(HandleResponse {
         messages: vec![],
@@ -1728,23 +2963,53 @@ pub fn query<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>, msg: QueryM
         QueryMsg::RoyaltyInfo { token_id, viewer } => {
             query_royalty(deps, token_id.as_deref(), viewer, None)
         }
+        QueryMsg::DefaultExtension {} => query_default_extension(&deps.storage),
         QueryMsg::ContractConfig {} => query_config(&deps.storage),
         QueryMsg::Minters {} => query_minters(deps),
+        QueryMsg::MetadataEditors {} => query_metadata_editors(deps),
         QueryMsg::NumTokens { viewer } => query_num_tokens(deps, viewer, None),
+        QueryMsg::CollectionStats { viewer } => query_collection_stats(deps, viewer, None),
         QueryMsg::AllTokens {
             viewer,
             start_after,
             limit,
         } => query_all_tokens(deps, viewer, start_after, limit, None),
+        QueryMsg::TokensByTrait {
+            trait_type,
+            value,
+            viewer,
+            start_after,
+            limit,
+        } => query_tokens_by_trait(deps, &trait_type, &value, viewer, start_after, limit, None),
         QueryMsg::OwnerOf {
             token_id,
             viewer,
             include_expired,
         } => query_owner_of(deps, &token_id, viewer, include_expired, None),
-        QueryMsg::NftInfo { token_id } => query_nft_info(&deps.storage, &token_id),
-        QueryMsg::PrivateMetadata { token_id, viewer } => {
-            query_private_meta(deps, &token_id, viewer, None)
-        }
+        QueryMsg::NftInfo {
+            token_id,
+            ipfs_gateway,
+            locale,
+        } => query_nft_info(
+            &deps.storage,
+            &token_id,
+            ipfs_gateway.as_deref(),
+            locale.as_deref(),
+        ),
+        QueryMsg::PrivateMetadata {
+            token_id,
+            viewer,
+            ipfs_gateway,
+            locale,
+        } => query_private_meta(
+            deps,
+            &token_id,
+            viewer,
+            ipfs_gateway.as_deref(),
+            locale.as_deref(),
+            None,
+        ),
+        QueryMsg::ViewStats { token_id, viewer } => query_view_stats(deps, &token_id, viewer, None),
         QueryMsg::AllNftInfo {
             token_id,
             viewer,
@@ -1754,7 +3019,23 @@ pub fn query<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>, msg: QueryM
             token_id,
             viewer,
             include_expired,
-        } => query_nft_dossier(deps, &token_id, viewer, include_expired, None),
+            ipfs_gateway,
+            locale,
+        } => query_nft_dossier(
+            deps,
+            &token_id,
+            viewer,
+            include_expired,
+            ipfs_gateway.as_deref(),
+            locale.as_deref(),
+            None,
+        ),
+        QueryMsg::BatchPublicDossier { token_ids } => query_batch_public_dossier(deps, &token_ids),
+        QueryMsg::ExportTokens {
+            viewer,
+            start_after,
+            limit,
+        } => query_export_tokens(deps, Some(viewer), start_after.as_deref(), limit, None),
         QueryMsg::TokenApprovals {
             token_id,
             viewing_key,
@@ -1776,6 +3057,11 @@ pub fn query<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>, msg: QueryM
             viewing_key,
             include_expired,
         } => query_approved_for_all(deps, Some(&owner), viewing_key, include_expired, None),
+        QueryMsg::PrefixApprovals {
+            owner,
+            viewing_key,
+            include_expired,
+        } => query_prefix_approvals(deps, Some(&owner), Some(viewing_key), include_expired, None),
         QueryMsg::Tokens {
             owner,
             viewer,
@@ -1783,6 +3069,27 @@ pub fn query<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>, msg: QueryM
             start_after,
             limit,
         } => query_tokens(deps, &owner, viewer, viewing_key, start_after, limit, None),
+        QueryMsg::InventoryCounts { owner, viewer } => {
+            query_inventory_counts(deps, &owner, viewer, None)
+        }
+        QueryMsg::OperatorTokens {
+            owner,
+            operator,
+            viewer,
+            start_after,
+            limit,
+        } => query_operator_tokens(
+            deps,
+            &owner,
+            &operator,
+            viewer,
+            start_after.as_deref(),
+            limit,
+            None,
+        ),
+        QueryMsg::WhoCanTransfer { token_id, viewer } => {
+            query_who_can_transfer(deps, &token_id, viewer, None)
+        }
         QueryMsg::VerifyTransferApproval {
             token_ids,
             address,
@@ -1795,17 +3102,58 @@ pub fn query<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>, msg: QueryM
             query_verify_approval(deps, &token_ids, viewer, None)
         }
         QueryMsg::IsUnwrapped { token_id } => query_is_unwrapped(&deps.storage, &token_id),
+        QueryMsg::BatchIsUnwrapped { token_ids } => {
+            query_batch_is_unwrapped(&deps.storage, &token_ids)
+        }
+        QueryMsg::IsReserved { token_id } => query_is_reserved(&deps.storage, &token_id),
+        QueryMsg::IsTransferable { token_id } => query_is_transferable(&deps.storage, &token_id),
+        QueryMsg::IsFrozen { token_id } => query_is_frozen(&deps.storage, &token_id),
+        QueryMsg::VerifyOwnership {
+            token_id,
+            claimed_owner,
+            viewer,
+        } => query_verify_ownership(deps, &token_id, &claimed_owner, viewer, None),
+        QueryMsg::ProtectedAttributeTypes { token_id } => {
+            query_protected_attribute_types(&deps.storage, &token_id)
+        }
+        QueryMsg::RarityScore { token_id } => query_rarity_score(&deps.storage, &token_id),
+        QueryMsg::TokenCreator { token_id } => query_token_creator(deps, &token_id),
+        QueryMsg::TokenTrait {
+            token_id,
+            trait_type,
+            viewer,
+        } => query_token_trait(deps, &token_id, &trait_type, viewer, None),
+        QueryMsg::MetadataCanonicalBytes {
+            token_id,
+            which,
+            viewer,
+        } => query_canonical_bytes(deps, &token_id, &which, viewer, None),
+        QueryMsg::MediaHashes { token_id, viewer } => {
+            query_media_hashes(deps, &token_id, viewer, None)
+        }
+        QueryMsg::TokenUri { token_id, viewer } => query_token_uri(deps, &token_id, viewer, None),
         QueryMsg::TransactionHistory {
             address,
             viewing_key,
             page,
             page_size,
+            tx_type,
+        } => {
+            let viewer = Some(ViewerInfo {
+                address,
+                viewing_key,
+            });
+            query_transactions(deps, viewer, page, page_size, tx_type, None)
+        }
+        QueryMsg::RevokedPermits {
+            address,
+            viewing_key,
         } => {
             let viewer = Some(ViewerInfo {
                 address,
                 viewing_key,
             });
-            query_transactions(deps, viewer, page, page_size, None)
+            query_revoked_permits(deps, viewer, None)
         }
         QueryMsg::RegisteredCodeHash { contract } => query_code_hash(deps, &contract),
         QueryMsg::WithPermit { permit, query } => permit_queries(deps, permit, query),
@@ -1847,17 +3195,56 @@ pub fn permit_queries<S: Storage, A: Api, Q: Querier>(
         QueryWithPermit::RoyaltyInfo { token_id } => {
             query_royalty(deps, token_id.as_deref(), None, Some(querier))
         }
-        QueryWithPermit::PrivateMetadata { token_id } => {
-            query_private_meta(deps, &token_id, None, Some(querier))
+        QueryWithPermit::PrivateMetadata {
+            token_id,
+            ipfs_gateway,
+            locale,
+        } => query_private_meta(
+            deps,
+            &token_id,
+            None,
+            ipfs_gateway.as_deref(),
+            locale.as_deref(),
+            Some(querier),
+        ),
+        QueryWithPermit::ViewStats { token_id } => {
+            query_view_stats(deps, &token_id, None, Some(querier))
+        }
+        QueryWithPermit::TokenTrait {
+            token_id,
+            trait_type,
+        } => query_token_trait(deps, &token_id, &trait_type, None, Some(querier)),
+        QueryWithPermit::MetadataCanonicalBytes { token_id, which } => {
+            query_canonical_bytes(deps, &token_id, &which, None, Some(querier))
+        }
+        QueryWithPermit::MediaHashes { token_id } => {
+            query_media_hashes(deps, &token_id, None, Some(querier))
+        }
+        QueryWithPermit::TokenUri { token_id } => {
+            query_token_uri(deps, &token_id, None, Some(querier))
         }
         QueryWithPermit::NftDossier {
             token_id,
             include_expired,
-        } => query_nft_dossier(deps, &token_id, None, include_expired, Some(querier)),
+            ipfs_gateway,
+            locale,
+        } => query_nft_dossier(
+            deps,
+            &token_id,
+            None,
+            include_expired,
+            ipfs_gateway.as_deref(),
+            locale.as_deref(),
+            Some(querier),
+        ),
         QueryWithPermit::OwnerOf {
             token_id,
             include_expired,
         } => query_owner_of(deps, &token_id, None, include_expired, Some(querier)),
+        QueryWithPermit::VerifyOwnership {
+            token_id,
+            claimed_owner,
+        } => query_verify_ownership(deps, &token_id, &claimed_owner, None, Some(querier)),
         QueryWithPermit::AllNftInfo {
             token_id,
             include_expired,
@@ -1868,13 +3255,34 @@ pub fn permit_queries<S: Storage, A: Api, Q: Querier>(
         QueryWithPermit::VerifyTransferApproval { token_ids } => {
             query_verify_approval(deps, &token_ids, None, Some(querier))
         }
-        QueryWithPermit::TransactionHistory { page, page_size } => {
-            query_transactions(deps, None, page, page_size, Some(querier))
-        }
+        QueryWithPermit::TransactionHistory {
+            page,
+            page_size,
+            tx_type,
+        } => query_transactions(deps, None, page, page_size, tx_type, Some(querier)),
+        QueryWithPermit::RevokedPermits {} => query_revoked_permits(deps, None, Some(querier)),
         QueryWithPermit::NumTokens {} => query_num_tokens(deps, None, Some(querier)),
+        QueryWithPermit::CollectionStats {} => query_collection_stats(deps, None, Some(querier)),
+        QueryWithPermit::ExportTokens { start_after, limit } => {
+            query_export_tokens(deps, None, start_after.as_deref(), limit, Some(querier))
+        }
         QueryWithPermit::AllTokens { start_after, limit } => {
             query_all_tokens(deps, None, start_after, limit, Some(querier))
         }
+        QueryWithPermit::TokensByTrait {
+            trait_type,
+            value,
+            start_after,
+            limit,
+        } => query_tokens_by_trait(
+            deps,
+            &trait_type,
+            &value,
+            None,
+            start_after,
+            limit,
+            Some(querier),
+        ),
         QueryWithPermit::TokenApprovals {
             token_id,
             include_expired,
@@ -1882,11 +3290,34 @@ pub fn permit_queries<S: Storage, A: Api, Q: Querier>(
         QueryWithPermit::ApprovedForAll { include_expired } => {
             query_approved_for_all(deps, None, None, include_expired, Some(querier))
         }
+        QueryWithPermit::PrefixApprovals { include_expired } => {
+            query_prefix_approvals(deps, None, None, include_expired, Some(querier))
+        }
         QueryWithPermit::Tokens {
             owner,
             start_after,
             limit,
         } => query_tokens(deps, &owner, None, None, start_after, limit, Some(querier)),
+        QueryWithPermit::InventoryCounts { owner } => {
+            query_inventory_counts(deps, &owner, None, Some(querier))
+        }
+        QueryWithPermit::OperatorTokens {
+            owner,
+            operator,
+            start_after,
+            limit,
+        } => query_operator_tokens(
+            deps,
+            &owner,
+            &operator,
+            None,
+            start_after.as_deref(),
+            limit,
+            Some(querier),
+        ),
+        QueryWithPermit::WhoCanTransfer { token_id } => {
+            query_who_can_transfer(deps, &token_id, None, Some(querier))
+        }
     }
 }
 
@@ -1904,17 +3335,42 @@ pub fn query_contract_creator<S: Storage, A: Api, Q: Querier>(
     })
 }
 
-/// Returns QueryResult displaying the contract's name and symbol
+/// Returns QueryResult displaying the contract's name, symbol, and enabled features
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+pub fn query_contract_info<S: ReadonlyStorage>(storage: &S) -> QueryResult {
+    let config: Config = load(storage, CONFIG_KEY)?;
+    let mut features = vec!["royalties".to_string(), "transfer_lock".to_string()];
+    if config.sealed_metadata_is_enabled {
+        features.push("sealed_metadata".to_string());
+    }
+    if config.burn_is_enabled {
+        features.push("burn".to_string());
+    }
+    if config.minter_may_update_metadata || config.owner_may_update_metadata {
+        features.push("metadata_updates".to_string());
+    }
+    if config.track_views {
+        features.push("view_tracking".to_string());
+    }
+
+    to_binary(&QueryAnswer::ContractInfo {
+        name: config.name,
+        symbol: config.symbol,
+        features,
+    })
+}
+
+/// Returns QueryResult displaying the default extension template used when minting clones
 ///
 /// # Arguments
 ///
 /// * `storage` - a reference to the contract's storage
-pub fn query_contract_info<S: ReadonlyStorage>(storage: &S) -> QueryResult {
-    let config: Config = load(storage, CONFIG_KEY)?;
-
-    to_binary(&QueryAnswer::ContractInfo {
-        name: config.name,
-        symbol: config.symbol,
+pub fn query_default_extension<S: ReadonlyStorage>(storage: &S) -> QueryResult {
+    to_binary(&QueryAnswer::DefaultExtension {
+        extension: may_load(storage, DEFAULT_EXTENSION_KEY)?,
     })
 }
 
@@ -2010,6 +3466,12 @@ pub fn query_config<S: ReadonlyStorage>(storage: &S) -> QueryResult {
         minter_may_update_metadata: config.minter_may_update_metadata,
         owner_may_update_metadata: config.owner_may_update_metadata,
         burn_is_enabled: config.burn_is_enabled,
+        max_attributes: config.max_attributes,
+        mutable_trait_types: config.mutable_trait_types,
+        track_views: config.track_views,
+        max_image_data_size: config.max_image_data_size,
+        max_royalty_bps: config.max_royalty_bps,
+        prng_algorithm: config.prng_algorithm,
     })
 }
 
@@ -2030,6 +3492,25 @@ pub fn query_minters<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>) ->
     })
 }
 
+/// Returns QueryResult displaying the list of authorized metadata editors
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_metadata_editors<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+) -> QueryResult {
+    let editors: Vec<CanonicalAddr> =
+        may_load(&deps.storage, METADATA_EDITORS_KEY)?.unwrap_or_else(Vec::new);
+
+    to_binary(&QueryAnswer::MetadataEditors {
+        editors: editors
+            .iter()
+            .map(|e| deps.api.human_address(e))
+            .collect::<StdResult<Vec<HumanAddr>>>()?,
+    })
+}
+
 /// Returns QueryResult displaying the number of tokens the contract controls
 ///
 /// # Arguments
@@ -2050,6 +3531,29 @@ pub fn query_num_tokens<S: Storage, A: Api, Q: Querier>(
     })
 }
 
+/// Returns QueryResult displaying aggregate metrics about the collection
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_collection_stats<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    // every mint increments mint_cnt and only burns remove tokens
+    to_binary(&QueryAnswer::CollectionStats {
+        token_count: config.token_cnt,
+        burned_count: config.mint_cnt.saturating_sub(config.token_cnt),
+        owner_count: Inventory::owner_count(&deps.storage)?,
+    })
+}
+
 /// Returns QueryResult displaying the list of tokens that the contract controls
 ///
 /// # Arguments
@@ -2093,7 +3597,73 @@ pub fn query_all_tokens<S: Storage, A: Api, Q: Querier>(
         // i can't overflow if it was less than a u32
         i += 1;
     }
-    to_binary(&QueryAnswer::TokenList { tokens })
+    // only provide a cursor if there is another token after the last one displayed
+    let mut next = None;
+    while i < config.mint_cnt {
+        if may_load::<String, _>(&map2id, &i.to_le_bytes())?.is_some() {
+            next = tokens.last().cloned();
+            break;
+        }
+        i += 1;
+    }
+    to_binary(&QueryAnswer::TokenList { tokens, next })
+}
+
+/// Returns QueryResult displaying the list of tokens whose public metadata has the specified
+/// unprotected trait
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `trait_type` - string slice of the trait_type to search for
+/// * `value` - string slice of the trait value to search for
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display token ids that come after this one
+/// * `limit` - optional max number of tokens to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_tokens_by_trait<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    trait_type: &str,
+    value: &str,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    let after_idx = start_after
+        .map(|id| {
+            let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+            may_load::<u32, _>(&map2idx, id.as_bytes())?
+                .ok_or_else(|| StdError::generic_err(format!("Token ID: {} not found", id)))
+        })
+        .transpose()?;
+    let cut_off = limit.unwrap_or(300) as usize;
+    // get one extra index to see if a cursor is needed
+    let mut idxs = get_trait_tokens(
+        &deps.storage,
+        trait_type,
+        value,
+        after_idx,
+        cut_off.saturating_add(1),
+        config.mint_cnt,
+    )?;
+    // only provide a cursor if there is another token after the last one displayed
+    let has_more = idxs.len() > cut_off;
+    idxs.truncate(cut_off);
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    let tokens = idxs
+        .iter()
+        .map(|i| load::<String, _>(&map2id, &i.to_le_bytes()))
+        .collect::<StdResult<Vec<String>>>()?;
+    let next = if has_more {
+        tokens.last().cloned()
+    } else {
+        None
+    };
+    to_binary(&QueryAnswer::TokenList { tokens, next })
 }
 
 /// Returns QueryResult displaying the owner of the input token if the requester is authorized
@@ -2124,45 +3694,311 @@ pub fn query_owner_of<S: Storage, A: Api, Q: Querier>(
     )))
 }
 
-/// Returns QueryResult displaying the public metadata of a token
+/// Returns QueryResult displaying the public metadata of a token
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
+/// * `ipfs_gateway` - optional gateway used to display ipfs urls
+/// * `locale` - optional locale used to display localized names and descriptions
+pub fn query_nft_info<S: ReadonlyStorage>(
+    storage: &S,
+    token_id: &str,
+    ipfs_gateway: Option<&str>,
+    locale: Option<&str>,
+) -> QueryResult {
+    check_ipfs_gateway(ipfs_gateway)?;
+    check_locale(locale)?;
+    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, storage);
+    let may_idx: Option<u32> = may_load(&map2idx, token_id.as_bytes())?;
+    // if token id was found
+    if let Some(idx) = may_idx {
+        let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, storage);
+        let mut meta: Metadata = may_load(&meta_store, &idx.to_le_bytes())?.unwrap_or(Metadata {
+            token_uri: None,
+            extension: None,
+        });
+        meta.rewrite_ipfs(ipfs_gateway);
+        meta.localize(locale);
+        return to_binary(&QueryAnswer::NftInfo {
+            token_uri: meta.token_uri,
+            extension: meta.extension,
+        });
+    }
+    let config: Config = load(storage, CONFIG_KEY)?;
+    // token id wasn't found
+    // if the token supply is public, let them know the token does not exist
+    if config.token_supply_is_public {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} not found",
+            token_id
+        )));
+    }
+    // otherwise, just return empty metadata
+    to_binary(&QueryAnswer::NftInfo {
+        token_uri: None,
+        extension: None,
+    })
+}
+
+/// Returns QueryResult displaying the private metadata of a token if permitted to
+/// view it
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `ipfs_gateway` - optional gateway used to display ipfs urls
+/// * `locale` - optional locale used to display localized names and descriptions
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_private_meta<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    ipfs_gateway: Option<&str>,
+    locale: Option<&str>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    check_ipfs_gateway(ipfs_gateway)?;
+    check_locale(locale)?;
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    check_perm_core(
+        deps,
+        &prep_info.block,
+        &prep_info.token,
+        token_id,
+        prep_info.viewer_raw.as_ref(),
+        prep_info.token.owner.as_slice(),
+        PermissionType::ViewMetadata.to_usize(),
+        &mut Vec::new(),
+        &prep_info.err_msg,
+    )?;
+    // don't display if private metadata is sealed
+    if !prep_info.token.unwrapped {
+        return Err(StdError::generic_err(
+            "Sealed metadata must be unwrapped by calling Reveal before it can be viewed",
+        ));
+    }
+    let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+    let mut meta: Metadata =
+        may_load(&meta_store, &prep_info.idx.to_le_bytes())?.unwrap_or(Metadata {
+            token_uri: None,
+            extension: None,
+        });
+    meta.rewrite_ipfs(ipfs_gateway);
+    meta.localize(locale);
+    to_binary(&QueryAnswer::PrivateMetadata {
+        token_uri: meta.token_uri,
+        extension: meta.extension,
+    })
+}
+
+/// Returns QueryResult displaying the number of recorded views of a token's private metadata.
+/// Only the token owner may view this
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_view_stats<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    if prep_info.viewer_raw.as_ref() != Some(&prep_info.token.owner) {
+        return Err(StdError::generic_err(prep_info.err_msg));
+    }
+    let cnt_store = ReadonlyPrefixedStorage::new(PREFIX_VIEW_CNT, &deps.storage);
+    to_binary(&QueryAnswer::ViewStats {
+        private_view_count: may_load(&cnt_store, &prep_info.idx.to_le_bytes())?.unwrap_or(0),
+    })
+}
+
+/// Returns QueryResult displaying the attributes of a token that have the specified trait_type.
+/// Values of protected attributes are only displayed if permitted to view the private metadata
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `trait_type` - string slice of the trait_type to display
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_token_trait<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    trait_type: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    let token_key = prep_info.idx.to_le_bytes();
+    let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+    let pub_ext = may_load::<Metadata, _>(&pub_store, &token_key)?.and_then(|m| m.extension);
+    let is_protected = matches!(
+        pub_ext.as_ref().and_then(|e| e.protected_attributes.as_ref()),
+        Some(p) if p.iter().any(|t| t == trait_type)
+    );
+    let ext = if is_protected {
+        check_perm_core(
+            deps,
+            &prep_info.block,
+            &prep_info.token,
+            token_id,
+            prep_info.viewer_raw.as_ref(),
+            prep_info.token.owner.as_slice(),
+            PermissionType::ViewMetadata.to_usize(),
+            &mut Vec::new(),
+            &prep_info.err_msg,
+        )?;
+        // don't display if private metadata is sealed
+        if !prep_info.token.unwrapped {
+            return Err(StdError::generic_err(
+                "Sealed metadata must be unwrapped by calling Reveal before it can be viewed",
+            ));
+        }
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        may_load::<Metadata, _>(&priv_store, &token_key)?.and_then(|m| m.extension)
+    } else {
+        pub_ext
+    };
+    let traits: Vec<Trait> = ext
+        .and_then(|e| e.attributes)
+        .map(|attrs| {
+            attrs
+                .into_iter()
+                .filter(|a| a.trait_type.as_deref() == Some(trait_type))
+                .collect()
+        })
+        .unwrap_or_else(Vec::new);
+    to_binary(&QueryAnswer::TokenTrait { traits })
+}
+
+/// Returns QueryResult displaying the url and sha256 hash of a token's media files.  Private
+/// media files are only displayed if permitted to view the private metadata
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_media_hashes<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    let token_key = prep_info.idx.to_le_bytes();
+    let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+    let public_media = media_hashes(may_load(&pub_store, &token_key)?);
+    // only display private media if permitted and the private metadata is not sealed
+    let private_media = if prep_info.token.unwrapped
+        && check_perm_core(
+            deps,
+            &prep_info.block,
+            &prep_info.token,
+            token_id,
+            prep_info.viewer_raw.as_ref(),
+            prep_info.token.owner.as_slice(),
+            PermissionType::ViewMetadata.to_usize(),
+            &mut Vec::new(),
+            &prep_info.err_msg,
+        )
+        .is_ok()
+    {
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        Some(media_hashes(may_load(&priv_store, &token_key)?))
+    } else {
+        None
+    };
+    to_binary(&QueryAnswer::MediaHashes {
+        public_media,
+        private_media,
+    })
+}
+
+/// Returns QueryResult displaying the canonical bytes of a token's public or private metadata
+/// that reveal commitments are computed from.  Private metadata is only displayed if the
+/// viewer is permitted to view it and it is not sealed
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `which` - a reference to which of the token's metadata should be displayed
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_canonical_bytes<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    which: &MetadataType,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    let prefix = match which {
+        MetadataType::Public => PREFIX_PUB_META,
+        MetadataType::Private => {
+            check_perm_core(
+                deps,
+                &prep_info.block,
+                &prep_info.token,
+                token_id,
+                prep_info.viewer_raw.as_ref(),
+                prep_info.token.owner.as_slice(),
+                PermissionType::ViewMetadata.to_usize(),
+                &mut Vec::new(),
+                &prep_info.err_msg,
+            )?;
+            // don't display if private metadata is sealed
+            if !prep_info.token.unwrapped {
+                return Err(StdError::generic_err(
+                    "Sealed metadata must be unwrapped by calling Reveal before it can be viewed",
+                ));
+            }
+            PREFIX_PRIV_META
+        }
+    };
+    let meta_store = ReadonlyPrefixedStorage::new(prefix, &deps.storage);
+    let may_meta: Option<Metadata> = may_load(&meta_store, &prep_info.idx.to_le_bytes())?;
+    let bytes = may_meta
+        .map(|m| m.canonical_bytes().map(Binary::from))
+        .transpose()?;
+    to_binary(&QueryAnswer::MetadataCanonicalBytes { bytes })
+}
+
+/// Returns Vec<MediaHash> listing the url and sha256 hash of every media file in the metadata
 ///
 /// # Arguments
 ///
-/// * `storage` - a reference to the contract's storage
-/// * `token_id` - string slice of the token id
-pub fn query_nft_info<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
-    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, storage);
-    let may_idx: Option<u32> = may_load(&map2idx, token_id.as_bytes())?;
-    // if token id was found
-    if let Some(idx) = may_idx {
-        let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, storage);
-        let meta: Metadata = may_load(&meta_store, &idx.to_le_bytes())?.unwrap_or(Metadata {
-            token_uri: None,
-            extension: None,
-        });
-        return to_binary(&QueryAnswer::NftInfo {
-            token_uri: meta.token_uri,
-            extension: meta.extension,
-        });
-    }
-    let config: Config = load(storage, CONFIG_KEY)?;
-    // token id wasn't found
-    // if the token supply is public, let them know the token does not exist
-    if config.token_supply_is_public {
-        return Err(StdError::generic_err(format!(
-            "Token ID: {} not found",
-            token_id
-        )));
-    }
-    // otherwise, just return empty metadata
-    to_binary(&QueryAnswer::NftInfo {
-        token_uri: None,
-        extension: None,
-    })
+/// * `metadata` - optional Metadata whose media files should be listed
+fn media_hashes(metadata: Option<Metadata>) -> Vec<MediaHash> {
+    metadata
+        .and_then(|m| m.extension)
+        .and_then(|e| e.media)
+        .map(|media| {
+            media
+                .into_iter()
+                .map(|f| MediaHash {
+                    url: f.url,
+                    sha256: f.sha256,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
-/// Returns QueryResult displaying the private metadata of a token if permitted to
-/// view it
+/// Returns QueryResult displaying only the token_uri of a token.  The private token_uri is
+/// only displayed if the public metadata has none and the querier is permitted to view the
+/// private metadata
 ///
 /// # Arguments
 ///
@@ -2170,39 +4006,37 @@ pub fn query_nft_info<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryR
 /// * `token_id` - string slice of the token id
 /// * `viewer` - optional address and key making an authenticated query request
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_private_meta<S: Storage, A: Api, Q: Querier>(
+pub fn query_token_uri<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
     token_id: &str,
     viewer: Option<ViewerInfo>,
     from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
     let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
-    check_perm_core(
-        deps,
-        &prep_info.block,
-        &prep_info.token,
-        token_id,
-        prep_info.viewer_raw.as_ref(),
-        prep_info.token.owner.as_slice(),
-        PermissionType::ViewMetadata.to_usize(),
-        &mut Vec::new(),
-        &prep_info.err_msg,
-    )?;
-    // don't display if private metadata is sealed
-    if !prep_info.token.unwrapped {
-        return Err(StdError::generic_err(
-            "Sealed metadata must be unwrapped by calling Reveal before it can be viewed",
-        ));
+    let token_key = prep_info.idx.to_le_bytes();
+    let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+    let mut token_uri = may_load::<Metadata, _>(&pub_store, &token_key)?.and_then(|m| m.token_uri);
+    // only fall back to the private token_uri if permitted and the private metadata is
+    // not sealed
+    if token_uri.is_none()
+        && prep_info.token.unwrapped
+        && check_perm_core(
+            deps,
+            &prep_info.block,
+            &prep_info.token,
+            token_id,
+            prep_info.viewer_raw.as_ref(),
+            prep_info.token.owner.as_slice(),
+            PermissionType::ViewMetadata.to_usize(),
+            &mut Vec::new(),
+            &prep_info.err_msg,
+        )
+        .is_ok()
+    {
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        token_uri = may_load::<Metadata, _>(&priv_store, &token_key)?.and_then(|m| m.token_uri);
     }
-    let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-    let meta: Metadata = may_load(&meta_store, &prep_info.idx.to_le_bytes())?.unwrap_or(Metadata {
-        token_uri: None,
-        extension: None,
-    });
-    to_binary(&QueryAnswer::PrivateMetadata {
-        token_uri: meta.token_uri,
-        extension: meta.extension,
-    })
+    to_binary(&QueryAnswer::TokenUri { token_uri })
 }
 
 /// Returns QueryResult displaying response of both the OwnerOf and NftInfo queries
@@ -2239,14 +4073,20 @@ pub fn query_all_nft_info<S: Storage, A: Api, Q: Querier>(
 /// * `token_id` - string slice of the token id
 /// * `viewer` - optional address and key making an authenticated query request
 /// * `include_expired` - optionally true if the Approval lists should include expired Approvals
+/// * `ipfs_gateway` - optional gateway used to display ipfs urls
+/// * `locale` - optional locale used to display localized names and descriptions
 /// * `from_permit` - address derived from an Owner permit, if applicable
 pub fn query_nft_dossier<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
     token_id: &str,
     viewer: Option<ViewerInfo>,
     include_expired: Option<bool>,
+    ipfs_gateway: Option<&str>,
+    locale: Option<&str>,
     from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
+    check_ipfs_gateway(ipfs_gateway)?;
+    check_locale(locale)?;
     let mut prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
     let incl_exp = include_expired.unwrap_or(false);
     let owner_slice = prep_info.token.owner.as_slice();
@@ -2282,7 +4122,11 @@ pub fn query_nft_dossier<S: Storage, A: Api, Q: Querier>(
     // get the public metadata
     let token_key = prep_info.idx.to_le_bytes();
     let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-    let public_metadata: Option<Metadata> = may_load(&pub_store, &token_key)?;
+    let mut public_metadata: Option<Metadata> = may_load(&pub_store, &token_key)?;
+    if let Some(meta) = public_metadata.as_mut() {
+        meta.rewrite_ipfs(ipfs_gateway);
+        meta.localize(locale);
+    }
     // get the private metadata if it is not sealed and if the viewer is permitted
     let mut display_private_metadata_error = None;
     let private_metadata = if let Err(err) = check_perm_core(
@@ -2308,7 +4152,11 @@ pub fn query_nft_dossier<S: Storage, A: Api, Q: Querier>(
         None
     } else {
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &token_key)?;
+        let mut priv_meta: Option<Metadata> = may_load(&priv_store, &token_key)?;
+        if let Some(meta) = priv_meta.as_mut() {
+            meta.rewrite_ipfs(ipfs_gateway);
+            meta.localize(locale);
+        }
         priv_meta
     };
     // get the royalty information if present
@@ -2392,6 +4240,187 @@ pub fn query_nft_dossier<S: Storage, A: Api, Q: Querier>(
     })
 }
 
+/// Returns QueryResult displaying the public information of many tokens.  This skips all
+/// private metadata access checks
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_ids` - list of token ids to display
+pub fn query_batch_public_dossier<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_ids: &[String],
+) -> QueryResult {
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    // TODO remove this when BlockInfo becomes available to queries
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let creator_raw: CanonicalAddr = load(&deps.storage, CREATOR_KEY)?;
+    let perm_type_info = PermissionTypeInfo {
+        view_owner_idx: PermissionType::ViewOwner.to_usize(),
+        view_meta_idx: PermissionType::ViewMetadata.to_usize(),
+        transfer_idx: PermissionType::Transfer.to_usize(),
+        num_types: PermissionType::Transfer.num_types(),
+    };
+    let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
+    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+    let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+    let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
+    let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, &deps.storage);
+    let mut dossiers: Vec<PublicDossier> = Vec::new();
+    for token_id in token_ids.iter() {
+        let err_msg = format!(
+            "You are not authorized to perform this action on token {}",
+            token_id
+        );
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they are not authorized for that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*err_msg)
+        };
+        let (mut token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+        let owner_slice = token.owner.as_slice();
+        let global_pass: bool =
+            may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
+        let (_, token_owner_exp, token_meta_exp) = gen_snip721_approvals(
+            &deps.api,
+            &block,
+            &mut token.permissions,
+            false,
+            &perm_type_info,
+        )?;
+        let mut all_perm: Vec<Permission> =
+            json_may_load(&all_store, owner_slice)?.unwrap_or_else(Vec::new);
+        let (_, all_owner_exp, all_meta_exp) =
+            gen_snip721_approvals(&deps.api, &block, &mut all_perm, false, &perm_type_info)?;
+        // determine if ownership is public
+        let (public_ownership_expiration, owner_is_public) = if global_pass {
+            (Some(Expiration::Never), true)
+        } else if token_owner_exp.is_some() {
+            (token_owner_exp, true)
+        } else {
+            (all_owner_exp, all_owner_exp.is_some())
+        };
+        // determine if private metadata is public
+        let (private_metadata_is_public_expiration, private_metadata_is_public) =
+            if token_meta_exp.is_some() {
+                (token_meta_exp, true)
+            } else {
+                (all_meta_exp, all_meta_exp.is_some())
+            };
+        let owner = if owner_is_public {
+            Some(deps.api.human_address(&token.owner)?)
+        } else {
+            None
+        };
+        let token_key = idx.to_le_bytes();
+        let royalty_info = may_load::<StoredRoyaltyInfo, _>(&roy_store, &token_key)?
+            .map(|r| r.to_human(&deps.api, true))
+            .transpose()?;
+        let mint_run: StoredMintRunInfo = load(&run_store, &token_key)?;
+        dossiers.push(PublicDossier {
+            token_id: token_id.clone(),
+            owner,
+            public_metadata: may_load(&pub_store, &token_key)?,
+            royalty_info,
+            mint_run_info: Some(mint_run.to_human(&deps.api, &creator_raw)?),
+            owner_is_public,
+            public_ownership_expiration,
+            private_metadata_is_public,
+            private_metadata_is_public_expiration,
+            token_is_unwrapped: token.unwrapped,
+        });
+    }
+    to_binary(&QueryAnswer::BatchPublicDossier { dossiers })
+}
+
+/// Returns QueryResult displaying all the information of an optionally paginated list of
+/// tokens so they can be minted in another contract.  This bypasses the usual access checks,
+/// so only the admin and minters may use it
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display token ids that come after this one
+/// * `limit` - optional max number of tokens to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_export_tokens<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let querier = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
+        StdError::generic_err("This is being called incorrectly if there is no querier address")
+    })?;
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    let minters: Vec<CanonicalAddr> =
+        may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_else(Vec::new);
+    if querier != config.admin && !minters.contains(&querier) {
+        return Err(StdError::generic_err(
+            "Only the admin and designated minters may export tokens",
+        ));
+    }
+    let mut i = start_after.map_or_else(
+        || Ok(0),
+        |id| {
+            let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+            let idx: u32 = may_load(&map2idx, id.as_bytes())?
+                .ok_or_else(|| StdError::generic_err(format!("Token ID: {} not found", id)))?;
+            idx.checked_add(1).ok_or_else(|| {
+                StdError::generic_err("This token was the last one the contract could mint")
+            })
+        },
+    )?;
+    let cut_off = limit.unwrap_or(50).min(50);
+    let creator_raw: CanonicalAddr = load(&deps.storage, CREATOR_KEY)?;
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+    let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+    let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+    let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
+    let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, &deps.storage);
+    let mut tokens: Vec<ExportedToken> = Vec::new();
+    let mut next = None;
+    while i < config.mint_cnt {
+        let token_key = i.to_le_bytes();
+        if let Some(token_id) = may_load::<String, _>(&map2id, &token_key)? {
+            // only provide a cursor if there is another token after the last one displayed
+            if tokens.len() as u32 >= cut_off {
+                next = tokens.last().map(|t| t.token_id.clone());
+                break;
+            }
+            let token: Token = json_may_load(&info_store, &token_key)?.ok_or_else(|| {
+                StdError::generic_err(format!("Unable to find token info for {}", token_id))
+            })?;
+            let royalty_info = may_load::<StoredRoyaltyInfo, _>(&roy_store, &token_key)?
+                .map(|r| r.to_human(&deps.api, false))
+                .transpose()?;
+            let mint_run: StoredMintRunInfo = load(&run_store, &token_key)?;
+            tokens.push(ExportedToken {
+                token_id,
+                owner: deps.api.human_address(&token.owner)?,
+                public_metadata: may_load(&pub_store, &token_key)?,
+                private_metadata: may_load(&priv_store, &token_key)?,
+                royalty_info,
+                mint_run_info: Some(mint_run.to_human(&deps.api, &creator_raw)?),
+                token_is_unwrapped: token.unwrapped,
+                transfer_locked: token.transfer_locked,
+            });
+        }
+        // i can't overflow if it was less than a u32
+        i += 1;
+    }
+    to_binary(&QueryAnswer::ExportTokens { tokens, next })
+}
+
 /// Returns QueryResult displaying the approvals in place for a specified token
 ///
 /// # Arguments
@@ -2581,28 +4610,157 @@ pub fn query_approved_for_all<S: Storage, A: Api, Q: Querier>(
                 operators: Vec::new(),
             });
         }
-        raw
-    };
-    // TODO remove this when BlockInfo becomes available to queries
-    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
-        height: 1,
-        time: 1,
-        chain_id: "not used".to_string(),
-    });
-    let mut operators: Vec<Cw721Approval> = Vec::new();
-    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-    let all_perm: Vec<Permission> =
-        json_may_load(&all_store, owner_raw.as_slice())?.unwrap_or_else(Vec::new);
-    gen_cw721_approvals(
-        &deps.api,
-        &block,
-        &all_perm,
-        &mut operators,
-        PermissionType::Transfer.to_usize(),
-        include_expired.unwrap_or(false),
-    )?;
-
-    to_binary(&QueryAnswer::ApprovedForAll { operators })
+        raw
+    };
+    // TODO remove this when BlockInfo becomes available to queries
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let mut operators: Vec<Cw721Approval> = Vec::new();
+    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+    let all_perm: Vec<Permission> =
+        json_may_load(&all_store, owner_raw.as_slice())?.unwrap_or_else(Vec::new);
+    gen_cw721_approvals(
+        &deps.api,
+        &block,
+        &all_perm,
+        &mut operators,
+        PermissionType::Transfer.to_usize(),
+        include_expired.unwrap_or(false),
+    )?;
+
+    to_binary(&QueryAnswer::ApprovedForAll { operators })
+}
+
+/// Returns QueryResult displaying the list of operators approved to transfer the owner's
+/// tokens whose ids start with a prefix.  Only the owner's viewing key will be accepted for
+/// this query
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `owner` - an optional reference to the address whose prefix approvals should be displayed
+/// * `viewing_key` - optional String of the owner's viewing key
+/// * `include_expired` - optionally true if the list should include expired approvals
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_prefix_approvals<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    owner: Option<&HumanAddr>,
+    viewing_key: Option<String>,
+    include_expired: Option<bool>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let owner_raw = if let Some(pmt) = from_permit {
+        pmt
+    } else {
+        let raw = deps.api.canonical_address(owner.ok_or_else(|| {
+            StdError::generic_err("This is being called incorrectly if there is no owner address")
+        })?)?;
+        check_key(
+            &deps.storage,
+            &raw,
+            viewing_key.ok_or_else(|| {
+                StdError::generic_err("This is being called incorrectly if there is no key")
+            })?,
+        )?;
+        raw
+    };
+    // TODO remove this when BlockInfo becomes available to queries
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let incl_exp = include_expired.unwrap_or(false);
+    let prefix_store = ReadonlyPrefixedStorage::new(PREFIX_PREFIX_APPROVALS, &deps.storage);
+    let stored: Vec<StoredPrefixApproval> =
+        may_load(&prefix_store, owner_raw.as_slice())?.unwrap_or_else(Vec::new);
+    let approvals = stored
+        .into_iter()
+        .filter(|a| incl_exp || !a.expires.is_expired(&block))
+        .map(|a| {
+            Ok(PrefixApproval {
+                operator: deps.api.human_address(&a.operator)?,
+                prefix: a.prefix,
+                expires: a.expires,
+            })
+        })
+        .collect::<StdResult<Vec<PrefixApproval>>>()?;
+
+    to_binary(&QueryAnswer::PrefixApprovals { approvals })
+}
+
+/// Returns QueryResult displaying the number of tokens belonging to the owner address, and
+/// how many of those are transfer locked or sealed
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `owner` - a reference to the address whose tokens should be counted
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_inventory_counts<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    owner: &HumanAddr,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let owner_raw = deps.api.canonical_address(owner)?;
+    let querier = get_querier(deps, viewer, from_permit)?;
+    // if querier is different than the owner, check if ownership is public
+    if querier.as_ref() != Some(&owner_raw) {
+        let config: Config = load(&deps.storage, CONFIG_KEY)?;
+        let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
+        let pass: bool =
+            may_load(&own_priv_store, owner_raw.as_slice())?.unwrap_or(config.owner_is_public);
+        // otherwise the querier needs view_owner permission for all the owner's tokens
+        if !pass {
+            // TODO remove this when BlockInfo becomes available to queries
+            let block: BlockInfo =
+                may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+                    height: 1,
+                    time: 1,
+                    chain_id: "not used".to_string(),
+                });
+            let global_raw = CanonicalAddr(Binary::from(b"public"));
+            let exp_idx = PermissionType::ViewOwner.to_usize();
+            let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+            let all_perm: Vec<Permission> =
+                json_may_load(&all_store, owner_raw.as_slice())?.unwrap_or_else(Vec::new);
+            let permitted = all_perm.iter().any(|p| {
+                (querier.as_ref() == Some(&p.address) || p.address == global_raw)
+                    && matches!(p.expirations[exp_idx], Some(e) if !e.is_expired(&block))
+            });
+            if !permitted {
+                return Err(StdError::generic_err(format!(
+                    "You are not authorized to view the inventory of {}",
+                    owner
+                )));
+            }
+        }
+    }
+    let inventory = Inventory::new(&deps.storage, owner_raw)?;
+    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+    let mut inv_iter = InventoryIter::new(&inventory);
+    let mut transfer_locked = 0u32;
+    let mut sealed = 0u32;
+    while let Some(idx) = inv_iter.next(&deps.storage)? {
+        let token: Token = json_may_load(&info_store, &idx.to_le_bytes())?
+            .ok_or_else(|| StdError::generic_err("Token info storage is corrupt"))?;
+        if token.transfer_locked {
+            transfer_locked += 1;
+        }
+        if !token.unwrapped {
+            sealed += 1;
+        }
+    }
+    to_binary(&QueryAnswer::InventoryCounts {
+        total: inventory.info.count,
+        transfer_locked,
+        sealed,
+    })
 }
 
 /// Returns QueryResult displaying an optionally paginated list of all tokens belonging to
@@ -2659,7 +4817,10 @@ pub fn query_tokens<S: Storage, A: Api, Q: Querier>(
     };
     // exit early if the limit is 0
     if cut_off == 0 {
-        return to_binary(&QueryAnswer::TokenList { tokens: Vec::new() });
+        return to_binary(&QueryAnswer::TokenList {
+            tokens: Vec::new(),
+            next: None,
+        });
     }
     // get list of owner's tokens
     let own_inv = Inventory::new(&deps.storage, owner_raw)?;
@@ -2752,6 +4913,7 @@ pub fn query_tokens<S: Storage, A: Api, Q: Querier>(
         InventoryIter::new(&own_inv)
     };
     let mut count = 0u32;
+    let mut next = None;
     while let Some(idx) = inv_iter.next(&deps.storage)? {
         if let Some(id) = may_load::<String, _>(&map2id, &idx.to_le_bytes())? {
             list_it = known_pass;
@@ -2782,12 +4944,163 @@ pub fn query_tokens<S: Storage, A: Api, Q: Querier>(
                 count += 1;
                 // exit if we hit the limit
                 if count >= cut_off {
+                    // provide a cursor if the owner has more tokens, even if the querier may
+                    // not be permitted to view them
+                    if inv_iter.next(&deps.storage)?.is_some() {
+                        next = tokens.last().cloned();
+                    }
                     break;
                 }
             }
         }
     }
-    to_binary(&QueryAnswer::TokenList { tokens })
+    to_binary(&QueryAnswer::TokenList { tokens, next })
+}
+
+/// Returns QueryResult displaying an optionally paginated list of the owner's tokens that the
+/// operator currently has permission to transfer, either per token or for the whole
+/// inventory.  Transfer locked and frozen tokens are omitted because they can not be
+/// transferred
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `owner` - a reference to the address whose tokens should be displayed
+/// * `operator` - a reference to the address whose transfer permissions are checked
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display tokens after this one in the owner's inventory
+/// * `limit` - optional max number of tokens to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_operator_tokens<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    owner: &HumanAddr,
+    operator: &HumanAddr,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let owner_raw = deps.api.canonical_address(owner)?;
+    let operator_raw = deps.api.canonical_address(operator)?;
+    let querier = get_querier(deps, viewer, from_permit)?;
+    if querier.as_ref() != Some(&owner_raw) && querier.as_ref() != Some(&operator_raw) {
+        return Err(StdError::generic_err(
+            "Only the owner or the operator may view the tokens the operator may transfer",
+        ));
+    }
+    let cut_off = limit.unwrap_or(30);
+    // TODO remove this when BlockInfo becomes available to queries
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let exp_idx = PermissionType::Transfer.to_usize();
+    let own_inv = Inventory::new(&deps.storage, owner_raw)?;
+    let owner_slice = own_inv.owner.as_slice();
+    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    let mut oper_for: Vec<CanonicalAddr> = Vec::new();
+    let operable = |token: &Token, idx: u32, id: &str, oper_for: &mut Vec<CanonicalAddr>| {
+        Ok(!token.transfer_locked
+            && !is_frozen(&deps.storage, idx)?
+            && check_perm_core(
+                deps,
+                &block,
+                token,
+                id,
+                Some(&operator_raw),
+                owner_slice,
+                exp_idx,
+                oper_for,
+                "",
+            )
+            .is_ok())
+    };
+    let mut inv_iter = if let Some(after) = start_after {
+        // the same error for every bad cursor, so an operator can not probe the owner's inventory
+        let cursor_err = format!("Token ID: {} is not one of the operator's tokens", after);
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let idx: u32 = may_load(&map2idx, after.as_bytes())?
+            .ok_or_else(|| StdError::generic_err(&cursor_err))?;
+        let token: Token = json_may_load(&info_store, &idx.to_le_bytes())?
+            .ok_or_else(|| StdError::generic_err("Token info storage is corrupt"))?;
+        if token.owner != own_inv.owner || !operable(&token, idx, after, &mut oper_for)? {
+            return Err(StdError::generic_err(cursor_err));
+        }
+        InventoryIter::start_after(&deps.storage, &own_inv, idx, &cursor_err)?
+    } else {
+        InventoryIter::new(&own_inv)
+    };
+    let mut tokens: Vec<String> = Vec::new();
+    let mut next = None;
+    if cut_off > 0 {
+        while let Some(idx) = inv_iter.next(&deps.storage)? {
+            if let Some(id) = may_load::<String, _>(&map2id, &idx.to_le_bytes())? {
+                if let Some(token) = json_may_load::<Token, _>(&info_store, &idx.to_le_bytes())? {
+                    if operable(&token, idx, &id, &mut oper_for)? {
+                        tokens.push(id);
+                        if tokens.len() >= cut_off as usize {
+                            // provide a cursor if the owner has more tokens, even if the
+                            // operator may not transfer them
+                            if inv_iter.next(&deps.storage)?.is_some() {
+                                next = tokens.last().cloned();
+                            }
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    to_binary(&QueryAnswer::TokenList { tokens, next })
+}
+
+/// Returns QueryResult displaying every address that currently has permission to transfer a
+/// token.  Only the token owner may view this
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_who_can_transfer<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    let viewer_raw = prep_info.viewer_raw.as_ref();
+    if viewer_raw != Some(&prep_info.token.owner) {
+        return Err(StdError::generic_err(prep_info.err_msg));
+    }
+    let (owner, token_approvals, operators) =
+        get_owner_of_resp(deps, &prep_info.block, &prep_info.token, viewer_raw, false)?;
+    let frozen = is_frozen(&deps.storage, prep_info.idx)?;
+    let prefix_store = ReadonlyPrefixedStorage::new(PREFIX_PREFIX_APPROVALS, &deps.storage);
+    let stored: Vec<StoredPrefixApproval> =
+        may_load(&prefix_store, prep_info.token.owner.as_slice())?.unwrap_or_else(Vec::new);
+    let prefix_approvals = stored
+        .into_iter()
+        .filter(|a| token_id.starts_with(&a.prefix) && !a.expires.is_expired(&prep_info.block))
+        .map(|a| {
+            Ok(PrefixApproval {
+                operator: deps.api.human_address(&a.operator)?,
+                prefix: a.prefix,
+                expires: a.expires,
+            })
+        })
+        .collect::<StdResult<Vec<PrefixApproval>>>()?;
+    to_binary(&QueryAnswer::WhoCanTransfer {
+        owner,
+        transfer_locked: prep_info.token.transfer_locked,
+        frozen,
+        token_approvals,
+        operators,
+        prefix_approvals,
+    })
 }
 
 /// Returns QueryResult displaying true if the token has been unwrapped.  If sealed metadata
@@ -2796,26 +5109,283 @@ pub fn query_tokens<S: Storage, A: Api, Q: Querier>(
 /// # Arguments
 ///
 /// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
 pub fn query_is_unwrapped<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
     let config: Config = load(storage, CONFIG_KEY)?;
-    let get_token_res = get_token(storage, token_id, None);
-    match get_token_res {
-        Err(err) => match err {
-            // if the token id is not found, but token supply is private, just say
-            // the token's wrapped state is the same as a newly minted token
-            StdError::GenericErr { msg, .. }
-                if !config.token_supply_is_public && msg.contains("Token ID") =>
-            {
-                to_binary(&QueryAnswer::IsUnwrapped {
-                    token_is_unwrapped: !config.sealed_metadata_is_enabled,
-                })
+    to_binary(&QueryAnswer::IsUnwrapped {
+        token_is_unwrapped: token_is_unwrapped(storage, &config, token_id)?,
+    })
+}
+
+/// Returns QueryResult displaying whether each of the listed tokens has been unwrapped, in
+/// the order requested
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_ids` - list of token ids
+pub fn query_batch_is_unwrapped<S: ReadonlyStorage>(
+    storage: &S,
+    token_ids: &[String],
+) -> QueryResult {
+    let config: Config = load(storage, CONFIG_KEY)?;
+    let statuses = token_ids
+        .iter()
+        .map(|id| {
+            Ok(UnwrappedStatus {
+                token_id: id.clone(),
+                unwrapped: token_is_unwrapped(storage, &config, id)?,
+            })
+        })
+        .collect::<StdResult<Vec<UnwrappedStatus>>>()?;
+    to_binary(&QueryAnswer::BatchIsUnwrapped { statuses })
+}
+
+/// Returns StdResult<bool> which is true if the token has been unwrapped
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `config` - a reference to the Config
+/// * `token_id` - string slice of the token id
+fn token_is_unwrapped<S: ReadonlyStorage>(
+    storage: &S,
+    config: &Config,
+    token_id: &str,
+) -> StdResult<bool> {
+    match get_token(storage, token_id, None) {
+        Ok((token, _idx)) => Ok(token.unwrapped),
+        // if the token id is not found, but token supply is private, just say
+        // the token's wrapped state is the same as a newly minted token
+        Err(StdError::GenericErr { msg, .. })
+            if !config.token_supply_is_public && msg.contains("Token ID") =>
+        {
+            Ok(!config.sealed_metadata_is_enabled)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns QueryResult displaying true if the token id has been reserved and not yet minted
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
+pub fn query_is_reserved<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
+    let res_store = ReadonlyPrefixedStorage::new(PREFIX_RESERVED, storage);
+    let may_reserved: Option<CanonicalAddr> = may_load(&res_store, token_id.as_bytes())?;
+    to_binary(&QueryAnswer::IsReserved {
+        is_reserved: may_reserved.is_some(),
+    })
+}
+
+/// Returns QueryResult displaying whether the token can currently be transferred.  If it
+/// can not, a reason code of "contract_paused" or "locked" is also displayed
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
+pub fn query_is_transferable<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
+    let config: Config = load(storage, CONFIG_KEY)?;
+    let (locked, frozen) = match get_token(storage, token_id, None) {
+        Ok((token, idx)) => (token.transfer_locked, is_frozen(storage, idx)?),
+        // if the token id is not found, but token supply is private, just say
+        // the token's state is the same as a newly minted token
+        Err(StdError::GenericErr { msg, .. })
+            if !config.token_supply_is_public && msg.contains("Token ID") =>
+        {
+            (false, false)
+        }
+        Err(err) => return Err(err),
+    };
+    let reason = if config.status >= ContractStatus::StopTransactions.to_u8() {
+        Some("contract_paused".to_string())
+    } else if frozen {
+        Some("frozen".to_string())
+    } else if locked {
+        Some("locked".to_string())
+    } else {
+        None
+    };
+    to_binary(&QueryAnswer::IsTransferable {
+        transferable: reason.is_none(),
+        reason,
+    })
+}
+
+/// Returns QueryResult displaying true if the token has been frozen by the admin
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
+pub fn query_is_frozen<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
+    let config: Config = load(storage, CONFIG_KEY)?;
+    let is_frozen = match get_token(storage, token_id, None) {
+        Ok((_token, idx)) => is_frozen(storage, idx)?,
+        // if the token id is not found, but token supply is private, just say
+        // the token is not frozen like a newly minted token
+        Err(StdError::GenericErr { msg, .. })
+            if !config.token_supply_is_public && msg.contains("Token ID") =>
+        {
+            false
+        }
+        Err(err) => return Err(err),
+    };
+    to_binary(&QueryAnswer::IsFrozen { is_frozen })
+}
+
+/// Returns QueryResult displaying true if the claimed owner owns the token.  The actual owner
+/// is never displayed.  The claimed owner may always verify whether they own the token, but
+/// anyone else must be permitted to view the token's owner, so that guessing addresses does not
+/// leak private ownership
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `claimed_owner` - a reference to the address claiming to own the token
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_verify_ownership<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    claimed_owner: &HumanAddr,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let claimed_raw = deps.api.canonical_address(claimed_owner)?;
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    let opt_viewer = prep_info.viewer_raw.as_ref();
+    if opt_viewer != Some(&claimed_raw) {
+        check_permission(
+            deps,
+            &prep_info.block,
+            &prep_info.token,
+            token_id,
+            opt_viewer,
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            &prep_info.err_msg,
+            prep_info.owner_is_public,
+        )?;
+    }
+    to_binary(&QueryAnswer::VerifyOwnership {
+        is_owner: prep_info.token.owner == claimed_raw,
+    })
+}
+
+/// Returns QueryResult displaying the protected trait_types listed in a token's public
+/// metadata
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
+pub fn query_protected_attribute_types<S: ReadonlyStorage>(
+    storage: &S,
+    token_id: &str,
+) -> QueryResult {
+    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, storage);
+    let may_idx: Option<u32> = may_load(&map2idx, token_id.as_bytes())?;
+    // if token id was found
+    if let Some(idx) = may_idx {
+        let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, storage);
+        let may_meta: Option<Metadata> = may_load(&meta_store, &idx.to_le_bytes())?;
+        let protected_attributes = may_meta
+            .and_then(|m| m.extension)
+            .and_then(|e| e.protected_attributes)
+            .unwrap_or_default();
+        return to_binary(&QueryAnswer::ProtectedAttributeTypes {
+            protected_attributes,
+        });
+    }
+    let config: Config = load(storage, CONFIG_KEY)?;
+    // token id wasn't found
+    // if the token supply is public, let them know the token does not exist
+    if config.token_supply_is_public {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} not found",
+            token_id
+        )));
+    }
+    // otherwise, just return an empty list
+    to_binary(&QueryAnswer::ProtectedAttributeTypes {
+        protected_attributes: Vec::new(),
+    })
+}
+
+/// Returns QueryResult displaying the rarity score of a token's public attributes
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
+pub fn query_rarity_score<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
+    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, storage);
+    let may_idx: Option<u32> = may_load(&map2idx, token_id.as_bytes())?;
+    // if token id was found
+    if let Some(idx) = may_idx {
+        let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, storage);
+        let may_meta: Option<Metadata> = may_load(&meta_store, &idx.to_le_bytes())?;
+        let mut score = 0u64;
+        // protected attributes are not counted, so they do not contribute
+        for attr in unprotected_attributes_of(may_meta.as_ref()).into_iter() {
+            let cnt = get_trait_count(storage, attr)?;
+            // tokens minted before counts were tracked do not contribute
+            if cnt > 0 {
+                score += RARITY_SCALE / cnt as u64;
             }
-            _ => Err(err),
-        },
-        Ok((token, _idx)) => to_binary(&QueryAnswer::IsUnwrapped {
-            token_is_unwrapped: token.unwrapped,
-        }),
+        }
+        return to_binary(&QueryAnswer::RarityScore { score });
+    }
+    let config: Config = load(storage, CONFIG_KEY)?;
+    // token id wasn't found
+    // if the token supply is public, let them know the token does not exist
+    if config.token_supply_is_public {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} not found",
+            token_id
+        )));
+    }
+    // otherwise, just return a score of zero
+    to_binary(&QueryAnswer::RarityScore { score: 0 })
+}
+
+/// Returns QueryResult displaying the address that minted a token
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+pub fn query_token_creator<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+) -> QueryResult {
+    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+    let may_idx: Option<u32> = may_load(&map2idx, token_id.as_bytes())?;
+    // if token id was found
+    if let Some(idx) = may_idx {
+        let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, &deps.storage);
+        let may_run: Option<StoredMintRunInfo> = may_load(&run_store, &idx.to_le_bytes())?;
+        let creator = may_run
+            .map(|r| deps.api.human_address(&r.token_creator))
+            .transpose()?;
+        return to_binary(&QueryAnswer::TokenCreator { creator });
+    }
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    // token id wasn't found
+    // if the token supply is public, let them know the token does not exist
+    if config.token_supply_is_public {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} not found",
+            token_id
+        )));
     }
+    // otherwise, just return no creator
+    to_binary(&QueryAnswer::TokenCreator { creator: None })
 }
 
 /// Returns QueryResult displaying an optionally paginated list of all transactions
@@ -2828,27 +5398,50 @@ pub fn query_is_unwrapped<S: ReadonlyStorage>(storage: &S, token_id: &str) -> Qu
 /// * `page` - an optional page number.  If given, the most recent `page` times `page_size`
 ///            transactions will be skipped
 /// * `page_size` - optional max number of transactions to display
+/// * `tx_type` - optional type of transactions to display
 /// * `from_permit` - address derived from an Owner permit, if applicable
 pub fn query_transactions<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
     viewer: Option<ViewerInfo>,
     page: Option<u32>,
     page_size: Option<u32>,
+    tx_type: Option<TxType>,
     from_permit: Option<CanonicalAddr>,
 ) -> StdResult<Binary> {
     let address_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
         StdError::generic_err("This is being called incorrectly if there is no querier address")
     })?;
-    let (txs, total) = get_txs(
-        &deps.api,
-        &deps.storage,
-        &address_raw,
-        page.unwrap_or(0),
-        page_size.unwrap_or(30),
-    )?;
+    let page = page.unwrap_or(0);
+    let page_size = page_size.unwrap_or(30);
+    let (txs, total) = if let Some(typ) = tx_type.as_ref() {
+        get_txs_of_type(&deps.api, &deps.storage, &address_raw, page, page_size, typ)?
+    } else {
+        get_txs(&deps.api, &deps.storage, &address_raw, page, page_size)?
+    };
     to_binary(&QueryAnswer::TransactionHistory { total, txs })
 }
 
+/// Returns QueryResult displaying the names of the permits an address has revoked
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_revoked_permits<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let address_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
+        StdError::generic_err("This is being called incorrectly if there is no querier address")
+    })?;
+    let name_store = ReadonlyPrefixedStorage::new(PREFIX_REVOKED_NAMES, &deps.storage);
+    let permit_names: Vec<String> =
+        may_load(&name_store, address_raw.as_slice())?.unwrap_or_else(Vec::new);
+    to_binary(&QueryAnswer::RevokedPermits { permit_names })
+}
+
 /// Returns QueryResult after verifying that the specified address has transfer approval
 /// for all the listed tokens
 ///
@@ -3330,6 +5923,20 @@ fn check_perm_core<S: Storage, A: Api, Q: Querier>(
         if token.owner == *sender {
             return Ok(());
         }
+        // check if the sender may transfer the owner's tokens with this id's prefix
+        if exp_idx == PermissionType::Transfer.to_usize() {
+            let prefix_store = ReadonlyPrefixedStorage::new(PREFIX_PREFIX_APPROVALS, &deps.storage);
+            let approvals: Vec<StoredPrefixApproval> =
+                may_load(&prefix_store, owner_slice)?.unwrap_or_else(Vec::new);
+            let approved = approvals.iter().any(|a| {
+                a.operator == *sender
+                    && token_id.starts_with(&a.prefix)
+                    && !a.expires.is_expired(block)
+            });
+            if approved {
+                return Ok(());
+            }
+        }
         // check if the token is public or the sender has token permission.
         // Can't use find because even if the global or sender permission expired, you
         // still want to see if the other is still valid
@@ -3410,6 +6017,110 @@ fn check_perm_core<S: Storage, A: Api, Q: Querier>(
     Ok(())
 }
 
+/// Returns StdResult<()>
+///
+/// returns an error if the token has been locked against transfers
+///
+/// # Arguments
+///
+/// * `token` - a reference to the Token
+/// * `token_id` - token ID String slice
+fn check_transfer_lock(token: &Token, token_id: &str) -> StdResult<()> {
+    if token.transfer_locked {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is locked against transfers",
+            token_id
+        )));
+    }
+    Ok(())
+}
+
+/// Returns StdResult<bool> which is true if the token has been frozen by the admin
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `idx` - index of the token
+fn is_frozen<S: ReadonlyStorage>(storage: &S, idx: u32) -> StdResult<bool> {
+    let frozen_store = ReadonlyPrefixedStorage::new(PREFIX_FROZEN, storage);
+    Ok(may_load::<bool, _>(&frozen_store, &idx.to_le_bytes())?.unwrap_or(false))
+}
+
+/// Returns StdResult<()>
+///
+/// returns an error if the token has been frozen by the admin
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `idx` - index of the token
+/// * `token_id` - token ID String slice
+fn check_frozen<S: ReadonlyStorage>(storage: &S, idx: u32, token_id: &str) -> StdResult<()> {
+    if is_frozen(storage, idx)? {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} has been frozen by the admin",
+            token_id
+        )));
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()>
+///
+/// if the sender was authorized by a limited use "all" transfer permission, counts the use and
+/// revokes the permission once its last use is consumed
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `block` - a reference to the current BlockInfo
+/// * `token` - a reference to the token being transferred, before its permissions are cleared
+/// * `sender` - a reference to the address performing the transfer
+/// * `oper_for` - a mutable reference to a list of owners that gave the sender "all" permission
+fn use_oper_transfer<S: Storage>(
+    storage: &mut S,
+    block: &BlockInfo,
+    token: &Token,
+    sender: &CanonicalAddr,
+    oper_for: &mut Vec<CanonicalAddr>,
+) -> StdResult<()> {
+    let transfer_idx = PermissionType::Transfer.to_usize();
+    // token approvals take precedence over operator permissions
+    let has_tok_perm = token.permissions.iter().any(|p| {
+        p.address == *sender
+            && matches!(p.expirations[transfer_idx], Some(e) if !e.is_expired(block))
+    });
+    if token.owner == *sender || has_tok_perm {
+        return Ok(());
+    }
+    let owner_slice = token.owner.as_slice();
+    let mut all_store = PrefixedStorage::new(PREFIX_ALL_PERMISSIONS, storage);
+    let mut all_perm: Vec<Permission> =
+        json_may_load(&all_store, owner_slice)?.unwrap_or_else(Vec::new);
+    if let Some(pos) = all_perm.iter().position(|p| p.address == *sender) {
+        let perm = &mut all_perm[pos];
+        if let Some(max) = perm.max_uses {
+            perm.uses += 1;
+            if perm.uses >= max {
+                perm.expirations[transfer_idx] = None;
+                perm.max_uses = None;
+                perm.uses = 0;
+                // the sender must pass the permission check again for any later tokens
+                oper_for.retain(|o| *o != token.owner);
+                if perm.expirations.iter().all(|e| e.is_none()) {
+                    all_perm.swap_remove(pos);
+                }
+            }
+            if all_perm.is_empty() {
+                remove(&mut all_store, owner_slice);
+            } else {
+                json_save(&mut all_store, owner_slice, &all_perm)?;
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Returns StdResult<(Token, u32)>
 ///
 /// returns the token information if the sender has authorization
@@ -3506,6 +6217,46 @@ fn check_status(contract_status: u8, priority: u8) -> StdResult<()> {
     Ok(())
 }
 
+/// Returns StdResult<(Token, u32)> which is the token and its index if the message sender is
+/// permitted to update its metadata
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of the contract's environment
+/// * `config` - a reference to the Config
+/// * `token_id` - token id String slice of token whose metadata should be updated
+fn get_token_for_meta_update<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    env: &Env,
+    config: &Config,
+    token_id: &str,
+) -> StdResult<(Token, u32)> {
+    let custom_err = format!("Not authorized to update metadata of token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if !(token.owner == sender_raw && config.owner_may_update_metadata) {
+        let minters: Vec<CanonicalAddr> =
+            may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_else(Vec::new);
+        if !(minters.contains(&sender_raw) && config.minter_may_update_metadata) {
+            // metadata editors may always update metadata
+            let editors: Vec<CanonicalAddr> =
+                may_load(&deps.storage, METADATA_EDITORS_KEY)?.unwrap_or_else(Vec::new);
+            if !editors.contains(&sender_raw) {
+                return Err(StdError::generic_err(custom_err));
+            }
+        }
+    }
+    Ok((token, idx))
+}
+
 /// Returns StdResult<()>
 ///
 /// sets new metadata
@@ -3517,13 +6268,14 @@ fn check_status(contract_status: u8, priority: u8) -> StdResult<()> {
 /// * `idx` - the token identifier index
 /// * `prefix` - storage prefix for the type of metadata being updated
 /// * `metadata` - a reference to the new metadata
-#[allow(clippy::too_many_arguments)]
+/// * `config` - a reference to the Config
 fn set_metadata_impl<S: Storage>(
     storage: &mut S,
     token: &Token,
     idx: u32,
     prefix: &[u8],
     metadata: &Metadata,
+    config: &Config,
 ) -> StdResult<()> {
     // do not allow the altering of sealed metadata
     if !token.unwrapped && prefix == PREFIX_PRIV_META {
@@ -3531,12 +6283,51 @@ fn set_metadata_impl<S: Storage>(
             "The private metadata of a sealed token can not be modified",
         ));
     }
-    enforce_metadata_field_exclusion(metadata)?;
+    metadata.validate(config)?;
+    let is_public = prefix == PREFIX_PUB_META;
+    let may_old: Option<Metadata> = if is_public || config.mutable_trait_types.is_some() {
+        let meta_store = ReadonlyPrefixedStorage::new(prefix, storage);
+        may_load(&meta_store, &idx.to_le_bytes())?
+    } else {
+        None
+    };
+    if let Some(mutable) = config.mutable_trait_types.as_ref() {
+        metadata.validate_trait_changes(&may_old.clone().unwrap_or_default(), mutable)?;
+    }
+    if is_public {
+        update_trait_index(storage, idx, may_old.as_ref(), Some(metadata))?;
+    }
     let mut meta_store = PrefixedStorage::new(prefix, storage);
     save(&mut meta_store, &idx.to_le_bytes(), metadata)?;
     Ok(())
 }
 
+/// Returns StdResult<()>
+///
+/// merges a patch into the existing metadata and saves the result
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `token` - a reference to the token whose metadata should be updated
+/// * `idx` - the token identifier index
+/// * `prefix` - storage prefix for the type of metadata being updated
+/// * `patch` - the MetadataPatch to apply
+/// * `config` - a reference to the Config
+fn patch_metadata_impl<S: Storage>(
+    storage: &mut S,
+    token: &Token,
+    idx: u32,
+    prefix: &[u8],
+    patch: MetadataPatch,
+    config: &Config,
+) -> StdResult<()> {
+    let meta_store = ReadonlyPrefixedStorage::new(prefix, storage);
+    let mut metadata: Metadata = may_load(&meta_store, &idx.to_le_bytes())?.unwrap_or_default();
+    metadata.apply_patch(patch);
+    set_metadata_impl(storage, token, idx, prefix, &metadata, config)
+}
+
 // enum used to return correct response from SetWhitelistedApproval
 pub enum SetAppResp {
     SetWhitelistedApproval,
@@ -3602,6 +6393,8 @@ pub struct ProcessAccInfo {
     pub accesses: [Option<AccessLevel>; 3],
     // optional expiration
     pub expires: Option<Expiration>,
+    // optional number of transfers a transfer permission may authorize
+    pub max_uses: Option<u32>,
     // true if this is an operator trying to set permissions
     pub from_oper: bool,
 }
@@ -3626,6 +6419,10 @@ fn process_accesses<S: Storage>(
     proc_info: &mut ProcessAccInfo,
     all_perm_in: Option<Vec<Permission>>,
 ) -> StdResult<()> {
+    // a limit of zero uses would grant an approval that can never be used
+    if proc_info.max_uses == Some(0) {
+        return Err(StdError::generic_err("max_uses must be at least 1"));
+    }
     let owner_slice = owner.as_slice();
     let expiration = proc_info.expires.unwrap_or_default();
     let expirations = vec![expiration; 3];
@@ -3762,14 +6559,31 @@ fn process_accesses<S: Storage>(
             let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, storage);
             all_perm = json_may_load(&all_store, owner_slice)?.unwrap_or_else(Vec::new);
         }
-        // if there was an update to the "all" permissions
-        if alter_perm_list(
+        let mut updated = alter_perm_list(
             &mut all_perm,
             &alt_all_perm,
             address,
             &expirations,
             num_perm_types,
-        ) {
+        );
+        // granting or revoking "all" transfer permission resets its use limit
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        if alt_all_perm.add[transfer_idx] || alt_all_perm.remove[transfer_idx] {
+            let max_uses = if alt_all_perm.add[transfer_idx] {
+                proc_info.max_uses
+            } else {
+                None
+            };
+            if let Some(perm) = all_perm.iter_mut().find(|p| p.address == *address) {
+                if perm.max_uses != max_uses || perm.uses != 0 {
+                    perm.max_uses = max_uses;
+                    perm.uses = 0;
+                    updated = true;
+                }
+            }
+        }
+        // if there was an update to the "all" permissions
+        if updated {
             let mut all_store = PrefixedStorage::new(PREFIX_ALL_PERMISSIONS, storage);
             // if deleted last permitted address
             if all_perm.is_empty() {
@@ -3783,14 +6597,39 @@ fn process_accesses<S: Storage>(
     // Shouldn't need to check if token was given because if it wasn't we would have thrown an
     // error before setting the has_update flag, but let's include the check anyway
     if alt_tok_perm.has_update && proc_info.token_given {
-        // if there was an update to the token permissions
-        if alter_perm_list(
+        // drop expired permissions before applying the update, so the new permission is kept
+        // even if it has already expired
+        let pruned = proc_info.token.prune_expired(&env.block);
+        let mut updated = alter_perm_list(
             &mut proc_info.token.permissions,
             &alt_tok_perm,
             address,
             &expirations,
             num_perm_types,
-        ) {
+        );
+        // granting or revoking token transfer permission resets its use limit
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        if alt_tok_perm.add[transfer_idx] || alt_tok_perm.remove[transfer_idx] {
+            let max_uses = if alt_tok_perm.add[transfer_idx] {
+                proc_info.max_uses
+            } else {
+                None
+            };
+            if let Some(perm) = proc_info
+                .token
+                .permissions
+                .iter_mut()
+                .find(|p| p.address == *address)
+            {
+                if perm.max_uses != max_uses || perm.uses != 0 {
+                    perm.max_uses = max_uses;
+                    perm.uses = 0;
+                    updated = true;
+                }
+            }
+        }
+        // if there was an update to the token permissions
+        if updated || pruned {
             let mut info_store = PrefixedStorage::new(PREFIX_INFOS, storage);
             json_save(
                 &mut info_store,
@@ -3848,16 +6687,18 @@ fn process_accesses<S: Storage>(
                 let may_tok: Option<Token> = json_may_load(&info_store, &tok_key)?;
                 if let Some(mut load_tok) = may_tok {
                     // shouldn't ever fail this ownership check, but let's be safe
-                    if load_tok.owner == *owner
-                        && alter_perm_list(
+                    if load_tok.owner == *owner {
+                        let pruned = load_tok.prune_expired(&env.block);
+                        if alter_perm_list(
                             &mut load_tok.permissions,
                             &alt_load_tok_perm,
                             address,
                             &load_all_exp,
                             num_perm_types,
-                        )
-                    {
-                        json_save(&mut info_store, &tok_key, &load_tok)?;
+                        ) || pruned
+                        {
+                            json_save(&mut info_store, &tok_key, &load_tok)?;
+                        }
                     }
                 }
             }
@@ -3954,6 +6795,8 @@ fn alter_perm_list(
     let mut new_perm = Permission {
         address: address.clone(),
         expirations: [None; 3],
+        max_uses: None,
+        uses: 0,
     };
     let (perm, found, pos) = if let Some(pos) = perms.iter().position(|p| p.address == *address) {
         if let Some(p) = perms.get_mut(pos) {
@@ -4159,6 +7002,7 @@ fn update_owner_inventory<S: Storage>(
 /// * `recipient` - the recipient's address
 /// * `oper_for` - a mutable reference to a list of owners that gave the sender "all" permission
 /// * `inv_updates` - a mutable reference to the list of token inventories to update
+/// * `flat_fees` - a mutable reference to the list of flat fee royalties owed for the transfers
 /// * `memo` - optional memo for the transfer tx
 #[allow(clippy::too_many_arguments)]
 fn transfer_impl<S: Storage, A: Api, Q: Querier>(
@@ -4170,8 +7014,15 @@ fn transfer_impl<S: Storage, A: Api, Q: Querier>(
     recipient: CanonicalAddr,
     oper_for: &mut Vec<CanonicalAddr>,
     inv_updates: &mut Vec<InventoryUpdate>,
+    flat_fees: &mut Vec<(CanonicalAddr, Coin)>,
     memo: Option<String>,
 ) -> StdResult<CanonicalAddr> {
+    if matches!(memo.as_ref(), Some(m) if m.len() > MAX_MEMO_LEN) {
+        return Err(StdError::generic_err(format!(
+            "Memo for token ID: {} exceeds the maximum length of {} bytes",
+            token_id, MAX_MEMO_LEN
+        )));
+    }
     let (mut token, idx) = get_token_if_permitted(
         deps,
         block,
@@ -4181,6 +7032,9 @@ fn transfer_impl<S: Storage, A: Api, Q: Querier>(
         oper_for,
         config,
     )?;
+    check_transfer_lock(&token, &token_id)?;
+    check_frozen(&deps.storage, idx, &token_id)?;
+    use_oper_transfer(&mut deps.storage, block, &token, sender, oper_for)?;
     let old_owner = token.owner;
     // throw error if ownership would not change
     if old_owner == recipient {
@@ -4196,6 +7050,17 @@ fn transfer_impl<S: Storage, A: Api, Q: Querier>(
     // save updated token info
     let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
     json_save(&mut info_store, &idx.to_le_bytes(), &token)?;
+    // add any flat fee royalties owed for transferring this token
+    let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
+    if let Some(roy_inf) = may_load::<StoredRoyaltyInfo, _>(&roy_store, &idx.to_le_bytes())? {
+        flat_fees.extend(roy_inf.royalties.into_iter().filter_map(
+            |StoredRoyalty {
+                 recipient,
+                 flat_fee,
+                 ..
+             }| { flat_fee.map(|f| (recipient, f)) },
+        ));
+    }
     // log the inventory changes
     for addr in update_addrs.into_iter() {
         let inv_upd = if let Some(inv) = inv_updates.iter_mut().find(|i| i.inventory.owner == addr)
@@ -4249,9 +7114,10 @@ pub struct SendFrom {
     pub token_ids: Vec<String>,
 }
 
-/// Returns StdResult<Vec<CosmosMsg>>
+/// Returns StdResult<(Vec<CosmosMsg>, Vec<LogAttribute>)>
 ///
-/// transfer or sends a list of tokens and returns a list of ReceiveNft callbacks if applicable
+/// transfer or sends a list of tokens and returns a list of ReceiveNft callbacks if applicable,
+/// along with the log attributes of any royalties paid
 ///
 /// # Arguments
 ///
@@ -4268,10 +7134,11 @@ fn send_list<S: Storage, A: Api, Q: Querier>(
     sender: &CanonicalAddr,
     transfers: Option<Vec<Transfer>>,
     sends: Option<Vec<Send>>,
-) -> StdResult<Vec<CosmosMsg>> {
+) -> StdResult<(Vec<CosmosMsg>, Vec<LogAttribute>)> {
     let mut messages: Vec<CosmosMsg> = Vec::new();
     let mut oper_for: Vec<CanonicalAddr> = Vec::new();
     let mut inv_updates: Vec<InventoryUpdate> = Vec::new();
+    let mut flat_fees: Vec<(CanonicalAddr, Coin)> = Vec::new();
     let num_perm_types = PermissionType::ViewOwner.num_types();
     if let Some(mut xfers) = transfers {
         for xfer in xfers.drain(..) {
@@ -4286,6 +7153,7 @@ fn send_list<S: Storage, A: Api, Q: Querier>(
                     recipient_raw.clone(),
                     &mut oper_for,
                     &mut inv_updates,
+                    &mut flat_fees,
                     xfer.memo.clone(),
                 )?;
             }
@@ -4305,6 +7173,7 @@ fn send_list<S: Storage, A: Api, Q: Querier>(
                     contract_raw.clone(),
                     &mut oper_for,
                     &mut inv_updates,
+                    &mut flat_fees,
                     send.memo.clone(),
                 )?;
                 // compile list of all tokens being sent from each owner in this Send
@@ -4332,9 +7201,108 @@ fn send_list<S: Storage, A: Api, Q: Querier>(
             )?);
         }
     }
+    let (payments, logs) = pay_flat_fees(&deps.api, env, flat_fees)?;
+    messages.extend(payments);
     save(&mut deps.storage, CONFIG_KEY, &config)?;
     update_owner_inventory(&mut deps.storage, &inv_updates, num_perm_types)?;
-    Ok(messages)
+    Ok((messages, logs))
+}
+
+/// Returns StdResult<()>
+///
+/// adds a Coin to a list of Coins, combining it with any Coin of the same denom
+///
+/// # Arguments
+///
+/// * `coins` - a mutable reference to the list of Coins
+/// * `coin` - a reference to the Coin to add
+fn add_coin(coins: &mut Vec<Coin>, coin: &Coin) -> StdResult<()> {
+    if let Some(existing) = coins.iter_mut().find(|c| c.denom == coin.denom) {
+        existing.amount = existing
+            .amount
+            .u128()
+            .checked_add(coin.amount.u128())
+            .map(Uint128)
+            .ok_or_else(|| StdError::generic_err(format!("Overflow adding {}", coin.denom)))?;
+    } else {
+        coins.push(coin.clone());
+    }
+    Ok(())
+}
+
+/// Returns StdResult<(Vec<CosmosMsg>, Vec<LogAttribute>)>
+///
+/// verifies the sender attached enough funds to pay the flat fee royalties owed for a list of
+/// transfers, and if so, returns the messages paying each recipient and refunding any funds sent
+/// in excess of the fees owed, along with the `royalty_recipient_N` and `royalty_amount_N` log
+/// attributes of each payment
+///
+/// # Arguments
+///
+/// * `api` - a reference to the Api used to convert human and canonical addresses
+/// * `env` - a reference to the Env of the contract's environment
+/// * `flat_fees` - list of recipients and the flat fees they are owed
+fn pay_flat_fees<A: Api>(
+    api: &A,
+    env: &Env,
+    flat_fees: Vec<(CanonicalAddr, Coin)>,
+) -> StdResult<(Vec<CosmosMsg>, Vec<LogAttribute>)> {
+    let mut owed: Vec<Coin> = Vec::new();
+    let mut payments: Vec<(CanonicalAddr, Vec<Coin>)> = Vec::new();
+    for (recipient, fee) in flat_fees.into_iter() {
+        add_coin(&mut owed, &fee)?;
+        if let Some(payment) = payments.iter_mut().find(|p| p.0 == recipient) {
+            add_coin(&mut payment.1, &fee)?;
+        } else {
+            payments.push((recipient, vec![fee]));
+        }
+    }
+    let mut sent: Vec<Coin> = Vec::new();
+    for coin in env.message.sent_funds.iter() {
+        add_coin(&mut sent, coin)?;
+    }
+    for due in owed.iter() {
+        let paid = sent
+            .iter_mut()
+            .find(|c| c.denom == due.denom)
+            .map(|c| &mut c.amount);
+        let available = paid.as_ref().map(|a| a.u128()).unwrap_or_default();
+        if available < due.amount.u128() {
+            return Err(StdError::generic_err(format!(
+                "Flat fee royalties of {}{} are owed for these transfers, but only {}{} was sent",
+                due.amount, due.denom, available, due.denom
+            )));
+        }
+        if let Some(amount) = paid {
+            *amount = Uint128(available - due.amount.u128());
+        }
+    }
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    let mut logs: Vec<LogAttribute> = Vec::new();
+    for (i, (recipient, amount)) in payments.into_iter().enumerate() {
+        let to_address = api.human_address(&recipient)?;
+        let coins: Vec<String> = amount
+            .iter()
+            .map(|c| format!("{}{}", c.amount, c.denom))
+            .collect();
+        logs.push(log(format!("royalty_recipient_{}", i + 1), &to_address));
+        logs.push(log(format!("royalty_amount_{}", i + 1), coins.join(",")));
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            from_address: env.contract.address.clone(),
+            to_address,
+            amount,
+        }));
+    }
+    // refund anything that was not needed to pay the fees
+    let refund: Vec<Coin> = sent.into_iter().filter(|c| c.amount.u128() > 0).collect();
+    if !refund.is_empty() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            from_address: env.contract.address.clone(),
+            to_address: env.message.sender.clone(),
+            amount: refund,
+        }));
+    }
+    Ok((messages, logs))
 }
 
 /// Returns StdResult<()>
@@ -4369,6 +7337,9 @@ fn burn_list<S: Storage, A: Api, Q: Querier>(
                 &mut oper_for,
                 config,
             )?;
+            check_transfer_lock(&token, &token_id)?;
+            check_frozen(&deps.storage, idx, &token_id)?;
+            use_oper_transfer(&mut deps.storage, block, &token, sender, &mut oper_for)?;
             // log the inventory change
             let inv_upd = if let Some(inv) = inv_updates
                 .iter_mut()
@@ -4401,7 +7372,9 @@ fn burn_list<S: Storage, A: Api, Q: Querier>(
             remove(&mut info_store, &token_key);
             // remove metadata if existent
             let mut pub_store = PrefixedStorage::new(PREFIX_PUB_META, &mut deps.storage);
+            let may_pub: Option<Metadata> = may_load(&pub_store, &token_key)?;
             remove(&mut pub_store, &token_key);
+            update_trait_index(&mut deps.storage, idx, may_pub.as_ref(), None)?;
             let mut priv_store = PrefixedStorage::new(PREFIX_PRIV_META, &mut deps.storage);
             remove(&mut priv_store, &token_key);
             // remove mint run info if existent
@@ -4410,6 +7383,9 @@ fn burn_list<S: Storage, A: Api, Q: Querier>(
             // remove royalty info if existent
             let mut roy_store = PrefixedStorage::new(PREFIX_ROYALTY_INFO, &mut deps.storage);
             remove(&mut roy_store, &token_key);
+            // remove reveal commitment if existent
+            let mut commit_store = PrefixedStorage::new(PREFIX_REVEAL_COMMIT, &mut deps.storage);
+            remove(&mut commit_store, &token_key);
 
             let brnr = if token.owner == *sender {
                 None
@@ -4457,7 +7433,7 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
     for mint in mints.drain(..) {
         let id = mint.token_id.unwrap_or(format!("{}", config.mint_cnt));
         // check if id already exists
-        let mut map2idx = PrefixedStorage::new(PREFIX_MAP_TO_INDEX, &mut deps.storage);
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
         let may_exist: Option<u32> = may_load(&map2idx, id.as_bytes())?;
         if may_exist.is_some() {
             return Err(StdError::generic_err(format!(
@@ -4465,11 +7441,30 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
                 id
             )));
         }
+        // if the id is reserved, only the minter that reserved it may mint it
+        let mut res_store = PrefixedStorage::new(PREFIX_RESERVED, &mut deps.storage);
+        let may_reserved: Option<CanonicalAddr> = may_load(&res_store, id.as_bytes())?;
+        if let Some(reserver) = may_reserved {
+            if reserver != *sender_raw {
+                return Err(StdError::generic_err(format!(
+                    "Token ID {} is reserved by another minter",
+                    id
+                )));
+            }
+            remove(&mut res_store, id.as_bytes());
+            let reserved_cnt: u32 = may_load(&deps.storage, RESERVED_CNT_KEY)?.unwrap_or(0);
+            save(
+                &mut deps.storage,
+                RESERVED_CNT_KEY,
+                &reserved_cnt.saturating_sub(1),
+            )?;
+        }
         // increment token count
         config.token_cnt = config.token_cnt.checked_add(1).ok_or_else(|| {
             StdError::generic_err("Attempting to mint more tokens than the implementation limit")
         })?;
         // map new token id to its index
+        let mut map2idx = PrefixedStorage::new(PREFIX_MAP_TO_INDEX, &mut deps.storage);
         save(&mut map2idx, id.as_bytes(), &config.mint_cnt)?;
         let recipient = if let Some(o) = mint.owner {
             deps.api.canonical_address(&o)?
@@ -4481,6 +7476,7 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
             owner: recipient.clone(),
             permissions: Vec::new(),
             unwrapped: !config.sealed_metadata_is_enabled,
+            transfer_locked: false,
         };
 
         // save new token info
@@ -4509,15 +7505,32 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
         //
         // save the metadata
         if let Some(pub_meta) = mint.public_metadata {
-            enforce_metadata_field_exclusion(&pub_meta)?;
+            pub_meta.validate(config)?;
+            update_trait_index(&mut deps.storage, config.mint_cnt, None, Some(&pub_meta))?;
             let mut pub_store = PrefixedStorage::new(PREFIX_PUB_META, &mut deps.storage);
             save(&mut pub_store, &token_key, &pub_meta)?;
         }
         if let Some(priv_meta) = mint.private_metadata {
-            enforce_metadata_field_exclusion(&priv_meta)?;
+            priv_meta.validate(config)?;
             let mut priv_store = PrefixedStorage::new(PREFIX_PRIV_META, &mut deps.storage);
             save(&mut priv_store, &token_key, &priv_meta)?;
         }
+        // save the reveal commitment
+        if let Some(commit) = mint.reveal_commitment {
+            if !config.sealed_metadata_is_enabled {
+                return Err(StdError::generic_err(
+                    "Sealed metadata functionality is not enabled for this contract",
+                ));
+            }
+            let hash = hex::decode(&commit)
+                .ok()
+                .filter(|h| h.len() == 32)
+                .ok_or_else(|| {
+                    StdError::generic_err("Reveal commitment must be a hex-encoded sha256 hash")
+                })?;
+            let mut commit_store = PrefixedStorage::new(PREFIX_REVEAL_COMMIT, &mut deps.storage);
+            save(&mut commit_store, &token_key, &hash)?;
+        }
         // save the mint run info
         let (mint_run, serial_number, quantity_minted_this_run) =
             if let Some(ser) = mint.serial_number {
@@ -4546,6 +7559,7 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
             mint.royalty_info.as_ref(),
             default_roy.as_ref(),
             &token_key,
+            config.max_royalty_bps,
         )?;
         //
         //
@@ -4587,15 +7601,31 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
 /// * `default` - an optional reference to the default StoredRoyaltyInfo to use if royalty_info is
 ///               not provided
 /// * `key` - the storage key (either token key or default key)
+/// * `max_bps` - optional maximum sum of the rates of a provided RoyaltyInfo in basis points
 fn store_royalties<S: Storage, A: Api>(
     storage: &mut S,
     api: &A,
     royalty_info: Option<&RoyaltyInfo>,
     default: Option<&StoredRoyaltyInfo>,
     key: &[u8],
+    max_bps: Option<u16>,
 ) -> StdResult<()> {
     // if RoyaltyInfo is provided, check and save it
     if let Some(royal_inf) = royalty_info {
+        for royalty in royal_inf.royalties.iter() {
+            if let Some(fee) = royalty.flat_fee.as_ref() {
+                if royalty.rate != 0 {
+                    return Err(StdError::generic_err(
+                        "A flat fee royalty must have a rate of 0",
+                    ));
+                }
+                if fee.amount.u128() == 0 {
+                    return Err(StdError::generic_err(
+                        "A flat fee royalty must have a non-zero amount",
+                    ));
+                }
+            }
+        }
         // the allowed message length won't let enough u16 rates to overflow u128
         let total_rates: u128 = royal_inf.royalties.iter().map(|r| r.rate as u128).sum();
         let (royalty_den, overflow) =
@@ -4610,6 +7640,18 @@ fn store_royalties<S: Storage, A: Api>(
                 "The sum of royalty rates must not exceed 100%",
             ));
         }
+        // no single rate can exceed the sum, so only the sum needs to be checked against the cap
+        if let Some(max) = max_bps {
+            let total_bps = U256::from(total_rates) * U256::from(10000);
+            if total_bps > U256::from(max) * royalty_den {
+                // round up so a fractional excess is never displayed as the cap itself
+                let shown = (total_bps + royalty_den - U256::from(1)) / royalty_den;
+                return Err(StdError::generic_err(format!(
+                    "Royalty rates total {} basis points, which exceeds the maximum of {}",
+                    shown, max
+                )));
+            }
+        }
         let stored = royal_inf.to_stored(api)?;
         save(storage, key, &stored)
     } else if let Some(def) = default {
@@ -4620,22 +7662,6 @@ fn store_royalties<S: Storage, A: Api>(
     }
 }
 
-/// Returns StdResult<()>
-///
-/// makes sure that Metadata does not have both `token_uri` and `extension`
-///
-/// # Arguments
-///
-/// * `metadata` - a reference to Metadata
-fn enforce_metadata_field_exclusion(metadata: &Metadata) -> StdResult<()> {
-    if metadata.token_uri.is_some() && metadata.extension.is_some() {
-        return Err(StdError::generic_err(
-            "Metadata can not have BOTH token_uri AND extension",
-        ));
-    }
-    Ok(())
-}
-
 /// Returns StdResult<Option<CanonicalAddr>> from determining the querying address (if possible) either
 /// from a permit validation or a ViewerInfo
 ///
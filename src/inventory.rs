@@ -10,6 +10,8 @@ pub const PREFIX_INVENTORY_INFO: &[u8] = b"invinfo";
 pub const PREFIX_INVENTORY_MAP: &[u8] = b"invmap";
 /// storage prefix for inventory cells
 pub const PREFIX_INVENTORY_CELL: &[u8] = b"invcell";
+/// storage key for the number of addresses that own at least one token
+pub const OWNER_CNT_KEY: &[u8] = b"ownercnt";
 
 /// list element
 #[derive(Serialize, Deserialize)]
@@ -122,6 +124,11 @@ impl Inventory {
                 "This would put your token count above the amount supported by the contract",
             )
         })?;
+        // a first token means a new owner
+        if self.info.count == 1 {
+            let owners: u32 = may_load(storage, OWNER_CNT_KEY)?.unwrap_or(0);
+            save(storage, OWNER_CNT_KEY, &owners.saturating_add(1))?;
+        }
         // save the InventoryInfo if desired
         if save_info {
             let mut info_store = PrefixedStorage::new(PREFIX_INVENTORY_INFO, storage);
@@ -161,6 +168,11 @@ impl Inventory {
             self.info.free = Some(inv_idx);
             // decrement the count
             self.info.count = self.info.count.saturating_sub(1);
+            // the last token leaving means one less owner
+            if self.info.count == 0 {
+                let owners: u32 = may_load(storage, OWNER_CNT_KEY)?.unwrap_or(0);
+                save(storage, OWNER_CNT_KEY, &owners.saturating_sub(1))?;
+            }
             // save the freed cell
             let mut cell_store =
                 PrefixedStorage::multilevel(&[PREFIX_INVENTORY_CELL, owner_slice], storage);
@@ -174,6 +186,17 @@ impl Inventory {
         Ok(())
     }
 
+    /// Returns StdResult<u32>
+    ///
+    /// returns the number of addresses that currently own at least one token
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - a reference to the contract's storage
+    pub fn owner_count<S: ReadonlyStorage>(storage: &S) -> StdResult<u32> {
+        Ok(may_load(storage, OWNER_CNT_KEY)?.unwrap_or(0))
+    }
+
     /// Returns StdResult<HashSet<u32>>
     ///
     /// creates a HashSet from the Inventory, optionally omitting a specified token_idx
@@ -8,7 +8,7 @@ use secret_toolkit::permit::Permit;
 use crate::expiration::Expiration;
 use crate::mint_run::{MintRunInfo, SerialNumber};
 use crate::royalties::{DisplayRoyaltyInfo, RoyaltyInfo};
-use crate::token::{Extension, Metadata};
+use crate::token::{Extension, Metadata, MetadataPatch, Trait, DEFAULT_MAX_IMAGE_DATA_SIZE};
 
 /// Instantiation message
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -70,6 +70,21 @@ pub struct InitConfig {
     /// Indicates whether burn functionality should be enabled
     /// default: False
     pub enable_burn: Option<bool>,
+    /// optional maximum number of attributes a token's metadata may have
+    /// default: None (unlimited)
+    pub max_attributes: Option<u16>,
+    /// indicates whether authorized viewers may record views of a token's private metadata
+    /// default: False
+    pub track_views: Option<bool>,
+    /// maximum length in bytes of a token's `image_data` SVG
+    /// default: 65536
+    pub max_image_data_size: Option<u32>,
+    /// optional maximum sum of a token's royalty rates in basis points
+    /// default: None (no cap beyond 100%)
+    pub max_royalty_bps: Option<u16>,
+    /// algorithm used to randomly draw traits for clones
+    /// default: chacha20
+    pub prng_algorithm: Option<PrngAlgorithm>,
 }
 
 impl Default for InitConfig {
@@ -82,6 +97,11 @@ impl Default for InitConfig {
             minter_may_update_metadata: Some(true),
             owner_may_update_metadata: Some(false),
             enable_burn: Some(false),
+            max_attributes: None,
+            track_views: Some(false),
+            max_image_data_size: Some(DEFAULT_MAX_IMAGE_DATA_SIZE),
+            max_royalty_bps: None,
+            prng_algorithm: Some(DEFAULT_PRNG_ALGORITHM),
         }
     }
 }
@@ -116,6 +136,9 @@ pub enum HandleMsg {
         serial_number: Option<SerialNumber>,
         /// optional royalty information for this token
         royalty_info: Option<RoyaltyInfo>,
+        /// optional hex-encoded sha256 hash of the serialized private metadata that must be
+        /// provided in RevealWithProof to unwrap a sealed token
+        reveal_commitment: Option<String>,
         /// optional memo for the tx
         memo: Option<String>,
         /// optional message length padding
@@ -140,10 +163,27 @@ pub enum HandleMsg {
         mint_run_id: Option<String>,
         /// number of clones to mint
         quantity: u32,
+        /// optional prefix used to generate the clones' token ids as `<prefix>-<index>`, with
+        /// the index continuing from the last clone mint with this prefix and zero-padded to a
+        /// fixed width so the ids sort in order.  If omitted, the ids are the mint indices
+        id_prefix: Option<String>,
         /// optional owner address. if omitted, owned by the message sender
         owner: Option<HumanAddr>,
         /// optional public metadata that can be seen by everyone
         public_metadata: Option<Metadata>,
+        /// if true, the public metadata extension fields are overlaid onto the contract's
+        /// default extension template.  default: False
+        use_default_extension: Option<bool>,
+        /// optional list of traits with exactly one trait per clone.  The list will be shuffled
+        /// and each clone will have one of the traits added to its public metadata attributes
+        trait_pool: Option<Vec<Trait>>,
+        /// optional rarity tiers.  Each clone independently draws one of the traits with
+        /// probability proportional to its weight, and it is added to its public metadata
+        /// attributes
+        rarity_tiers: Option<Vec<WeightedTrait>>,
+        /// optional numeric traits.  Each clone is given a random value between 0 and max
+        /// for every one of them, which is added to its public metadata attributes
+        numeric_traits: Option<Vec<NumericTrait>>,
         /// optional private metadata that can only be seen by the owner and whitelist
         private_metadata: Option<Metadata>,
         /// optional royalty information for these tokens
@@ -153,6 +193,13 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// reserve token ids so that only the reserving minter may mint them
+    ReserveTokenIds {
+        /// list of token ids to reserve
+        token_ids: Vec<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// set the public and/or private metadata.  This can be called by either the token owner or
     /// a valid minter if they have been given this power by the appropriate config values
     SetMetadata {
@@ -165,6 +212,18 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// update only the provided fields of the public and/or private metadata, leaving all other
+    /// fields unchanged.  This can be called by anyone permitted to call SetMetadata
+    PatchMetadata {
+        /// id of the token whose metadata should be updated
+        token_id: String,
+        /// the optional update to the public metadata
+        public_metadata: Option<MetadataPatch>,
+        /// the optional update to the private metadata
+        private_metadata: Option<MetadataPatch>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// set royalty information.  If no token ID is provided, this royalty info will become the default
     /// RoyaltyInfo for any new tokens minted on the contract.  If a token ID is provided, this can only
     /// be called by the token creator and only when the creator is the current owner
@@ -179,6 +238,14 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// set the default extension template that MintNftClones can overlay clone metadata onto.
+    /// This can only be called by a minter
+    SetDefaultExtension {
+        /// the new template.  If None, the existing template will be deleted
+        extension: Option<Extension>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// Reveal the private metadata of a sealed token and mark the token as having been unwrapped
     Reveal {
         /// id of the token to unwrap
@@ -186,6 +253,53 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// unwrap a sealed token that was minted with a reveal commitment.  The provided private
+    /// metadata must hash to the commitment, and will become the token's private metadata
+    RevealWithProof {
+        /// id of the token to unwrap
+        token_id: String,
+        /// the private metadata whose hash matches the reveal commitment
+        private_metadata: Metadata,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// Reveal the private metadata of a list of sealed tokens.  The sender must own every
+    /// listed token, and tokens that have already been unwrapped or that have a reveal
+    /// commitment are skipped
+    BatchReveal {
+        /// ids of the tokens to unwrap
+        token_ids: Vec<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// lock or unlock a token against transfers, sends, and burns.  Only the token owner may
+    /// do this
+    SetTransferLock {
+        /// id of the token to lock/unlock
+        token_id: String,
+        /// true if the token should be locked
+        locked: bool,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// lock or unlock a list of tokens against transfers, sends, and burns.  The sender must
+    /// own every listed token, or none of them will be changed
+    BatchSetTransferLock {
+        /// ids of the tokens to lock/unlock
+        token_ids: Vec<String>,
+        /// true if the tokens should be locked
+        locked: bool,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// record a view of a token's private metadata.  Only an address permitted to view the
+    /// private metadata may do this, and only if view tracking is enabled
+    RecordView {
+        /// id of the token that was viewed
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// if a contract was instantiated to make ownership public by default, this will allow
     /// an address to make the ownership of their tokens private.  The address can still use
     /// SetGlobalApproval to make ownership public either inventory-wide or for a specific token
@@ -234,6 +348,10 @@ pub enum HandleMsg {
         token_id: String,
         /// optional expiration for this approval
         expires: Option<Expiration>,
+        /// optional number of transfers and burns this approval may authorize before it is
+        /// revoked.  Transferring or burning a token clears its approvals, so any limit allows
+        /// a single use
+        max_uses: Option<u32>,
         /// optional message length padding
         padding: Option<String>,
     },
@@ -256,6 +374,9 @@ pub enum HandleMsg {
         operator: HumanAddr,
         /// optional expiration for this approval
         expires: Option<Expiration>,
+        /// optional number of transfers and burns this approval may authorize before it is
+        /// revoked
+        max_uses: Option<u32>,
         /// optional message length padding
         padding: Option<String>,
     },
@@ -267,6 +388,28 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// gives the operator permission to transfer all of the message sender's tokens whose
+    /// ids start with the prefix
+    ApproveAllWithPrefix {
+        /// address being granted permission to transfer
+        operator: HumanAddr,
+        /// prefix of the ids of the tokens the operator may transfer
+        prefix: String,
+        /// optional expiration for this approval
+        expires: Option<Expiration>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// revokes the operator's permission to transfer the message sender's tokens whose ids
+    /// start with the prefix
+    RevokeAllWithPrefix {
+        /// address whose permission is revoked
+        operator: HumanAddr,
+        /// prefix of the approval being revoked
+        prefix: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// transfer a token
     TransferNft {
         /// recipient of the transfer
@@ -371,6 +514,27 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// add addresses that may update the metadata of any token
+    AddMetadataEditors {
+        /// list of addresses that can now update metadata
+        editors: Vec<HumanAddr>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// revoke metadata update authority from addresses
+    RemoveMetadataEditors {
+        /// list of addresses no longer allowed to update metadata
+        editors: Vec<HumanAddr>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// define list of addresses that may update the metadata of any token
+    SetMetadataEditors {
+        /// list of addresses with metadata update authority
+        editors: Vec<HumanAddr>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// change address with administrative power
     ChangeAdmin {
         /// address with admin authority
@@ -386,6 +550,44 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// set the maximum number of attributes a token's metadata may have.  None means unlimited
+    SetMaxAttributes {
+        /// optional maximum number of attributes
+        max_attributes: Option<u16>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set the maximum sum of royalty rates, in basis points, that new RoyaltyInfo may have.
+    /// None means only the 100% limit applies
+    SetMaxRoyalty {
+        /// optional maximum sum of royalty rates in basis points
+        max_royalty_bps: Option<u16>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set the trait_types whose attributes may be modified after minting.  None or an
+    /// empty list means every attribute may be modified
+    SetMutableTraitTypes {
+        /// optional list of the trait_types that may be modified
+        trait_types: Option<Vec<String>>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// freeze a token so that it can not be transferred, sent, or burned until it is unfrozen.
+    /// Only the admin may freeze tokens
+    FreezeToken {
+        /// id of the token to freeze
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// unfreeze a token that was frozen by the admin
+    UnfreezeToken {
+        /// id of the token to unfreeze
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// disallow the use of a permit
     RevokePermit {
         /// name of the permit that is no longer valid
@@ -424,10 +626,31 @@ pub struct Mint {
     pub serial_number: Option<SerialNumber>,
     /// optional royalty info for this token
     pub royalty_info: Option<RoyaltyInfo>,
+    /// optional hex-encoded sha256 hash of the serialized private metadata that must be
+    /// provided in RevealWithProof to unwrap a sealed token
+    pub reveal_commitment: Option<String>,
     /// optional memo for the tx
     pub memo: Option<String>,
 }
 
+/// a trait that may be drawn for a clone, with a relative weight
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
+pub struct WeightedTrait {
+    /// the trait added to the clone's public metadata attributes if drawn
+    pub attribute: Trait,
+    /// relative likelihood of this trait being drawn
+    pub weight: u32,
+}
+
+/// a numeric trait that is given a random value for each clone
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
+pub struct NumericTrait {
+    /// name of the trait
+    pub trait_type: String,
+    /// the largest value the trait may have
+    pub max: u32,
+}
+
 /// token burn info used when doing a BatchBurnNft
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
 pub struct Burn {
@@ -486,18 +709,47 @@ pub enum HandleAnswer {
         /// token id of the last minted clone
         last_minted: String,
     },
+    ReserveTokenIds {
+        status: ResponseStatus,
+    },
     SetMetadata {
         status: ResponseStatus,
     },
+    PatchMetadata {
+        status: ResponseStatus,
+    },
     SetRoyaltyInfo {
         status: ResponseStatus,
     },
+    SetDefaultExtension {
+        status: ResponseStatus,
+    },
     MakeOwnershipPrivate {
         status: ResponseStatus,
     },
     Reveal {
         status: ResponseStatus,
     },
+    RevealWithProof {
+        status: ResponseStatus,
+    },
+    BatchReveal {
+        /// number of tokens that were unwrapped
+        revealed: u32,
+        /// ids of the tokens that were skipped because they have a reveal commitment and must
+        /// be unwrapped with RevealWithProof
+        needs_proof: Vec<String>,
+    },
+    SetTransferLock {
+        status: ResponseStatus,
+    },
+    BatchSetTransferLock {
+        /// number of tokens whose lock status changed
+        updated: u32,
+    },
+    RecordView {
+        status: ResponseStatus,
+    },
     Approve {
         status: ResponseStatus,
     },
@@ -510,6 +762,12 @@ pub enum HandleAnswer {
     RevokeAll {
         status: ResponseStatus,
     },
+    ApproveAllWithPrefix {
+        status: ResponseStatus,
+    },
+    RevokeAllWithPrefix {
+        status: ResponseStatus,
+    },
     SetGlobalApproval {
         status: ResponseStatus,
     },
@@ -550,12 +808,36 @@ pub enum HandleAnswer {
     SetMinters {
         status: ResponseStatus,
     },
+    AddMetadataEditors {
+        status: ResponseStatus,
+    },
+    RemoveMetadataEditors {
+        status: ResponseStatus,
+    },
+    SetMetadataEditors {
+        status: ResponseStatus,
+    },
     ChangeAdmin {
         status: ResponseStatus,
     },
     SetContractStatus {
         status: ResponseStatus,
     },
+    SetMaxAttributes {
+        status: ResponseStatus,
+    },
+    SetMaxRoyalty {
+        status: ResponseStatus,
+    },
+    SetMutableTraitTypes {
+        status: ResponseStatus,
+    },
+    FreezeToken {
+        status: ResponseStatus,
+    },
+    UnfreezeToken {
+        status: ResponseStatus,
+    },
     RevokePermit {
         status: ResponseStatus,
     },
@@ -609,6 +891,15 @@ pub enum TxAction {
     },
 }
 
+/// tx types used to filter the transaction history
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TxType {
+    Transfer,
+    Mint,
+    Burn,
+}
+
 /// tx for display
 #[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
 #[serde(rename_all = "snake_case")]
@@ -636,12 +927,21 @@ pub enum QueryMsg {
     ContractConfig {},
     /// display the list of authorized minters
     Minters {},
+    /// display the list of authorized metadata editors
+    MetadataEditors {},
     /// display the number of tokens controlled by the contract.  The token supply must
     /// either be public, or the querier must be an authenticated minter
     NumTokens {
         /// optional address and key requesting to view the number of tokens
         viewer: Option<ViewerInfo>,
     },
+    /// display aggregate collection metrics: live tokens, burned tokens, and the number
+    /// of distinct owners.  The token supply must either be public, or the querier must
+    /// be an authenticated minter
+    CollectionStats {
+        /// optional address and key requesting to view the collection stats
+        viewer: Option<ViewerInfo>,
+    },
     /// display an optionally paginated list of all the tokens controlled by the contract.
     /// The token supply must either be public, or the querier must be an authenticated
     /// minter
@@ -653,6 +953,21 @@ pub enum QueryMsg {
         /// optional number of token ids to display
         limit: Option<u32>,
     },
+    /// display an optionally paginated list of the tokens whose public metadata has the
+    /// specified trait.  Traits whose trait_type is protected are not searchable.  The token
+    /// supply must either be public, or the querier must be an authenticated minter
+    TokensByTrait {
+        /// trait_type to search for.  An empty string matches traits without a trait_type
+        trait_type: String,
+        /// trait value to search for
+        value: String,
+        /// optional address and key requesting to view the list of tokens
+        viewer: Option<ViewerInfo>,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of token ids to display
+        limit: Option<u32>,
+    },
     /// display the owner of the specified token if authorized to view it.  If the requester
     /// is also the token's owner, the response will also include a list of any addresses
     /// that can transfer this token.  The transfer approval list is for CW721 compliance,
@@ -666,7 +981,13 @@ pub enum QueryMsg {
         include_expired: Option<bool>,
     },
     /// displays the public metadata of a token
-    NftInfo { token_id: String },
+    NftInfo {
+        token_id: String,
+        /// optional https gateway used to display ipfs:// urls as gateway urls
+        ipfs_gateway: Option<String>,
+        /// optional BCP-47 locale used to display localized names and descriptions
+        locale: Option<String>,
+    },
     /// displays all the information contained in the OwnerOf and NftInfo queries
     AllNftInfo {
         token_id: String,
@@ -681,6 +1002,17 @@ pub enum QueryMsg {
         token_id: String,
         /// optional address and key requesting to view the private metadata
         viewer: Option<ViewerInfo>,
+        /// optional https gateway used to display ipfs:// urls as gateway urls
+        ipfs_gateway: Option<String>,
+        /// optional BCP-47 locale used to display localized names and descriptions
+        locale: Option<String>,
+    },
+    /// display the number of recorded views of a token's private metadata.  Only the token
+    /// owner may view this
+    ViewStats {
+        token_id: String,
+        /// optional address and key requesting to view the stats
+        viewer: Option<ViewerInfo>,
     },
     /// displays all the information about a token that the viewer has permission to
     /// see.  This may include the owner, the public metadata, the private metadata, royalty
@@ -692,6 +1024,23 @@ pub enum QueryMsg {
         /// optionally include expired Approvals in the response list.  If ommitted or
         /// false, expired Approvals will be filtered out of the response
         include_expired: Option<bool>,
+        /// optional https gateway used to display ipfs:// urls as gateway urls
+        ipfs_gateway: Option<String>,
+        /// optional BCP-47 locale used to display localized names and descriptions
+        locale: Option<String>,
+    },
+    /// displays the public information of many tokens.  This never checks private metadata
+    /// access and does not require authentication
+    BatchPublicDossier { token_ids: Vec<String> },
+    /// display all the information of an optionally paginated list of tokens so they can be
+    /// minted in another contract.  Only the admin and minters may export tokens
+    ExportTokens {
+        /// address and viewing key of the admin or minter
+        viewer: ViewerInfo,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of tokens to display.  This is capped at 50
+        limit: Option<u32>,
     },
     /// list all the approvals in place for a specified token if given the owner's viewing
     /// key
@@ -727,6 +1076,16 @@ pub enum QueryMsg {
         /// false, expired Approvals will be filtered out of the response
         include_expired: Option<bool>,
     },
+    /// displays a list of the operators approved to transfer the owner's tokens whose ids
+    /// start with a prefix.  Only the owner's viewing key will authorize this query
+    PrefixApprovals {
+        owner: HumanAddr,
+        /// viewing key of the owner
+        viewing_key: String,
+        /// optionally include expired approvals in the response list.  If ommitted or
+        /// false, expired approvals will be filtered out of the response
+        include_expired: Option<bool>,
+    },
     /// displays a list of all the tokens belonging to the input owner in which the viewer
     /// has view_owner permission
     Tokens {
@@ -740,8 +1099,98 @@ pub enum QueryMsg {
         /// optional number of token ids to display
         limit: Option<u32>,
     },
+    /// display an optionally paginated list of the owner's tokens that the operator currently
+    /// has permission to transfer, excluding transfer locked tokens.  The viewer must be
+    /// either the owner or the operator
+    OperatorTokens {
+        owner: HumanAddr,
+        operator: HumanAddr,
+        /// address and viewing key of the querier
+        viewer: Option<ViewerInfo>,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of token ids to display
+        limit: Option<u32>,
+    },
+    /// display every address that currently has permission to transfer a token, either for
+    /// that token alone or for the owner's whole inventory.  Only the token owner may view this
+    WhoCanTransfer {
+        token_id: String,
+        /// address and viewing key of the querier
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the number of tokens the owner has, and how many of them are transfer locked
+    /// or still sealed.  The viewer must be the owner or have view_owner permission for all
+    /// of the owner's tokens
+    InventoryCounts {
+        owner: HumanAddr,
+        /// optional address and viewing key of the querier
+        viewer: Option<ViewerInfo>,
+    },
     /// display if a token is unwrapped
     IsUnwrapped { token_id: String },
+    /// display if each of the listed tokens is unwrapped
+    BatchIsUnwrapped { token_ids: Vec<String> },
+    /// display if a token id has been reserved
+    IsReserved { token_id: String },
+    /// display if a token can currently be transferred, and if not, why
+    IsTransferable { token_id: String },
+    /// display if a token has been frozen by the admin
+    IsFrozen { token_id: String },
+    /// display if the claimed owner owns a token.  This never displays the actual owner.  The
+    /// querier must be the claimed owner or be permitted to view the token's owner
+    VerifyOwnership {
+        token_id: String,
+        /// address claiming to own the token
+        claimed_owner: HumanAddr,
+        /// optional address and key requesting to verify the owner
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the trait_types listed as protected in a token's public metadata.  This does
+    /// not display the values of the protected traits
+    ProtectedAttributeTypes { token_id: String },
+    /// display a token's rarity score, the sum over its public attributes of 1 / the number
+    /// of tokens with the same trait value, scaled by 1,000,000
+    RarityScore { token_id: String },
+    /// display the address that minted a token.  This does not change when the token is
+    /// transferred
+    TokenCreator { token_id: String },
+    /// display every attribute of a token's public metadata with the specified trait_type.  If
+    /// the trait_type is listed in the public metadata's protected_attributes, the matching
+    /// attributes of the private metadata are displayed instead if the viewer is permitted
+    /// to view the private metadata
+    TokenTrait {
+        token_id: String,
+        /// trait_type of the attributes to display
+        trait_type: String,
+        /// optional address and key requesting to view the attributes
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the url and sha256 hash of every media file in a token's metadata.  Private
+    /// media files are only displayed if the viewer is permitted to view the private metadata
+    MediaHashes {
+        token_id: String,
+        /// optional address and key requesting to view the media hashes
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the canonical bytes of a token's public or private metadata that reveal
+    /// commitments are computed from.  Private metadata is only displayed if the viewer is
+    /// permitted to view it
+    MetadataCanonicalBytes {
+        token_id: String,
+        /// which of the token's metadata to display
+        which: MetadataType,
+        /// optional address and key requesting to view the metadata
+        viewer: Option<ViewerInfo>,
+    },
+    /// display only the token_uri of a token.  The public token_uri is displayed if there is
+    /// one, otherwise the private token_uri is displayed if the viewer is permitted to view
+    /// the private metadata
+    TokenUri {
+        token_id: String,
+        /// optional address and key requesting to view the token_uri
+        viewer: Option<ViewerInfo>,
+    },
     /// verify that the specified address has approval to transfer every listed token
     VerifyTransferApproval {
         /// list of tokens to verify approval for
@@ -761,6 +1210,14 @@ pub enum QueryMsg {
         page: Option<u32>,
         /// optional number of transactions per page
         page_size: Option<u32>,
+        /// optionally only display transactions of this type
+        tx_type: Option<TxType>,
+    },
+    /// display the names of the permits an address has revoked
+    RevokedPermits {
+        address: HumanAddr,
+        /// viewing key
+        viewing_key: String,
     },
     /// display the code hash a contract has registered with the token contract and whether
     /// the contract implements BatchReceivenft
@@ -777,6 +1234,8 @@ pub enum QueryMsg {
         /// optional address and key requesting to view the royalty information
         viewer: Option<ViewerInfo>,
     },
+    /// display the default extension template used by MintNftClones
+    DefaultExtension {},
     /// display the contract's creator
     ContractCreator {},
     /// perform queries by passing permits instead of viewing keys
@@ -788,6 +1247,68 @@ pub enum QueryMsg {
     },
 }
 
+/// url and integrity hash of a media file
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MediaHash {
+    /// url to the file
+    pub url: String,
+    /// optional hex-encoded sha256 hash of the file
+    pub sha256: Option<String>,
+}
+
+/// the unwrapped status of a token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UnwrappedStatus {
+    pub token_id: String,
+    pub unwrapped: bool,
+}
+
+/// the public information of a token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PublicDossier {
+    /// id of the token
+    pub token_id: String,
+    /// owner of the token if ownership is public
+    pub owner: Option<HumanAddr>,
+    /// the public metadata
+    pub public_metadata: Option<Metadata>,
+    /// royalty information without the recipient addresses
+    pub royalty_info: Option<DisplayRoyaltyInfo>,
+    /// mint run information
+    pub mint_run_info: Option<MintRunInfo>,
+    /// true if ownership is public
+    pub owner_is_public: bool,
+    /// expiration of public ownership
+    pub public_ownership_expiration: Option<Expiration>,
+    /// true if the private metadata is public
+    pub private_metadata_is_public: bool,
+    /// expiration of the private metadata being public
+    pub private_metadata_is_public_expiration: Option<Expiration>,
+    /// true if the token has been unwrapped
+    pub token_is_unwrapped: bool,
+}
+
+/// all the information of a token needed to mint it in another contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExportedToken {
+    /// id of the token
+    pub token_id: String,
+    /// owner of the token
+    pub owner: HumanAddr,
+    /// the public metadata
+    pub public_metadata: Option<Metadata>,
+    /// the private metadata
+    pub private_metadata: Option<Metadata>,
+    /// royalty information including the recipient addresses
+    pub royalty_info: Option<DisplayRoyaltyInfo>,
+    /// mint run information
+    pub mint_run_info: Option<MintRunInfo>,
+    /// true if the token has been unwrapped
+    pub token_is_unwrapped: bool,
+    /// true if the token is locked against transfers
+    pub transfer_locked: bool,
+}
+
 /// SNIP721 Approval
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Snip721Approval {
@@ -810,6 +1331,17 @@ pub struct Cw721Approval {
     pub expires: Expiration,
 }
 
+/// approval to transfer all of an owner's tokens whose ids start with a prefix
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PrefixApproval {
+    /// address that can transfer the tokens
+    pub operator: HumanAddr,
+    /// prefix of the ids of the tokens the operator may transfer
+    pub prefix: String,
+    /// expiration of this approval
+    pub expires: Expiration,
+}
+
 /// response of CW721 OwnerOf
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Cw721OwnerOfResponse {
@@ -825,6 +1357,8 @@ pub enum QueryAnswer {
     ContractInfo {
         name: String,
         symbol: String,
+        /// optional capabilities enabled on this instance
+        features: Vec<String>,
     },
     ContractConfig {
         token_supply_is_public: bool,
@@ -834,15 +1368,40 @@ pub enum QueryAnswer {
         minter_may_update_metadata: bool,
         owner_may_update_metadata: bool,
         burn_is_enabled: bool,
+        max_attributes: Option<u16>,
+        mutable_trait_types: Option<Vec<String>>,
+        track_views: bool,
+        max_image_data_size: u32,
+        max_royalty_bps: Option<u16>,
+        prng_algorithm: PrngAlgorithm,
     },
     Minters {
         minters: Vec<HumanAddr>,
     },
+    MetadataEditors {
+        editors: Vec<HumanAddr>,
+    },
     NumTokens {
         count: u32,
     },
+    CollectionStats {
+        /// number of tokens currently in existence
+        token_count: u32,
+        /// number of tokens that have been burned
+        burned_count: u32,
+        /// number of addresses that own at least one token
+        owner_count: u32,
+    },
     TokenList {
         tokens: Vec<String>,
+        /// the last token id displayed if there may be more tokens.  Pass this as
+        /// `start_after` to display the next page
+        next: Option<String>,
+    },
+    InventoryCounts {
+        total: u32,
+        transfer_locked: u32,
+        sealed: u32,
     },
     OwnerOf {
         owner: HumanAddr,
@@ -870,6 +1429,9 @@ pub enum QueryAnswer {
         token_uri: Option<String>,
         extension: Option<Extension>,
     },
+    ViewStats {
+        private_view_count: u32,
+    },
     AllNftInfo {
         access: Cw721OwnerOfResponse,
         info: Option<Metadata>,
@@ -888,16 +1450,89 @@ pub enum QueryAnswer {
         token_approvals: Option<Vec<Snip721Approval>>,
         inventory_approvals: Option<Vec<Snip721Approval>>,
     },
+    BatchPublicDossier {
+        dossiers: Vec<PublicDossier>,
+    },
+    ExportTokens {
+        tokens: Vec<ExportedToken>,
+        /// the last token id displayed if there are more tokens.  Pass this as `start_after`
+        /// to display the next page
+        next: Option<String>,
+    },
     ApprovedForAll {
         operators: Vec<Cw721Approval>,
     },
+    PrefixApprovals {
+        approvals: Vec<PrefixApproval>,
+    },
     IsUnwrapped {
         token_is_unwrapped: bool,
     },
+    BatchIsUnwrapped {
+        statuses: Vec<UnwrappedStatus>,
+    },
+    IsReserved {
+        is_reserved: bool,
+    },
+    IsTransferable {
+        transferable: bool,
+        /// reason code if the token can not be transferred
+        reason: Option<String>,
+    },
+    IsFrozen {
+        is_frozen: bool,
+    },
+    VerifyOwnership {
+        is_owner: bool,
+    },
+    ProtectedAttributeTypes {
+        protected_attributes: Vec<String>,
+    },
+    RarityScore {
+        score: u64,
+    },
+    TokenCreator {
+        /// None if the token was minted without recording its creator
+        creator: Option<HumanAddr>,
+    },
+    TokenTrait {
+        traits: Vec<Trait>,
+    },
+    MediaHashes {
+        public_media: Vec<MediaHash>,
+        /// None if the querier is not permitted to view the private metadata or it is sealed
+        private_media: Option<Vec<MediaHash>>,
+    },
+    MetadataCanonicalBytes {
+        /// JSON serialization of the metadata, or None if the token does not have that metadata
+        bytes: Option<Binary>,
+    },
+    TokenUri {
+        /// None if the token's metadata uses an extension instead of a token_uri, or if the
+        /// querier is not permitted to view the private token_uri
+        token_uri: Option<String>,
+    },
     VerifyTransferApproval {
         approved_for_all: bool,
         first_unapproved_token: Option<String>,
     },
+    WhoCanTransfer {
+        owner: HumanAddr,
+        /// true if the token is transfer locked, in which case no one may transfer it
+        transfer_locked: bool,
+        /// true if the token has been frozen by the admin, in which case no one may transfer it
+        frozen: bool,
+        /// addresses approved to transfer this token
+        token_approvals: Vec<Cw721Approval>,
+        /// addresses approved to transfer all of the owner's tokens
+        operators: Vec<Cw721Approval>,
+        /// addresses approved to transfer the owner's tokens whose ids start with a prefix of
+        /// this token's id
+        prefix_approvals: Vec<PrefixApproval>,
+    },
+    RevokedPermits {
+        permit_names: Vec<String>,
+    },
     TransactionHistory {
         /// total transaction count
         total: u64,
@@ -910,6 +1545,9 @@ pub enum QueryAnswer {
     RoyaltyInfo {
         royalty_info: Option<DisplayRoyaltyInfo>,
     },
+    DefaultExtension {
+        extension: Option<Extension>,
+    },
     ContractCreator {
         creator: Option<HumanAddr>,
     },
@@ -926,6 +1564,8 @@ pub enum ResponseStatus {
 #[serde(rename_all = "snake_case")]
 pub enum ContractStatus {
     Normal,
+    /// transfers, sends, and burns are allowed, but not mints or metadata/royalty changes
+    StopMutations,
     StopTransactions,
     StopAll,
 }
@@ -935,12 +1575,34 @@ impl ContractStatus {
     pub fn to_u8(&self) -> u8 {
         match self {
             ContractStatus::Normal => 0,
-            ContractStatus::StopTransactions => 1,
-            ContractStatus::StopAll => 2,
+            ContractStatus::StopMutations => 1,
+            ContractStatus::StopTransactions => 2,
+            ContractStatus::StopAll => 3,
         }
     }
 }
 
+/// algorithm a contract's random number generator uses to draw traits
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum PrngAlgorithm {
+    /// ChaCha stream cipher with 20 rounds
+    Chacha20,
+    /// ChaCha stream cipher with 8 rounds, which is faster but has a smaller security margin
+    Chacha8,
+}
+
+/// algorithm used when the init config does not specify one
+pub const DEFAULT_PRNG_ALGORITHM: PrngAlgorithm = PrngAlgorithm::Chacha20;
+
+/// which of a token's metadata a query applies to
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataType {
+    Public,
+    Private,
+}
+
 /// queries using permits instead of viewing keys
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -953,7 +1615,41 @@ pub enum QueryWithPermit {
         token_id: Option<String>,
     },
     /// displays the private metadata if permitted to view it
-    PrivateMetadata { token_id: String },
+    PrivateMetadata {
+        token_id: String,
+        /// optional https gateway used to display ipfs:// urls as gateway urls
+        ipfs_gateway: Option<String>,
+        /// optional BCP-47 locale used to display localized names and descriptions
+        locale: Option<String>,
+    },
+    /// display the number of recorded views of a token's private metadata.  Only the token
+    /// owner may view this
+    ViewStats { token_id: String },
+    /// display every attribute of a token's public metadata with the specified trait_type.  If
+    /// the trait_type is listed in the public metadata's protected_attributes, the matching
+    /// attributes of the private metadata are displayed instead if the permit creator is
+    /// permitted to view the private metadata
+    TokenTrait {
+        token_id: String,
+        /// trait_type of the attributes to display
+        trait_type: String,
+    },
+    /// display the url and sha256 hash of every media file in a token's metadata.  Private
+    /// media files are only displayed if the permit creator is permitted to view the private
+    /// metadata
+    MediaHashes { token_id: String },
+    /// display the canonical bytes of a token's public or private metadata that reveal
+    /// commitments are computed from.  Private metadata is only displayed if the permit
+    /// creator is permitted to view it
+    MetadataCanonicalBytes {
+        token_id: String,
+        /// which of the token's metadata to display
+        which: MetadataType,
+    },
+    /// display only the token_uri of a token.  The public token_uri is displayed if there is
+    /// one, otherwise the private token_uri is displayed if the permit creator is permitted
+    /// to view the private metadata
+    TokenUri { token_id: String },
     /// displays all the information about a token that the viewer has permission to
     /// see.  This may include the owner, the public metadata, the private metadata, royalty
     /// information, mint run information, and the token and inventory approvals
@@ -962,6 +1658,10 @@ pub enum QueryWithPermit {
         /// optionally include expired Approvals in the response list.  If ommitted or
         /// false, expired Approvals will be filtered out of the response
         include_expired: Option<bool>,
+        /// optional https gateway used to display ipfs:// urls as gateway urls
+        ipfs_gateway: Option<String>,
+        /// optional BCP-47 locale used to display localized names and descriptions
+        locale: Option<String>,
     },
     /// display the owner of the specified token if authorized to view it.  If the requester
     /// is also the token's owner, the response will also include a list of any addresses
@@ -973,6 +1673,13 @@ pub enum QueryWithPermit {
         /// false, expired Approvals will be filtered out of the response
         include_expired: Option<bool>,
     },
+    /// display if the claimed owner owns a token.  This never displays the actual owner.  The
+    /// permit creator must be the claimed owner or be permitted to view the token's owner
+    VerifyOwnership {
+        token_id: String,
+        /// address claiming to own the token
+        claimed_owner: HumanAddr,
+    },
     /// displays all the information contained in the OwnerOf and NftInfo queries
     AllNftInfo {
         token_id: String,
@@ -998,10 +1705,18 @@ pub enum QueryWithPermit {
         page: Option<u32>,
         /// optional number of transactions per page
         page_size: Option<u32>,
+        /// optionally only display transactions of this type
+        tx_type: Option<TxType>,
     },
+    /// display the names of the permits the permit creator has revoked
+    RevokedPermits {},
     /// display the number of tokens controlled by the contract.  The token supply must
     /// either be public, or the querier must be an authenticated minter
     NumTokens {},
+    /// display aggregate collection metrics: live tokens, burned tokens, and the number
+    /// of distinct owners.  The token supply must either be public, or the querier must
+    /// be an authenticated minter
+    CollectionStats {},
     /// display an optionally paginated list of all the tokens controlled by the contract.
     /// The token supply must either be public, or the querier must be an authenticated
     /// minter
@@ -1011,6 +1726,18 @@ pub enum QueryWithPermit {
         /// optional number of token ids to display
         limit: Option<u32>,
     },
+    /// display an optionally paginated list of the tokens whose public metadata has the
+    /// specified trait.  Traits whose trait_type is protected are not searchable
+    TokensByTrait {
+        /// trait_type to search for.  An empty string matches traits without a trait_type
+        trait_type: String,
+        /// trait value to search for
+        value: String,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of token ids to display
+        limit: Option<u32>,
+    },
     /// list all the approvals in place for a specified token if given the owner's permit
     TokenApprovals {
         token_id: String,
@@ -1026,6 +1753,13 @@ pub enum QueryWithPermit {
         /// false, expired Approvals will be filtered out of the response
         include_expired: Option<bool>,
     },
+    /// displays a list of the operators approved to transfer the permit creator's tokens
+    /// whose ids start with a prefix
+    PrefixApprovals {
+        /// optionally include expired approvals in the response list.  If ommitted or
+        /// false, expired approvals will be filtered out of the response
+        include_expired: Option<bool>,
+    },
     /// displays a list of all the tokens belonging to the input owner in which the permit
     /// creator has view_owner permission
     Tokens {
@@ -1035,4 +1769,30 @@ pub enum QueryWithPermit {
         /// optional number of token ids to display
         limit: Option<u32>,
     },
+    /// display the number of tokens the owner has, and how many of them are transfer locked
+    /// or still sealed.  The permit creator must be the owner or have view_owner permission
+    /// for all of the owner's tokens
+    InventoryCounts { owner: HumanAddr },
+    /// display an optionally paginated list of the owner's tokens that the operator currently
+    /// has permission to transfer, excluding transfer locked tokens.  The permit creator must
+    /// be either the owner or the operator
+    OperatorTokens {
+        owner: HumanAddr,
+        operator: HumanAddr,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of token ids to display
+        limit: Option<u32>,
+    },
+    /// display every address that currently has permission to transfer a token, either for
+    /// that token alone or for the owner's whole inventory.  Only the token owner may view this
+    WhoCanTransfer { token_id: String },
+    /// display all the information of an optionally paginated list of tokens so they can be
+    /// minted in another contract.  Only the admin and minters may export tokens
+    ExportTokens {
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of tokens to display.  This is capped at 50
+        limit: Option<u32>,
+    },
 }
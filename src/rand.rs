@@ -1,6 +1,9 @@
-use rand_chacha::ChaChaRng;
+use cosmwasm_std::{StdError, StdResult};
+use rand_chacha::{ChaCha20Rng, ChaCha8Rng};
 use rand_core::{RngCore, SeedableRng};
 
+use crate::msg::{PrngAlgorithm, DEFAULT_PRNG_ALGORITHM};
+
 use sha2::{Digest, Sha256};
 
 pub fn sha_256(data: &[u8]) -> [u8; 32] {
@@ -13,12 +16,47 @@ pub fn sha_256(data: &[u8]) -> [u8; 32] {
     result
 }
 
+/// the stream cipher backing a Prng
+enum PrngStream {
+    ChaCha20(ChaCha20Rng),
+    ChaCha8(ChaCha8Rng),
+}
+
+impl PrngStream {
+    fn from_seed(algorithm: &PrngAlgorithm, seed: [u8; 32]) -> Self {
+        match algorithm {
+            PrngAlgorithm::Chacha20 => PrngStream::ChaCha20(ChaCha20Rng::from_seed(seed)),
+            PrngAlgorithm::Chacha8 => PrngStream::ChaCha8(ChaCha8Rng::from_seed(seed)),
+        }
+    }
+
+    fn algorithm(&self) -> PrngAlgorithm {
+        match self {
+            PrngStream::ChaCha20(_) => PrngAlgorithm::Chacha20,
+            PrngStream::ChaCha8(_) => PrngAlgorithm::Chacha8,
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            PrngStream::ChaCha20(rng) => rng.fill_bytes(dest),
+            PrngStream::ChaCha8(rng) => rng.fill_bytes(dest),
+        }
+    }
+}
+
 pub struct Prng {
-    rng: ChaChaRng,
+    rng: PrngStream,
 }
 
 impl Prng {
     pub fn new(seed: &[u8], entropy: &[u8]) -> Self {
+        Prng::with_algorithm(&DEFAULT_PRNG_ALGORITHM, seed, entropy)
+    }
+
+    /// Returns a Prng seeded from the seed and entropy that uses the specified algorithm.  The
+    /// same algorithm, seed, and entropy will always yield the same output
+    pub fn with_algorithm(algorithm: &PrngAlgorithm, seed: &[u8], entropy: &[u8]) -> Self {
         let mut hasher = Sha256::new();
 
         // write input message
@@ -29,7 +67,7 @@ impl Prng {
         let mut hash_bytes = [0u8; 32];
         hash_bytes.copy_from_slice(hash.as_slice());
 
-        let rng: ChaChaRng = ChaChaRng::from_seed(hash_bytes);
+        let rng = PrngStream::from_seed(algorithm, hash_bytes);
 
         Self { rng }
     }
@@ -40,6 +78,63 @@ impl Prng {
 
         bytes
     }
+
+    /// Shuffles the items in place using the Fisher-Yates algorithm.  The permutation only
+    /// depends on the seed and entropy used to create the Prng (and how much of its output has
+    /// already been consumed), so the same seed will always yield the same permutation
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = (self.next_u64() % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// Mixes additional entropy into the generator.  The new state is derived from both the
+    /// current stream and the supplied bytes, so prior state is never discarded, and the same
+    /// seed followed by the same sequence of reseeds will always yield the same output
+    pub fn reseed(&mut self, extra_entropy: &[u8]) {
+        let mut hasher = Sha256::new();
+        hasher.update(self.rand_bytes());
+        hasher.update(extra_entropy);
+        let hash = hasher.finalize();
+
+        let mut hash_bytes = [0u8; 32];
+        hash_bytes.copy_from_slice(hash.as_slice());
+
+        self.rng = PrngStream::from_seed(&self.rng.algorithm(), hash_bytes);
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.rand_bytes()[..8]);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Returns an index into `weights`, chosen with probability proportional to its weight.
+    /// The draw is 128 bits wide, so the bias of reducing it modulo the total weight is
+    /// negligible.  Errors if there are no weights or they are all zero
+    pub fn weighted_index(&mut self, weights: &[u32]) -> StdResult<usize> {
+        let total: u128 = weights.iter().map(|w| *w as u128).sum();
+        if total == 0 {
+            return Err(StdError::generic_err(
+                "At least one weight must be greater than zero",
+            ));
+        }
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&self.rand_bytes()[..16]);
+        let mut draw = u128::from_le_bytes(bytes) % total;
+        for (i, w) in weights.iter().enumerate() {
+            let w = *w as u128;
+            if draw < w {
+                return Ok(i);
+            }
+            draw -= w;
+        }
+        // the draw is always less than the total, so this is never reached
+        Err(StdError::generic_err(
+            "Weighted draw exceeded the total weight",
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -72,4 +167,124 @@ mod tests {
         assert_eq!(r3, rng.rand_bytes());
         assert_eq!(r4, rng.rand_bytes());
     }
+
+    #[test]
+    fn test_shuffle() {
+        let original: Vec<u32> = (0..20).collect();
+        let mut first = original.clone();
+        let mut rng = Prng::new(b"foo", b"bar!");
+        rng.shuffle(&mut first);
+        // same seed yields the same permutation
+        let mut second = original.clone();
+        let mut rng = Prng::new(b"foo", b"bar!");
+        rng.shuffle(&mut second);
+        assert_eq!(first, second);
+        assert_ne!(first, original);
+        // a different seed yields a different permutation
+        let mut third = original.clone();
+        let mut rng = Prng::new(b"foo", b"baz!");
+        rng.shuffle(&mut third);
+        assert_ne!(first, third);
+        // no elements are lost or duplicated
+        first.sort_unstable();
+        third.sort_unstable();
+        assert_eq!(first, original);
+        assert_eq!(third, original);
+        // empty and single element lists are left alone
+        let mut empty: Vec<u32> = Vec::new();
+        rng.shuffle(&mut empty);
+        assert!(empty.is_empty());
+        let mut single = vec![7u32];
+        rng.shuffle(&mut single);
+        assert_eq!(single, vec![7]);
+    }
+
+    #[test]
+    fn test_reseed() {
+        let mut plain = Prng::new(b"foo", b"bar!");
+        let mut reseeded = Prng::new(b"foo", b"bar!");
+        assert_eq!(plain.rand_bytes(), reseeded.rand_bytes());
+        // reseeding changes the subsequent output
+        reseeded.reseed(b"token 1");
+        let after = reseeded.rand_bytes();
+        assert_ne!(plain.rand_bytes(), after);
+        // replaying the same seed and reseeds is deterministic
+        let mut replay = Prng::new(b"foo", b"bar!");
+        replay.rand_bytes();
+        replay.reseed(b"token 1");
+        assert_eq!(replay.rand_bytes(), after);
+        // different extra entropy yields different output
+        let mut other = Prng::new(b"foo", b"bar!");
+        other.rand_bytes();
+        other.reseed(b"token 2");
+        assert_ne!(other.rand_bytes(), after);
+        // prior state is kept, so the same reseed from a different stream differs
+        let mut different = Prng::new(b"foo", b"baz!");
+        different.rand_bytes();
+        different.reseed(b"token 1");
+        assert_ne!(different.rand_bytes(), after);
+    }
+
+    #[test]
+    fn test_algorithms() {
+        // the default algorithm reproduces the original output
+        let mut default = Prng::new(b"foo", b"bar!");
+        let mut chacha20 = Prng::with_algorithm(&PrngAlgorithm::Chacha20, b"foo", b"bar!");
+        let first = chacha20.rand_bytes();
+        assert_eq!(default.rand_bytes(), first);
+        assert_eq!(first[..4], [155, 11, 21, 97]);
+        // each algorithm is deterministic
+        let mut chacha8 = Prng::with_algorithm(&PrngAlgorithm::Chacha8, b"foo", b"bar!");
+        let mut replay = Prng::with_algorithm(&PrngAlgorithm::Chacha8, b"foo", b"bar!");
+        let eight = chacha8.rand_bytes();
+        assert_eq!(replay.rand_bytes(), eight);
+        // different algorithms yield different output from the same seed
+        assert_ne!(eight, first);
+        // reseeding keeps the algorithm
+        chacha8.reseed(b"token 1");
+        replay.reseed(b"token 1");
+        chacha20.reseed(b"token 1");
+        let reseeded = chacha8.rand_bytes();
+        assert_eq!(replay.rand_bytes(), reseeded);
+        assert_ne!(chacha20.rand_bytes(), reseeded);
+    }
+
+    #[test]
+    fn test_weighted_index() {
+        // all zero or empty weights are rejected
+        let mut rng = Prng::new(b"foo", b"bar!");
+        assert!(rng.weighted_index(&[0, 0, 0]).is_err());
+        assert!(rng.weighted_index(&[]).is_err());
+        // zero weights are never drawn
+        for _ in 0..100 {
+            assert_eq!(rng.weighted_index(&[0, 5, 0]).unwrap(), 1);
+        }
+        // the distribution roughly matches the weights
+        let weights = [70u32, 20, 10];
+        let draws = 10000;
+        let mut counts = [0u32; 3];
+        for _ in 0..draws {
+            counts[rng.weighted_index(&weights).unwrap()] += 1;
+        }
+        for (count, weight) in counts.iter().zip(weights.iter()) {
+            let expected = draws * weight / 100;
+            // allow 2% of the total draws each way
+            assert!(
+                (*count as i64 - expected as i64).abs() < (draws / 50) as i64,
+                "count {} too far from expected {}",
+                count,
+                expected
+            );
+        }
+        // the same seed yields the same picks
+        let mut first = Prng::new(b"seed", b"entropy");
+        let mut second = Prng::new(b"seed", b"entropy");
+        let picks: Vec<usize> = (0..50)
+            .map(|_| first.weighted_index(&weights).unwrap())
+            .collect();
+        let replay: Vec<usize> = (0..50)
+            .map(|_| second.weighted_index(&weights).unwrap())
+            .collect();
+        assert_eq!(picks, replay);
+    }
 }
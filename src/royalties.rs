@@ -1,6 +1,8 @@
-use cosmwasm_std::{Api, CanonicalAddr, HumanAddr, StdResult};
+use cosmwasm_std::{Api, CanonicalAddr, Coin, HumanAddr, StdError, StdResult, Uint128};
+use primitive_types::U256;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 
 /// data for a single royalty
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
@@ -9,6 +11,10 @@ pub struct Royalty {
     pub recipient: HumanAddr,
     /// royalty rate
     pub rate: u16,
+    /// optional flat fee paid to the recipient whenever the token is transferred, instead of
+    /// a percentage of the sale price.  A flat fee royalty must have a rate of 0
+    #[serde(default)]
+    pub flat_fee: Option<Coin>,
 }
 
 impl Royalty {
@@ -21,6 +27,7 @@ impl Royalty {
         Ok(StoredRoyalty {
             recipient: api.canonical_address(&self.recipient)?,
             rate: self.rate,
+            flat_fee: self.flat_fee.clone(),
         })
     }
 }
@@ -59,6 +66,8 @@ pub struct DisplayRoyalty {
     pub recipient: Option<HumanAddr>,
     /// royalty rate
     pub rate: u16,
+    /// optional flat fee paid to the recipient whenever the token is transferred
+    pub flat_fee: Option<Coin>,
 }
 
 /// display all royalty information
@@ -70,6 +79,53 @@ pub struct DisplayRoyaltyInfo {
     pub royalties: Vec<DisplayRoyalty>,
 }
 
+impl DisplayRoyaltyInfo {
+    /// Returns StdResult<Vec<Uint128>> listing the amount owed to each royalty recipient from a
+    /// sale, in the same order as the royalties.  Each amount is rounded down, and the remainder
+    /// left over from rounding is given to the recipient with the largest rate (the first one
+    /// listed if tied), so the amounts always sum to exactly the total royalty owed on the sale
+    ///
+    /// # Arguments
+    ///
+    /// * `sale_amount` - the amount the token sold for
+    pub fn payouts(&self, sale_amount: Uint128) -> StdResult<Vec<Uint128>> {
+        let (royalty_den, overflow) =
+            U256::from(10).overflowing_pow(U256::from(self.decimal_places_in_rates));
+        if overflow {
+            return Err(StdError::generic_err(
+                "The number of decimal places used in the royalty rates is larger than supported",
+            ));
+        }
+        let sale = U256::from(sale_amount.u128());
+        let total_rates: u128 = self.royalties.iter().map(|r| r.rate as u128).sum();
+        let total = sale * U256::from(total_rates) / royalty_den;
+        let mut payouts: Vec<U256> = self
+            .royalties
+            .iter()
+            .map(|r| sale * U256::from(r.rate) / royalty_den)
+            .collect();
+        let mut largest = 0;
+        for (i, royalty) in self.royalties.iter().enumerate() {
+            if royalty.rate > self.royalties[largest].rate {
+                largest = i;
+            }
+        }
+        // the rounded down shares can never sum to more than the total
+        let paid = payouts.iter().fold(U256::zero(), |acc, p| acc + *p);
+        if let Some(payout) = payouts.get_mut(largest) {
+            *payout += total - paid;
+        }
+        payouts
+            .into_iter()
+            .map(|p| {
+                u128::try_from(p).map(Uint128).map_err(|_| {
+                    StdError::generic_err("Royalty payout is larger than the supported maximum")
+                })
+            })
+            .collect()
+    }
+}
+
 /// data for storing a single royalty
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
 pub struct StoredRoyalty {
@@ -77,6 +133,8 @@ pub struct StoredRoyalty {
     pub recipient: CanonicalAddr,
     /// royalty rate
     pub rate: u16,
+    /// optional flat fee paid to the recipient whenever the token is transferred
+    pub flat_fee: Option<Coin>,
 }
 
 impl StoredRoyalty {
@@ -95,6 +153,7 @@ impl StoredRoyalty {
         Ok(DisplayRoyalty {
             recipient,
             rate: self.rate,
+            flat_fee: self.flat_fee.clone(),
         })
     }
 }
@@ -11,7 +11,8 @@ use secret_toolkit::{
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::expiration::Expiration;
-use crate::msg::{Tx, TxAction};
+use crate::msg::{PrngAlgorithm, Tx, TxAction, TxType};
+use crate::token::{Metadata, Trait};
 
 /// storage key for config
 pub const CONFIG_KEY: &[u8] = b"config";
@@ -19,6 +20,8 @@ pub const CONFIG_KEY: &[u8] = b"config";
 pub const BLOCK_KEY: &[u8] = b"blockinfo";
 /// storage key for minters
 pub const MINTERS_KEY: &[u8] = b"minters";
+/// storage key for metadata editors
+pub const METADATA_EDITORS_KEY: &[u8] = b"metaeditors";
 /// storage key for this contract's address
 pub const MY_ADDRESS_KEY: &[u8] = b"myaddr";
 /// storage key for prng seed
@@ -27,6 +30,10 @@ pub const PRNG_SEED_KEY: &[u8] = b"prngseed";
 pub const CREATOR_KEY: &[u8] = b"creator";
 /// storage key for the default RoyaltyInfo to use if none is supplied when minting
 pub const DEFAULT_ROYALTY_KEY: &[u8] = b"defaultroy";
+/// storage key for the default Extension template used when minting clones
+pub const DEFAULT_EXTENSION_KEY: &[u8] = b"defaultext";
+/// storage key for the number of reserved token ids
+pub const RESERVED_CNT_KEY: &[u8] = b"reservedcnt";
 /// prefix for storage that maps ids to indices
 pub const PREFIX_MAP_TO_INDEX: &[u8] = b"map2idx";
 /// prefix for storage that maps indices to ids
@@ -45,6 +52,8 @@ pub const PREFIX_MINT_RUN: &[u8] = b"mintrun";
 pub const PREFIX_TXS: &[u8] = b"rawtxs";
 /// prefix for storage of tx ids
 pub const PREFIX_TX_IDS: &[u8] = b"txids";
+/// prefix for storage of tx ids of each tx type
+pub const PREFIX_TX_IDS_BY_TYPE: &[u8] = b"txidsbytype";
 /// prefix for storage of owner's list of "all" permissions
 pub const PREFIX_ALL_PERMISSIONS: &[u8] = b"allpermissions";
 /// prefix for storage of owner's list of tokens permitted to addresses
@@ -57,8 +66,29 @@ pub const PREFIX_VIEW_KEY: &[u8] = b"viewkeys";
 pub const PREFIX_RECEIVERS: &[u8] = b"receivers";
 /// prefix for the storage of mint run numbers
 pub const PREFIX_MINT_RUN_NUM: &[u8] = b"runnum";
+/// prefix for the storage of sealed private metadata reveal commitments
+pub const PREFIX_REVEAL_COMMIT: &[u8] = b"revealcommit";
+/// prefix for the storage of the minters that reserved token ids
+pub const PREFIX_RESERVED: &[u8] = b"reserved";
+/// prefix for the storage of the number of tokens with each unprotected public trait value
+pub const PREFIX_TRAIT_CNT: &[u8] = b"traitcnt";
+/// prefix for the storage of the shards of the indices of the tokens with each unprotected
+/// public trait value
+pub const PREFIX_TRAIT_TOKENS: &[u8] = b"traittokens";
+/// prefix for the storage of the number of recorded private metadata views of each token
+pub const PREFIX_VIEW_CNT: &[u8] = b"viewcnt";
 /// prefix for the storage of revoked permits
 pub const PREFIX_REVOKED_PERMITS: &str = "revoke";
+/// prefix for the storage of the list of names of each address' revoked permits
+pub const PREFIX_REVOKED_NAMES: &[u8] = b"revokednames";
+/// prefix for the storage of each owner's token id prefix approvals
+pub const PREFIX_PREFIX_APPROVALS: &[u8] = b"prefixapprovals";
+/// prefix for the storage of the tokens frozen by the admin
+pub const PREFIX_FROZEN: &[u8] = b"frozen";
+/// prefix for storage of the next index used to generate clone token ids from an id prefix
+pub const PREFIX_CLONE_ID_NEXT: &[u8] = b"cloneidnext";
+/// number of consecutive token indices whose trait index entries share one storage key
+pub const TRAIT_SHARD_SIZE: u32 = 64;
 
 /// Token contract config
 #[derive(Serialize, Debug, Deserialize, Clone, PartialEq)]
@@ -91,6 +121,18 @@ pub struct Config {
     pub owner_may_update_metadata: bool,
     /// is burn enabled
     pub burn_is_enabled: bool,
+    /// optional maximum number of attributes a token's metadata may have
+    pub max_attributes: Option<u16>,
+    /// optional list of the trait_types that may be modified after minting
+    pub mutable_trait_types: Option<Vec<String>>,
+    /// may authorized viewers record views of private metadata
+    pub track_views: bool,
+    /// maximum length in bytes of a token's image_data SVG
+    pub max_image_data_size: u32,
+    /// optional maximum sum of new royalty rates in basis points
+    pub max_royalty_bps: Option<u16>,
+    /// algorithm used to randomly draw traits for clones
+    pub prng_algorithm: PrngAlgorithm,
 }
 
 /// tx type and specifics
@@ -237,11 +279,11 @@ pub fn store_transfer<S: Storage>(
         recipient,
     } = tx.action
     {
-        append_tx_for_addr(storage, config.tx_cnt, &from)?;
-        append_tx_for_addr(storage, config.tx_cnt, &recipient)?;
+        append_tx_for_addr(storage, config.tx_cnt, &from, &TxType::Transfer)?;
+        append_tx_for_addr(storage, config.tx_cnt, &recipient, &TxType::Transfer)?;
         if let Some(sndr) = sender.as_ref() {
             if *sndr != recipient {
-                append_tx_for_addr(storage, config.tx_cnt, sndr)?;
+                append_tx_for_addr(storage, config.tx_cnt, sndr, &TxType::Transfer)?;
             }
         }
     }
@@ -281,9 +323,9 @@ pub fn store_mint<S: Storage>(
     let mut tx_store = PrefixedStorage::new(PREFIX_TXS, storage);
     json_save(&mut tx_store, &config.tx_cnt.to_le_bytes(), &tx)?;
     if let StoredTxAction::Mint { minter, recipient } = tx.action {
-        append_tx_for_addr(storage, config.tx_cnt, &recipient)?;
+        append_tx_for_addr(storage, config.tx_cnt, &recipient, &TxType::Mint)?;
         if recipient != minter {
-            append_tx_for_addr(storage, config.tx_cnt, &minter)?;
+            append_tx_for_addr(storage, config.tx_cnt, &minter, &TxType::Mint)?;
         }
     }
     config.tx_cnt += 1;
@@ -322,30 +364,56 @@ pub fn store_burn<S: Storage>(
     let mut tx_store = PrefixedStorage::new(PREFIX_TXS, storage);
     json_save(&mut tx_store, &config.tx_cnt.to_le_bytes(), &tx)?;
     if let StoredTxAction::Burn { owner, burner } = tx.action {
-        append_tx_for_addr(storage, config.tx_cnt, &owner)?;
+        append_tx_for_addr(storage, config.tx_cnt, &owner, &TxType::Burn)?;
         if let Some(bnr) = burner.as_ref() {
-            append_tx_for_addr(storage, config.tx_cnt, bnr)?;
+            append_tx_for_addr(storage, config.tx_cnt, bnr, &TxType::Burn)?;
         }
     }
     config.tx_cnt += 1;
     Ok(())
 }
 
-/// Returns StdResult<()> after saving tx id
+/// Returns StdResult<()> after saving tx id in the address' list of all txs and in its list
+/// of txs of this type
 ///
 /// # Arguments
 ///
 /// * `storage` - a mutable reference to the storage this item should go to
 /// * `tx_id` - the tx id to store
 /// * `address` - a reference to the address for which to store this tx id
+/// * `tx_type` - a reference to the type of the tx
 fn append_tx_for_addr<S: Storage>(
     storage: &mut S,
     tx_id: u64,
     address: &CanonicalAddr,
+    tx_type: &TxType,
 ) -> StdResult<()> {
     let mut store = PrefixedStorage::multilevel(&[PREFIX_TX_IDS, address.as_slice()], storage);
     let mut store = AppendStoreMut::attach_or_create(&mut store)?;
-    store.push(&tx_id)
+    store.push(&tx_id)?;
+    let mut type_store = PrefixedStorage::multilevel(
+        &[
+            PREFIX_TX_IDS_BY_TYPE,
+            address.as_slice(),
+            tx_type_key(tx_type),
+        ],
+        storage,
+    );
+    let mut type_store = AppendStoreMut::attach_or_create(&mut type_store)?;
+    type_store.push(&tx_id)
+}
+
+/// Returns &[u8] of the storage key used for the list of txs of a type
+///
+/// # Arguments
+///
+/// * `tx_type` - a reference to the TxType
+fn tx_type_key(tx_type: &TxType) -> &'static [u8] {
+    match tx_type {
+        TxType::Transfer => b"transfer",
+        TxType::Mint => b"mint",
+        TxType::Burn => b"burn",
+    }
 }
 
 /// Returns StdResult<(Vec<Tx>, u64)> of the txs to display and the total count of txs
@@ -396,6 +464,73 @@ pub fn get_txs<A: Api, S: ReadonlyStorage>(
     txs.map(|t| (t, count))
 }
 
+/// Returns StdResult<(Vec<Tx>, u64)> of the txs of the specified type to display along with
+/// the total count of txs of that type
+///
+/// # Arguments
+///
+/// * `api` - a reference to the Api used to convert human and canonical addresses
+/// * `storage` - a reference to the contract's storage
+/// * `address` - a reference to the address whose txs to display
+/// * `page` - page to start displaying
+/// * `page_size` - number of txs per page
+/// * `tx_type` - a reference to the type of txs to display
+pub fn get_txs_of_type<A: Api, S: ReadonlyStorage>(
+    api: &A,
+    storage: &S,
+    address: &CanonicalAddr,
+    page: u32,
+    page_size: u32,
+    tx_type: &TxType,
+) -> StdResult<(Vec<Tx>, u64)> {
+    let id_store = ReadonlyPrefixedStorage::multilevel(
+        &[
+            PREFIX_TX_IDS_BY_TYPE,
+            address.as_slice(),
+            tx_type_key(tx_type),
+        ],
+        storage,
+    );
+
+    // Try to access the storage of tx ids of this type for the account.
+    // If it doesn't exist yet, return an empty list of txs.
+    let id_store = if let Some(result) = AppendStore::<u64, _>::attach(&id_store) {
+        result?
+    } else {
+        return Ok((vec![], 0));
+    };
+    let count = id_store.len() as u64;
+    // access tx storage
+    let tx_store = ReadonlyPrefixedStorage::new(PREFIX_TXS, storage);
+    // only the txs on the requested page are loaded, starting from the latest tx
+    let txs: StdResult<Vec<Tx>> = id_store
+        .iter()
+        .rev()
+        .skip((page * page_size) as usize)
+        .take(page_size as usize)
+        .map(|id| {
+            id.map(|id| {
+                json_load(&tx_store, &id.to_le_bytes())
+                    .and_then(|tx: StoredTx| tx.into_humanized(api))
+            })
+            .and_then(|x| x)
+        })
+        .collect();
+
+    txs.map(|t| (t, count))
+}
+
+/// approval to transfer all of an owner's tokens whose ids start with a prefix
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct StoredPrefixApproval {
+    /// approved address
+    pub operator: CanonicalAddr,
+    /// prefix of the ids of the tokens the operator may transfer
+    pub prefix: String,
+    /// expiration of this approval
+    pub expires: Expiration,
+}
+
 /// permission to view token info/transfer tokens
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct Permission {
@@ -403,6 +538,166 @@ pub struct Permission {
     pub address: CanonicalAddr,
     /// list of permission expirations for this address
     pub expirations: [Option<Expiration>; 3],
+    /// optional number of transfers an "all" transfer permission may authorize
+    #[serde(default)]
+    pub max_uses: Option<u32>,
+    /// number of transfers the "all" transfer permission has authorized
+    #[serde(default)]
+    pub uses: u32,
+}
+
+/// Returns StdResult<()> after updating the count of tokens with each unprotected public trait
+/// value, and the index of the tokens with each of those values, to reflect a token's public
+/// metadata being replaced.  The index of each value is split into shards of TRAIT_SHARD_SIZE
+/// consecutive token indices, so an update only rewrites the shard holding this token
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `idx` - the token's index
+/// * `old` - optional reference to the public metadata being removed
+/// * `new` - optional reference to the public metadata being saved
+pub fn update_trait_index<S: Storage>(
+    storage: &mut S,
+    idx: u32,
+    old: Option<&Metadata>,
+    new: Option<&Metadata>,
+) -> StdResult<()> {
+    let shard_key = (idx / TRAIT_SHARD_SIZE).to_le_bytes();
+    for attr in unprotected_attributes_of(old).into_iter() {
+        let trait_type = attr.trait_type.as_deref().unwrap_or("").as_bytes();
+        let value = attr.value.as_bytes();
+        let mut tok_store =
+            PrefixedStorage::multilevel(&[PREFIX_TRAIT_TOKENS, trait_type, value], storage);
+        let mut tokens: Vec<u32> = may_load(&tok_store, &shard_key)?.unwrap_or_else(Vec::new);
+        if let Ok(pos) = tokens.binary_search(&idx) {
+            tokens.remove(pos);
+            if tokens.is_empty() {
+                remove(&mut tok_store, &shard_key);
+            } else {
+                save(&mut tok_store, &shard_key, &tokens)?;
+            }
+        }
+        let mut cnt_store = PrefixedStorage::multilevel(&[PREFIX_TRAIT_CNT, trait_type], storage);
+        let cnt = may_load::<u32, _>(&cnt_store, value)?
+            .unwrap_or(0)
+            .saturating_sub(1);
+        if cnt == 0 {
+            remove(&mut cnt_store, value);
+        } else {
+            save(&mut cnt_store, value, &cnt)?;
+        }
+    }
+    for attr in unprotected_attributes_of(new).into_iter() {
+        let trait_type = attr.trait_type.as_deref().unwrap_or("").as_bytes();
+        let value = attr.value.as_bytes();
+        let mut tok_store =
+            PrefixedStorage::multilevel(&[PREFIX_TRAIT_TOKENS, trait_type, value], storage);
+        let mut tokens: Vec<u32> = may_load(&tok_store, &shard_key)?.unwrap_or_else(Vec::new);
+        if let Err(pos) = tokens.binary_search(&idx) {
+            tokens.insert(pos, idx);
+            save(&mut tok_store, &shard_key, &tokens)?;
+        }
+        let mut cnt_store = PrefixedStorage::multilevel(&[PREFIX_TRAIT_CNT, trait_type], storage);
+        let cnt = may_load::<u32, _>(&cnt_store, value)?.unwrap_or(0);
+        save(&mut cnt_store, value, &(cnt + 1))?;
+    }
+    Ok(())
+}
+
+/// Returns StdResult<u32> of the number of tokens whose public metadata has the specified
+/// unprotected trait
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `attr` - a reference to the trait being counted
+pub fn get_trait_count<S: ReadonlyStorage>(storage: &S, attr: &Trait) -> StdResult<u32> {
+    let cnt_store = ReadonlyPrefixedStorage::multilevel(
+        &[
+            PREFIX_TRAIT_CNT,
+            attr.trait_type.as_deref().unwrap_or("").as_bytes(),
+        ],
+        storage,
+    );
+    Ok(may_load(&cnt_store, attr.value.as_bytes())?.unwrap_or(0))
+}
+
+/// Returns StdResult<Vec<u32>> of up to `limit` indices of the tokens whose public metadata has
+/// the specified unprotected trait, in ascending order.  Only the shards from the one holding
+/// `start_after` to the one holding the last minted index are read
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `trait_type` - the trait_type to look up.  An empty string matches traits without one
+/// * `value` - the trait value to look up
+/// * `start_after` - optionally only return indices greater than this one
+/// * `limit` - max number of indices to return
+/// * `mint_cnt` - the number of token indices that have been assigned
+pub fn get_trait_tokens<S: ReadonlyStorage>(
+    storage: &S,
+    trait_type: &str,
+    value: &str,
+    start_after: Option<u32>,
+    limit: usize,
+    mint_cnt: u32,
+) -> StdResult<Vec<u32>> {
+    let mut idxs: Vec<u32> = Vec::new();
+    if limit == 0 || mint_cnt == 0 {
+        return Ok(idxs);
+    }
+    let tok_store = ReadonlyPrefixedStorage::multilevel(
+        &[PREFIX_TRAIT_TOKENS, trait_type.as_bytes(), value.as_bytes()],
+        storage,
+    );
+    let first = start_after.map_or(0, |a| a / TRAIT_SHARD_SIZE);
+    let last = (mint_cnt - 1) / TRAIT_SHARD_SIZE;
+    for shard in first..=last {
+        let tokens: Vec<u32> = may_load(&tok_store, &shard.to_le_bytes())?.unwrap_or_else(Vec::new);
+        for idx in tokens
+            .into_iter()
+            .filter(|i| !matches!(start_after, Some(a) if *i <= a))
+        {
+            idxs.push(idx);
+            if idxs.len() >= limit {
+                return Ok(idxs);
+            }
+        }
+    }
+    Ok(idxs)
+}
+
+/// Returns the list of attributes in optional metadata whose trait_types are not listed in
+/// the metadata's protected_attributes
+///
+/// # Arguments
+///
+/// * `meta` - optional reference to the metadata
+pub fn unprotected_attributes_of(meta: Option<&Metadata>) -> Vec<&Trait> {
+    let protected = meta
+        .and_then(|m| m.extension.as_ref())
+        .and_then(|e| e.protected_attributes.as_ref());
+    attributes_of(meta)
+        .into_iter()
+        .filter(|a| {
+            !matches!(
+                protected,
+                Some(p) if p.iter().any(|t| Some(t.as_str()) == a.trait_type.as_deref())
+            )
+        })
+        .collect()
+}
+
+/// Returns the list of attributes in optional metadata
+///
+/// # Arguments
+///
+/// * `meta` - optional reference to the metadata
+fn attributes_of(meta: Option<&Metadata>) -> Vec<&Trait> {
+    meta.and_then(|m| m.extension.as_ref())
+        .and_then(|e| e.attributes.as_ref())
+        .map_or_else(Vec::new, |a| a.iter().collect())
 }
 
 /// permission types
@@ -1,9 +1,36 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::CanonicalAddr;
+use cosmwasm_std::{to_vec, BlockInfo, CanonicalAddr, StdError, StdResult};
 
-use crate::state::Permission;
+use crate::rand::Prng;
+use crate::state::{Config, Permission};
+
+/// url schemes accepted in metadata url fields
+pub const ALLOWED_URI_SCHEMES: [&str; 4] = ["http://", "https://", "ipfs://", "ar://"];
+
+/// media file types and the file extensions accepted for each
+pub const MEDIA_FILE_TYPES: [(&str, &[&str]); 6] = [
+    (
+        "image",
+        &["png", "jpg", "jpeg", "gif", "svg", "webp", "bmp", "avif"],
+    ),
+    ("video", &["mp4", "webm", "mov", "m4v", "ogv"]),
+    ("audio", &["mp3", "wav", "ogg", "flac", "aac", "m4a"]),
+    ("text", &["txt", "md", "json", "html", "csv", "xml"]),
+    ("font", &["ttf", "otf", "woff", "woff2"]),
+    (
+        "application",
+        &["pdf", "zip", "json", "glb", "gltf", "usdz"],
+    ),
+];
+
+/// default maximum length in bytes of a token's `image_data` SVG
+pub const DEFAULT_MAX_IMAGE_DATA_SIZE: u32 = 65536;
+
+/// content that may execute script when an `image_data` SVG is rendered.  Matched without
+/// regard to case
+pub const SVG_FORBIDDEN_CONTENT: [&str; 2] = ["<script", "javascript:"];
 
 /// token
 #[derive(Serialize, Deserialize)]
@@ -15,6 +42,34 @@ pub struct Token {
     /// true if this token has been unwrapped.  If sealed metadata is not enabled, all
     /// tokens are considered unwrapped
     pub unwrapped: bool,
+    /// true if the owner has locked this token against transfers, sends, and burns
+    #[serde(default)]
+    pub transfer_locked: bool,
+}
+
+impl Token {
+    /// Returns bool, true if any permission was pruned
+    ///
+    /// removes every expired permission expiration, and removes any permission that no longer
+    /// has an unexpired expiration
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - a reference to the current BlockInfo
+    pub fn prune_expired(&mut self, block: &BlockInfo) -> bool {
+        let mut pruned = false;
+        for perm in self.permissions.iter_mut() {
+            for exp in perm.expirations.iter_mut() {
+                if matches!(exp, Some(e) if e.is_expired(block)) {
+                    *exp = None;
+                    pruned = true;
+                }
+            }
+        }
+        self.permissions
+            .retain(|p| p.expirations.iter().any(|e| e.is_some()));
+        pruned
+    }
 }
 
 /// token metadata
@@ -27,6 +82,387 @@ pub struct Metadata {
     pub extension: Option<Extension>,
 }
 
+impl Metadata {
+    /// Returns StdResult<()> that will error unless exactly one of `token_uri` and `extension`
+    /// is provided
+    pub fn assert_valid(&self) -> StdResult<()> {
+        match (self.token_uri.is_some(), self.extension.is_some()) {
+            (true, true) => Err(StdError::generic_err(
+                "Metadata can not have BOTH token_uri AND extension",
+            )),
+            (false, false) => Err(StdError::generic_err(
+                "Metadata must have EITHER token_uri OR extension",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns StdResult<()> that will error if the metadata fails any of the checks applied
+    /// before metadata is saved
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - a reference to the Config holding the image_data and attribute limits
+    pub fn validate(&self, config: &Config) -> StdResult<()> {
+        self.assert_valid()?;
+        self.validate_uris()?;
+        self.validate_media_hashes()?;
+        self.validate_media_types()?;
+        self.validate_image_data(config.max_image_data_size)?;
+        self.validate_background_color()?;
+        self.validate_localizations()?;
+        self.validate_attribute_count(config.max_attributes)
+    }
+
+    /// Returns StdResult<Vec<u8>> of the JSON serialization of this metadata that reveal
+    /// commitments and revealed metadata hashes are computed from.  Fields are always
+    /// serialized in the order they are declared, and omitted fields are serialized as null
+    pub fn canonical_bytes(&self) -> StdResult<Vec<u8>> {
+        to_vec(self)
+    }
+
+    /// merges a patch into this metadata, only overwriting the fields the patch provides
+    ///
+    /// # Arguments
+    ///
+    /// * `patch` - the MetadataPatch to apply
+    pub fn apply_patch(&mut self, patch: MetadataPatch) {
+        if patch.token_uri.is_some() {
+            self.token_uri = patch.token_uri.clone();
+        }
+        if patch.has_extension_fields() {
+            let ext = self.extension.get_or_insert_with(Extension::default);
+            if patch.image.is_some() {
+                ext.image = patch.image;
+            }
+            if patch.image_data.is_some() {
+                ext.image_data = patch.image_data;
+            }
+            if patch.external_url.is_some() {
+                ext.external_url = patch.external_url;
+            }
+            if patch.description.is_some() {
+                ext.description = patch.description;
+            }
+            if patch.name.is_some() {
+                ext.name = patch.name;
+            }
+            if patch.attributes.is_some() {
+                ext.attributes = patch.attributes;
+            }
+            if patch.background_color.is_some() {
+                ext.background_color = patch.background_color;
+            }
+            if patch.animation_url.is_some() {
+                ext.animation_url = patch.animation_url;
+            }
+            if patch.youtube_url.is_some() {
+                ext.youtube_url = patch.youtube_url;
+            }
+            if patch.media.is_some() {
+                ext.media = patch.media;
+            }
+            if patch.protected_attributes.is_some() {
+                ext.protected_attributes = patch.protected_attributes;
+            }
+            if patch.localizations.is_some() {
+                ext.localizations = patch.localizations;
+            }
+        }
+    }
+
+    /// Returns StdResult<()> that will error if any url field does not use one of the
+    /// allowed schemes.  `image_data` holds raw SVG and is not checked
+    pub fn validate_uris(&self) -> StdResult<()> {
+        check_uri("token_uri", self.token_uri.as_ref())?;
+        if let Some(ext) = self.extension.as_ref() {
+            check_uri("image", ext.image.as_ref())?;
+            check_uri("external_url", ext.external_url.as_ref())?;
+            check_uri("animation_url", ext.animation_url.as_ref())?;
+            check_uri("youtube_url", ext.youtube_url.as_ref())?;
+            if let Some(media) = ext.media.as_ref() {
+                for file in media.iter() {
+                    check_uri("media url", Some(&file.url))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<()> that will error if the metadata has more attributes than allowed
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attributes` - optional maximum number of attributes.  None means unlimited
+    pub fn validate_attribute_count(&self, max_attributes: Option<u16>) -> StdResult<()> {
+        if let Some(max) = max_attributes {
+            let count = self
+                .extension
+                .as_ref()
+                .and_then(|e| e.attributes.as_ref())
+                .map_or(0, |a| a.len());
+            if count > max as usize {
+                return Err(StdError::generic_err(format!(
+                    "Metadata has {} attributes, but the maximum allowed is {}",
+                    count, max
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// replaces the name and description with those of the localization matching the locale
+    /// for display.  Fields the localization does not provide, or all fields if no locale is
+    /// provided or none of the localizations match it, are left unchanged
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - optional locale used to display localized names and descriptions
+    pub fn localize(&mut self, locale: Option<&str>) {
+        if let Some(l) = locale {
+            if let Some(ext) = self.extension.as_mut() {
+                let matched = ext
+                    .localizations
+                    .as_ref()
+                    .and_then(|locs| locs.iter().find(|loc| loc.locale.eq_ignore_ascii_case(l)))
+                    .cloned();
+                if let Some(loc) = matched {
+                    if loc.name.is_some() {
+                        ext.name = loc.name;
+                    }
+                    if loc.description.is_some() {
+                        ext.description = loc.description;
+                    }
+                }
+            }
+        }
+    }
+
+    /// rewrites every `ipfs://<cid>` url field as `<gateway>/<cid>` for display.  Nothing is
+    /// changed if no gateway is provided
+    ///
+    /// # Arguments
+    ///
+    /// * `gateway` - optional gateway used to display ipfs urls
+    pub fn rewrite_ipfs(&mut self, gateway: Option<&str>) {
+        if let Some(g) = gateway {
+            if let Some(uri) = self.token_uri.as_mut() {
+                rewrite_ipfs_uri(uri, g);
+            }
+            if let Some(ext) = self.extension.as_mut() {
+                let uris = vec![
+                    ext.image.as_mut(),
+                    ext.external_url.as_mut(),
+                    ext.animation_url.as_mut(),
+                    ext.youtube_url.as_mut(),
+                ];
+                for uri in uris.into_iter().flatten() {
+                    rewrite_ipfs_uri(uri, g);
+                }
+                if let Some(media) = ext.media.as_mut() {
+                    for file in media.iter_mut() {
+                        rewrite_ipfs_uri(&mut file.url, g);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns StdResult<()> that will error if updating from the old metadata would add,
+    /// remove, or change any attribute whose trait_type is not in the mutable list
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - a reference to the metadata being replaced
+    /// * `mutable` - list of the trait_types that may be modified
+    pub fn validate_trait_changes(&self, old: &Metadata, mutable: &[String]) -> StdResult<()> {
+        let locked = |meta: &Metadata| -> Vec<Trait> {
+            meta.extension
+                .as_ref()
+                .and_then(|e| e.attributes.as_ref())
+                .map_or_else(Vec::new, |a| {
+                    a.iter()
+                        .filter(
+                            |t| !matches!(t.trait_type.as_ref(), Some(y) if mutable.contains(y)),
+                        )
+                        .cloned()
+                        .collect()
+                })
+        };
+        let old_locked = locked(old);
+        let new_locked = locked(self);
+        if old_locked != new_locked {
+            // name the first locked trait_type that differs
+            let pos = (0..)
+                .find(|i| new_locked.get(*i) != old_locked.get(*i))
+                .unwrap_or(0);
+            let changed = new_locked
+                .get(pos)
+                .or_else(|| old_locked.get(pos))
+                .and_then(|t| t.trait_type.clone())
+                .unwrap_or_else(|| "with no trait_type".to_string());
+            return Err(StdError::generic_err(format!(
+                "Attribute {} may not be modified after minting",
+                changed
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<()> that will error if any media file has a sha256 that is not a
+    /// hex-encoded sha256 hash
+    pub fn validate_media_hashes(&self) -> StdResult<()> {
+        if let Some(media) = self.extension.as_ref().and_then(|e| e.media.as_ref()) {
+            for file in media.iter() {
+                check_sha256(file.sha256.as_ref())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<()> that will error if the `image_data` SVG is longer than allowed,
+    /// or contains script tags or javascript urls
+    ///
+    /// # Arguments
+    ///
+    /// * `max_size` - maximum length of `image_data` in bytes
+    pub fn validate_image_data(&self, max_size: u32) -> StdResult<()> {
+        if let Some(svg) = self.extension.as_ref().and_then(|e| e.image_data.as_ref()) {
+            if svg.len() > max_size as usize {
+                return Err(StdError::generic_err(format!(
+                    "image_data is {} bytes, but the maximum allowed is {}",
+                    svg.len(),
+                    max_size
+                )));
+            }
+            let lower = svg.to_lowercase();
+            if let Some(bad) = SVG_FORBIDDEN_CONTENT.iter().find(|b| lower.contains(*b)) {
+                return Err(StdError::generic_err(format!(
+                    "image_data may not contain {}",
+                    bad
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<()> that will error if the `background_color` is not exactly six
+    /// hexadecimal characters
+    pub fn validate_background_color(&self) -> StdResult<()> {
+        if let Some(color) = self
+            .extension
+            .as_ref()
+            .and_then(|e| e.background_color.as_ref())
+        {
+            if color.len() != 6 || !color.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(StdError::generic_err(format!(
+                    "Invalid background_color: {}.  Colors must be six hexadecimal characters without a leading #",
+                    color
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<()> that will error if any localization's locale is not a BCP-47
+    /// language tag
+    pub fn validate_localizations(&self) -> StdResult<()> {
+        if let Some(locs) = self
+            .extension
+            .as_ref()
+            .and_then(|e| e.localizations.as_ref())
+        {
+            for loc in locs.iter() {
+                check_locale(Some(&loc.locale))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<()> that will error if any media file has an unknown file_type, or
+    /// an extension that is not accepted for its file_type.  Media files without a file_type
+    /// are not checked
+    pub fn validate_media_types(&self) -> StdResult<()> {
+        if let Some(media) = self.extension.as_ref().and_then(|e| e.media.as_ref()) {
+            for file in media.iter() {
+                check_media_type(file)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// partial metadata update.  Only the fields that are provided will overwrite the existing
+/// metadata, all other fields are left unchanged
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug, Default)]
+pub struct MetadataPatch {
+    /// optional new uri for off-chain metadata
+    pub token_uri: Option<String>,
+    /// optional new url to the image
+    pub image: Option<String>,
+    /// optional new raw SVG image data
+    pub image_data: Option<String>,
+    /// optional new url to allow users to view the item on your site
+    pub external_url: Option<String>,
+    /// optional new item description
+    pub description: Option<String>,
+    /// optional new name of the item
+    pub name: Option<String>,
+    /// optional new item attributes.  This replaces the entire attribute list
+    pub attributes: Option<Vec<Trait>>,
+    /// optional new background color
+    pub background_color: Option<String>,
+    /// optional new url to a multimedia attachment
+    pub animation_url: Option<String>,
+    /// optional new url to a YouTube video
+    pub youtube_url: Option<String>,
+    /// optional new media files.  This replaces the entire media list
+    pub media: Option<Vec<MediaFile>>,
+    /// optional new list of protected trait_types
+    pub protected_attributes: Option<Vec<String>>,
+    /// optional new localized names and descriptions.  This replaces the entire list
+    pub localizations: Option<Vec<Localization>>,
+}
+
+impl From<Extension> for MetadataPatch {
+    /// Returns a MetadataPatch that overwrites every field the extension provides
+    fn from(ext: Extension) -> Self {
+        MetadataPatch {
+            token_uri: None,
+            image: ext.image,
+            image_data: ext.image_data,
+            external_url: ext.external_url,
+            description: ext.description,
+            name: ext.name,
+            attributes: ext.attributes,
+            background_color: ext.background_color,
+            animation_url: ext.animation_url,
+            youtube_url: ext.youtube_url,
+            media: ext.media,
+            protected_attributes: ext.protected_attributes,
+            localizations: ext.localizations,
+        }
+    }
+}
+
+impl MetadataPatch {
+    /// Returns true if the patch updates any extension field
+    fn has_extension_fields(&self) -> bool {
+        self.image.is_some()
+            || self.image_data.is_some()
+            || self.external_url.is_some()
+            || self.description.is_some()
+            || self.name.is_some()
+            || self.attributes.is_some()
+            || self.background_color.is_some()
+            || self.animation_url.is_some()
+            || self.youtube_url.is_some()
+            || self.media.is_some()
+            || self.protected_attributes.is_some()
+            || self.localizations.is_some()
+    }
+}
+
 /// metadata extension
 /// You can add any metadata fields you need here.  These fields are based on
 /// https://docs.opensea.io/docs/metadata-standards and are the metadata fields that
@@ -59,6 +495,8 @@ pub struct Extension {
     /// a select list of trait_types that are in the private metadata.  This will only ever be used
     /// in public metadata
     pub protected_attributes: Option<Vec<String>>,
+    /// names and descriptions of the item translated for other locales
+    pub localizations: Option<Vec<Localization>>,
 }
 
 /// attribute trait
@@ -74,10 +512,48 @@ pub struct Trait {
     pub max_value: Option<String>,
 }
 
+impl Trait {
+    /// Returns Trait with a random numeric value between 0 and `max` inclusive, displayed as
+    /// a "number" with `max` as its max_value
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - a mutable reference to the Prng used to draw the value
+    /// * `trait_type` - name of the trait
+    /// * `max` - the largest value the trait may have
+    pub fn new_numeric(p: &mut Prng, trait_type: &str, max: u32) -> Trait {
+        let range = max as u64 + 1;
+        // reject draws from the incomplete final range so every value is equally likely
+        let zone = u64::MAX - (u64::MAX % range);
+        let mut draw = p.next_u64();
+        while draw >= zone {
+            draw = p.next_u64();
+        }
+        Trait {
+            display_type: Some("number".to_string()),
+            trait_type: Some(trait_type.to_string()),
+            value: (draw % range).to_string(),
+            max_value: Some(max.to_string()),
+        }
+    }
+}
+
+/// localized name and description
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug, Default)]
+pub struct Localization {
+    /// BCP-47 language tag of the locale, such as `en` or `pt-BR`
+    pub locale: String,
+    /// optional name of the item in this locale
+    pub name: Option<String>,
+    /// optional item description in this locale
+    pub description: Option<String>,
+}
+
 /// media file
 #[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug, Default)]
 pub struct MediaFile {
-    /// file type
+    /// file type.  If provided, it must be one of the MEDIA_FILE_TYPES, and the extension
+    /// must be one accepted for it
     /// Stashh currently uses: "image", "video", "audio", "text", "font", "application"
     pub file_type: Option<String>,
     /// file extension
@@ -86,6 +562,8 @@ pub struct MediaFile {
     pub authentication: Option<Authentication>,
     /// url to the file.  Urls should be prefixed with `http://`, `https://`, `ipfs://`, or `ar://`
     pub url: String,
+    /// optional hex-encoded sha256 hash of the file used to verify its integrity
+    pub sha256: Option<String>,
 }
 
 /// media file authentication
@@ -96,3 +574,136 @@ pub struct Authentication {
     /// username used in basic authentication
     pub user: Option<String>,
 }
+
+/// Returns StdResult<()> that will error if the optional ipfs gateway is not an `https://` url
+///
+/// # Arguments
+///
+/// * `gateway` - optional gateway used to display ipfs urls
+pub fn check_ipfs_gateway(gateway: Option<&str>) -> StdResult<()> {
+    if let Some(g) = gateway {
+        if !g.to_lowercase().starts_with("https://") || g.len() == 8 {
+            return Err(StdError::generic_err(format!(
+                "Invalid ipfs gateway: {}.  Gateways must be prefixed with https://",
+                g
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()> that will error if the optional locale is not a BCP-47 language tag,
+/// a 2 to 8 letter language subtag followed by any number of `-` separated subtags of 1 to 8
+/// letters or digits
+///
+/// # Arguments
+///
+/// * `locale` - optional locale to check
+pub fn check_locale(locale: Option<&str>) -> StdResult<()> {
+    if let Some(l) = locale {
+        let mut subtags = l.split('-');
+        let language_ok = matches!(
+            subtags.next(),
+            Some(lang) if (2..=8).contains(&lang.len()) && lang.chars().all(|c| c.is_ascii_alphabetic())
+        );
+        let subtags_ok = subtags.all(|tag| {
+            (1..=8).contains(&tag.len()) && tag.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+        if !language_ok || !subtags_ok {
+            return Err(StdError::generic_err(format!(
+                "Invalid locale: {}.  Locales must be BCP-47 language tags such as en or pt-BR",
+                l
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// rewrites an `ipfs://<cid>` url as `<gateway>/<cid>`.  Any other url is left unchanged
+///
+/// # Arguments
+///
+/// * `uri` - a mutable reference to the url
+/// * `gateway` - the gateway to display the url with
+fn rewrite_ipfs_uri(uri: &mut String, gateway: &str) {
+    if uri.to_lowercase().starts_with("ipfs://") && uri.len() > 7 {
+        *uri = format!("{}/{}", gateway.trim_end_matches('/'), &uri[7..]);
+    }
+}
+
+/// Returns StdResult<()> that will error if the optional hash is not 64 hex characters
+///
+/// # Arguments
+///
+/// * `hash` - optional reference to the hex-encoded hash
+fn check_sha256(hash: Option<&String>) -> StdResult<()> {
+    if let Some(h) = hash {
+        if h.len() != 64 || !h.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(StdError::generic_err(format!(
+                "Invalid media sha256: {}.  Hashes must be 64 hexadecimal characters",
+                h
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()> that will error if the media file's file_type is unknown, or its
+/// extension is not accepted for that file_type
+///
+/// # Arguments
+///
+/// * `file` - a reference to the MediaFile
+fn check_media_type(file: &MediaFile) -> StdResult<()> {
+    if let Some(file_type) = file.file_type.as_ref() {
+        let lower = file_type.to_lowercase();
+        let accepted = MEDIA_FILE_TYPES
+            .iter()
+            .find(|(t, _)| *t == lower)
+            .map(|(_, exts)| exts)
+            .ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "Invalid media file_type: {}.  File types must be one of image, video, audio, text, font, or application",
+                    file_type
+                ))
+            })?;
+        if let Some(ext) = file.extension.as_ref() {
+            let ext_lower = ext.trim_start_matches('.').to_lowercase();
+            if !accepted.iter().any(|a| *a == ext_lower) {
+                return Err(StdError::generic_err(format!(
+                    "Media extension {} is not valid for file_type {}",
+                    ext, file_type
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()> that will error if the optional uri does not start with one of the
+/// allowed schemes
+///
+/// # Arguments
+///
+/// * `field` - name of the field being checked, used in the error message
+/// * `uri` - optional reference to the uri
+fn check_uri(field: &str, uri: Option<&String>) -> StdResult<()> {
+    if let Some(u) = uri {
+        if !ALLOWED_URI_SCHEMES
+            .iter()
+            .any(|scheme| u.to_lowercase().starts_with(scheme))
+        {
+            return Err(StdError::generic_err(format!(
+                "Invalid {}: {}.  Urls must be prefixed with http://, https://, ipfs://, or ar://",
+                field, u
+            )));
+        }
+        if u.to_lowercase() == "ipfs://" {
+            return Err(StdError::generic_err(format!(
+                "Invalid {}: {}.  ipfs urls must include a CID",
+                field, u
+            )));
+        }
+    }
+    Ok(())
+}
@@ -5,22 +5,24 @@ mod tests {
     use crate::inventory::Inventory;
     use crate::msg::{
         AccessLevel, Burn, ContractStatus, HandleAnswer, HandleMsg, InitConfig, InitMsg, Mint,
-        PostInitCallback, QueryAnswer, QueryMsg, ReceiverInfo, Send, Transfer, Tx, TxAction,
+        PostInitCallback, PrefixApproval, QueryAnswer, QueryMsg, ReceiverInfo, ResponseStatus,
+        Send, Transfer, Tx, TxAction, TxType, ViewerInfo,
     };
+    use crate::rand::sha_256;
     use crate::receiver::Snip721ReceiveMsg;
     use crate::state::{
         get_txs, json_load, json_may_load, load, may_load, AuthList, Config, Permission,
         PermissionType, CONFIG_KEY, MINTERS_KEY, PREFIX_ALL_PERMISSIONS, PREFIX_AUTHLIST,
         PREFIX_INFOS, PREFIX_MAP_TO_ID, PREFIX_MAP_TO_INDEX, PREFIX_OWNER_PRIV, PREFIX_PRIV_META,
-        PREFIX_PUB_META, PREFIX_RECEIVERS, PREFIX_VIEW_KEY,
+        PREFIX_PUB_META, PREFIX_RECEIVERS, PREFIX_REVEAL_COMMIT, PREFIX_VIEW_KEY, RESERVED_CNT_KEY,
     };
-    use crate::token::{Extension, Metadata, Token};
+    use crate::token::{Extension, MediaFile, Metadata, MetadataPatch, Token, Trait};
     use crate::viewing_key::{ViewingKey, VIEWING_KEY_SIZE};
     use cosmwasm_std::testing::*;
     use cosmwasm_std::{
-        from_binary, to_binary, Api, Binary, BlockInfo, CanonicalAddr, Coin, CosmosMsg, Env,
-        Extern, HandleResponse, HumanAddr, InitResponse, MessageInfo, StdError, StdResult, Uint128,
-        WasmMsg,
+        from_binary, to_binary, to_vec, Api, Binary, BlockInfo, CanonicalAddr, Coin, CosmosMsg,
+        Env, Extern, HandleResponse, HumanAddr, InitResponse, LogAttribute, MessageInfo, StdError,
+        StdResult, Uint128, WasmMsg,
     };
     use cosmwasm_storage::ReadonlyPrefixedStorage;
     use secret_toolkit::utils::space_pad;
@@ -96,6 +98,60 @@ mod tests {
         (init(&mut deps, env, init_msg), deps)
     }
 
+    fn init_helper_with_init_config(
+        init_config: InitConfig,
+    ) -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    // returns a MintNft message that mints the token to alice with the public metadata
+    fn mint_msg(token_id: &str, public_metadata: Metadata) -> HandleMsg {
+        HandleMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(public_metadata),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        }
+    }
+
+    // returns metadata with only the extension
+    fn extension_meta(extension: Extension) -> Metadata {
+        Metadata {
+            token_uri: None,
+            extension: Some(extension),
+        }
+    }
+
+    // returns a mock Env at an earlier block, so that a handle does not prune approvals that
+    // have expired at the default mock block
+    fn mock_env_at(sender: &str, height: u64, time: u64) -> Env {
+        let mut env = mock_env(sender, &[]);
+        env.block.height = height;
+        env.block.time = time;
+        env
+    }
+
     fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
         match error {
             Ok(_response) => panic!("Expected error, but had Ok response"),
@@ -223,7 +279,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("NFT1".to_string()),
                 description: Some("pub1".to_string()),
-                image: Some("uri1".to_string()),
+                image: Some("https://uri1".to_string()),
                 ..Extension::default()
             }),
         };
@@ -232,7 +288,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("NFT2".to_string()),
                 description: Some("priv2".to_string()),
-                image: Some("uri2".to_string()),
+                image: Some("https://uri2".to_string()),
                 ..Extension::default()
             }),
         };
@@ -244,6 +300,7 @@ mod tests {
                 private_metadata: None,
                 royalty_info: None,
                 serial_number: None,
+                reveal_commitment: None,
                 memo: None,
             },
             Mint {
@@ -253,6 +310,7 @@ mod tests {
                 private_metadata: Some(priv2.clone()),
                 royalty_info: None,
                 serial_number: None,
+                reveal_commitment: None,
                 memo: None,
             },
             Mint {
@@ -262,6 +320,7 @@ mod tests {
                 private_metadata: None,
                 royalty_info: None,
                 serial_number: None,
+                reveal_commitment: None,
                 memo: None,
             },
             Mint {
@@ -271,6 +330,7 @@ mod tests {
                 private_metadata: None,
                 royalty_info: None,
                 serial_number: None,
+                reveal_commitment: None,
                 memo: Some("has id 3".to_string()),
             },
         ];
@@ -417,13 +477,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("MyNFT".to_string()),
                     description: None,
-                    image: Some("uri".to_string()),
+                    image: Some("https://uri".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -442,17 +503,18 @@ mod tests {
             token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
             public_metadata: Some(Metadata {
-                token_uri: Some("uri".to_string()),
+                token_uri: Some("https://uri".to_string()),
                 extension: Some(Extension {
                     name: Some("MyNFT".to_string()),
                     description: None,
-                    image: Some("uri".to_string()),
+                    image: Some("https://uri".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -469,13 +531,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("MyNFT".to_string()),
                     description: None,
-                    image: Some("uri".to_string()),
+                    image: Some("https://uri".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -495,7 +558,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("MyNFT".to_string()),
                 description: None,
-                image: Some("uri".to_string()),
+                image: Some("https://uri".to_string()),
                 ..Extension::default()
             }),
         });
@@ -504,7 +567,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("MyNFTpriv".to_string()),
                 description: Some("Nifty".to_string()),
-                image: Some("privuri".to_string()),
+                image: Some("https://privuri".to_string()),
                 ..Extension::default()
             }),
         });
@@ -515,6 +578,7 @@ mod tests {
             private_metadata: priv_expect.clone(),
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -576,7 +640,7 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("MyNFT".to_string()),
                     description: None,
-                    image: Some("uri".to_string()),
+                    image: Some("https://uri".to_string()),
                     ..Extension::default()
                 }),
             }),
@@ -585,12 +649,13 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("MyNFTpriv".to_string()),
                     description: Some("Nifty".to_string()),
-                    image: Some("privuri".to_string()),
+                    image: Some("https://privuri".to_string()),
                     ..Extension::default()
                 }),
             }),
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -615,6 +680,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: Some("Admin wants his own".to_string()),
             padding: None,
         };
@@ -681,34 +747,7 @@ mod tests {
 
     // test updating public metadata
     #[test]
-    fn test_set_public_metadata() {
-        let (init_result, mut deps) =
-            init_helper_with_config(true, false, false, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
-
-        // test token does not exist when supply is public
-        let handle_msg = HandleMsg::SetMetadata {
-            token_id: "SNIP20".to_string(),
-            public_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("New Name".to_string()),
-                    description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
-                    ..Extension::default()
-                }),
-            }),
-            private_metadata: None,
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Token ID: SNIP20 not found"));
-
+    fn test_metadata_uri_validation() {
         let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
@@ -716,396 +755,403 @@ mod tests {
             init_result.err().unwrap()
         );
 
-        // test token does not exist when supply is private
-        let handle_msg = HandleMsg::SetMetadata {
-            token_id: "SNIP20".to_string(),
-            public_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("New Name".to_string()),
-                    description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
-                    ..Extension::default()
+        // test every accepted scheme
+        let schemes = ["http://", "https://", "ipfs://", "ar://"];
+        for (i, scheme) in schemes.iter().enumerate() {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(format!("NFT{}", i)),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: Some(Metadata {
+                    token_uri: Some(format!("{}uri", scheme)),
+                    extension: None,
                 }),
-            }),
-            private_metadata: None,
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Not authorized to update metadata of token SNIP20"));
-
-        // test setting metadata when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+                private_metadata: Some(Metadata {
+                    token_uri: None,
+                    extension: Some(Extension {
+                        image: Some(format!("{}image", scheme)),
+                        external_url: Some(format!("{}external", scheme)),
+                        animation_url: Some(format!("{}animation", scheme)),
+                        youtube_url: Some(format!("{}youtube", scheme)),
+                        media: Some(vec![MediaFile {
+                            file_type: Some("image".to_string()),
+                            extension: Some("png".to_string()),
+                            authentication: None,
+                            url: format!("{}media", scheme),
+                            sha256: None,
+                        }]),
+                        ..Extension::default()
+                    }),
+                }),
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
 
-        let handle_msg = HandleMsg::SetMetadata {
-            token_id: "MyNFT".to_string(),
+        // test that urls are optional and image_data is exempt
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("SVG".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
             public_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: None,
-                    image: Some("uri".to_string()),
+                    image_data: Some("<svg></svg>".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        assert!(handle_result.is_ok());
 
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::Normal,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // test rejected token_uri on mint
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("FTP".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
             public_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: None,
-                    image: Some("uri".to_string()),
-                    ..Extension::default()
-                }),
+                token_uri: Some("ftp://uri".to_string()),
+                extension: None,
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Invalid token_uri: ftp://uri"));
 
-        // test not minter nor owner
-        let handle_msg = HandleMsg::SetMetadata {
-            token_id: "MyNFT".to_string(),
-            public_metadata: Some(Metadata {
+        // test rejected media url in private metadata on mint
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("FTP2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
-                    name: Some("New Name".to_string()),
-                    description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
+                    media: Some(vec![MediaFile {
+                        file_type: None,
+                        extension: None,
+                        authentication: None,
+                        url: "ftp://media".to_string(),
+                        sha256: None,
+                    }]),
                     ..Extension::default()
                 }),
             }),
-            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Not authorized to update metadata"));
+        assert!(error.contains("Invalid media url: ftp://media"));
 
-        // test owner tries but not allowed to change metadata
+        // test rejected extension url on SetMetadata
         let handle_msg = HandleMsg::SetMetadata {
-            token_id: "MyNFT".to_string(),
+            token_id: "NFT0".to_string(),
             public_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
-                    name: Some("New Name".to_string()),
-                    description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
+                    image: Some("https://image".to_string()),
+                    external_url: Some("ftp://external".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Not authorized to update metadata"));
+        assert!(error.contains("Invalid external_url: ftp://external"));
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let idx: u32 = load(&map2idx, "NFT0".as_bytes()).unwrap();
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &idx.to_le_bytes()).unwrap();
+        assert_eq!(pub_meta.token_uri, Some("http://uri".to_string()));
+    }
 
-        // test minter tries, but not allowed
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+    // test media sha256 hashes are validated on mint and SetMetadata
+    #[test]
+    fn test_media_hash_validation() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
+        let media_meta = |sha256: &str| {
+            extension_meta(Extension {
+                media: Some(vec![MediaFile {
+                    file_type: Some("image".to_string()),
+                    extension: Some("png".to_string()),
+                    authentication: None,
+                    url: "https://media".to_string(),
+                    sha256: Some(sha256.to_string()),
+                }]),
+                ..Extension::default()
+            })
+        };
+        let valid = "a".repeat(64);
+
+        // test hash that is too short
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("NFT1".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: None,
-                    image: Some("uri".to_string()),
-                    ..Extension::default()
-                }),
-            }),
+            public_metadata: Some(media_meta("abcd")),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetMetadata {
-            token_id: "MyNFT".to_string(),
-            public_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("New Name".to_string()),
-                    description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
-                    ..Extension::default()
-                }),
-            }),
-            private_metadata: None,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Invalid media sha256: abcd"));
+
+        // test hash with non-hex characters
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: Some(media_meta(&"g".repeat(64))),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Not authorized to update metadata"));
+        assert!(error.contains("Hashes must be 64 hexadecimal characters"));
 
-        // sanity check: minter updates
-        let (init_result, mut deps) = init_helper_default();
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        // sanity check
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("NFT3".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: None,
-                    image: Some("uri".to_string()),
-                    ..Extension::default()
-                }),
-            }),
+            public_metadata: Some(media_meta(&valid)),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let set_expect = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("New Name".to_string()),
-                description: Some("I changed the metadata".to_string()),
-                image: Some("new uri".to_string()),
-                ..Extension::default()
-            }),
-        });
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test hash that is too long on SetMetadata
         let handle_msg = HandleMsg::SetMetadata {
-            token_id: "MyNFT".to_string(),
-            public_metadata: set_expect.clone(),
+            token_id: "NFT3".to_string(),
+            public_metadata: Some(media_meta(&"A".repeat(65))),
             private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Hashes must be 64 hexadecimal characters"));
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let idx: u32 = load(&map2idx, "NFT3".as_bytes()).unwrap();
         let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &0u32.to_le_bytes()).unwrap();
-        assert_eq!(pub_meta, set_expect.unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &0u32.to_le_bytes()).unwrap();
-        assert!(priv_meta.is_none());
+        let pub_meta: Metadata = load(&pub_store, &idx.to_le_bytes()).unwrap();
+        assert_eq!(pub_meta, media_meta(&valid));
     }
 
     #[test]
-    fn test_set_private_metadata() {
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, true, false, false);
+    fn test_metadata_field_exclusion() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
 
-        // test token does not exist when supply is private
-        let handle_msg = HandleMsg::SetMetadata {
-            token_id: "SNIP20".to_string(),
-            public_metadata: None,
-            private_metadata: Some(Metadata {
+        // test neither token_uri nor extension on mint
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("Empty".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(Metadata {
                 token_uri: None,
-                extension: Some(Extension {
-                    name: Some("New Name".to_string()),
-                    description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
-                    ..Extension::default()
-                }),
+                extension: None,
             }),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Not authorized to update metadata of token SNIP20"));
-
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, true, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        assert!(error.contains("Metadata must have EITHER token_uri OR extension"));
 
+        // test both token_uri and extension in private metadata on mint
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("Both".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: None,
-                    image: Some("uri".to_string()),
-                    ..Extension::default()
-                }),
+            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: Some("https://uri".to_string()),
+                extension: Some(Extension::default()),
             }),
-            private_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test trying to change sealed metadata before it has been unwrapped
-        let (init_result, mut deps) =
-            init_helper_with_config(true, false, true, true, true, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata can not have BOTH token_uri AND extension"));
 
+        // test each field alone is accepted
         let handle_msg = HandleMsg::MintNft {
             token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(Metadata {
+                token_uri: Some("https://uri".to_string()),
+                extension: None,
+            }),
             private_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: None,
-                    image: Some("uri".to_string()),
+                    name: Some("MyNFTpriv".to_string()),
                     ..Extension::default()
                 }),
             }),
-            public_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test neither token_uri nor extension on SetMetadata
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: None,
+            }),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata must have EITHER token_uri OR extension"));
+
+        // test both token_uri and extension on SetMetadata
         let handle_msg = HandleMsg::SetMetadata {
             token_id: "MyNFT".to_string(),
             public_metadata: None,
             private_metadata: Some(Metadata {
+                token_uri: Some("https://uri".to_string()),
+                extension: Some(Extension::default()),
+            }),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata can not have BOTH token_uri AND extension"));
+
+        // test switching each metadata to the other field
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
-                    name: Some("New Name".to_string()),
-                    description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
+                    name: Some("MyNFT".to_string()),
                     ..Extension::default()
                 }),
             }),
+            private_metadata: Some(Metadata {
+                token_uri: Some("ipfs://privuri".to_string()),
+                extension: None,
+            }),
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The private metadata of a sealed token can not be modified"));
+        assert!(handle_result.is_ok());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &0u32.to_le_bytes()).unwrap();
+        assert!(pub_meta.token_uri.is_none());
+        assert_eq!(pub_meta.extension.unwrap().name, Some("MyNFT".to_string()));
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Metadata = load(&priv_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(priv_meta.token_uri, Some("ipfs://privuri".to_string()));
+        assert!(priv_meta.extension.is_none());
+    }
+
+    #[test]
+    fn test_set_public_metadata() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
 
         // test token does not exist when supply is public
         let handle_msg = HandleMsg::SetMetadata {
             token_id: "SNIP20".to_string(),
-            public_metadata: None,
-            private_metadata: Some(Metadata {
+            public_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
                     name: Some("New Name".to_string()),
                     description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
+                    image: Some("https://new uri".to_string()),
                     ..Extension::default()
                 }),
             }),
+            private_metadata: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
         assert!(error.contains("Token ID: SNIP20 not found"));
 
-        let handle_msg = HandleMsg::Reveal {
-            token_id: "MyNFT".to_string(),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &token_key).unwrap();
-        assert!(token.unwrapped);
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
 
-        // test setting both token_uri and extension
+        // test token does not exist when supply is private
         let handle_msg = HandleMsg::SetMetadata {
-            token_id: "MyNFT".to_string(),
-            private_metadata: Some(Metadata {
-                token_uri: Some("uri".to_string()),
+            token_id: "SNIP20".to_string(),
+            public_metadata: Some(Metadata {
+                token_uri: None,
                 extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: None,
-                    image: Some("uri".to_string()),
+                    name: Some("New Name".to_string()),
+                    description: Some("I changed the metadata".to_string()),
+                    image: Some("https://new uri".to_string()),
                     ..Extension::default()
                 }),
             }),
-            public_metadata: None,
+            private_metadata: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Metadata can not have BOTH token_uri AND extension"));
-
-        // sanity check, minter changing metadata after owner unwrapped
-        let set_pub = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("New Name Pub".to_string()),
-                description: Some("Minter changed the public metadata".to_string()),
-                image: Some("new uri pub".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let set_priv = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("New Name Priv".to_string()),
-                description: Some("Minter changed the private metadata".to_string()),
-                image: Some("new uri priv".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let handle_msg = HandleMsg::SetMetadata {
-            token_id: "MyNFT".to_string(),
-            public_metadata: set_pub.clone(),
-            private_metadata: set_priv.clone(),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
-        assert_eq!(pub_meta, set_pub.unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &token_key).unwrap();
-        assert_eq!(priv_meta, set_priv.unwrap());
+        assert!(error.contains("Not authorized to update metadata of token SNIP20"));
 
         // test setting metadata when status prevents it
         let handle_msg = HandleMsg::SetContractStatus {
@@ -1116,16 +1162,16 @@ mod tests {
 
         let handle_msg = HandleMsg::SetMetadata {
             token_id: "MyNFT".to_string(),
-            public_metadata: None,
-            private_metadata: Some(Metadata {
+            public_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
                     name: Some("MyNFT".to_string()),
                     description: None,
-                    image: Some("uri".to_string()),
+                    image: Some("https://uri".to_string()),
                     ..Extension::default()
                 }),
             }),
+            private_metadata: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
@@ -1133,940 +1179,3336 @@ mod tests {
         assert!(error.contains("The contract admin has temporarily disabled this action"));
 
         let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: None,
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test owner trying when not authorized
+        // test not minter nor owner
         let handle_msg = HandleMsg::SetMetadata {
             token_id: "MyNFT".to_string(),
-            public_metadata: None,
-            private_metadata: Some(Metadata {
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("New Name".to_string()),
+                    description: Some("I changed the metadata".to_string()),
+                    image: Some("https://new uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Not authorized to update metadata"));
+
+        // test owner tries but not allowed to change metadata
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
                     name: Some("New Name".to_string()),
                     description: Some("I changed the metadata".to_string()),
-                    image: Some("new uri".to_string()),
+                    image: Some("https://new uri".to_string()),
                     ..Extension::default()
                 }),
             }),
+            private_metadata: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Not authorized to update metadata of token MyNFT"));
+        assert!(error.contains("Not authorized to update metadata"));
 
-        // test authorized owner creates new metadata when it didn't exist before
+        // test minter tries, but not allowed
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, true, false);
+            init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let pub_expect = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT".to_string()),
-                description: None,
-                image: Some("uri".to_string()),
-                ..Extension::default()
-            }),
-        });
         let handle_msg = HandleMsg::MintNft {
             token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub_expect.clone(),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: None,
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let priv_expect = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("New Name".to_string()),
-                description: Some("Owner changed the metadata".to_string()),
-                image: Some("new uri".to_string()),
-                ..Extension::default()
-            }),
-        });
         let handle_msg = HandleMsg::SetMetadata {
             token_id: "MyNFT".to_string(),
-            public_metadata: None,
-            private_metadata: priv_expect.clone(),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("New Name".to_string()),
+                    description: Some("I changed the metadata".to_string()),
+                    image: Some("https://new uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &token_key).unwrap();
-        assert_eq!(priv_meta, priv_expect.unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
-        assert_eq!(pub_meta, pub_expect.unwrap());
-    }
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Not authorized to update metadata"));
 
-    // test Reveal
-    #[test]
-    fn test_reveal() {
-        let (init_result, mut deps) =
-            init_helper_with_config(true, false, true, false, false, false, false);
+        // sanity check: minter updates
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        // test token does not exist when supply is public
-        let handle_msg = HandleMsg::Reveal {
-            token_id: "MyNFT".to_string(),
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: None,
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Token ID: MyNFT not found"));
-
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, true, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
-
-        // test token does not exist when supply is private
-        let handle_msg = HandleMsg::Reveal {
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let set_expect = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("New Name".to_string()),
+                description: Some("I changed the metadata".to_string()),
+                image: Some("https://new uri".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::SetMetadata {
             token_id: "MyNFT".to_string(),
+            public_metadata: set_expect.clone(),
+            private_metadata: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("You do not own token MyNFT"));
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(pub_meta, set_expect.unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &0u32.to_le_bytes()).unwrap();
+        assert!(priv_meta.is_none());
+    }
 
+    #[test]
+    fn test_patch_metadata() {
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+            init_helper_with_config(true, false, false, false, true, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        // test reveal when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let attributes = Some(vec![Trait {
+            display_type: None,
+            trait_type: Some("size".to_string()),
+            value: "large".to_string(),
+            max_value: None,
+        }]);
         let handle_msg = HandleMsg::MintNft {
             token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: Some(Metadata {
+            public_metadata: Some(Metadata {
                 token_uri: None,
                 extension: Some(Extension {
-                    name: Some("MySealedNFT".to_string()),
-                    description: Some("Sealed metadata test".to_string()),
-                    image: Some("sealed_uri".to_string()),
+                    name: Some("MyNFT".to_string()),
+                    description: Some("Original".to_string()),
+                    image: Some("https://uri".to_string()),
+                    attributes: attributes.clone(),
                     ..Extension::default()
                 }),
             }),
-            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: Some("https://privuri".to_string()),
+                extension: None,
+            }),
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        let handle_msg = HandleMsg::Reveal {
+        // test unauthorized patch
+        let handle_msg = HandleMsg::PatchMetadata {
             token_id: "MyNFT".to_string(),
+            public_metadata: Some(MetadataPatch {
+                description: Some("Hacked".to_string()),
+                ..MetadataPatch::default()
+            }),
+            private_metadata: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        assert!(error.contains("Not authorized to update metadata of token MyNFT"));
+
+        // test token does not exist when supply is public
+        let handle_msg = HandleMsg::PatchMetadata {
+            token_id: "NFT2".to_string(),
+            public_metadata: Some(MetadataPatch {
+                description: Some("Updated".to_string()),
+                ..MetadataPatch::default()
+            }),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT2 not found"));
 
+        // test status prevents it
         let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+            level: ContractStatus::StopAll,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test sealed metadata not enabled
-        let handle_msg = HandleMsg::Reveal {
+        let handle_msg = HandleMsg::PatchMetadata {
             token_id: "MyNFT".to_string(),
+            public_metadata: Some(MetadataPatch {
+                description: Some("Updated".to_string()),
+                ..MetadataPatch::default()
+            }),
+            private_metadata: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Sealed metadata functionality is not enabled for this contract"));
-
-        // test someone other than owner tries to unwrap
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, true, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        let seal_meta = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MySealedNFT".to_string()),
-                description: Some("Sealed metadata test".to_string()),
-                image: Some("sealed_uri".to_string()),
-                ..Extension::default()
+        // test selective field update
+        let handle_msg = HandleMsg::PatchMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: Some(MetadataPatch {
+                description: Some("Updated".to_string()),
+                ..MetadataPatch::default()
             }),
-        });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: seal_meta.clone(),
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::Reveal {
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::PatchMetadata { status } => assert_eq!(status, ResponseStatus::Success),
+            _ => panic!("unexpected"),
+        }
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &0u32.to_le_bytes()).unwrap();
+        let ext = pub_meta.extension.unwrap();
+        assert_eq!(ext.description, Some("Updated".to_string()));
+        assert_eq!(ext.name, Some("MyNFT".to_string()));
+        assert_eq!(ext.image, Some("https://uri".to_string()));
+        assert_eq!(ext.attributes, attributes);
+        assert!(pub_meta.token_uri.is_none());
+
+        // test patching extension fields into token_uri metadata is rejected
+        let handle_msg = HandleMsg::PatchMetadata {
             token_id: "MyNFT".to_string(),
+            public_metadata: None,
+            private_metadata: Some(MetadataPatch {
+                name: Some("Private Name".to_string()),
+                ..MetadataPatch::default()
+            }),
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("You do not own token MyNFT"));
+        assert!(error.contains("Metadata can not have BOTH token_uri AND extension"));
 
-        // sanity check, unwrap to public metadata
-        let handle_msg = HandleMsg::Reveal {
+        // test patching a token_uri
+        let handle_msg = HandleMsg::PatchMetadata {
             token_id: "MyNFT".to_string(),
+            public_metadata: None,
+            private_metadata: Some(MetadataPatch {
+                token_uri: Some("ipfs://newprivuri".to_string()),
+                ..MetadataPatch::default()
+            }),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &token_key).unwrap();
-        assert!(priv_meta.is_none());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
-        assert_eq!(pub_meta, seal_meta.clone().unwrap());
+        let priv_meta: Metadata = load(&priv_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(priv_meta.token_uri, Some("ipfs://newprivuri".to_string()));
+        assert!(priv_meta.extension.is_none());
 
-        // test trying to unwrap token that has already been unwrapped
-        let handle_msg = HandleMsg::Reveal {
+        // test patched urls are still validated
+        let handle_msg = HandleMsg::PatchMetadata {
             token_id: "MyNFT".to_string(),
+            public_metadata: Some(MetadataPatch {
+                image: Some("ftp://image".to_string()),
+                ..MetadataPatch::default()
+            }),
+            private_metadata: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("This token has already been unwrapped"));
+        assert!(error.contains("Invalid image: ftp://image"));
+    }
 
-        // sanity check, unwrap but keep private
+    #[test]
+    fn test_set_private_metadata() {
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, true, true, false, false, false);
+            init_helper_with_config(false, false, false, false, true, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: seal_meta.clone(),
+
+        // test token does not exist when supply is private
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "SNIP20".to_string(),
             public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::Reveal {
-            token_id: "MyNFT".to_string(),
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("New Name".to_string()),
+                    description: Some("I changed the metadata".to_string()),
+                    image: Some("https://new uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &token_key).unwrap();
-        assert_eq!(priv_meta, seal_meta.unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &token_key).unwrap();
-        assert!(pub_meta.is_none());
-    }
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Not authorized to update metadata of token SNIP20"));
 
-    // test owner setting approval for specific addresses
-    #[test]
-    fn test_set_whitelisted_approval() {
         let (init_result, mut deps) =
-            init_helper_with_config(true, false, false, false, false, false, false);
+            init_helper_with_config(false, false, false, false, true, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
 
-        // test token does not exist when supply is public
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: None,
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Token ID: NFT1 not found"));
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
+        // test trying to change sealed metadata before it has been unwrapped
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+            init_helper_with_config(true, false, true, true, true, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
 
-        // test token does not exist when supply is private
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("You do not own token NFT1"));
-
-        let pub1 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("My1".to_string()),
-                description: Some("Public 1".to_string()),
-                image: Some("URI 1".to_string()),
-                ..Extension::default()
-            }),
-        });
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
+            token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub1.clone(),
-            private_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: None,
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            public_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let pub2 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("My2".to_string()),
-                description: Some("Public 2".to_string()),
-                image: Some("URI 2".to_string()),
-                ..Extension::default()
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("New Name".to_string()),
+                    description: Some("I changed the metadata".to_string()),
+                    image: Some("https://new uri".to_string()),
+                    ..Extension::default()
+                }),
             }),
-        });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub2.clone(),
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg); // test burn when status prevents it
-        let pub3 = Some(Metadata {
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The private metadata of a sealed token can not be modified"));
+
+        // test token does not exist when supply is public
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "SNIP20".to_string(),
+            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("New Name".to_string()),
+                    description: Some("I changed the metadata".to_string()),
+                    image: Some("https://new uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: SNIP20 not found"));
+
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &token_key).unwrap();
+        assert!(token.unwrapped);
+
+        // test setting both token_uri and extension
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            private_metadata: Some(Metadata {
+                token_uri: Some("https://uri".to_string()),
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: None,
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            public_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata can not have BOTH token_uri AND extension"));
+
+        // sanity check, minter changing metadata after owner unwrapped
+        let set_pub = Some(Metadata {
             token_uri: None,
             extension: Some(Extension {
-                name: Some("My3".to_string()),
-                description: Some("Public 3".to_string()),
-                image: Some("URI 3".to_string()),
+                name: Some("New Name Pub".to_string()),
+                description: Some("Minter changed the public metadata".to_string()),
+                image: Some("https://new uri pub".to_string()),
                 ..Extension::default()
             }),
         });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT3".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub3.clone(),
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let pub4 = Some(Metadata {
+        let set_priv = Some(Metadata {
             token_uri: None,
             extension: Some(Extension {
-                name: Some("My4".to_string()),
-                description: Some("Public 4".to_string()),
-                image: Some("URI 4".to_string()),
+                name: Some("New Name Priv".to_string()),
+                description: Some("Minter changed the private metadata".to_string()),
+                image: Some("https://new uri priv".to_string()),
                 ..Extension::default()
             }),
         });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT4".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub4.clone(),
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: set_pub.clone(),
+            private_metadata: set_priv.clone(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
+        assert_eq!(pub_meta, set_pub.unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Metadata = load(&priv_store, &token_key).unwrap();
+        assert_eq!(priv_meta, set_priv.unwrap());
 
-        // test trying to set approval when status does not allow
+        // test setting metadata when status prevents it
         let handle_msg = HandleMsg::SetContractStatus {
             level: ContractStatus::StopAll,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: None,
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
         assert!(error.contains("The contract admin has temporarily disabled this action"));
-        // setting approval is ok even during StopTransactions status
+
         let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+            level: ContractStatus::Normal,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // only allow the owner to use SetWhitelistedApproval
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("You do not own token NFT1"));
-
-        // try approving a token without specifying which token
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        // test owner trying when not authorized
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("New Name".to_string()),
+                    description: Some("I changed the metadata".to_string()),
+                    image: Some("https://new uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains(
-            "Attempted to grant/revoke permission for a token, but did not specify a token ID"
-        ));
+        assert!(error.contains("Not authorized to update metadata of token MyNFT"));
 
-        // try revoking a token approval without specifying which token
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::RevokeToken),
-            expires: None,
+        // test authorized owner creates new metadata when it didn't exist before
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, true, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let pub_expect = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT".to_string()),
+                description: None,
+                image: Some("https://uri".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub_expect.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains(
-            "Attempted to grant/revoke permission for a token, but did not specify a token ID"
-        ));
-
-        // sanity check
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtTime(1000000)),
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let priv_expect = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("New Name".to_string()),
+                description: Some("Owner changed the metadata".to_string()),
+                image: Some("https://new uri".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: None,
+            private_metadata: priv_expect.clone(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let charlie_raw = deps
-            .api
-            .canonical_address(&HumanAddr("charlie".to_string()))
-            .unwrap();
-        let david_raw = deps
-            .api
-            .canonical_address(&HumanAddr("david".to_string()))
-            .unwrap();
-        let edmund_raw = deps
-            .api
-            .canonical_address(&HumanAddr("edmund".to_string()))
-            .unwrap();
-        let frank_raw = deps
-            .api
-            .canonical_address(&HumanAddr("frank".to_string()))
-            .unwrap();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-        // confirm ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permissions and that the token data did not get modified
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let nft1_key = 0u32.to_le_bytes();
-        let nft2_key = 1u32.to_le_bytes();
-        let nft3_key = 2u32.to_le_bytes();
-        let nft4_key = 3u32.to_le_bytes();
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
+        let priv_meta: Metadata = load(&priv_store, &token_key).unwrap();
+        assert_eq!(priv_meta, priv_expect.unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
+        assert_eq!(pub_meta, pub_expect.unwrap());
+    }
+
+    // test Reveal
+    #[test]
+    fn test_reveal() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, true, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists has bob with NFT1 permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
 
-        // verify it doesn't duplicate any entries if adding permissions that already
-        // exist
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtTime(1000000)),
+        // test token does not exist when supply is public
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permissions and that the token data did not get modified
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT not found"));
+
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists has bob with NFT1 permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
 
-        // verify changing an existing ALL expiration while adding token access
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtHeight(1000)),
+        // test token does not exist when supply is private
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm ALL permission with new expiration
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(1000))
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        // confirm NFT2 permissions and that the token data did not get modified
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft2_key).unwrap();
-        assert_eq!(pub_meta, pub2.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft2_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(1000))
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not own token MyNFT"));
+
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists added bob's NFT2 transfer permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
 
-        // verify default expiration of "never"
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        // test reveal when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm NFT3 permissions
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists added bob's nft3 transfer permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 3);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MySealedNFT".to_string()),
+                    description: Some("Sealed metadata test".to_string()),
+                    image: Some("https://sealed_uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // verify revoking a token permission that never existed doesn't break anything
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::RevokeToken),
-            expires: None,
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm NFT4 permissions
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm AuthLists are correct
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 3);
-        assert!(!bob_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
 
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(1500000)),
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test sealed metadata not enabled
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("edmund".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtHeight(2000)),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Sealed metadata functionality is not enabled for this contract"));
+
+        // test someone other than owner tries to unwrap
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        let seal_meta = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MySealedNFT".to_string()),
+                description: Some("Sealed metadata test".to_string()),
+                image: Some("https://sealed_uri".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: seal_meta.clone(),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not own token MyNFT"));
 
-        // test revoking token permission
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::RevokeToken),
-            // expiration is ignored when only performing revoking actions
-            expires: Some(Expiration::AtTime(5)),
+        // sanity check, unwrap to public metadata
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm didn't affect ALL permissions
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 3);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(1000))
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
-        assert_eq!(
-            david_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1500000))
-        );
-        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
-        let edmund_oper_perm = all_perm.iter().find(|p| p.address == edmund_raw).unwrap();
-        assert_eq!(
-            edmund_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        assert_eq!(edmund_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(edmund_oper_perm.expirations[view_owner_idx], None);
-        // confirm NFT2 permissions and that the token data did not get modified
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft2_key).unwrap();
-        assert_eq!(pub_meta, pub2.clone().unwrap());
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft2_key).unwrap();
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &token_key).unwrap();
         assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 1);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .is_none());
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
-        assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists still has bob, but not with NFT2 transfer permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 2);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(!bob_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
+        assert_eq!(pub_meta, seal_meta.clone().unwrap());
 
-        // test revoking a token permission when address has ALL permission removes the ALL
-        // permission, and adds token permissions for all the other tokens not revoked
-        // giving them the expiration of the removed ALL permission
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: Some(AccessLevel::RevokeToken),
-            view_private_metadata: None,
-            transfer: None,
-            expires: Some(Expiration::AtTime(5)),
+        // test trying to unwrap token that has already been unwrapped
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 1000,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("This token has already been unwrapped"));
+
+        // sanity check, unwrap but keep private
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, true, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: seal_meta.clone(),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Metadata = load(&priv_store, &token_key).unwrap();
+        assert_eq!(priv_meta, seal_meta.unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &token_key).unwrap();
+        assert!(pub_meta.is_none());
+    }
+
+    // test unwrapping a sealed token that was minted with a reveal commitment
+    #[test]
+    fn test_reveal_with_proof() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let placeholder = Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("Unrevealed".to_string()),
+                ..Extension::default()
+            }),
+        };
+        let hidden = Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MySealedNFT".to_string()),
+                image: Some("https://sealed_uri".to_string()),
+                ..Extension::default()
+            }),
+        };
+        let commitment = hex::encode(sha_256(&to_vec(&hidden).unwrap()));
+
+        // test commitment that is not a sha256 hash
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("BadCommit".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: Some(placeholder.clone()),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: Some("not hex".to_string()),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Reveal commitment must be a hex-encoded sha256 hash"));
+
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: Some(placeholder.clone()),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: Some(commitment),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test plain Reveal is blocked when there is a commitment
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("must be unwrapped with RevealWithProof"));
+
+        // test proof that does not match the commitment
+        let handle_msg = HandleMsg::RevealWithProof {
+            token_id: "MyNFT".to_string(),
+            private_metadata: placeholder.clone(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("does not match the reveal commitment"));
+
+        // test non-owner can not reveal
+        let handle_msg = HandleMsg::RevealWithProof {
+            token_id: "MyNFT".to_string(),
+            private_metadata: hidden.clone(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not own token MyNFT"));
+
+        // sanity check, matching proof unwraps to public metadata
+        let handle_msg = HandleMsg::RevealWithProof {
+            token_id: "MyNFT".to_string(),
+            private_metadata: hidden.clone(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let answer: HandleAnswer = from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match answer {
+            HandleAnswer::RevealWithProof { status } => assert_eq!(status, ResponseStatus::Success),
+            _ => panic!("unexpected"),
+        }
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &token_key).unwrap();
+        assert!(priv_meta.is_none());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
+        assert_eq!(pub_meta, hidden);
+        let commit_store = ReadonlyPrefixedStorage::new(PREFIX_REVEAL_COMMIT, &deps.storage);
+        let commit: Option<Vec<u8>> = may_load(&commit_store, &token_key).unwrap();
+        assert!(commit.is_none());
+
+        // test proof on a token without a commitment
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NoCommit".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: Some(placeholder),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::RevealWithProof {
+            token_id: "NoCommit".to_string(),
+            private_metadata: hidden,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("This token does not have a reveal commitment"));
+    }
+
+    // test revealing a batch of sealed and unsealed tokens
+    #[test]
+    fn test_batch_reveal() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let sealed_meta = |name: &str| {
+            extension_meta(Extension {
+                name: Some(name.to_string()),
+                ..Extension::default()
+            })
+        };
+        let mint = |id: &str, owner: &str, reveal_commitment: Option<String>| HandleMsg::MintNft {
+            token_id: Some(id.to_string()),
+            owner: Some(HumanAddr(owner.to_string())),
+            private_metadata: Some(sealed_meta(id)),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment,
+            memo: None,
+            padding: None,
+        };
+        for (id, owner) in [
+            ("NFT1", "alice"),
+            ("NFT2", "alice"),
+            ("NFT3", "alice"),
+            ("NFT4", "bob"),
+            ("NFT5", "alice"),
+        ]
+        .iter()
+        {
+            let handle_result = handle(&mut deps, mock_env("admin", &[]), mint(id, owner, None));
+            assert!(handle_result.is_ok());
+        }
+        let commitment = hex::encode(sha_256(&to_vec(&sealed_meta("Committed")).unwrap()));
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint("Committed", "alice", Some(commitment)),
+        );
+        assert!(handle_result.is_ok());
+        let is_unwrapped = |deps: &Extern<MockStorage, MockApi, MockQuerier>, id: &str| {
+            let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+            let index: u32 = load(&map2idx, id.as_bytes()).unwrap();
+            let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+            let token: Token = json_load(&info_store, &index.to_le_bytes()).unwrap();
+            token.unwrapped
+        };
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "NFT3".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test a batch with a token the sender does not own reveals nothing
+        let handle_msg = HandleMsg::BatchReveal {
+            token_ids: vec!["NFT1".to_string(), "NFT4".to_string()],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not own token NFT4"));
+        assert!(!is_unwrapped(&deps, "NFT1"));
+
+        // test unwrapped and duplicate tokens are skipped
+        let handle_msg = HandleMsg::BatchReveal {
+            token_ids: vec![
+                "NFT1".to_string(),
+                "NFT3".to_string(),
+                "NFT2".to_string(),
+                "NFT1".to_string(),
+            ],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let answer: HandleAnswer = from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match answer {
+            HandleAnswer::BatchReveal { revealed, .. } => assert_eq!(revealed, 2),
+            _ => panic!("unexpected"),
+        }
+        assert!(is_unwrapped(&deps, "NFT1"));
+        assert!(is_unwrapped(&deps, "NFT2"));
+        assert!(!is_unwrapped(&deps, "NFT4"));
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: u32 = load(&map2idx, "NFT2".as_bytes()).unwrap();
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &index.to_le_bytes()).unwrap();
+        assert_eq!(pub_meta, sealed_meta("NFT2"));
+
+        // test an already revealed batch reveals nothing
+        let handle_msg = HandleMsg::BatchReveal {
+            token_ids: vec!["NFT1".to_string(), "NFT2".to_string()],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let answer: HandleAnswer = from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match answer {
+            HandleAnswer::BatchReveal { revealed, .. } => assert_eq!(revealed, 0),
+            _ => panic!("unexpected"),
+        }
+
+        // test tokens with a reveal commitment are skipped and reported without stopping the
+        // rest of the batch
+        let handle_msg = HandleMsg::BatchReveal {
+            token_ids: vec![
+                "Committed".to_string(),
+                "NFT5".to_string(),
+                "Committed".to_string(),
+            ],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let answer: HandleAnswer = from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match answer {
+            HandleAnswer::BatchReveal {
+                revealed,
+                needs_proof,
+            } => {
+                assert_eq!(revealed, 1);
+                assert_eq!(needs_proof, vec!["Committed".to_string()]);
+            }
+            _ => panic!("unexpected"),
+        }
+        assert!(!is_unwrapped(&deps, "Committed"));
+        assert!(is_unwrapped(&deps, "NFT5"));
+    }
+
+    // test owner setting approval for specific addresses
+    #[test]
+    fn test_set_whitelisted_approval() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test token does not exist when supply is public
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 not found"));
+
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test token does not exist when supply is private
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not own token NFT1"));
+
+        let pub1 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("My1".to_string()),
+                description: Some("Public 1".to_string()),
+                image: Some("https://URI 1".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub1.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let pub2 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("My2".to_string()),
+                description: Some("Public 2".to_string()),
+                image: Some("https://URI 2".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub2.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg); // test burn when status prevents it
+        let pub3 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("My3".to_string()),
+                description: Some("Public 3".to_string()),
+                image: Some("https://URI 3".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT3".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub3.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let pub4 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("My4".to_string()),
+                description: Some("Public 4".to_string()),
+                image: Some("https://URI 4".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT4".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub4.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test trying to set approval when status does not allow
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        // setting approval is ok even during StopTransactions status
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // only allow the owner to use SetWhitelistedApproval
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not own token NFT1"));
+
+        // try approving a token without specifying which token
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "Attempted to grant/revoke permission for a token, but did not specify a token ID"
+        ));
+
+        // try revoking a token approval without specifying which token
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::RevokeToken),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "Attempted to grant/revoke permission for a token, but did not specify a token ID"
+        ));
+
+        // sanity check
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(1000000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let david_raw = deps
+            .api
+            .canonical_address(&HumanAddr("david".to_string()))
+            .unwrap();
+        let edmund_raw = deps
+            .api
+            .canonical_address(&HumanAddr("edmund".to_string()))
+            .unwrap();
+        let frank_raw = deps
+            .api
+            .canonical_address(&HumanAddr("frank".to_string()))
+            .unwrap();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        // confirm ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permissions and that the token data did not get modified
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let nft1_key = 0u32.to_le_bytes();
+        let nft2_key = 1u32.to_le_bytes();
+        let nft3_key = 2u32.to_le_bytes();
+        let nft4_key = 3u32.to_le_bytes();
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists has bob with NFT1 permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+
+        // verify it doesn't duplicate any entries if adding permissions that already
+        // exist
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(1000000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permissions and that the token data did not get modified
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists has bob with NFT1 permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+
+        // verify changing an existing ALL expiration while adding token access
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtHeight(1000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm ALL permission with new expiration
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(1000))
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        // confirm NFT2 permissions and that the token data did not get modified
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft2_key).unwrap();
+        assert_eq!(pub_meta, pub2.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft2_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(1000))
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists added bob's NFT2 transfer permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+
+        // verify default expiration of "never"
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm NFT3 permissions
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists added bob's nft3 transfer permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 3);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+
+        // verify revoking a token permission that never existed doesn't break anything
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::RevokeToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm NFT4 permissions
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm AuthLists are correct
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 3);
+        assert!(!bob_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(1500000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("edmund".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtHeight(2000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // test revoking token permission
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::RevokeToken),
+            // expiration is ignored when only performing revoking actions
+            expires: Some(Expiration::AtTime(5)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm didn't affect ALL permissions
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 3);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(1000))
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
+        assert_eq!(
+            david_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1500000))
+        );
+        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
+        let edmund_oper_perm = all_perm.iter().find(|p| p.address == edmund_raw).unwrap();
+        assert_eq!(
+            edmund_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        assert_eq!(edmund_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(edmund_oper_perm.expirations[view_owner_idx], None);
+        // confirm NFT2 permissions and that the token data did not get modified
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft2_key).unwrap();
+        assert_eq!(pub_meta, pub2.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft2_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 1);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .is_none());
+        let charlie_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .unwrap();
+        assert_eq!(
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists still has bob, but not with NFT2 transfer permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 2);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(!bob_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+
+        // test revoking a token permission when address has ALL permission removes the ALL
+        // permission, and adds token permissions for all the other tokens not revoked
+        // giving them the expiration of the removed ALL permission
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: Some(AccessLevel::RevokeToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: Some(Expiration::AtTime(5)),
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 1000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("alice".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm only bob's ALL permission is gone
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 2);
+        assert!(all_perm.iter().find(|p| p.address == bob_raw).is_none());
+        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
+        assert_eq!(
+            david_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1500000))
+        );
+        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
+        let edmund_oper_perm = all_perm.iter().find(|p| p.address == edmund_raw).unwrap();
+        assert_eq!(
+            edmund_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        assert_eq!(edmund_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(edmund_oper_perm.expirations[view_owner_idx], None);
+        // confirm NFT1 permission added view_owner for bob with the old ALL permission
+        // expiration, and did not touch the existing transfer permission for bob
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(
+            bob_tok_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(1000))
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
+        assert!(priv_meta.is_none());
+        // confirm NFT2 permission for bob and charlie
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(
+            bob_tok_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(1000))
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[transfer_idx], None);
+        let charlie_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .unwrap();
+        assert_eq!(
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        // confirm NFT3 permissions and that the token data did not get modified
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
+        assert_eq!(pub_meta, pub3.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // confirm NFT4 permission for bob
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(
+            bob_tok_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(1000))
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[transfer_idx], None);
+        // confirm AuthLists still has bob, but not with NFT3 view_owner permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 2);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert_eq!(bob_auth.tokens[view_owner_idx].len(), 3);
+        assert!(bob_auth.tokens[view_owner_idx].contains(&0u32));
+        assert!(bob_auth.tokens[view_owner_idx].contains(&1u32));
+        assert!(!bob_auth.tokens[view_owner_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_owner_idx].contains(&3u32));
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+
+        // test revoking all view_owner permission
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            // will be ignored but specifying shouldn't screw anything up
+            token_id: Some("NFT4".to_string()),
+            view_owner: Some(AccessLevel::None),
+            view_private_metadata: None,
+            transfer: None,
+            // will be ignored but specifying shouldn't screw anything up
+            expires: Some(Expiration::AtTime(5)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 100, 1000), handle_msg);
+        // confirm only bob's ALL permission is gone
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 2);
+        assert!(all_perm.iter().find(|p| p.address == bob_raw).is_none());
+        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
+        assert_eq!(
+            david_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1500000))
+        );
+        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
+        let edmund_oper_perm = all_perm.iter().find(|p| p.address == edmund_raw).unwrap();
+        assert_eq!(
+            edmund_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        assert_eq!(edmund_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(edmund_oper_perm.expirations[view_owner_idx], None);
+        // confirm NFT1 removed view_owner permission for bob, and did not touch the existing
+        // transfer permission for bob
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
+        assert!(priv_meta.is_none());
+        // confirm NFT2 permission removed bob but left and charlie
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .is_none());
+        let charlie_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .unwrap();
+        assert_eq!(
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        // confirm NFT3 permissions and that the token data did not get modified
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
+        assert_eq!(pub_meta, pub3.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // confirm NFT4 permission removed bob
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm AuthLists still has bob, but only for NFT1 and 3 transfer permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 2);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+
+        // test if approving a token for an address that already has ALL permission does
+        // nothing if the given expiration is the same
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("edmund".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtHeight(2000)),
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 1000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("alice".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm edmund still has ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 2);
+        let edmund_oper_perm = all_perm.iter().find(|p| p.address == edmund_raw).unwrap();
+        assert_eq!(
+            edmund_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        assert_eq!(edmund_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(edmund_oper_perm.expirations[view_owner_idx], None);
+        // confirm NFT4 permissions did not add edmund
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft4_key).unwrap();
+        assert_eq!(pub_meta, pub4.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft4_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert!(token.permissions.is_empty());
+        // confirm edmund did not get added to AuthList
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 2);
+        assert!(auth_list.iter().find(|a| a.address == edmund_raw).is_none());
+
+        // test approving a token for an address that already has ALL permission updates that
+        // token's permission's expiration, removes ALL permission, and sets token permission
+        // for all other tokens using the ALL permission's expiration
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("edmund".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtHeight(3000)),
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 1000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("alice".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm edmund's ALL permission is gone
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        assert!(all_perm.iter().find(|p| p.address == edmund_raw).is_none());
+        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
+        assert_eq!(
+            david_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1500000))
+        );
+        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
+        // confirm NFT1 added permission for edmund,
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        // confirm NFT2 added permission for edmund,
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .is_none());
+        let charlie_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .unwrap();
+        assert_eq!(
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        // confirm NFT3 added permission for edmund and that the token data did not get modified
+        // and did not touch the existing transfer permission for bob
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
+        assert_eq!(pub_meta, pub3.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 2);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        // confirm NFT4 permission added edmund with input expiration
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(3000))
+        );
+        // confirm AuthLists added edmund for transferring on every tokens
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 3);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
+        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
+        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
+        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
+
+        // test that approving a token when the address has an expired ALL permission
+        // deletes the ALL permission and performs like a regular ApproveToken (does not
+        // add approve permission to all the other tokens)
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::Never),
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 2000000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("alice".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm davids's ALL permission is gone
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let may_oper: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(may_oper.is_none());
+        // confirm NFT3 did not add permission for david and that the token data did not get modified
+        // and did not touch the existing transfer permission for bob
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
+        assert_eq!(pub_meta, pub3.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 2);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        // confirm NFT4 permission added david with input expiration
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(3000))
+        );
+        let david_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == david_raw)
+            .unwrap();
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // confirm AuthLists added david for transferring on NFT4
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 4);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
+        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
+        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
+        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
+        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
+        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(david_auth.tokens[view_meta_idx].is_empty());
+        assert!(david_auth.tokens[view_owner_idx].is_empty());
+
+        // giving frank ALL permission for later test
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("frank".to_string()),
+            // will be ignored but specifying shouldn't screw anything up
+            token_id: Some("NFT4".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: None,
+            expires: Some(Expiration::AtHeight(5000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 100, 1000), handle_msg);
+        // confirm frank's ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let frank_oper_perm = all_perm.iter().find(|p| p.address == frank_raw).unwrap();
+        assert_eq!(
+            frank_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(5000))
+        );
+        assert_eq!(frank_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(frank_oper_perm.expirations[transfer_idx], None);
+        // confirm NFT4 did not add permission for frank
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft4_key).unwrap();
+        assert_eq!(pub_meta, pub4.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 2);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .is_none());
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(3000))
+        );
+        let david_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == david_raw)
+            .unwrap();
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // confirm AuthLists did not add frank
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 4);
+        assert!(auth_list.iter().find(|a| a.address == frank_raw).is_none());
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
+        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
+        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
+        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
+        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
+        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(david_auth.tokens[view_meta_idx].is_empty());
+        assert!(david_auth.tokens[view_owner_idx].is_empty());
+
+        // test revoking a token permission when address has ALL permission removes the ALL
+        // permission, and adds token permissions for all the other tokens not revoked
+        // giving them the expiration of the removed ALL permission
+        // This is same as above, but testing when the address has no AuthList already
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("frank".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: Some(AccessLevel::RevokeToken),
+            view_private_metadata: None,
+            transfer: None,
+            // this will be ignored
+            expires: Some(Expiration::AtTime(5)),
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 1000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("alice".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm frank's ALL permission is gone
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        // confirm NFT1 permission added view_owner for frank with the old ALL permission
+        // expiration
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 3);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        let frank_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .unwrap();
+        assert_eq!(
+            frank_tok_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(5000))
+        );
+        assert_eq!(frank_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(frank_tok_perm.expirations[transfer_idx], None);
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
+        assert!(priv_meta.is_none());
+        // confirm NFT2 permission
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 3);
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        let charlie_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .unwrap();
+        assert_eq!(
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        let frank_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .unwrap();
+        assert_eq!(
+            frank_tok_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(5000))
+        );
+        assert_eq!(frank_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(frank_tok_perm.expirations[transfer_idx], None);
+        // confirm NFT3 permissions do not include frank and that the token data did not get
+        // modified
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
+        assert_eq!(pub_meta, pub3.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 2);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .is_none());
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        // confirm NFT4 permission
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert_eq!(token.permissions.len(), 3);
+        let frank_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .unwrap();
+        assert_eq!(
+            frank_tok_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(5000))
+        );
+        assert_eq!(frank_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(frank_tok_perm.expirations[transfer_idx], None);
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(3000))
+        );
+        let david_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == david_raw)
+            .unwrap();
+        assert_eq!(
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists added frank with view_owner permissions for all butNFT3
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 5);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
+        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
+        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
+        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
+        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
+        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(david_auth.tokens[view_meta_idx].is_empty());
+        assert!(david_auth.tokens[view_owner_idx].is_empty());
+        let frank_auth = auth_list.iter().find(|a| a.address == frank_raw).unwrap();
+        assert_eq!(frank_auth.tokens[view_owner_idx].len(), 3);
+        assert!(frank_auth.tokens[view_owner_idx].contains(&0u32));
+        assert!(frank_auth.tokens[view_owner_idx].contains(&1u32));
+        assert!(frank_auth.tokens[view_owner_idx].contains(&3u32));
+        assert!(frank_auth.tokens[view_meta_idx].is_empty());
+        assert!(frank_auth.tokens[transfer_idx].is_empty());
+
+        // test granting ALL permission when the address has some token permissions
+        // This should remove all the token permissions and the AuthList
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("frank".to_string()),
+            token_id: None,
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: None,
+            expires: Some(Expiration::AtHeight(2500)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 100, 1000), handle_msg);
+        // confirm frank's ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let frank_oper_perm = all_perm.iter().find(|p| p.address == frank_raw).unwrap();
+        assert_eq!(
+            frank_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtHeight(2500))
+        );
+        assert_eq!(frank_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(frank_oper_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permission removed frank
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .is_none());
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        // confirm NFT2 permission removed frank
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        let charlie_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .unwrap();
+        assert_eq!(
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .is_none());
+        // confirm NFT4 permission removed frank
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .is_none());
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(3000))
+        );
+        let david_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == david_raw)
+            .unwrap();
+        assert_eq!(
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists removed frank
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 4);
+        assert!(auth_list.iter().find(|a| a.address == frank_raw).is_none());
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
+        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
+        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
+        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
+        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
+        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(david_auth.tokens[view_meta_idx].is_empty());
+        assert!(david_auth.tokens[view_owner_idx].is_empty());
+
+        // test revoking all permissions when address has ALL
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("frank".to_string()),
+            token_id: None,
+            view_owner: Some(AccessLevel::None),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm frank's ALL permission is gone
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        // confirm NFT1 permission removed frank
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .is_none());
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        // confirm NFT2 permission removed frank
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .is_none());
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        let charlie_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .unwrap();
+        assert_eq!(
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        // confirm NFT4 permission removed frank
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == frank_raw)
+            .is_none());
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(3000))
+        );
+        let david_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == david_raw)
+            .unwrap();
+        assert_eq!(
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists removed frank
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 4);
+        assert!(auth_list.iter().find(|a| a.address == frank_raw).is_none());
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
+        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
+        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
+        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
+        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
+        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
+        assert!(david_auth.tokens[view_meta_idx].is_empty());
+        assert!(david_auth.tokens[view_owner_idx].is_empty());
+
+        // test revoking a token which is address' last permission
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::RevokeToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 100, 1000), handle_msg);
+        // confirm NFT2 permission removed charlie
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        assert!(token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .is_none());
+        let edmund_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == edmund_raw)
+            .unwrap();
+        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            edmund_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000))
+        );
+        // confirm AuthLists removed charlie
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert!(auth_list
+            .iter()
+            .find(|a| a.address == charlie_raw)
+            .is_none());
+
+        // verify that storage entry for AuthLists gets removed when all are gone
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::None),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::None),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("edmund".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::None),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // verify no ALL permissions left
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        // confirm NFT1 permissions are empty
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm NFT2 permissions are empty
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm NFT3 permissions are empty (and info is intact)
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
+        assert_eq!(pub_meta, pub3.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert!(token.permissions.is_empty());
+        // confirm NFT4 permissions are empty
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // verify no AuthLists left
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
+
+        // verify revoking doesn't break anything when there are no permissions
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("edmund".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: Some(AccessLevel::RevokeToken),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::None),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // verify no ALL permissions left
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        // confirm NFT1 permissions are empty
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm NFT2 permissions are empty
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm NFT3 permissions are empty (and info is intact)
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
+        assert_eq!(pub_meta, pub3.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert!(token.permissions.is_empty());
+        // confirm NFT4 permissions are empty
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // verify no AuthLists left
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
+    }
+
+    // test approve from the cw721 spec
+    #[test]
+    fn test_cw721_approve() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, true, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test token does not exist when supply is public
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT not found"));
+
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test token does not exist when supply is private
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Not authorized to grant/revoke transfer permission for token MyNFT")
+        );
+
+        let priv_expect = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT".to_string()),
+                description: Some("metadata".to_string()),
+                image: Some("https://uri".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: priv_expect.clone(),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test contract status does not allow
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test unauthorized address attempt
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Not authorized to grant/revoke transfer permission for token MyNFT")
+        );
+
+        // test expired operator attempt
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(1000000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("MyNFT".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(500000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 2000000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Transfer authority for all tokens of alice has expired"));
+
+        let tok_key = 0u32.to_le_bytes();
+        let tok2_key = 1u32.to_le_bytes();
+        let tok3_key = 2u32.to_le_bytes();
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let david_raw = deps
+            .api
+            .canonical_address(&HumanAddr("david".to_string()))
+            .unwrap();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+
+        // test operator tries to grant permission to another operator.  This should
+        // not do anything but end successfully
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 1000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
                 },
                 contract_key: Some("".to_string()),
                 contract_code_hash: "".to_string(),
             },
             handle_msg,
         );
-        // confirm only bob's ALL permission is gone
+        // confirm charlie still has ALL permission
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
         let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
         assert_eq!(all_perm.len(), 2);
-        assert!(all_perm.iter().find(|p| p.address == bob_raw).is_none());
-        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
-        assert_eq!(
-            david_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1500000))
-        );
-        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
-        let edmund_oper_perm = all_perm.iter().find(|p| p.address == edmund_raw).unwrap();
+        let charlie_oper_perm = all_perm.iter().find(|p| p.address == charlie_raw).unwrap();
         assert_eq!(
-            edmund_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
+            charlie_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(500000))
         );
-        assert_eq!(edmund_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(edmund_oper_perm.expirations[view_owner_idx], None);
-        // confirm NFT1 permission added view_owner for bob with the old ALL permission
-        // expiration, and did not touch the existing transfer permission for bob
+        assert_eq!(charlie_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_oper_perm.expirations[view_owner_idx], None);
+        // confirm token permission did not add charlie
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(1000))
-        );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm charlie did not get added to Authlist
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
+
+        // sanity check:  operator sets approval for an expired operator
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 750000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
         );
+        // confirm charlie's expired ALL permission was removed
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
+        // confirm token permission added charlie with default expiration
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
         assert_eq!(token.owner, alice_raw);
         assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
-        assert!(priv_meta.is_none());
-        // confirm NFT2 permission for bob and charlie
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(1000))
-        );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[transfer_idx], None);
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv_expect.clone().unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        assert!(pub_meta.is_none());
+        assert_eq!(token.permissions.len(), 1);
         let charlie_tok_perm = token
             .permissions
             .iter()
@@ -2078,204 +4520,374 @@ mod tests {
         );
         assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
         assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        // confirm NFT3 permissions and that the token data did not get modified
+        // confirm AuthList added charlie
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+
+        // sanity check:  owner sets approval for an operator with only that one token
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: Some(Expiration::AtHeight(200)),
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("alice".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm bob's ALL permission was removed
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        // confirm token permission added bob
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
         assert_eq!(token.owner, alice_raw);
         assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
-        assert_eq!(pub_meta, pub3.clone().unwrap());
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 1);
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv_expect.clone().unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        assert!(pub_meta.is_none());
+        assert_eq!(token.permissions.len(), 2);
         let bob_tok_perm = token
             .permissions
             .iter()
             .find(|p| p.address == bob_raw)
             .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
         assert_eq!(
             bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm NFT4 permission for bob
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(1000))
+            Some(Expiration::AtHeight(200))
         );
         assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[transfer_idx], None);
-        // confirm AuthLists still has bob, but not with NFT3 view_owner permission
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthList added bob
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
         assert_eq!(auth_list.len(), 2);
         let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
         assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
         assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert_eq!(bob_auth.tokens[view_owner_idx].len(), 3);
-        assert!(bob_auth.tokens[view_owner_idx].contains(&0u32));
-        assert!(bob_auth.tokens[view_owner_idx].contains(&1u32));
-        assert!(!bob_auth.tokens[view_owner_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_owner_idx].contains(&3u32));
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
 
-        // test revoking all view_owner permission
+        // used to test auto-setting individual token permissions when only one token
+        // of many is approved with a different expiration than an operator's expiration
+        let priv2 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT2".to_string()),
+                description: Some("metadata2".to_string()),
+                image: Some("https://uri2".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: priv2.clone(),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT3".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT3".to_string()),
+                    description: Some("metadata3".to_string()),
+                    image: Some("https://uri3".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            // will be ignored but specifying shouldn't screw anything up
-            token_id: Some("NFT4".to_string()),
-            view_owner: Some(AccessLevel::None),
+            address: HumanAddr("david".to_string()),
+            token_id: None,
+            view_owner: None,
             view_private_metadata: None,
-            transfer: None,
-            // will be ignored but specifying shouldn't screw anything up
-            expires: Some(Expiration::AtTime(5)),
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(1000000)),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm only bob's ALL permission is gone
+        // confirm david is an operator
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
         let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 2);
-        assert!(all_perm.iter().find(|p| p.address == bob_raw).is_none());
+        assert_eq!(all_perm.len(), 1);
         let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
         assert_eq!(
             david_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1500000))
+            Some(Expiration::AtTime(1000000))
         );
         assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
         assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
-        let edmund_oper_perm = all_perm.iter().find(|p| p.address == edmund_raw).unwrap();
-        assert_eq!(
-            edmund_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("david".to_string()),
+            token_id: "MyNFT2".to_string(),
+            expires: Some(Expiration::AtHeight(300)),
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("alice".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
         );
-        assert_eq!(edmund_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(edmund_oper_perm.expirations[view_owner_idx], None);
-        // confirm NFT1 removed view_owner permission for bob, and did not touch the existing
-        // transfer permission for bob
+        // confirm david's ALL permission was removed
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        // confirm MyNFT token permission added david with ALL permission's expiration
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.permissions.len(), 3);
+        let david_tok_perm = token
             .permissions
             .iter()
-            .find(|p| p.address == bob_raw)
+            .find(|p| p.address == david_raw)
             .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
         assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
+            david_tok_perm.expirations[transfer_idx],
             Some(Expiration::AtTime(1000000))
         );
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
-        assert!(priv_meta.is_none());
-        // confirm NFT2 permission removed bob but left and charlie
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
+        // confirm MyNFT2 token permission added david with input expiration
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        let token: Token = json_load(&info_store, &tok2_key).unwrap();
         assert_eq!(token.permissions.len(), 1);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .is_none());
-        let charlie_tok_perm = token
+        let david_tok_perm = token
             .permissions
             .iter()
-            .find(|p| p.address == charlie_raw)
+            .find(|p| p.address == david_raw)
             .unwrap();
         assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(300))
         );
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        // confirm NFT3 permissions and that the token data did not get modified
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
         assert_eq!(token.owner, alice_raw);
         assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
-        assert_eq!(pub_meta, pub3.clone().unwrap());
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
-        assert!(priv_meta.is_none());
+        let priv_meta: Metadata = load(&priv_store, &tok2_key).unwrap();
+        assert_eq!(priv_meta, priv2.clone().unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok2_key).unwrap();
+        assert!(pub_meta.is_none());
+        // confirm MyNFT3 token permission added david with ALL permission's expiration
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok3_key).unwrap();
         assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
+        let david_tok_perm = token
             .permissions
             .iter()
-            .find(|p| p.address == bob_raw)
+            .find(|p| p.address == david_raw)
             .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
         assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
         );
-        // confirm NFT4 permission removed bob
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm AuthLists still has bob, but only for NFT1 and 3 transfer permission
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthList added david
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 2);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(auth_list.len(), 3);
+        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[transfer_idx].len(), 3);
+        assert!(david_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(david_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(david_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(david_auth.tokens[view_meta_idx].is_empty());
+        assert!(david_auth.tokens[view_owner_idx].is_empty());
+    }
 
-        // test if approving a token for an address that already has ALL permission does
-        // nothing if the given expiration is the same
+    // test Revoke from cw721 spec
+    #[test]
+    fn test_cw721_revoke() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test token does not exist when supply is public
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT not found"));
+
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test token does not exist when supply is private
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Not authorized to grant/revoke transfer permission for token MyNFT")
+        );
+
+        let priv_expect = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT".to_string()),
+                description: Some("metadata".to_string()),
+                image: Some("https://uri".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: priv_expect.clone(),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test contract status does not allow
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test unauthorized address attempt
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Not authorized to grant/revoke transfer permission for token MyNFT")
+        );
+
+        // test expired operator attempt
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(1000000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("edmund".to_string()),
-            token_id: Some("NFT4".to_string()),
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("MyNFT".to_string()),
             view_owner: None,
             view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtHeight(2000)),
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(500000)),
             padding: None,
         };
-        let _handle_result = handle(
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(
             &mut deps,
             Env {
                 block: BlockInfo {
                     height: 100,
-                    time: 1000,
+                    time: 2000000,
                     chain_id: "cosmos-testnet-14002".to_string(),
                 },
                 message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
+                    sender: HumanAddr("bob".to_string()),
                     sent_funds: vec![],
                 },
                 contract: cosmwasm_std::ContractInfo {
@@ -2286,45 +4898,63 @@ mod tests {
             },
             handle_msg,
         );
-        // confirm edmund still has ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 2);
-        let edmund_oper_perm = all_perm.iter().find(|p| p.address == edmund_raw).unwrap();
-        assert_eq!(
-            edmund_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Transfer authority for all tokens of alice has expired"));
+
+        let tok_key = 0u32.to_le_bytes();
+        let tok2_key = 1u32.to_le_bytes();
+        let tok3_key = 2u32.to_le_bytes();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let david_raw = deps
+            .api
+            .canonical_address(&HumanAddr("david".to_string()))
+            .unwrap();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+
+        // test operator tries to revoke permission from another operator
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 1000,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
         );
-        assert_eq!(edmund_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(edmund_oper_perm.expirations[view_owner_idx], None);
-        // confirm NFT4 permissions did not add edmund
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft4_key).unwrap();
-        assert_eq!(pub_meta, pub4.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft4_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert!(token.permissions.is_empty());
-        // confirm edmund did not get added to AuthList
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 2);
-        assert!(auth_list.iter().find(|a| a.address == edmund_raw).is_none());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Can not revoke transfer permission from an existing operator"));
 
-        // test approving a token for an address that already has ALL permission updates that
-        // token's permission's expiration, removes ALL permission, and sets token permission
-        // for all other tokens using the ALL permission's expiration
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("edmund".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtHeight(3000)),
+        // sanity check:  operator revokes approval from an expired operator will delete
+        // the expired ALL permission
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
         let _handle_result = handle(
@@ -2332,11 +4962,11 @@ mod tests {
             Env {
                 block: BlockInfo {
                     height: 100,
-                    time: 1000,
+                    time: 750000,
                     chain_id: "cosmos-testnet-14002".to_string(),
                 },
                 message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
+                    sender: HumanAddr("bob".to_string()),
                     sent_funds: vec![],
                 },
                 contract: cosmwasm_std::ContractInfo {
@@ -2347,159 +4977,77 @@ mod tests {
             },
             handle_msg,
         );
-        // confirm edmund's ALL permission is gone
+        // confirm charlie's expired ALL permission was removed
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
         let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
         assert_eq!(all_perm.len(), 1);
-        assert!(all_perm.iter().find(|p| p.address == edmund_raw).is_none());
-        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
-        assert_eq!(
-            david_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1500000))
-        );
-        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
-        // confirm NFT1 added permission for edmund,
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        // confirm NFT2 added permission for edmund,
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .is_none());
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
-        assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        // confirm NFT3 added permission for edmund and that the token data did not get modified
-        // and did not touch the existing transfer permission for bob
+        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
+        // confirm token permission is still empty
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
-        assert_eq!(pub_meta, pub3.clone().unwrap());
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert!(token.permissions.is_empty());
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 2);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv_expect.clone().unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        assert!(pub_meta.is_none());
+        // confirm AuthList is still empty
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
+
+        // sanity check: operator approves, then revokes
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: Some(Expiration::AtHeight(200)),
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
         );
-        // confirm NFT4 permission added edmund with input expiration
+        // confirm charlie does not have ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
+        // confirm token permission added charlie
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
         assert_eq!(token.permissions.len(), 1);
-        let edmund_tok_perm = token
+        let charlie_tok_perm = token
             .permissions
             .iter()
-            .find(|p| p.address == edmund_raw)
+            .find(|p| p.address == charlie_raw)
             .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
         assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(3000))
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(200))
         );
-        // confirm AuthLists added edmund for transferring on every tokens
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 3);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
-        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
-        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
-        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
-        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
-
-        // test that approving a token when the address has an expired ALL permission
-        // deletes the ALL permission and performs like a regular ApproveToken (does not
-        // add approve permission to all the other tokens)
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::Never),
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
         let _handle_result = handle(
@@ -2507,11 +5055,11 @@ mod tests {
             Env {
                 block: BlockInfo {
                     height: 100,
-                    time: 2000000,
+                    time: 100,
                     chain_id: "cosmos-testnet-14002".to_string(),
                 },
                 message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
+                    sender: HumanAddr("bob".to_string()),
                     sent_funds: vec![],
                 },
                 contract: cosmwasm_std::ContractInfo {
@@ -2522,204 +5070,61 @@ mod tests {
             },
             handle_msg,
         );
-        // confirm davids's ALL permission is gone
+        // confirm charlie does not have ALL permission
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let may_oper: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
-        assert!(may_oper.is_none());
-        // confirm NFT3 did not add permission for david and that the token data did not get modified
-        // and did not touch the existing transfer permission for bob
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
+        // confirm token permission removed charlie
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
         assert_eq!(token.owner, alice_raw);
         assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
-        assert_eq!(pub_meta, pub3.clone().unwrap());
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 2);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        // confirm NFT4 permission added david with input expiration
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(3000))
-        );
-        let david_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == david_raw)
-            .unwrap();
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm AuthLists added david for transferring on NFT4
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv_expect.clone().unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        assert!(pub_meta.is_none());
+        assert!(token.permissions.is_empty());
+        // confirm AuthList removed charlie
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 4);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
-        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
-        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
-        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
-        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
-        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
-        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(david_auth.tokens[view_meta_idx].is_empty());
-        assert!(david_auth.tokens[view_owner_idx].is_empty());
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
 
-        // giving frank ALL permission for later test
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("frank".to_string()),
-            // will be ignored but specifying shouldn't screw anything up
-            token_id: Some("NFT4".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: None,
-            expires: Some(Expiration::AtHeight(5000)),
+        // verify revoking a non-existent permission does not break anything
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm frank's ALL permission
+        // confirm charlie does not have ALL permission
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
         let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let frank_oper_perm = all_perm.iter().find(|p| p.address == frank_raw).unwrap();
-        assert_eq!(
-            frank_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(5000))
-        );
-        assert_eq!(frank_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(frank_oper_perm.expirations[transfer_idx], None);
-        // confirm NFT4 did not add permission for frank
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft4_key).unwrap();
-        assert_eq!(pub_meta, pub4.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 2);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .is_none());
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(3000))
-        );
-        let david_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == david_raw)
-            .unwrap();
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm AuthLists did not add frank
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 4);
-        assert!(auth_list.iter().find(|a| a.address == frank_raw).is_none());
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
-        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
-        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
-        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
-        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
-        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
-        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(david_auth.tokens[view_meta_idx].is_empty());
-        assert!(david_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(all_perm.len(), 1);
+        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
+        // confirm token does not list charlie
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv_expect.clone().unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        assert!(pub_meta.is_none());
+        assert!(token.permissions.is_empty());
+        // confirm AuthList doesn not contain charlie
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
 
-        // test revoking a token permission when address has ALL permission removes the ALL
-        // permission, and adds token permissions for all the other tokens not revoked
-        // giving them the expiration of the removed ALL permission
-        // This is same as above, but testing when the address has no AuthList already
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("frank".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: Some(AccessLevel::RevokeToken),
-            view_private_metadata: None,
-            transfer: None,
-            // this will be ignored
-            expires: Some(Expiration::AtTime(5)),
+        // sanity check:  owner revokes token approval for an operator with only that one token
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
         let _handle_result = handle(
@@ -2727,7 +5132,7 @@ mod tests {
             Env {
                 block: BlockInfo {
                     height: 100,
-                    time: 1000,
+                    time: 100,
                     chain_id: "cosmos-testnet-14002".to_string(),
                 },
                 message: MessageInfo {
@@ -2742,449 +5147,156 @@ mod tests {
             },
             handle_msg,
         );
-        // confirm frank's ALL permission is gone
+        // confirm bob's ALL permission was removed
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
         let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
         assert!(all_perm.is_none());
-        // confirm NFT1 permission added view_owner for frank with the old ALL permission
-        // expiration
+        // confirm token permission is empty
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 3);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        let frank_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .unwrap();
-        assert_eq!(
-            frank_tok_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(5000))
-        );
-        assert_eq!(frank_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(frank_tok_perm.expirations[transfer_idx], None);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
         assert_eq!(token.owner, alice_raw);
         assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
-        assert!(priv_meta.is_none());
-        // confirm NFT2 permission
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.permissions.len(), 3);
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
-        assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        let frank_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .unwrap();
-        assert_eq!(
-            frank_tok_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(5000))
-        );
-        assert_eq!(frank_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(frank_tok_perm.expirations[transfer_idx], None);
-        // confirm NFT3 permissions do not include frank and that the token data did not get
-        // modified
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv_expect.clone().unwrap());
         let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
-        assert_eq!(pub_meta, pub3.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 2);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .is_none());
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        // confirm NFT4 permission
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert_eq!(token.permissions.len(), 3);
-        let frank_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .unwrap();
-        assert_eq!(
-            frank_tok_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(5000))
-        );
-        assert_eq!(frank_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(frank_tok_perm.expirations[transfer_idx], None);
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(3000))
-        );
-        let david_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == david_raw)
-            .unwrap();
-        assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists added frank with view_owner permissions for all butNFT3
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        assert!(pub_meta.is_none());
+        assert!(token.permissions.is_empty());
+        // confirm AuthList does not contain bob
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 5);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
-        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
-        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
-        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
-        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
-        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
-        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(david_auth.tokens[view_meta_idx].is_empty());
-        assert!(david_auth.tokens[view_owner_idx].is_empty());
-        let frank_auth = auth_list.iter().find(|a| a.address == frank_raw).unwrap();
-        assert_eq!(frank_auth.tokens[view_owner_idx].len(), 3);
-        assert!(frank_auth.tokens[view_owner_idx].contains(&0u32));
-        assert!(frank_auth.tokens[view_owner_idx].contains(&1u32));
-        assert!(frank_auth.tokens[view_owner_idx].contains(&3u32));
-        assert!(frank_auth.tokens[view_meta_idx].is_empty());
-        assert!(frank_auth.tokens[transfer_idx].is_empty());
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
 
-        // test granting ALL permission when the address has some token permissions
-        // This should remove all the token permissions and the AuthList
+        // used to test auto-setting individual token permissions when only one token
+        // of many is revoked from an operator
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let priv2 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT2".to_string()),
+                description: Some("metadata2".to_string()),
+                image: Some("https://uri2".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: priv2.clone(),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT3".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT3".to_string()),
+                    description: Some("metadata3".to_string()),
+                    image: Some("https://uri3".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("frank".to_string()),
+            address: HumanAddr("david".to_string()),
             token_id: None,
-            view_owner: Some(AccessLevel::All),
+            view_owner: None,
             view_private_metadata: None,
-            transfer: None,
-            expires: Some(Expiration::AtHeight(2500)),
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::Never),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm frank's ALL permission
+        // confirm david is an operator
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
         let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
         assert_eq!(all_perm.len(), 1);
-        let frank_oper_perm = all_perm.iter().find(|p| p.address == frank_raw).unwrap();
-        assert_eq!(
-            frank_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtHeight(2500))
-        );
-        assert_eq!(frank_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(frank_oper_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permission removed frank
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .is_none());
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        // confirm NFT2 permission removed frank
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
-        assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .is_none());
-        // confirm NFT4 permission removed frank
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .is_none());
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(3000))
-        );
-        let david_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == david_raw)
-            .unwrap();
+        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
         assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
+            david_oper_perm.expirations[transfer_idx],
             Some(Expiration::Never)
         );
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists removed frank
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 4);
-        assert!(auth_list.iter().find(|a| a.address == frank_raw).is_none());
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
-        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
-        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
-        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
-        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
-        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
-        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(david_auth.tokens[view_meta_idx].is_empty());
-        assert!(david_auth.tokens[view_owner_idx].is_empty());
-
-        // test revoking all permissions when address has ALL
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("frank".to_string()),
-            token_id: None,
-            view_owner: Some(AccessLevel::None),
-            view_private_metadata: None,
-            transfer: None,
-            expires: None,
+        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
+        let handle_msg = HandleMsg::Revoke {
+            spender: HumanAddr("david".to_string()),
+            token_id: "MyNFT2".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm frank's ALL permission is gone
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
-        assert!(all_perm.is_none());
-        // confirm NFT1 permission removed frank
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .is_none());
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        // confirm NFT2 permission removed frank
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .is_none());
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
-        );
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
-        assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("alice".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
         );
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        // confirm NFT4 permission removed frank
+        // confirm david's ALL permission was removed
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        // confirm MyNFT token permission added david with ALL permission's expiration
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == frank_raw)
-            .is_none());
-        let edmund_tok_perm = token
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let david_tok_perm = token
             .permissions
             .iter()
-            .find(|p| p.address == edmund_raw)
+            .find(|p| p.address == david_raw)
             .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
         assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(3000))
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
         );
+        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
+        // confirm MyNFT2 token permission does not contain david
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok2_key).unwrap();
+        assert!(token.permissions.is_empty());
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Metadata = load(&priv_store, &tok2_key).unwrap();
+        assert_eq!(priv_meta, priv2.clone().unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok2_key).unwrap();
+        assert!(pub_meta.is_none());
+        // confirm MyNFT3 token permission added david with ALL permission's expiration
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok3_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
         let david_tok_perm = token
             .permissions
             .iter()
@@ -3196,308 +5308,205 @@ mod tests {
         );
         assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
         assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists removed frank
+        // confirm AuthList added david
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 4);
-        assert!(auth_list.iter().find(|a| a.address == frank_raw).is_none());
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 2);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
-        let edmund_auth = auth_list.iter().find(|a| a.address == edmund_raw).unwrap();
-        assert_eq!(edmund_auth.tokens[transfer_idx].len(), 4);
-        assert!(edmund_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(edmund_auth.tokens[transfer_idx].contains(&3u32));
-        assert!(edmund_auth.tokens[view_meta_idx].is_empty());
-        assert!(edmund_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(auth_list.len(), 1);
         let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
-        assert!(david_auth.tokens[transfer_idx].contains(&3u32));
+        assert_eq!(david_auth.tokens[transfer_idx].len(), 2);
+        assert!(david_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(!david_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(david_auth.tokens[transfer_idx].contains(&2u32));
         assert!(david_auth.tokens[view_meta_idx].is_empty());
         assert!(david_auth.tokens[view_owner_idx].is_empty());
+    }
 
-        // test revoking a token which is address' last permission
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::RevokeToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm NFT2 permission removed charlie
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        assert!(token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .is_none());
-        let edmund_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == edmund_raw)
-            .unwrap();
-        assert_eq!(edmund_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(edmund_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            edmund_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(2000))
+    // test burn
+    #[test]
+    fn test_burn() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        // confirm AuthLists removed charlie
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert!(auth_list
-            .iter()
-            .find(|a| a.address == charlie_raw)
-            .is_none());
 
-        // verify that storage entry for AuthLists gets removed when all are gone
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::None),
-            expires: None,
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: Some("metadata".to_string()),
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::None),
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test burn when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("edmund".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::None),
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "MyNFT".to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // verify no ALL permissions left
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
-        assert!(all_perm.is_none());
-        // confirm NFT1 permissions are empty
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm NFT2 permissions are empty
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm NFT3 permissions are empty (and info is intact)
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
-        assert_eq!(pub_meta, pub3.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert!(token.permissions.is_empty());
-        // confirm NFT4 permissions are empty
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // verify no AuthLists left
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
 
-        // verify revoking doesn't break anything when there are no permissions
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("edmund".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: Some(AccessLevel::RevokeToken),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::None),
-            expires: None,
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // verify no ALL permissions left
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
-        assert!(all_perm.is_none());
-        // confirm NFT1 permissions are empty
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm NFT2 permissions are empty
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm NFT3 permissions are empty (and info is intact)
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft3_key).unwrap();
-        assert_eq!(pub_meta, pub3.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft3_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert!(token.permissions.is_empty());
-        // confirm NFT4 permissions are empty
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft4_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // verify no AuthLists left
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
-    }
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test when burn is disabled
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "MyNFT".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Burn functionality is not enabled for this token"));
 
-    // test approve from the cw721 spec
-    #[test]
-    fn test_cw721_approve() {
         let (init_result, mut deps) =
-            init_helper_with_config(true, false, true, false, false, false, false);
+            init_helper_with_config(true, false, false, false, false, false, true);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
 
-        // test token does not exist when supply is public
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("bob".to_string()),
+        // test token not found when supply is public
+        let handle_msg = HandleMsg::BurnNft {
             token_id: "MyNFT".to_string(),
-            expires: None,
+            memo: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
         assert!(error.contains("Token ID: MyNFT not found"));
 
-        let (init_result, mut deps) = init_helper_default();
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, true);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
 
-        // test token does not exist when supply is private
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("bob".to_string()),
+        // test token not found when supply is private
+        let handle_msg = HandleMsg::BurnNft {
             token_id: "MyNFT".to_string(),
-            expires: None,
+            memo: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(
-            error.contains("Not authorized to grant/revoke transfer permission for token MyNFT")
-        );
+        assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
 
-        let priv_expect = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT".to_string()),
-                description: Some("metadata".to_string()),
-                image: Some("uri".to_string()),
-                ..Extension::default()
-            }),
-        });
         let handle_msg = HandleMsg::MintNft {
             token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv_expect.clone(),
-            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: Some("privmetadata".to_string()),
+                    image: Some("https://privuri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: Some("pubmetadata".to_string()),
+                    image: Some("https://puburi".to_string()),
+                    ..Extension::default()
+                }),
+            }),
             royalty_info: None,
             serial_number: None,
-            memo: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test contract status does not allow
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("bob".to_string()),
+        // test unauthorized addres
+        let handle_msg = HandleMsg::BurnNft {
             token_id: "MyNFT".to_string(),
-            expires: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
 
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::Normal,
+        // test expired token approval
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test unauthorized address attempt
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("bob".to_string()),
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::BurnNft {
             token_id: "MyNFT".to_string(),
-            expires: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(
-            error.contains("Not authorized to grant/revoke transfer permission for token MyNFT")
+        let handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("charlie".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
         );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Access to token MyNFT has expired"));
 
-        // test expired operator attempt
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(1000000)),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("MyNFT".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(500000)),
+        // test expired ALL approval
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("bob".to_string()),
+            expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("charlie".to_string()),
+        let handle_msg = HandleMsg::BurnNft {
             token_id: "MyNFT".to_string(),
-            expires: None,
+            memo: None,
             padding: None,
         };
         let handle_result = handle(
@@ -3505,7 +5514,7 @@ mod tests {
             Env {
                 block: BlockInfo {
                     height: 100,
-                    time: 2000000,
+                    time: 100,
                     chain_id: "cosmos-testnet-14002".to_string(),
                 },
                 message: MessageInfo {
@@ -3521,11 +5530,16 @@ mod tests {
             handle_msg,
         );
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Transfer authority for all tokens of alice has expired"));
+        assert!(error.contains("Access to all tokens of alice has expired"));
 
         let tok_key = 0u32.to_le_bytes();
         let tok2_key = 1u32.to_le_bytes();
         let tok3_key = 2u32.to_le_bytes();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
         let bob_raw = deps
             .api
             .canonical_address(&HumanAddr("bob".to_string()))
@@ -3538,29 +5552,19 @@ mod tests {
             .api
             .canonical_address(&HumanAddr("david".to_string()))
             .unwrap();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
-        let transfer_idx = PermissionType::Transfer.to_usize();
 
-        // test operator tries to grant permission to another operator.  This should
-        // not do anything but end successfully
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("charlie".to_string()),
+        // sanity check: operator burns
+        let handle_msg = HandleMsg::BurnNft {
             token_id: "MyNFT".to_string(),
-            expires: None,
+            memo: Some("Burn, baby, burn!".to_string()),
             padding: None,
         };
         let _handle_result = handle(
             &mut deps,
             Env {
                 block: BlockInfo {
-                    height: 100,
-                    time: 1000,
+                    height: 1,
+                    time: 100,
                     chain_id: "cosmos-testnet-14002".to_string(),
                 },
                 message: MessageInfo {
@@ -3575,43 +5579,143 @@ mod tests {
             },
             handle_msg,
         );
-        // confirm charlie still has ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 2);
-        let charlie_oper_perm = all_perm.iter().find(|p| p.address == charlie_raw).unwrap();
+        // confirm token was removed from the maps
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: Option<u32> = may_load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: Option<String> = may_load(&map2id, &tok_key).unwrap();
+        assert!(id.is_none());
+        // confirm token info was deleted from storage
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Option<Token> = json_may_load(&info_store, &tok_key).unwrap();
+        assert!(token.is_none());
+        // confirm the metadata has been deleted from storage
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &tok_key).unwrap();
+        assert!(priv_meta.is_none());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        assert!(pub_meta.is_none());
+        // confirm the tx was logged to both parties
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].token_id, "MyNFT".to_string());
         assert_eq!(
-            charlie_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(500000))
+            txs[0].action,
+            TxAction::Burn {
+                owner: HumanAddr("alice".to_string()),
+                burner: Some(HumanAddr("bob".to_string())),
+            }
         );
-        assert_eq!(charlie_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_oper_perm.expirations[view_owner_idx], None);
-        // confirm token permission did not add charlie
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm charlie did not get added to Authlist
+        assert_eq!(txs[0].memo, Some("Burn, baby, burn!".to_string()));
+        let (tx2, total) = get_txs(&deps.api, &deps.storage, &bob_raw, 0, 1).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(txs, tx2);
+        // confirm charlie's AuthList was removed because the only token was burned
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
         assert!(auth_list.is_none());
+        // confirm the token was removed from the owner's list
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 0);
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
 
-        // sanity check:  operator sets approval for an expired operator
+        let transfer_idx = PermissionType::Transfer.to_usize();
+
+        let priv2 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT2".to_string()),
+                description: Some("privmetadata2".to_string()),
+                image: Some("https://privuri2".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let pub2 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT2".to_string()),
+                description: Some("pubmetadata2".to_string()),
+                image: Some("https://puburi2".to_string()),
+                ..Extension::default()
+            }),
+        });
+        // sanity check: address with token permission burns it
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: priv2.clone(),
+            public_metadata: pub2.clone(),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let priv3 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT3".to_string()),
+                description: Some("privmetadata3".to_string()),
+                image: Some("https://privuri3".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let pub3 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT3".to_string()),
+                description: Some("pubmetadata3".to_string()),
+                image: Some("https://puburi3".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT3".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: priv3.clone(),
+            public_metadata: pub3.clone(),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let handle_msg = HandleMsg::Approve {
             spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            expires: None,
+            token_id: "MyNFT2".to_string(),
+            expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("david".to_string()),
+            token_id: "MyNFT3".to_string(),
+            expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "MyNFT2".to_string(),
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(
             &mut deps,
             Env {
                 block: BlockInfo {
-                    height: 100,
-                    time: 750000,
+                    height: 1,
+                    time: 100,
                     chain_id: "cosmos-testnet-14002".to_string(),
                 },
                 message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
+                    sender: HumanAddr("charlie".to_string()),
                     sent_funds: vec![],
                 },
                 contract: cosmwasm_std::ContractInfo {
@@ -3622,830 +5726,1044 @@ mod tests {
             },
             handle_msg,
         );
-        // confirm charlie's expired ALL permission was removed
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
-        // confirm token permission added charlie with default expiration
+        // confirm token was removed from the maps
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: Option<u32> = may_load(&map2idx, "MyNFT2".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: Option<String> = may_load(&map2id, &01u32.to_le_bytes()).unwrap();
+        assert!(id.is_none());
+        // confirm token info was deleted from storage
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        let token: Option<Token> = json_may_load(&info_store, &tok2_key).unwrap();
+        assert!(token.is_none());
+        // confirm MyNFT3 is intact
+        let token: Token = json_load(&info_store, &tok3_key).unwrap();
+        let david_perm = token.permissions.iter().find(|p| p.address == david_raw);
+        assert!(david_perm.is_some());
         assert_eq!(token.owner, alice_raw);
         assert!(token.unwrapped);
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv_expect.clone().unwrap());
+        let priv_meta: Metadata = load(&priv_store, &tok3_key).unwrap();
+        assert_eq!(priv_meta, priv3.unwrap());
         let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        let pub_meta: Metadata = load(&pub_store, &tok3_key).unwrap();
+        assert_eq!(pub_meta, pub3.unwrap());
+        // confirm the MyNFT2 metadata has been deleted from storage
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &tok2_key).unwrap();
+        assert!(priv_meta.is_none());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok2_key).unwrap();
         assert!(pub_meta.is_none());
-        assert_eq!(token.permissions.len(), 1);
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
+        // confirm the tx was logged to both parties
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
+        assert_eq!(total, 5);
+        assert_eq!(txs[0].token_id, "MyNFT2".to_string());
         assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
+            txs[0].action,
+            TxAction::Burn {
+                owner: HumanAddr("alice".to_string()),
+                burner: Some(HumanAddr("charlie".to_string())),
+            }
         );
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthList added charlie
+        assert!(txs[0].memo.is_none());
+        let (tx2, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(txs, tx2);
+        // confirm charlie's AuthList was removed because his only approved token was burned
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
         assert_eq!(auth_list.len(), 1);
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw);
+        assert!(charlie_auth.is_none());
+        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
+        assert!(david_auth.tokens[transfer_idx].contains(&2u32));
+        // confirm the token was removed from the owner's list
+        assert!(!Inventory::owns(&deps.storage, &alice_raw, 1).unwrap());
+        assert!(Inventory::owns(&deps.storage, &alice_raw, 2).unwrap());
 
-        // sanity check:  owner sets approval for an operator with only that one token
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
-            expires: Some(Expiration::AtHeight(200)),
+        // sanity check: owner burns
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "MyNFT3".to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        // confirm bob's ALL permission was removed
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
-        assert!(all_perm.is_none());
-        // confirm token permission added bob
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm token was removed from the maps
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: Option<u32> = may_load(&map2idx, "MyNFT3".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: Option<String> = may_load(&map2id, &02u32.to_le_bytes()).unwrap();
+        assert!(id.is_none());
+        // confirm token info was deleted from storage
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
+        let token: Option<Token> = json_may_load(&info_store, &tok3_key).unwrap();
+        assert!(token.is_none());
+        // confirm the metadata has been deleted from storage
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv_expect.clone().unwrap());
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &tok3_key).unwrap();
+        assert!(priv_meta.is_none());
         let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok3_key).unwrap();
         assert!(pub_meta.is_none());
-        assert_eq!(token.permissions.len(), 2);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
+        // confirm the tx was logged
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
+        assert_eq!(total, 6);
+        assert_eq!(txs[0].token_id, "MyNFT3".to_string());
         assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(200))
+            txs[0].action,
+            TxAction::Burn {
+                owner: HumanAddr("alice".to_string()),
+                burner: None,
+            }
+        );
+        assert!(txs[0].memo.is_none());
+        // confirm david's AuthList was removed because the only token was burned
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
+        // confirm the token was removed from the owner's list
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 0);
+        assert!(!inventory.contains(&deps.storage, 2).unwrap());
+    }
+
+    // test batch burn
+    #[test]
+    fn test_batch_burn() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test burn when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test when burn is disabled
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Burn functionality is not enabled for this token"));
+
+        // set up for batch burn test
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthList added bob
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 2);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // used to test auto-setting individual token permissions when only one token
-        // of many is approved with a different expiration than an operator's expiration
-        let priv2 = Some(Metadata {
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let priv3 = Some(Metadata {
             token_uri: None,
             extension: Some(Extension {
-                name: Some("MyNFT2".to_string()),
-                description: Some("metadata2".to_string()),
-                image: Some("uri2".to_string()),
+                name: Some("MyNFT3".to_string()),
+                description: Some("privmetadata3".to_string()),
+                image: Some("https://privuri3".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let pub3 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT3".to_string()),
+                description: Some("pubmetadata3".to_string()),
+                image: Some("https://puburi3".to_string()),
                 ..Extension::default()
             }),
         });
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT2".to_string()),
+            token_id: Some("NFT3".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv2.clone(),
+            private_metadata: priv3.clone(),
+            public_metadata: pub3.clone(),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT4".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT3".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT3".to_string()),
-                    description: Some("metadata3".to_string()),
-                    image: Some("uri3".to_string()),
-                    ..Extension::default()
-                }),
-            }),
+            token_id: Some("NFT5".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT6".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT7".to_string()),
+            owner: Some(HumanAddr("charlie".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT8".to_string()),
+            owner: Some(HumanAddr("charlie".to_string())),
+            private_metadata: None,
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT5".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT5".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT6".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT7".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
             token_id: None,
             view_owner: None,
             view_private_metadata: None,
             transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(1000000)),
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm david is an operator
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
-        assert_eq!(
-            david_oper_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("david".to_string()),
-            token_id: "MyNFT2".to_string(),
-            expires: Some(Expiration::AtHeight(300)),
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+
+        // test bob burning a list, but trying to burn the same token twice
+        let burns = vec![
+            Burn {
+                token_ids: vec!["NFT1".to_string(), "NFT3".to_string()],
+                memo: None,
+            },
+            Burn {
+                token_ids: vec!["NFT6".to_string()],
+                memo: None,
+            },
+            Burn {
+                token_ids: vec!["NFT6".to_string()],
+                memo: None,
+            },
+            Burn {
+                token_ids: vec!["NFT8".to_string()],
+                memo: Some("Phew!".to_string()),
+            },
+        ];
+        let handle_msg = HandleMsg::BatchBurnNft {
+            burns,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        // confirm david's ALL permission was removed
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
-        assert!(all_perm.is_none());
-        // confirm MyNFT token permission added david with ALL permission's expiration
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.permissions.len(), 3);
-        let david_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == david_raw)
-            .unwrap();
-        assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        // confirm MyNFT2 token permission added david with input expiration
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok2_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let david_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == david_raw)
-            .unwrap();
-        assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(300))
-        );
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok2_key).unwrap();
-        assert_eq!(priv_meta, priv2.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok2_key).unwrap();
-        assert!(pub_meta.is_none());
-        // confirm MyNFT3 token permission added david with ALL permission's expiration
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok3_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let david_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == david_raw)
-            .unwrap();
-        assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthList added david
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 3);
-        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[transfer_idx].len(), 3);
-        assert!(david_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(david_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(david_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(david_auth.tokens[view_meta_idx].is_empty());
-        assert!(david_auth.tokens[view_owner_idx].is_empty());
-    }
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        // because the token no longer exists after burning it, it will say you are not
+        // authorized if supply is private, and token not found if public
+        assert!(error.contains("You are not authorized to perform this action on token NFT6"));
 
-    // test Revoke from cw721 spec
-    #[test]
-    fn test_cw721_revoke() {
+        // set up for batch burn test
         let (init_result, mut deps) =
-            init_helper_with_config(true, false, false, false, false, false, false);
+            init_helper_with_config(false, false, false, false, false, false, true);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        // test token does not exist when supply is public
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Token ID: MyNFT not found"));
-
-        let (init_result, mut deps) = init_helper_default();
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test token does not exist when supply is private
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(
-            error.contains("Not authorized to grant/revoke transfer permission for token MyNFT")
-        );
-
-        let priv_expect = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT".to_string()),
-                description: Some("metadata".to_string()),
-                image: Some("uri".to_string()),
-                ..Extension::default()
-            }),
-        });
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("NFT3".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv_expect.clone(),
+            private_metadata: priv3.clone(),
+            public_metadata: pub3.clone(),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT4".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test contract status does not allow
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT5".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT6".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
-
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT7".to_string()),
+            owner: Some(HumanAddr("charlie".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test unauthorized address attempt
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT8".to_string()),
+            owner: Some(HumanAddr("charlie".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(
-            error.contains("Not authorized to grant/revoke transfer permission for token MyNFT")
-        );
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test expired operator attempt
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
             view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(1000000)),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("charlie".to_string()),
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
             view_owner: None,
             view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(500000)),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            padding: None,
-        };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 2000000,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Transfer authority for all tokens of alice has expired"));
-
-        let tok_key = 0u32.to_le_bytes();
-        let tok2_key = 1u32.to_le_bytes();
-        let tok3_key = 2u32.to_le_bytes();
-        let charlie_raw = deps
-            .api
-            .canonical_address(&HumanAddr("charlie".to_string()))
-            .unwrap();
-        let david_raw = deps
-            .api
-            .canonical_address(&HumanAddr("david".to_string()))
-            .unwrap();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-
-        // test operator tries to revoke permission from another operator
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
             padding: None,
         };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 1000,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Can not revoke transfer permission from an existing operator"));
-
-        // sanity check:  operator revokes approval from an expired operator will delete
-        // the expired ALL permission
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT5".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 750000,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        // confirm charlie's expired ALL permission was removed
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
-        // confirm token permission is still empty
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert!(token.permissions.is_empty());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv_expect.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
-        assert!(pub_meta.is_none());
-        // confirm AuthList is still empty
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
-
-        // sanity check: operator approves, then revokes
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            expires: Some(Expiration::AtHeight(200)),
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT5".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        // confirm charlie does not have ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
-        // confirm token permission added charlie
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
-        assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(200))
-        );
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT6".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        // confirm charlie does not have ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
-        // confirm token permission removed charlie
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv_expect.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
-        assert!(pub_meta.is_none());
-        assert!(token.permissions.is_empty());
-        // confirm AuthList removed charlie
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
-
-        // verify revoking a non-existent permission does not break anything
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT7".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm charlie does not have ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        assert!(all_perm.iter().find(|p| p.address == charlie_raw).is_none());
-        // confirm token does not list charlie
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv_expect.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
-        assert!(pub_meta.is_none());
-        assert!(token.permissions.is_empty());
-        // confirm AuthList doesn not contain charlie
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
-
-        // sanity check:  owner revokes token approval for an operator with only that one token
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+
+        // test bob burning a list, but one is not authorized
+        let burns = vec![
+            Burn {
+                token_ids: vec![
+                    "NFT1".to_string(),
+                    "NFT3".to_string(),
+                    "NFT6".to_string(),
+                    "NFT2".to_string(),
+                ],
+                memo: None,
             },
-            handle_msg,
+            Burn {
+                token_ids: vec!["NFT8".to_string()],
+                memo: Some("Phew!".to_string()),
+            },
+        ];
+        let handle_msg = HandleMsg::BatchBurnNft {
+            burns,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+
+        // set up for batch burn test
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, false, false, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        // confirm bob's ALL permission was removed
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
-        assert!(all_perm.is_none());
-        // confirm token permission is empty
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv_expect.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
-        assert!(pub_meta.is_none());
-        assert!(token.permissions.is_empty());
-        // confirm AuthList does not contain bob
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // used to test auto-setting individual token permissions when only one token
-        // of many is revoked from an operator
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::Normal,
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let priv2 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT2".to_string()),
-                description: Some("metadata2".to_string()),
-                image: Some("uri2".to_string()),
-                ..Extension::default()
-            }),
-        });
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT2".to_string()),
+            token_id: Some("NFT3".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv2.clone(),
+            private_metadata: priv3.clone(),
+            public_metadata: pub3.clone(),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT4".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT5".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT6".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT7".to_string()),
+            owner: Some(HumanAddr("charlie".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT8".to_string()),
+            owner: Some(HumanAddr("charlie".to_string())),
+            private_metadata: None,
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT3".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT3".to_string()),
-                    description: Some("metadata3".to_string()),
-                    image: Some("uri3".to_string()),
-                    ..Extension::default()
-                }),
-            }),
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT5".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT5".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT6".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT7".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
             token_id: None,
             view_owner: None,
             view_private_metadata: None,
             transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::Never),
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm david is an operator
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let david_oper_perm = all_perm.iter().find(|p| p.address == david_raw).unwrap();
-        assert_eq!(
-            david_oper_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(david_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_oper_perm.expirations[view_owner_idx], None);
-        let handle_msg = HandleMsg::Revoke {
-            spender: HumanAddr("david".to_string()),
-            token_id: "MyNFT2".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+
+        // test bob burning NFT1 and 3 from alice with token permission,
+        // burning NFT6 as the owner,
+        // and burning NFT7 and NFT8 with ALL permission
+        let burns = vec![
+            Burn {
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            },
+            Burn {
+                token_ids: vec!["NFT3".to_string()],
+                memo: None,
+            },
+            Burn {
+                token_ids: vec!["NFT6".to_string(), "NFT7".to_string(), "NFT8".to_string()],
+                memo: Some("Phew!".to_string()),
+            },
+        ];
+        let handle_msg = HandleMsg::BatchBurnNft {
+            burns,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        // confirm david's ALL permission was removed
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
-        assert!(all_perm.is_none());
-        // confirm MyNFT token permission added david with ALL permission's expiration
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        let tok1_key = 0u32.to_le_bytes();
+        let tok2_key = 1u32.to_le_bytes();
+        let tok3_key = 2u32.to_le_bytes();
+        let tok6_key = 5u32.to_le_bytes();
+        let tok7_key = 6u32.to_le_bytes();
+        let tok8_key = 6u32.to_le_bytes();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let bob_key = bob_raw.as_slice();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let charlie_key = charlie_raw.as_slice();
+        // confirm correct tokens were removed from the maps
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: Option<u32> = may_load(&map2idx, "NFT1".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let index: Option<u32> = may_load(&map2idx, "NFT2".as_bytes()).unwrap();
+        assert!(index.is_some());
+        let index: Option<u32> = may_load(&map2idx, "NFT3".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let index: Option<u32> = may_load(&map2idx, "NFT4".as_bytes()).unwrap();
+        assert!(index.is_some());
+        let index: Option<u32> = may_load(&map2idx, "NFT5".as_bytes()).unwrap();
+        assert!(index.is_some());
+        let index: Option<u32> = may_load(&map2idx, "NFT6".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let index: Option<u32> = may_load(&map2idx, "NFT7".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let index: Option<u32> = may_load(&map2idx, "NFT8".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: Option<String> = may_load(&map2id, &0u32.to_le_bytes()).unwrap();
+        assert!(id.is_none());
+        let id: Option<String> = may_load(&map2id, &1u32.to_le_bytes()).unwrap();
+        assert!(id.is_some());
+        let id: Option<String> = may_load(&map2id, &2u32.to_le_bytes()).unwrap();
+        assert!(id.is_none());
+        let id: Option<String> = may_load(&map2id, &3u32.to_le_bytes()).unwrap();
+        assert!(id.is_some());
+        let id: Option<String> = may_load(&map2id, &4u32.to_le_bytes()).unwrap();
+        assert!(id.is_some());
+        let id: Option<String> = may_load(&map2id, &5u32.to_le_bytes()).unwrap();
+        assert!(id.is_none());
+        let id: Option<String> = may_load(&map2id, &6u32.to_le_bytes()).unwrap();
+        assert!(id.is_none());
+        let id: Option<String> = may_load(&map2id, &7u32.to_le_bytes()).unwrap();
+        assert!(id.is_none());
+        // confirm token infos were deleted from storage
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        let token: Option<Token> = json_may_load(&info_store, &tok1_key).unwrap();
+        assert!(token.is_none());
+        let token: Option<Token> = json_may_load(&info_store, &tok3_key).unwrap();
+        assert!(token.is_none());
+        let token: Option<Token> = json_may_load(&info_store, &tok6_key).unwrap();
+        assert!(token.is_none());
+        let token: Option<Token> = json_may_load(&info_store, &tok7_key).unwrap();
+        assert!(token.is_none());
+        let token: Option<Token> = json_may_load(&info_store, &tok8_key).unwrap();
+        assert!(token.is_none());
+        // confirm NFT3 metadata has been deleted from storage
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &tok3_key).unwrap();
+        assert!(priv_meta.is_none());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok3_key).unwrap();
+        assert!(pub_meta.is_none());
+        // confirm NFT2 is intact
+        let token: Token = json_load(&info_store, &tok2_key).unwrap();
         assert_eq!(token.permissions.len(), 1);
-        let david_tok_perm = token
+        let charlie_tok_perm = token
             .permissions
             .iter()
-            .find(|p| p.address == david_raw)
+            .find(|p| p.address == charlie_raw)
             .unwrap();
         assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
+            charlie_tok_perm.expirations[view_meta_idx],
             Some(Expiration::Never)
         );
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        // confirm MyNFT2 token permission does not contain david
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok2_key).unwrap();
-        assert!(token.permissions.is_empty());
+        assert_eq!(charlie_tok_perm.expirations[transfer_idx], None);
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
         assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok2_key).unwrap();
-        assert_eq!(priv_meta, priv2.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok2_key).unwrap();
-        assert!(pub_meta.is_none());
-        // confirm MyNFT3 token permission added david with ALL permission's expiration
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok3_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let david_tok_perm = token
-            .permissions
+        assert!(!token.unwrapped);
+        // confirm owner lists are correct
+        // alice only owns NFT2
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 1).unwrap());
+        // bob owns NFT4 and NFT5
+        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 2);
+        assert!(inventory.contains(&deps.storage, 3).unwrap());
+        assert!(inventory.contains(&deps.storage, 4).unwrap());
+        // charlie does not own any
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 0);
+        // confirm AuthLists are correct
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        // alice gave charlie view metadata permission on NFT2
+        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(alice_list.len(), 1);
+        let charlie_auth = alice_list
             .iter()
-            .find(|p| p.address == david_raw)
+            .find(|a| a.address == charlie_raw)
             .unwrap();
+        assert_eq!(charlie_auth.tokens[view_meta_idx].len(), 1);
+        assert!(charlie_auth.tokens[view_meta_idx].contains(&1u32));
+        assert!(charlie_auth.tokens[transfer_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        // bob gave charlie view owner and view metadata permission on NFT5
+        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
+        assert_eq!(bob_list.len(), 2);
+        let charlie_auth = bob_list.iter().find(|a| a.address == charlie_raw).unwrap();
+        assert_eq!(charlie_auth.tokens[view_meta_idx].len(), 1);
+        assert!(charlie_auth.tokens[view_meta_idx].contains(&4u32));
+        assert!(charlie_auth.tokens[transfer_idx].is_empty());
+        assert_eq!(charlie_auth.tokens[view_owner_idx].len(), 1);
+        assert!(charlie_auth.tokens[view_owner_idx].contains(&4u32));
+        // bob gave alice view owner permission on NFT4 and NFT5
+        // and transfer permission on NFT5
+        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
+        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
+        assert!(alice_auth.tokens[transfer_idx].contains(&4u32));
+        assert!(alice_auth.tokens[view_meta_idx].is_empty());
+        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 2);
+        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
+        assert!(alice_auth.tokens[view_owner_idx].contains(&4u32));
+        // charlie has no tokens so should not have any AuthLists
+        let charlie_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
+        assert!(charlie_list.is_none());
+        // confirm one of the txs
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &bob_raw, 0, 3).unwrap();
+        assert_eq!(total, 8);
+        assert_eq!(txs.len(), 3);
+        assert_eq!(txs[0].token_id, "NFT8".to_string());
         assert_eq!(
-            david_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
+            txs[0].action,
+            TxAction::Burn {
+                owner: HumanAddr("charlie".to_string()),
+                burner: Some(HumanAddr("bob".to_string())),
+            }
         );
-        assert_eq!(david_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(david_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthList added david
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[transfer_idx].len(), 2);
-        assert!(david_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(!david_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(david_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(david_auth.tokens[view_meta_idx].is_empty());
-        assert!(david_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(txs[0].memo, Some("Phew!".to_string()));
+        assert_eq!(txs[1].memo, Some("Phew!".to_string()));
+        assert_eq!(txs[2].memo, Some("Phew!".to_string()));
+        let (tx2, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
+        assert_eq!(total, 4);
+        assert_eq!(txs[0], tx2[0]);
     }
 
-    // test burn
+    // test transfer
     #[test]
-    fn test_burn() {
+    fn test_transfer() {
         let (init_result, mut deps) =
             init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
@@ -4462,25 +6780,27 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("MyNFT".to_string()),
                     description: Some("metadata".to_string()),
-                    image: Some("uri".to_string()),
+                    image: Some("https://uri".to_string()),
                     ..Extension::default()
                 }),
             }),
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test burn when status prevents it
+        // test transfer when status prevents it
         let handle_msg = HandleMsg::SetContractStatus {
             level: ContractStatus::StopTransactions,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::BurnNft {
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
             token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
@@ -4495,18 +6815,8 @@ mod tests {
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test when burn is disabled
-        let handle_msg = HandleMsg::BurnNft {
-            token_id: "MyNFT".to_string(),
-            memo: None,
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Burn functionality is not enabled for this token"));
-
         let (init_result, mut deps) =
-            init_helper_with_config(true, false, false, false, false, false, true);
+            init_helper_with_config(true, false, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
@@ -4514,7 +6824,8 @@ mod tests {
         );
 
         // test token not found when supply is public
-        let handle_msg = HandleMsg::BurnNft {
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
             token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
@@ -4524,7 +6835,7 @@ mod tests {
         assert!(error.contains("Token ID: MyNFT not found"));
 
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, true);
+            init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
@@ -4532,7 +6843,8 @@ mod tests {
         );
 
         // test token not found when supply is private
-        let handle_msg = HandleMsg::BurnNft {
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
             token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
@@ -4541,41 +6853,55 @@ mod tests {
         let error = extract_error_msg(handle_result);
         assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
 
+        let priv1 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT".to_string()),
+                description: Some("privmetadata".to_string()),
+                image: Some("https://privuri".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let pub1 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT".to_string()),
+                description: Some("pubmetadata".to_string()),
+                image: Some("https://puburi".to_string()),
+                ..Extension::default()
+            }),
+        });
         let handle_msg = HandleMsg::MintNft {
             token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: Some("privmetadata".to_string()),
-                    image: Some("privuri".to_string()),
-                    ..Extension::default()
-                }),
-            }),
-            public_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: Some("pubmetadata".to_string()),
-                    image: Some("puburi".to_string()),
-                    ..Extension::default()
-                }),
-            }),
+            private_metadata: priv1.clone(),
+            public_metadata: pub1.clone(),
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test unauthorized addres
-        let handle_msg = HandleMsg::BurnNft {
+        // test unauthorized sender (but we'll give him view owner access)
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("MyNFT".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
             token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
         assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
 
@@ -4584,10 +6910,12 @@ mod tests {
             spender: HumanAddr("charlie".to_string()),
             token_id: "MyNFT".to_string(),
             expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::BurnNft {
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
             token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
@@ -4619,10 +6947,12 @@ mod tests {
         let handle_msg = HandleMsg::ApproveAll {
             operator: HumanAddr("bob".to_string()),
             expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::BurnNft {
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
             token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
@@ -4651,8 +6981,6 @@ mod tests {
         assert!(error.contains("Access to all tokens of alice has expired"));
 
         let tok_key = 0u32.to_le_bytes();
-        let tok2_key = 1u32.to_le_bytes();
-        let tok3_key = 2u32.to_le_bytes();
         let alice_raw = deps
             .api
             .canonical_address(&HumanAddr("alice".to_string()))
@@ -4666,15 +6994,22 @@ mod tests {
             .api
             .canonical_address(&HumanAddr("charlie".to_string()))
             .unwrap();
+        let charlie_key = charlie_raw.as_slice();
         let david_raw = deps
             .api
             .canonical_address(&HumanAddr("david".to_string()))
             .unwrap();
+        let david_key = david_raw.as_slice();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
 
-        // sanity check: operator burns
-        let handle_msg = HandleMsg::BurnNft {
+        // confirm that transfering to the same address that owns the token does not
+        // erase the current permissions
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("alice".to_string()),
             token_id: "MyNFT".to_string(),
-            memo: Some("Burn, baby, burn!".to_string()),
+            memo: Some("Xfer it".to_string()),
             padding: None,
         };
         let _handle_result = handle(
@@ -4697,126 +7032,167 @@ mod tests {
             },
             handle_msg,
         );
-        // confirm token was removed from the maps
+        // confirm token was not removed from the maps
         let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: Option<u32> = may_load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        assert!(index.is_none());
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
         let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: Option<String> = may_load(&map2id, &tok_key).unwrap();
-        assert!(id.is_none());
-        // confirm token info was deleted from storage
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
+        // confirm token info is the same
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Option<Token> = json_may_load(&info_store, &tok_key).unwrap();
-        assert!(token.is_none());
-        // confirm the metadata has been deleted from storage
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert_eq!(token.permissions.len(), 2);
+        let charlie_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .unwrap();
+        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            charlie_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(10))
+        );
+        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[transfer_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[view_owner_idx],
+            Some(Expiration::Never)
+        );
+        assert!(token.unwrapped);
+        // confirm no transfer tx was logged (latest should be the mint tx)
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(
+            txs[0].action,
+            TxAction::Mint {
+                minter: HumanAddr("admin".to_string()),
+                recipient: HumanAddr("alice".to_string()),
+            }
+        );
+        // confirm the owner list is correct
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        // confirm charlie's and bob's AuthList were not changed
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(alice_list.len(), 2);
+        let charlie_auth = alice_list
+            .iter()
+            .find(|a| a.address == charlie_raw)
+            .unwrap();
+        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
+        assert!(charlie_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
+        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[view_owner_idx].len(), 1);
+        assert!(bob_auth.tokens[view_owner_idx].contains(&0u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[transfer_idx].is_empty());
+
+        // sanity check: operator transfers
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("david".to_string()),
+            token_id: "MyNFT".to_string(),
+            memo: Some("Xfer it".to_string()),
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 1,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm token was not removed from the maps
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
+        // confirm token belongs to david now and permissions have been cleared
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, david_raw);
+        assert!(token.permissions.is_empty());
+        assert!(token.unwrapped);
+        // confirm the metadata is intact
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &tok_key).unwrap();
-        assert!(priv_meta.is_none());
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv1.clone().unwrap());
         let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok_key).unwrap();
-        assert!(pub_meta.is_none());
-        // confirm the tx was logged to both parties
+        let pub_meta: Metadata = load(&pub_store, &tok_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        // confirm the tx was logged to all involved parties
         let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
         assert_eq!(total, 2);
         assert_eq!(txs.len(), 1);
         assert_eq!(txs[0].token_id, "MyNFT".to_string());
         assert_eq!(
             txs[0].action,
-            TxAction::Burn {
-                owner: HumanAddr("alice".to_string()),
-                burner: Some(HumanAddr("bob".to_string())),
+            TxAction::Transfer {
+                from: HumanAddr("alice".to_string()),
+                sender: Some(HumanAddr("bob".to_string())),
+                recipient: HumanAddr("david".to_string()),
             }
         );
-        assert_eq!(txs[0].memo, Some("Burn, baby, burn!".to_string()));
+        assert_eq!(txs[0].memo, Some("Xfer it".to_string()));
         let (tx2, total) = get_txs(&deps.api, &deps.storage, &bob_raw, 0, 1).unwrap();
         assert_eq!(total, 1);
+        let (tx3, total) = get_txs(&deps.api, &deps.storage, &david_raw, 0, 1).unwrap();
+        assert_eq!(total, 1);
         assert_eq!(txs, tx2);
-        // confirm charlie's AuthList was removed because the only token was burned
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
-        // confirm the token was removed from the owner's list
+        assert_eq!(tx2, tx3);
+        // confirm both owner lists are correct
         let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
         assert_eq!(inventory.info.count, 0);
         assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        let inventory = Inventory::new(&deps.storage, david_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        // confirm charlie's and bob's AuthList were removed because the only token was xferred
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
+        // confirm david did not inherit any AuthLists from the xfer
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, david_key).unwrap();
+        assert!(auth_list.is_none());
 
-        let transfer_idx = PermissionType::Transfer.to_usize();
-
-        let priv2 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT2".to_string()),
-                description: Some("privmetadata2".to_string()),
-                image: Some("privuri2".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let pub2 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT2".to_string()),
-                description: Some("pubmetadata2".to_string()),
-                image: Some("puburi2".to_string()),
-                ..Extension::default()
-            }),
-        });
-        // sanity check: address with token permission burns it
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv2.clone(),
-            public_metadata: pub2.clone(),
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let priv3 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT3".to_string()),
-                description: Some("privmetadata3".to_string()),
-                image: Some("privuri3".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let pub3 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT3".to_string()),
-                description: Some("pubmetadata3".to_string()),
-                image: Some("puburi3".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT3".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv3.clone(),
-            public_metadata: pub3.clone(),
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // sanity check: address with token permission xfers it to itself
         let handle_msg = HandleMsg::Approve {
             spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT2".to_string(),
-            expires: Some(Expiration::AtHeight(10)),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("david".to_string()),
-            token_id: "MyNFT3".to_string(),
+            token_id: "MyNFT".to_string(),
             expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::BurnNft {
-            token_id: "MyNFT2".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
         };
@@ -4840,114 +7216,114 @@ mod tests {
             },
             handle_msg,
         );
-        // confirm token was removed from the maps
+        // confirm token was not removed from the list
         let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: Option<u32> = may_load(&map2idx, "MyNFT2".as_bytes()).unwrap();
-        assert!(index.is_none());
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
         let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: Option<String> = may_load(&map2id, &01u32.to_le_bytes()).unwrap();
-        assert!(id.is_none());
-        // confirm token info was deleted from storage
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
+        // confirm token belongs to charlie now and permissions have been cleared
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Option<Token> = json_may_load(&info_store, &tok2_key).unwrap();
-        assert!(token.is_none());
-        // confirm MyNFT3 is intact
-        let token: Token = json_load(&info_store, &tok3_key).unwrap();
-        let david_perm = token.permissions.iter().find(|p| p.address == david_raw);
-        assert!(david_perm.is_some());
-        assert_eq!(token.owner, alice_raw);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, charlie_raw);
+        assert!(token.permissions.is_empty());
         assert!(token.unwrapped);
+        // confirm the metadata is intact
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok3_key).unwrap();
-        assert_eq!(priv_meta, priv3.unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &tok3_key).unwrap();
-        assert_eq!(pub_meta, pub3.unwrap());
-        // confirm the MyNFT2 metadata has been deleted from storage
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &tok2_key).unwrap();
-        assert!(priv_meta.is_none());
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv1.clone().unwrap());
         let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok2_key).unwrap();
-        assert!(pub_meta.is_none());
-        // confirm the tx was logged to both parties
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
-        assert_eq!(total, 5);
-        assert_eq!(txs[0].token_id, "MyNFT2".to_string());
+        let pub_meta: Metadata = load(&pub_store, &tok_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        // confirm the tx was logged to all involved parties
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 10).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].token_id, "MyNFT".to_string());
         assert_eq!(
             txs[0].action,
-            TxAction::Burn {
-                owner: HumanAddr("alice".to_string()),
-                burner: Some(HumanAddr("charlie".to_string())),
+            TxAction::Transfer {
+                from: HumanAddr("david".to_string()),
+                sender: Some(HumanAddr("charlie".to_string())),
+                recipient: HumanAddr("charlie".to_string()),
             }
         );
-        assert!(txs[0].memo.is_none());
-        let (tx2, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
-        assert_eq!(total, 1);
+        assert_eq!(txs[0].memo, None);
+        let (tx2, total) = get_txs(&deps.api, &deps.storage, &david_raw, 0, 1).unwrap();
+        assert_eq!(total, 2);
         assert_eq!(txs, tx2);
-        // confirm charlie's AuthList was removed because his only approved token was burned
+        // confirm both owner lists are correct
+        let inventory = Inventory::new(&deps.storage, david_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 0);
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        // confirm charlie's AuthList was removed because the only token was xferred
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let charlie_auth = auth_list.iter().find(|a| a.address == charlie_raw);
-        assert!(charlie_auth.is_none());
-        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[transfer_idx].len(), 1);
-        assert!(david_auth.tokens[transfer_idx].contains(&2u32));
-        // confirm the token was removed from the owner's list
-        assert!(!Inventory::owns(&deps.storage, &alice_raw, 1).unwrap());
-        assert!(Inventory::owns(&deps.storage, &alice_raw, 2).unwrap());
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, david_key).unwrap();
+        assert!(auth_list.is_none());
+        // confirm charlie did not inherit any AuthLists from the xfer
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
+        assert!(auth_list.is_none());
 
-        // sanity check: owner burns
-        let handle_msg = HandleMsg::BurnNft {
-            token_id: "MyNFT3".to_string(),
+        // sanity check: owner xfers
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("alice".to_string()),
+            token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm token was removed from the maps
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        // confirm token was not removed from the maps
         let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: Option<u32> = may_load(&map2idx, "MyNFT3".as_bytes()).unwrap();
-        assert!(index.is_none());
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
         let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: Option<String> = may_load(&map2id, &02u32.to_le_bytes()).unwrap();
-        assert!(id.is_none());
-        // confirm token info was deleted from storage
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
+        // confirm token belongs to alice now and permissions have been cleared
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Option<Token> = json_may_load(&info_store, &tok3_key).unwrap();
-        assert!(token.is_none());
-        // confirm the metadata has been deleted from storage
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &tok3_key).unwrap();
-        assert!(priv_meta.is_none());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok3_key).unwrap();
-        assert!(pub_meta.is_none());
-        // confirm the tx was logged
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
-        assert_eq!(total, 6);
-        assert_eq!(txs[0].token_id, "MyNFT3".to_string());
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.permissions.is_empty());
+        assert!(token.unwrapped);
+        // confirm the tx was logged to all involved parties
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(txs[0].token_id, "MyNFT".to_string());
         assert_eq!(
             txs[0].action,
-            TxAction::Burn {
-                owner: HumanAddr("alice".to_string()),
-                burner: None,
+            TxAction::Transfer {
+                from: HumanAddr("charlie".to_string()),
+                sender: None,
+                recipient: HumanAddr("alice".to_string()),
             }
         );
-        assert!(txs[0].memo.is_none());
-        // confirm david's AuthList was removed because the only token was burned
+        assert_eq!(txs[0].memo, None);
+        let (tx2, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(txs, tx2);
+        // confirm both owner lists are correct
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 0);
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        // confirm charlie's AuthList was removed because the only token was xferred
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
         assert!(auth_list.is_none());
-        // confirm the token was removed from the owner's list
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 0);
-        assert!(!inventory.contains(&deps.storage, 2).unwrap());
+        // confirm charlie did not inherit any AuthLists from the xfer
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
+        assert!(auth_list.is_none());
     }
 
-    // test batch burn
+    // test batch transfer
     #[test]
-    fn test_batch_burn() {
+    fn test_batch_transfer() {
         let (init_result, mut deps) =
             init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
@@ -4955,52 +7331,325 @@ mod tests {
             "Init failed: {}",
             init_result.err().unwrap()
         );
+
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
+            token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: Some("metadata".to_string()),
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test burn when status prevents it
+        // test transfer when status prevents it
         let handle_msg = HandleMsg::SetContractStatus {
             level: ContractStatus::StopTransactions,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::BurnNft {
-            token_id: "NFT1".to_string(),
+        let transfers = vec![Transfer {
+            recipient: HumanAddr("bob".to_string()),
+            token_ids: vec!["MyNFT".to_string()],
+            memo: None,
+        }];
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let transfers = vec![Transfer {
+            recipient: HumanAddr("bob".to_string()),
+            token_ids: vec!["MyNFT".to_string()],
             memo: None,
+        }];
+
+        // test token not found when supply is public
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT not found"));
+
+        let tok_key = 0u32.to_le_bytes();
+        let tok5_key = 4u32.to_le_bytes();
+        let tok3_key = 2u32.to_le_bytes();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let bob_key = bob_raw.as_slice();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let charlie_key = charlie_raw.as_slice();
+        let david_raw = deps
+            .api
+            .canonical_address(&HumanAddr("david".to_string()))
+            .unwrap();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+
+        // set up for batch transfer test
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: vec![
+                Mint {
+                    token_id: Some("NFT1".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT2".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT3".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT4".to_string()),
+                    owner: Some(HumanAddr("bob".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT5".to_string()),
+                    owner: Some(HumanAddr("bob".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT6".to_string()),
+                    owner: Some(HumanAddr("charlie".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+            ],
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT5".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT6".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
-
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::Normal,
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let transfers = vec![
+            Transfer {
+                recipient: HumanAddr("charlie".to_string()),
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            },
+            Transfer {
+                recipient: HumanAddr("alice".to_string()),
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            },
+            Transfer {
+                recipient: HumanAddr("bob".to_string()),
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            },
+            Transfer {
+                recipient: HumanAddr("david".to_string()),
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            },
+        ];
 
-        // test when burn is disabled
-        let handle_msg = HandleMsg::BurnNft {
-            token_id: "NFT1".to_string(),
-            memo: None,
+        // test transferring the same token among address the sender has ALL permission,
+        // but then breaks when it gets to an address he does not have authority for
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Burn functionality is not enabled for this token"));
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+        // confirm it didn't die until david tried to transfer itaway from bob
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, bob_raw);
 
-        // set up for batch burn test
+        // set up for batch transfer test
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, true);
+            init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
@@ -5013,6 +7662,7 @@ mod tests {
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -5025,35 +7675,19 @@ mod tests {
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let priv3 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT3".to_string()),
-                description: Some("privmetadata3".to_string()),
-                image: Some("privuri3".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let pub3 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT3".to_string()),
-                description: Some("pubmetadata3".to_string()),
-                image: Some("puburi3".to_string()),
-                ..Extension::default()
-            }),
-        });
         let handle_msg = HandleMsg::MintNft {
             token_id: Some("NFT3".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv3.clone(),
-            public_metadata: pub3.clone(),
+            private_metadata: None,
+            public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -5065,6 +7699,7 @@ mod tests {
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -5076,44 +7711,23 @@ mod tests {
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let handle_msg = HandleMsg::MintNft {
             token_id: Some("NFT6".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT7".to_string()),
-            owner: Some(HumanAddr("charlie".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT8".to_string()),
             owner: Some(HumanAddr("charlie".to_string())),
             private_metadata: None,
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
             token_id: Some("NFT1".to_string()),
@@ -5129,13 +7743,13 @@ mod tests {
             token_id: Some("NFT1".to_string()),
             view_owner: Some(AccessLevel::ApproveToken),
             view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
+            transfer: None,
             expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
+            address: HumanAddr("bob".to_string()),
             token_id: Some("NFT2".to_string()),
             view_owner: None,
             view_private_metadata: Some(AccessLevel::ApproveToken),
@@ -5147,59 +7761,69 @@ mod tests {
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
             token_id: Some("NFT3".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
             transfer: Some(AccessLevel::ApproveToken),
             expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
+            address: HumanAddr("david".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
             transfer: None,
             expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT5".to_string()),
+            token_id: Some("NFT4".to_string()),
             view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
             transfer: Some(AccessLevel::ApproveToken),
             expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
+            address: HumanAddr("alice".to_string()),
             token_id: Some("NFT5".to_string()),
             view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
             transfer: None,
             expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
+            address: HumanAddr("bob".to_string()),
             token_id: Some("NFT6".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
+            view_owner: None,
             view_private_metadata: Some(AccessLevel::ApproveToken),
             transfer: None,
             expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT7".to_string()),
+            address: HumanAddr("david".to_string()),
+            token_id: None,
             view_owner: None,
             view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::All),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
             expires: None,
             padding: None,
         };
@@ -5214,490 +7838,630 @@ mod tests {
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let transfers = vec![
+            Transfer {
+                recipient: HumanAddr("charlie".to_string()),
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            },
+            Transfer {
+                recipient: HumanAddr("alice".to_string()),
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            },
+            Transfer {
+                recipient: HumanAddr("bob".to_string()),
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            },
+        ];
+
+        // test transferring the same token among address the sender has ALL permission
+        // and verify the AuthLists are correct after all the transfers
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
+        // confirm token was not removed from the maps
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: u32 = load(&map2idx, "NFT1".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("NFT1".to_string(), id);
+        // confirm token has the correct owner and the permissions were cleared
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, bob_raw);
+        assert!(token.permissions.is_empty());
+        assert!(token.unwrapped);
+        // confirm transfer txs were logged
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 10).unwrap();
+        assert_eq!(total, 6);
+        assert_eq!(txs.len(), 6);
+        assert_eq!(
+            txs[2].action,
+            TxAction::Transfer {
+                from: HumanAddr("alice".to_string()),
+                sender: Some(HumanAddr("david".to_string())),
+                recipient: HumanAddr("charlie".to_string()),
+            }
+        );
+        assert_eq!(
+            txs[1].action,
+            TxAction::Transfer {
+                from: HumanAddr("charlie".to_string()),
+                sender: Some(HumanAddr("david".to_string())),
+                recipient: HumanAddr("alice".to_string()),
+            }
+        );
+        assert_eq!(
+            txs[0].action,
+            TxAction::Transfer {
+                from: HumanAddr("alice".to_string()),
+                sender: Some(HumanAddr("david".to_string())),
+                recipient: HumanAddr("bob".to_string()),
+            }
+        );
+        // confirm the owner list is correct
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 2);
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        assert!(inventory.contains(&deps.storage, 1).unwrap());
+        assert!(inventory.contains(&deps.storage, 2).unwrap());
+        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 3);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        assert!(inventory.contains(&deps.storage, 3).unwrap());
+        assert!(inventory.contains(&deps.storage, 4).unwrap());
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 5).unwrap());
+        // confirm authLists were updated correctly
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(alice_list.len(), 2);
+        let david_auth = alice_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[view_meta_idx].len(), 1);
+        assert!(david_auth.tokens[view_meta_idx].contains(&2u32));
+        assert!(david_auth.tokens[transfer_idx].is_empty());
+        assert!(david_auth.tokens[view_owner_idx].is_empty());
+        let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[view_owner_idx].len(), 1);
+        assert!(bob_auth.tokens[view_owner_idx].contains(&2u32));
+        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 2);
+        assert!(bob_auth.tokens[view_meta_idx].contains(&1u32));
+        assert!(bob_auth.tokens[view_meta_idx].contains(&2u32));
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
+        assert_eq!(bob_list.len(), 1);
+        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
+        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 2);
+        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
+        assert!(alice_auth.tokens[view_owner_idx].contains(&4u32));
+        assert_eq!(alice_auth.tokens[view_meta_idx].len(), 1);
+        assert!(alice_auth.tokens[view_meta_idx].contains(&3u32));
+        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
+        assert!(alice_auth.tokens[transfer_idx].contains(&3u32));
+        let charlie_list: Vec<AuthList> = load(&auth_store, charlie_key).unwrap();
+        assert_eq!(charlie_list.len(), 1);
+        let bob_auth = charlie_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
+        assert!(bob_auth.tokens[view_meta_idx].contains(&5u32));
+        assert!(bob_auth.tokens[transfer_idx].is_empty());
 
-        // test bob burning a list, but trying to burn the same token twice
-        let burns = vec![
-            Burn {
-                token_ids: vec!["NFT1".to_string(), "NFT3".to_string()],
+        let transfers = vec![
+            Transfer {
+                recipient: HumanAddr("charlie".to_string()),
+                token_ids: vec!["NFT1".to_string()],
                 memo: None,
             },
-            Burn {
-                token_ids: vec!["NFT6".to_string()],
+            Transfer {
+                recipient: HumanAddr("alice".to_string()),
+                token_ids: vec!["NFT5".to_string()],
                 memo: None,
             },
-            Burn {
-                token_ids: vec!["NFT6".to_string()],
+            Transfer {
+                recipient: HumanAddr("bob".to_string()),
+                token_ids: vec!["NFT3".to_string()],
                 memo: None,
             },
-            Burn {
-                token_ids: vec!["NFT8".to_string()],
-                memo: Some("Phew!".to_string()),
-            },
         ];
-        let handle_msg = HandleMsg::BatchBurnNft {
-            burns,
+
+        // test bobs trnsfer two of his tokens and one of alice's
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        // because the token no longer exists after burning it, it will say you are not
-        // authorized if supply is private, and token not found if public
-        assert!(error.contains("You are not authorized to perform this action on token NFT6"));
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        // confirm tokens have the correct owner and the permissions were cleared
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, charlie_raw);
+        assert!(token.permissions.is_empty());
+        let token: Token = json_load(&info_store, &tok3_key).unwrap();
+        assert_eq!(token.owner, bob_raw);
+        assert!(token.permissions.is_empty());
+        let token: Token = json_load(&info_store, &tok5_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.permissions.is_empty());
+        // confirm the owner list is correct
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 2);
+        assert!(inventory.contains(&deps.storage, 1).unwrap());
+        assert!(inventory.contains(&deps.storage, 4).unwrap());
+        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 2);
+        assert!(inventory.contains(&deps.storage, 2).unwrap());
+        assert!(inventory.contains(&deps.storage, 3).unwrap());
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 2);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        assert!(inventory.contains(&deps.storage, 5).unwrap());
+        // confirm authLists were updated correctly
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(alice_list.len(), 1);
+        let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
+        assert!(bob_auth.tokens[view_meta_idx].contains(&1u32));
+        assert!(bob_auth.tokens[transfer_idx].is_empty());
+        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
+        assert_eq!(bob_list.len(), 1);
+        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
+        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 1);
+        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
+        assert_eq!(alice_auth.tokens[view_meta_idx].len(), 1);
+        assert!(alice_auth.tokens[view_meta_idx].contains(&3u32));
+        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
+        assert!(alice_auth.tokens[transfer_idx].contains(&3u32));
+        let charlie_list: Vec<AuthList> = load(&auth_store, charlie_key).unwrap();
+        assert_eq!(charlie_list.len(), 1);
+        let bob_auth = charlie_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
+        assert!(bob_auth.tokens[view_meta_idx].contains(&5u32));
+        assert!(bob_auth.tokens[transfer_idx].is_empty());
 
-        // set up for batch burn test
+        // set up for batch transfer test
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, true);
+            init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT3".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv3.clone(),
-            public_metadata: pub3.clone(),
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT4".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT5".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT6".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT7".to_string()),
-            owner: Some(HumanAddr("charlie".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT8".to_string()),
-            owner: Some(HumanAddr("charlie".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: vec![
+                Mint {
+                    token_id: Some("NFT1".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT2".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT3".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT4".to_string()),
+                    owner: Some(HumanAddr("bob".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT5".to_string()),
+                    owner: Some(HumanAddr("bob".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT6".to_string()),
+                    owner: Some(HumanAddr("charlie".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+            ],
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
+            token_id: None,
             view_owner: None,
             view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::All),
             expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT5".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
             view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT5".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
+            transfer: Some(AccessLevel::All),
             expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT6".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers: vec![
+                Transfer {
+                    recipient: HumanAddr("charlie".to_string()),
+                    token_ids: vec!["NFT2".to_string(), "NFT3".to_string(), "NFT4".to_string()],
+                    memo: Some("test memo".to_string()),
+                },
+                Transfer {
+                    recipient: HumanAddr("charlie".to_string()),
+                    token_ids: vec!["NFT1".to_string(), "NFT5".to_string()],
+                    memo: None,
+                },
+            ],
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT7".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "ckey".to_string(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
-            padding: None,
+        // confirm charlie's tokens
+        let query_msg = QueryMsg::Tokens {
+            owner: HumanAddr("charlie".to_string()),
+            viewer: None,
+            viewing_key: Some("ckey".to_string()),
+            start_after: None,
+            limit: Some(30),
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-
-        // test bob burning a list, but one is not authorized
-        let burns = vec![
-            Burn {
-                token_ids: vec![
-                    "NFT1".to_string(),
-                    "NFT3".to_string(),
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens, .. } => {
+                let expected = vec![
                     "NFT6".to_string(),
                     "NFT2".to_string(),
-                ],
-                memo: None,
+                    "NFT3".to_string(),
+                    "NFT4".to_string(),
+                    "NFT1".to_string(),
+                    "NFT5".to_string(),
+                ];
+                assert_eq!(tokens, expected);
+            }
+            _ => panic!("unexpected"),
+        }
+        let xfer4 = Tx {
+            tx_id: 8,
+            block_height: 12345,
+            block_time: 1571797419,
+            token_id: "NFT4".to_string(),
+            memo: Some("test memo".to_string()),
+            action: TxAction::Transfer {
+                from: HumanAddr("bob".to_string()),
+                sender: None,
+                recipient: HumanAddr("charlie".to_string()),
             },
-            Burn {
-                token_ids: vec!["NFT8".to_string()],
-                memo: Some("Phew!".to_string()),
+        };
+        let xfer1 = Tx {
+            tx_id: 9,
+            block_height: 12345,
+            block_time: 1571797419,
+            token_id: "NFT1".to_string(),
+            memo: None,
+            action: TxAction::Transfer {
+                from: HumanAddr("alice".to_string()),
+                sender: Some(HumanAddr("bob".to_string())),
+                recipient: HumanAddr("charlie".to_string()),
             },
-        ];
-        let handle_msg = HandleMsg::BatchBurnNft {
-            burns,
+        };
+        let query_msg = QueryMsg::TransactionHistory {
+            address: HumanAddr("charlie".to_string()),
+            viewing_key: "ckey".to_string(),
+            page: None,
+            page_size: None,
+            tx_type: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(total, 6);
+                assert_eq!(txs[1], xfer1);
+                assert_eq!(txs[2], xfer4);
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::Tokens {
+            owner: HumanAddr("alice".to_string()),
+            viewer: None,
+            viewing_key: Some("akey".to_string()),
+            start_after: None,
+            limit: Some(30),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens, .. } => {
+                assert!(tokens.is_empty());
+            }
+            _ => panic!("unexpected"),
+        }
+    }
 
-        // set up for batch burn test
+    // test send
+    #[test]
+    fn test_send() {
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, true, false, false, false, true);
+            init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
+            token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("MyNFT".to_string()),
+                    description: Some("metadata".to_string()),
+                    image: Some("https://uri".to_string()),
+                    ..Extension::default()
+                }),
+            }),
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT3".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv3.clone(),
-            public_metadata: pub3.clone(),
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+
+        // test send when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT4".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("bob".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: None,
             memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT5".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT6".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
+
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test token not found when supply is public
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("bob".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: None,
             memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT7".to_string()),
-            owner: Some(HumanAddr("charlie".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT not found"));
+
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test token not found when supply is private
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("bob".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: None,
             memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
+
+        let priv1 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT".to_string()),
+                description: Some("privmetadata".to_string()),
+                image: Some("https://privuri".to_string()),
+                ..Extension::default()
+            }),
+        });
+        let pub1 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("MyNFT".to_string()),
+                description: Some("pubmetadata".to_string()),
+                image: Some("https://puburi".to_string()),
+                ..Extension::default()
+            }),
+        });
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT8".to_string()),
-            owner: Some(HumanAddr("charlie".to_string())),
-            private_metadata: None,
-            public_metadata: None,
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: priv1.clone(),
+            public_metadata: pub1.clone(),
             royalty_info: None,
             serial_number: None,
-            memo: None,
+            reveal_commitment: None,
+            memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
+        // test unauthorized sender (but we'll give him view owner access)
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT1".to_string()),
+            token_id: Some("MyNFT".to_string()),
             view_owner: Some(AccessLevel::ApproveToken),
             view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
             transfer: None,
             expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT5".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("bob".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT5".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
+
+        // test expired token approval
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT6".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("bob".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT7".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("charlie".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Access to token MyNFT has expired"));
+
+        // test expired ALL approval
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("bob".to_string()),
+            expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("charlie".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-
-        // test bob burning NFT1 and 3 from alice with token permission,
-        // burning NFT6 as the owner,
-        // and burning NFT7 and NFT8 with ALL permission
-        let burns = vec![
-            Burn {
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-            Burn {
-                token_ids: vec!["NFT3".to_string()],
-                memo: None,
-            },
-            Burn {
-                token_ids: vec!["NFT6".to_string(), "NFT7".to_string(), "NFT8".to_string()],
-                memo: Some("Phew!".to_string()),
+        let handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 100,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
             },
-        ];
-        let handle_msg = HandleMsg::BatchBurnNft {
-            burns,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Access to all tokens of alice has expired"));
 
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-        let tok1_key = 0u32.to_le_bytes();
-        let tok2_key = 1u32.to_le_bytes();
-        let tok3_key = 2u32.to_le_bytes();
-        let tok6_key = 5u32.to_le_bytes();
-        let tok7_key = 6u32.to_le_bytes();
-        let tok8_key = 6u32.to_le_bytes();
+        let tok_key = 0u32.to_le_bytes();
         let alice_raw = deps
             .api
             .canonical_address(&HumanAddr("alice".to_string()))
@@ -5707,152 +8471,356 @@ mod tests {
             .api
             .canonical_address(&HumanAddr("bob".to_string()))
             .unwrap();
-        let bob_key = bob_raw.as_slice();
         let charlie_raw = deps
             .api
             .canonical_address(&HumanAddr("charlie".to_string()))
             .unwrap();
         let charlie_key = charlie_raw.as_slice();
-        // confirm correct tokens were removed from the maps
+        let david_raw = deps
+            .api
+            .canonical_address(&HumanAddr("david".to_string()))
+            .unwrap();
+        let david_key = david_raw.as_slice();
+
+        // confirm that sending to the same address that owns the token throws an error
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("alice".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: None,
+            memo: Some("Xfer it".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 1,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "Attempting to transfer token ID: MyNFT to the address that already owns it"
+        ));
+
+        // sanity check: operator sends
+        // msg to go with ReceiveNft
+        let send_msg = Some(
+            to_binary(&HandleMsg::RevokeAll {
+                operator: HumanAddr("zoe".to_string()),
+                padding: None,
+            })
+            .unwrap(),
+        );
+        // register david's ReceiveNft
+        let handle_msg = HandleMsg::RegisterReceiveNft {
+            code_hash: "david code hash".to_string(),
+            also_implements_batch_receive_nft: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("david".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: send_msg.clone(),
+            memo: Some("Xfer it".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 1,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm the receive nft msg was created
+        let handle_resp = handle_result.unwrap();
+        let messages = handle_resp.messages;
+        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::ReceiveNft {
+            sender: HumanAddr("alice".to_string()),
+            token_id: "MyNFT".to_string(),
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
+        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("david".to_string()),
+            callback_code_hash: "david code hash".to_string(),
+            msg: Binary(msg_fr_al.to_vec()),
+            send: vec![],
+        });
+        assert_eq!(messages[0], msg_fr_al);
+        // confirm token was not removed from the maps
         let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: Option<u32> = may_load(&map2idx, "NFT1".as_bytes()).unwrap();
-        assert!(index.is_none());
-        let index: Option<u32> = may_load(&map2idx, "NFT2".as_bytes()).unwrap();
-        assert!(index.is_some());
-        let index: Option<u32> = may_load(&map2idx, "NFT3".as_bytes()).unwrap();
-        assert!(index.is_none());
-        let index: Option<u32> = may_load(&map2idx, "NFT4".as_bytes()).unwrap();
-        assert!(index.is_some());
-        let index: Option<u32> = may_load(&map2idx, "NFT5".as_bytes()).unwrap();
-        assert!(index.is_some());
-        let index: Option<u32> = may_load(&map2idx, "NFT6".as_bytes()).unwrap();
-        assert!(index.is_none());
-        let index: Option<u32> = may_load(&map2idx, "NFT7".as_bytes()).unwrap();
-        assert!(index.is_none());
-        let index: Option<u32> = may_load(&map2idx, "NFT8".as_bytes()).unwrap();
-        assert!(index.is_none());
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
         let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: Option<String> = may_load(&map2id, &0u32.to_le_bytes()).unwrap();
-        assert!(id.is_none());
-        let id: Option<String> = may_load(&map2id, &1u32.to_le_bytes()).unwrap();
-        assert!(id.is_some());
-        let id: Option<String> = may_load(&map2id, &2u32.to_le_bytes()).unwrap();
-        assert!(id.is_none());
-        let id: Option<String> = may_load(&map2id, &3u32.to_le_bytes()).unwrap();
-        assert!(id.is_some());
-        let id: Option<String> = may_load(&map2id, &4u32.to_le_bytes()).unwrap();
-        assert!(id.is_some());
-        let id: Option<String> = may_load(&map2id, &5u32.to_le_bytes()).unwrap();
-        assert!(id.is_none());
-        let id: Option<String> = may_load(&map2id, &6u32.to_le_bytes()).unwrap();
-        assert!(id.is_none());
-        let id: Option<String> = may_load(&map2id, &7u32.to_le_bytes()).unwrap();
-        assert!(id.is_none());
-        // confirm token infos were deleted from storage
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
+        // confirm token belongs to david now and permissions have been cleared
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, david_raw);
+        assert!(token.permissions.is_empty());
+        assert!(token.unwrapped);
+        // confirm the metadata is intact
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv1.clone().unwrap());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &tok_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        // confirm the tx was logged to all involved parties
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].token_id, "MyNFT".to_string());
+        assert_eq!(
+            txs[0].action,
+            TxAction::Transfer {
+                from: HumanAddr("alice".to_string()),
+                sender: Some(HumanAddr("bob".to_string())),
+                recipient: HumanAddr("david".to_string()),
+            }
+        );
+        assert_eq!(txs[0].memo, Some("Xfer it".to_string()));
+        let (tx2, total) = get_txs(&deps.api, &deps.storage, &bob_raw, 0, 1).unwrap();
+        assert_eq!(total, 1);
+        let (tx3, total) = get_txs(&deps.api, &deps.storage, &david_raw, 0, 1).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(txs, tx2);
+        assert_eq!(tx2, tx3);
+        // confirm both owner lists are correct
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 0);
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        let inventory = Inventory::new(&deps.storage, david_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        // confirm charlie's and bob's AuthList were removed because the only token was xferred
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
+        // confirm david did not inherit any AuthLists from the xfer
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, david_key).unwrap();
+        assert!(auth_list.is_none());
+
+        // sanity check: address with token permission xfers it to itself and specifies its
+        // code hash and that it implements BatchReceiveNft in the SendNft msg
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: Some(Expiration::AtHeight(10)),
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("charlie".to_string()),
+            receiver_info: Some(ReceiverInfo {
+                recipient_code_hash: "supplied in the send".to_string(),
+                also_implements_batch_receive_nft: Some(true),
+            }),
+            token_id: "MyNFT".to_string(),
+            msg: send_msg.clone(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 1,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("charlie".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        // confirm the batch receive nft msg was created
+        let handle_resp = handle_result.unwrap();
+        let messages = handle_resp.messages;
+        let mut msg_fr_dv = to_binary(&Snip721ReceiveMsg::BatchReceiveNft {
+            sender: HumanAddr("charlie".to_string()),
+            from: HumanAddr("david".to_string()),
+            token_ids: vec!["MyNFT".to_string()],
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_dv = space_pad(&mut msg_fr_dv.0, 256usize);
+        let msg_fr_dv = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("charlie".to_string()),
+            callback_code_hash: "supplied in the send".to_string(),
+            msg: Binary(msg_fr_dv.to_vec()),
+            send: vec![],
+        });
+        assert_eq!(messages[0], msg_fr_dv);
+        // confirm token was not removed from the list
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
+        // confirm token belongs to charlie now and permissions have been cleared
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Option<Token> = json_may_load(&info_store, &tok1_key).unwrap();
-        assert!(token.is_none());
-        let token: Option<Token> = json_may_load(&info_store, &tok3_key).unwrap();
-        assert!(token.is_none());
-        let token: Option<Token> = json_may_load(&info_store, &tok6_key).unwrap();
-        assert!(token.is_none());
-        let token: Option<Token> = json_may_load(&info_store, &tok7_key).unwrap();
-        assert!(token.is_none());
-        let token: Option<Token> = json_may_load(&info_store, &tok8_key).unwrap();
-        assert!(token.is_none());
-        // confirm NFT3 metadata has been deleted from storage
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, charlie_raw);
+        assert!(token.permissions.is_empty());
+        assert!(token.unwrapped);
+        // confirm the metadata is intact
         let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &tok3_key).unwrap();
-        assert!(priv_meta.is_none());
+        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
+        assert_eq!(priv_meta, priv1.clone().unwrap());
         let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Option<Metadata> = may_load(&pub_store, &tok3_key).unwrap();
-        assert!(pub_meta.is_none());
-        // confirm NFT2 is intact
-        let token: Token = json_load(&info_store, &tok2_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
+        let pub_meta: Metadata = load(&pub_store, &tok_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        // confirm the tx was logged to all involved parties
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 10).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].token_id, "MyNFT".to_string());
         assert_eq!(
-            charlie_tok_perm.expirations[view_meta_idx],
-            Some(Expiration::Never)
+            txs[0].action,
+            TxAction::Transfer {
+                from: HumanAddr("david".to_string()),
+                sender: Some(HumanAddr("charlie".to_string())),
+                recipient: HumanAddr("charlie".to_string()),
+            }
         );
-        assert_eq!(charlie_tok_perm.expirations[transfer_idx], None);
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(token.owner, alice_raw);
-        assert!(!token.unwrapped);
-        // confirm owner lists are correct
-        // alice only owns NFT2
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 1).unwrap());
-        // bob owns NFT4 and NFT5
-        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(inventory.contains(&deps.storage, 3).unwrap());
-        assert!(inventory.contains(&deps.storage, 4).unwrap());
-        // charlie does not own any
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(txs[0].memo, None);
+        let (tx2, total) = get_txs(&deps.api, &deps.storage, &david_raw, 0, 1).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(txs, tx2);
+        // confirm both owner lists are correct
+        let inventory = Inventory::new(&deps.storage, david_raw.clone()).unwrap();
         assert_eq!(inventory.info.count, 0);
-        // confirm AuthLists are correct
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        // confirm charlie's AuthList was removed because the only token was xferred
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        // alice gave charlie view metadata permission on NFT2
-        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(alice_list.len(), 1);
-        let charlie_auth = alice_list
-            .iter()
-            .find(|a| a.address == charlie_raw)
-            .unwrap();
-        assert_eq!(charlie_auth.tokens[view_meta_idx].len(), 1);
-        assert!(charlie_auth.tokens[view_meta_idx].contains(&1u32));
-        assert!(charlie_auth.tokens[transfer_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
-        // bob gave charlie view owner and view metadata permission on NFT5
-        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
-        assert_eq!(bob_list.len(), 2);
-        let charlie_auth = bob_list.iter().find(|a| a.address == charlie_raw).unwrap();
-        assert_eq!(charlie_auth.tokens[view_meta_idx].len(), 1);
-        assert!(charlie_auth.tokens[view_meta_idx].contains(&4u32));
-        assert!(charlie_auth.tokens[transfer_idx].is_empty());
-        assert_eq!(charlie_auth.tokens[view_owner_idx].len(), 1);
-        assert!(charlie_auth.tokens[view_owner_idx].contains(&4u32));
-        // bob gave alice view owner permission on NFT4 and NFT5
-        // and transfer permission on NFT5
-        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
-        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
-        assert!(alice_auth.tokens[transfer_idx].contains(&4u32));
-        assert!(alice_auth.tokens[view_meta_idx].is_empty());
-        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 2);
-        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
-        assert!(alice_auth.tokens[view_owner_idx].contains(&4u32));
-        // charlie has no tokens so should not have any AuthLists
-        let charlie_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
-        assert!(charlie_list.is_none());
-        // confirm one of the txs
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &bob_raw, 0, 3).unwrap();
-        assert_eq!(total, 8);
-        assert_eq!(txs.len(), 3);
-        assert_eq!(txs[0].token_id, "NFT8".to_string());
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, david_key).unwrap();
+        assert!(auth_list.is_none());
+        // confirm charlie did not inherit any AuthLists from the xfer
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
+        assert!(auth_list.is_none());
+
+        // sanity check: owner sends and specifies a code hash in the send
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("alice".to_string()),
+            receiver_info: Some(ReceiverInfo {
+                recipient_code_hash: "supplied in the send".to_string(),
+                also_implements_batch_receive_nft: None,
+            }),
+            token_id: "MyNFT".to_string(),
+            msg: send_msg.clone(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        // confirm the receive nft msg was created
+        let handle_resp = handle_result.unwrap();
+        let messages = handle_resp.messages;
+        let mut msg_fr_ch = to_binary(&Snip721ReceiveMsg::ReceiveNft {
+            sender: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_ch = space_pad(&mut msg_fr_ch.0, 256usize);
+        let msg_fr_ch = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("alice".to_string()),
+            callback_code_hash: "supplied in the send".to_string(),
+            msg: Binary(msg_fr_ch.to_vec()),
+            send: vec![],
+        });
+        assert_eq!(messages[0], msg_fr_ch);
+        // confirm token was not removed from the maps
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let token_key = index.to_le_bytes();
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+        let id: String = load(&map2id, &token_key).unwrap();
+        assert_eq!("MyNFT".to_string(), id);
+        // confirm token belongs to alice now and permissions have been cleared
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.permissions.is_empty());
+        assert!(token.unwrapped);
+        // confirm the tx was logged to all involved parties
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(txs[0].token_id, "MyNFT".to_string());
         assert_eq!(
             txs[0].action,
-            TxAction::Burn {
-                owner: HumanAddr("charlie".to_string()),
-                burner: Some(HumanAddr("bob".to_string())),
+            TxAction::Transfer {
+                from: HumanAddr("charlie".to_string()),
+                sender: None,
+                recipient: HumanAddr("alice".to_string()),
             }
         );
-        assert_eq!(txs[0].memo, Some("Phew!".to_string()));
-        assert_eq!(txs[1].memo, Some("Phew!".to_string()));
-        assert_eq!(txs[2].memo, Some("Phew!".to_string()));
-        let (tx2, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
-        assert_eq!(total, 4);
-        assert_eq!(txs[0], tx2[0]);
+        assert_eq!(txs[0].memo, None);
+        let (tx2, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(txs, tx2);
+        // confirm both owner lists are correct
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 0);
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        // confirm charlie's AuthList was removed because the only token was xferred
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
+        assert!(auth_list.is_none());
+        // confirm charlie did not inherit any AuthLists from the xfer
+        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
+        assert!(auth_list.is_none());
     }
 
-    // test transfer
+    // test batch send
     #[test]
-    fn test_transfer() {
+    fn test_batch_send() {
         let (init_result, mut deps) =
             init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
@@ -5869,28 +8837,34 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("MyNFT".to_string()),
                     description: Some("metadata".to_string()),
-                    image: Some("uri".to_string()),
+                    image: Some("https://uri".to_string()),
                     ..Extension::default()
                 }),
             }),
             public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test transfer when status prevents it
+        // test send when status prevents it
         let handle_msg = HandleMsg::SetContractStatus {
             level: ContractStatus::StopTransactions,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
+        let sends = vec![Send {
+            contract: HumanAddr("bob".to_string()),
+            receiver_info: None,
+            token_ids: vec!["MyNFT".to_string()],
+            msg: None,
             memo: None,
+        }];
+        let handle_msg = HandleMsg::BatchSendNft {
+            sends,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
@@ -5910,18 +8884,50 @@ mod tests {
             "Init failed: {}",
             init_result.err().unwrap()
         );
+        let sends = vec![Send {
+            contract: HumanAddr("bob".to_string()),
+            receiver_info: None,
+            token_ids: vec!["MyNFT".to_string()],
+            msg: None,
+            memo: None,
+        }];
 
         // test token not found when supply is public
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
-            memo: None,
+        let handle_msg = HandleMsg::BatchSendNft {
+            sends,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
         assert!(error.contains("Token ID: MyNFT not found"));
 
+        let tok_key = 0u32.to_le_bytes();
+        let tok5_key = 4u32.to_le_bytes();
+        let tok3_key = 2u32.to_le_bytes();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let bob_key = bob_raw.as_slice();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let charlie_key = charlie_raw.as_slice();
+        let david_raw = deps
+            .api
+            .canonical_address(&HumanAddr("david".to_string()))
+            .unwrap();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+
+        // set up for batch send test
         let (init_result, mut deps) =
             init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
@@ -5929,52 +8935,92 @@ mod tests {
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        // test token not found when supply is private
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        let priv1 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT".to_string()),
-                description: Some("privmetadata".to_string()),
-                image: Some("privuri".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let pub1 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT".to_string()),
-                description: Some("pubmetadata".to_string()),
-                image: Some("puburi".to_string()),
-                ..Extension::default()
-            }),
-        });
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("NFT2".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv1.clone(),
-            public_metadata: pub1.clone(),
+            private_metadata: None,
+            public_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT3".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT4".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT5".to_string()),
+            owner: Some(HumanAddr("bob".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT6".to_string()),
+            owner: Some(HumanAddr("charlie".to_string())),
+            private_metadata: None,
+            public_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test unauthorized sender (but we'll give him view owner access)
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT1".to_string()),
             view_owner: Some(AccessLevel::ApproveToken),
             view_private_metadata: None,
             transfer: None,
@@ -5982,1323 +9028,1237 @@ mod tests {
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
-            memo: None,
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
-
-        // test expired token approval
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            expires: Some(Expiration::AtHeight(10)),
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("bob".to_string()),
-            token_id: "MyNFT".to_string(),
-            memo: None,
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
             padding: None,
         };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("charlie".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Access to token MyNFT has expired"));
-
-        // test expired ALL approval
-        let handle_msg = HandleMsg::ApproveAll {
-            operator: HumanAddr("bob".to_string()),
-            expires: Some(Expiration::AtHeight(10)),
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT4".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("alice".to_string()),
+            token_id: Some("NFT5".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT6".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            memo: None,
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
             padding: None,
         };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::RegisterReceiveNft {
+            code_hash: "charlie code hash".to_string(),
+            also_implements_batch_receive_nft: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        // msg to go with ReceiveNft
+        let send_msg = Some(
+            to_binary(&HandleMsg::RevokeAll {
+                operator: HumanAddr("zoe".to_string()),
+                padding: None,
+            })
+            .unwrap(),
         );
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Access to all tokens of alice has expired"));
-
-        let tok_key = 0u32.to_le_bytes();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let charlie_raw = deps
-            .api
-            .canonical_address(&HumanAddr("charlie".to_string()))
-            .unwrap();
-        let charlie_key = charlie_raw.as_slice();
-        let david_raw = deps
-            .api
-            .canonical_address(&HumanAddr("david".to_string()))
-            .unwrap();
-        let david_key = david_raw.as_slice();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let sends = vec![
+            Send {
+                contract: HumanAddr("charlie".to_string()),
+                receiver_info: None,
+                token_ids: vec!["NFT1".to_string()],
+                msg: send_msg.clone(),
+                memo: None,
+            },
+            Send {
+                contract: HumanAddr("alice".to_string()),
+                receiver_info: None,
+                token_ids: vec!["NFT1".to_string()],
+                msg: send_msg.clone(),
+                memo: None,
+            },
+            Send {
+                contract: HumanAddr("bob".to_string()),
+                receiver_info: Some(ReceiverInfo {
+                    recipient_code_hash: "supplied in the send".to_string(),
+                    also_implements_batch_receive_nft: None,
+                }),
+                token_ids: vec!["NFT1".to_string()],
+                msg: send_msg.clone(),
+                memo: None,
+            },
+        ];
 
-        // confirm that transfering to the same address that owns the token does not
-        // erase the current permissions
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("alice".to_string()),
-            token_id: "MyNFT".to_string(),
-            memo: Some("Xfer it".to_string()),
+        // test sending the same token among address the sender has ALL permission
+        // and verify the AuthLists are correct after all the transfers
+        let handle_msg = HandleMsg::BatchSendNft {
+            sends,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 1,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
+        let handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
+        // confirm the receive nft msgs were created
+        let handle_resp = handle_result.unwrap();
+        let messages = handle_resp.messages;
+        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::ReceiveNft {
+            sender: HumanAddr("alice".to_string()),
+            token_id: "NFT1".to_string(),
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
+        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("charlie".to_string()),
+            callback_code_hash: "charlie code hash".to_string(),
+            msg: Binary(msg_fr_al.to_vec()),
+            send: vec![],
+        });
+        assert_eq!(messages[0], msg_fr_al);
+        assert_eq!(messages.len(), 2);
+        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::ReceiveNft {
+            sender: HumanAddr("alice".to_string()),
+            token_id: "NFT1".to_string(),
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
+        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("bob".to_string()),
+            callback_code_hash: "supplied in the send".to_string(),
+            msg: Binary(msg_fr_al.to_vec()),
+            send: vec![],
+        });
+        assert_eq!(messages[1], msg_fr_al);
         // confirm token was not removed from the maps
         let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
+        let index: u32 = load(&map2idx, "NFT1".as_bytes()).unwrap();
         let token_key = index.to_le_bytes();
         let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
         let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
-        // confirm token info is the same
+        assert_eq!("NFT1".to_string(), id);
+        // confirm token has the correct owner and the permissions were cleared
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, bob_raw);
+        assert!(token.permissions.is_empty());
+        assert!(token.unwrapped);
+        // confirm transfer txs were logged
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 10).unwrap();
+        assert_eq!(total, 6);
+        assert_eq!(txs.len(), 6);
+        assert_eq!(
+            txs[2].action,
+            TxAction::Transfer {
+                from: HumanAddr("alice".to_string()),
+                sender: Some(HumanAddr("david".to_string())),
+                recipient: HumanAddr("charlie".to_string()),
+            }
+        );
+        assert_eq!(
+            txs[1].action,
+            TxAction::Transfer {
+                from: HumanAddr("charlie".to_string()),
+                sender: Some(HumanAddr("david".to_string())),
+                recipient: HumanAddr("alice".to_string()),
+            }
+        );
+        assert_eq!(
+            txs[0].action,
+            TxAction::Transfer {
+                from: HumanAddr("alice".to_string()),
+                sender: Some(HumanAddr("david".to_string())),
+                recipient: HumanAddr("bob".to_string()),
+            }
+        );
+        // confirm the owner list is correct
+        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 2);
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        assert!(inventory.contains(&deps.storage, 1).unwrap());
+        assert!(inventory.contains(&deps.storage, 2).unwrap());
+        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 3);
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        assert!(inventory.contains(&deps.storage, 3).unwrap());
+        assert!(inventory.contains(&deps.storage, 4).unwrap());
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(inventory.contains(&deps.storage, 5).unwrap());
+        // confirm authLists were updated correctly
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(alice_list.len(), 2);
+        let david_auth = alice_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[view_meta_idx].len(), 1);
+        assert!(david_auth.tokens[view_meta_idx].contains(&2u32));
+        assert!(david_auth.tokens[transfer_idx].is_empty());
+        assert!(david_auth.tokens[view_owner_idx].is_empty());
+        let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[view_owner_idx].len(), 1);
+        assert!(bob_auth.tokens[view_owner_idx].contains(&2u32));
+        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 2);
+        assert!(bob_auth.tokens[view_meta_idx].contains(&1u32));
+        assert!(bob_auth.tokens[view_meta_idx].contains(&2u32));
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
+        assert_eq!(bob_list.len(), 1);
+        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
+        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 2);
+        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
+        assert!(alice_auth.tokens[view_owner_idx].contains(&4u32));
+        assert_eq!(alice_auth.tokens[view_meta_idx].len(), 1);
+        assert!(alice_auth.tokens[view_meta_idx].contains(&3u32));
+        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
+        assert!(alice_auth.tokens[transfer_idx].contains(&3u32));
+        let charlie_list: Vec<AuthList> = load(&auth_store, charlie_key).unwrap();
+        assert_eq!(charlie_list.len(), 1);
+        let bob_auth = charlie_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
+        assert!(bob_auth.tokens[view_meta_idx].contains(&5u32));
+        assert!(bob_auth.tokens[transfer_idx].is_empty());
+
+        let sends = vec![
+            Send {
+                contract: HumanAddr("charlie".to_string()),
+                receiver_info: None,
+                token_ids: vec!["NFT1".to_string()],
+                msg: send_msg.clone(),
+                memo: None,
+            },
+            Send {
+                contract: HumanAddr("alice".to_string()),
+                receiver_info: None,
+                token_ids: vec!["NFT5".to_string()],
+                msg: send_msg.clone(),
+                memo: None,
+            },
+            Send {
+                contract: HumanAddr("bob".to_string()),
+                receiver_info: Some(ReceiverInfo {
+                    recipient_code_hash: "supplied in the send".to_string(),
+                    also_implements_batch_receive_nft: Some(true),
+                }),
+                token_ids: vec!["NFT3".to_string()],
+                msg: send_msg.clone(),
+                memo: None,
+            },
+        ];
+
+        // test bob transfers two of his tokens and one of alice's
+        let handle_msg = HandleMsg::BatchSendNft {
+            sends,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        // confirm the receive nft msgs were created
+        let handle_resp = handle_result.unwrap();
+        let messages = handle_resp.messages;
+        let mut msg_fr_b = to_binary(&Snip721ReceiveMsg::ReceiveNft {
+            sender: HumanAddr("bob".to_string()),
+            token_id: "NFT1".to_string(),
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_b = space_pad(&mut msg_fr_b.0, 256usize);
+        let msg_fr_b = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("charlie".to_string()),
+            callback_code_hash: "charlie code hash".to_string(),
+            msg: Binary(msg_fr_b.to_vec()),
+            send: vec![],
+        });
+        assert_eq!(messages[0], msg_fr_b);
+        assert_eq!(messages.len(), 2);
+        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::BatchReceiveNft {
+            sender: HumanAddr("bob".to_string()),
+            from: HumanAddr("alice".to_string()),
+            token_ids: vec!["NFT3".to_string()],
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
+        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("bob".to_string()),
+            callback_code_hash: "supplied in the send".to_string(),
+            msg: Binary(msg_fr_al.to_vec()),
+            send: vec![],
+        });
+        assert_eq!(messages[1], msg_fr_al);
+        // confirm tokens have the correct owner and the permissions were cleared
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
         let token: Token = json_load(&info_store, &tok_key).unwrap();
+        assert_eq!(token.owner, charlie_raw);
+        assert!(token.permissions.is_empty());
+        let token: Token = json_load(&info_store, &tok3_key).unwrap();
+        assert_eq!(token.owner, bob_raw);
+        assert!(token.permissions.is_empty());
+        let token: Token = json_load(&info_store, &tok5_key).unwrap();
         assert_eq!(token.owner, alice_raw);
-        assert_eq!(token.permissions.len(), 2);
-        let charlie_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == charlie_raw)
-            .unwrap();
-        assert_eq!(charlie_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            charlie_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtHeight(10))
-        );
-        assert_eq!(charlie_tok_perm.expirations[view_owner_idx], None);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[transfer_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[view_owner_idx],
-            Some(Expiration::Never)
-        );
-        assert!(token.unwrapped);
-        // confirm no transfer tx was logged (latest should be the mint tx)
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
-        assert_eq!(total, 1);
-        assert_eq!(
-            txs[0].action,
-            TxAction::Mint {
-                minter: HumanAddr("admin".to_string()),
-                recipient: HumanAddr("alice".to_string()),
-            }
-        );
+        assert!(token.permissions.is_empty());
         // confirm the owner list is correct
         let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
+        assert_eq!(inventory.info.count, 2);
+        assert!(inventory.contains(&deps.storage, 1).unwrap());
+        assert!(inventory.contains(&deps.storage, 4).unwrap());
+        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 2);
+        assert!(inventory.contains(&deps.storage, 2).unwrap());
+        assert!(inventory.contains(&deps.storage, 3).unwrap());
+        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
+        assert_eq!(inventory.info.count, 2);
         assert!(inventory.contains(&deps.storage, 0).unwrap());
-        // confirm charlie's and bob's AuthList were not changed
+        assert!(inventory.contains(&deps.storage, 5).unwrap());
+        // confirm authLists were updated correctly
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(alice_list.len(), 2);
-        let charlie_auth = alice_list
-            .iter()
-            .find(|a| a.address == charlie_raw)
-            .unwrap();
-        assert_eq!(charlie_auth.tokens[transfer_idx].len(), 1);
-        assert!(charlie_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(charlie_auth.tokens[view_meta_idx].is_empty());
-        assert!(charlie_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(alice_list.len(), 1);
         let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[view_owner_idx].len(), 1);
-        assert!(bob_auth.tokens[view_owner_idx].contains(&0u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
+        assert!(bob_auth.tokens[view_meta_idx].contains(&1u32));
+        assert!(bob_auth.tokens[transfer_idx].is_empty());
+        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
+        assert_eq!(bob_list.len(), 1);
+        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
+        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 1);
+        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
+        assert_eq!(alice_auth.tokens[view_meta_idx].len(), 1);
+        assert!(alice_auth.tokens[view_meta_idx].contains(&3u32));
+        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
+        assert!(alice_auth.tokens[transfer_idx].contains(&3u32));
+        let charlie_list: Vec<AuthList> = load(&auth_store, charlie_key).unwrap();
+        assert_eq!(charlie_list.len(), 1);
+        let bob_auth = charlie_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
+        assert!(bob_auth.tokens[view_meta_idx].contains(&5u32));
         assert!(bob_auth.tokens[transfer_idx].is_empty());
 
-        // sanity check: operator transfers
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("david".to_string()),
-            token_id: "MyNFT".to_string(),
-            memo: Some("Xfer it".to_string()),
-            padding: None,
-        };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 1,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
+        // set up for batch send test
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: vec![
+                Mint {
+                    token_id: Some("NFT1".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
                 },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
+                Mint {
+                    token_id: Some("NFT2".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
                 },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                Mint {
+                    token_id: Some("NFT3".to_string()),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
                 },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        // confirm token was not removed from the maps
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
-        // confirm token belongs to david now and permissions have been cleared
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, david_raw);
-        assert!(token.permissions.is_empty());
-        assert!(token.unwrapped);
-        // confirm the metadata is intact
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv1.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &tok_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
-        // confirm the tx was logged to all involved parties
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
-        assert_eq!(total, 2);
-        assert_eq!(txs.len(), 1);
-        assert_eq!(txs[0].token_id, "MyNFT".to_string());
-        assert_eq!(
-            txs[0].action,
-            TxAction::Transfer {
-                from: HumanAddr("alice".to_string()),
-                sender: Some(HumanAddr("bob".to_string())),
-                recipient: HumanAddr("david".to_string()),
-            }
-        );
-        assert_eq!(txs[0].memo, Some("Xfer it".to_string()));
-        let (tx2, total) = get_txs(&deps.api, &deps.storage, &bob_raw, 0, 1).unwrap();
-        assert_eq!(total, 1);
-        let (tx3, total) = get_txs(&deps.api, &deps.storage, &david_raw, 0, 1).unwrap();
-        assert_eq!(total, 1);
-        assert_eq!(txs, tx2);
-        assert_eq!(tx2, tx3);
-        // confirm both owner lists are correct
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 0);
-        assert!(!inventory.contains(&deps.storage, 0).unwrap());
-        let inventory = Inventory::new(&deps.storage, david_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        // confirm charlie's and bob's AuthList were removed because the only token was xferred
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
-        // confirm david did not inherit any AuthLists from the xfer
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, david_key).unwrap();
-        assert!(auth_list.is_none());
-
-        // sanity check: address with token permission xfers it to itself
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            expires: Some(Expiration::AtHeight(10)),
+                Mint {
+                    token_id: Some("NFT4".to_string()),
+                    owner: Some(HumanAddr("bob".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT5".to_string()),
+                    owner: Some(HumanAddr("bob".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("NFT6".to_string()),
+                    owner: Some(HumanAddr("charlie".to_string())),
+                    private_metadata: None,
+                    public_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                },
+            ],
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            memo: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 1,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("charlie".to_string()),
-                    sent_funds: vec![],
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let handle_msg = HandleMsg::RegisterReceiveNft {
+            code_hash: "alice code hash".to_string(),
+            also_implements_batch_receive_nft: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::RegisterReceiveNft {
+            code_hash: "charlie code hash".to_string(),
+            also_implements_batch_receive_nft: Some(true),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let send_msg = Some(
+            to_binary(&HandleMsg::RevokeAll {
+                operator: HumanAddr("zoe".to_string()),
+                padding: None,
+            })
+            .unwrap(),
+        );
+        let handle_msg = HandleMsg::BatchSendNft {
+            sends: vec![
+                Send {
+                    contract: HumanAddr("charlie".to_string()),
+                    receiver_info: None,
+                    token_ids: vec!["NFT2".to_string(), "NFT3".to_string(), "NFT4".to_string()],
+                    msg: send_msg.clone(),
+                    memo: Some("test memo".to_string()),
                 },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                Send {
+                    contract: HumanAddr("alice".to_string()),
+                    receiver_info: None,
+                    token_ids: vec!["NFT3".to_string(), "NFT4".to_string(), "NFT6".to_string()],
+                    msg: None,
+                    memo: None,
                 },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        // confirm token was not removed from the list
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
-        // confirm token belongs to charlie now and permissions have been cleared
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, charlie_raw);
-        assert!(token.permissions.is_empty());
-        assert!(token.unwrapped);
-        // confirm the metadata is intact
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv1.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &tok_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
-        // confirm the tx was logged to all involved parties
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 10).unwrap();
-        assert_eq!(total, 1);
-        assert_eq!(txs.len(), 1);
-        assert_eq!(txs[0].token_id, "MyNFT".to_string());
-        assert_eq!(
-            txs[0].action,
-            TxAction::Transfer {
-                from: HumanAddr("david".to_string()),
-                sender: Some(HumanAddr("charlie".to_string())),
-                recipient: HumanAddr("charlie".to_string()),
-            }
-        );
-        assert_eq!(txs[0].memo, None);
-        let (tx2, total) = get_txs(&deps.api, &deps.storage, &david_raw, 0, 1).unwrap();
-        assert_eq!(total, 2);
-        assert_eq!(txs, tx2);
-        // confirm both owner lists are correct
-        let inventory = Inventory::new(&deps.storage, david_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 0);
-        assert!(!inventory.contains(&deps.storage, 0).unwrap());
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        // confirm charlie's AuthList was removed because the only token was xferred
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, david_key).unwrap();
-        assert!(auth_list.is_none());
-        // confirm charlie did not inherit any AuthLists from the xfer
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
-        assert!(auth_list.is_none());
-
-        // sanity check: owner xfers
-        let handle_msg = HandleMsg::TransferNft {
-            recipient: HumanAddr("alice".to_string()),
-            token_id: "MyNFT".to_string(),
-            memo: None,
+            ],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let handle_resp = handle_result.unwrap();
+        let messages = handle_resp.messages;
+        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::BatchReceiveNft {
+            sender: HumanAddr("bob".to_string()),
+            from: HumanAddr("alice".to_string()),
+            token_ids: vec!["NFT2".to_string(), "NFT3".to_string()],
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
+        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("charlie".to_string()),
+            callback_code_hash: "charlie code hash".to_string(),
+            msg: Binary(msg_fr_al.to_vec()),
+            send: vec![],
+        });
+        let mut msf_fr_b = to_binary(&Snip721ReceiveMsg::BatchReceiveNft {
+            sender: HumanAddr("bob".to_string()),
+            from: HumanAddr("bob".to_string()),
+            token_ids: vec!["NFT4".to_string()],
+            msg: send_msg.clone(),
+        })
+        .unwrap();
+        let msg_fr_b = space_pad(&mut msf_fr_b.0, 256usize);
+        let msg_fr_b = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("charlie".to_string()),
+            callback_code_hash: "charlie code hash".to_string(),
+            msg: Binary(msg_fr_b.to_vec()),
+            send: vec![],
+        });
+        let mut msg_fr_c3 = to_binary(&Snip721ReceiveMsg::ReceiveNft {
+            sender: HumanAddr("charlie".to_string()),
+            token_id: "NFT3".to_string(),
+            msg: None,
+        })
+        .unwrap();
+        let msg_fr_c3 = space_pad(&mut msg_fr_c3.0, 256usize);
+        let msg_fr_c3 = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("alice".to_string()),
+            callback_code_hash: "alice code hash".to_string(),
+            msg: Binary(msg_fr_c3.to_vec()),
+            send: vec![],
+        });
+        let mut msg_fr_c4 = to_binary(&Snip721ReceiveMsg::ReceiveNft {
+            sender: HumanAddr("charlie".to_string()),
+            token_id: "NFT4".to_string(),
+            msg: None,
+        })
+        .unwrap();
+        let msg_fr_c4 = space_pad(&mut msg_fr_c4.0, 256usize);
+        let msg_fr_c4 = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("alice".to_string()),
+            callback_code_hash: "alice code hash".to_string(),
+            msg: Binary(msg_fr_c4.to_vec()),
+            send: vec![],
+        });
+        let mut msg_fr_c6 = to_binary(&Snip721ReceiveMsg::ReceiveNft {
+            sender: HumanAddr("charlie".to_string()),
+            token_id: "NFT6".to_string(),
+            msg: None,
+        })
+        .unwrap();
+        let msg_fr_c6 = space_pad(&mut msg_fr_c6.0, 256usize);
+        let msg_fr_c6 = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: HumanAddr("alice".to_string()),
+            callback_code_hash: "alice code hash".to_string(),
+            msg: Binary(msg_fr_c6.to_vec()),
+            send: vec![],
+        });
+        let expected_msgs = vec![msg_fr_al, msg_fr_b, msg_fr_c3, msg_fr_c4, msg_fr_c6];
+        assert_eq!(messages, expected_msgs);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "ckey".to_string(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        // confirm token was not removed from the maps
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
-        // confirm token belongs to alice now and permissions have been cleared
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.permissions.is_empty());
-        assert!(token.unwrapped);
-        // confirm the tx was logged to all involved parties
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
-        assert_eq!(total, 2);
-        assert_eq!(txs[0].token_id, "MyNFT".to_string());
-        assert_eq!(
-            txs[0].action,
-            TxAction::Transfer {
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm alice's tokens
+        let query_msg = QueryMsg::Tokens {
+            owner: HumanAddr("alice".to_string()),
+            viewer: None,
+            viewing_key: Some("akey".to_string()),
+            start_after: None,
+            limit: Some(30),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens, .. } => {
+                let expected = vec![
+                    "NFT1".to_string(),
+                    "NFT4".to_string(),
+                    "NFT3".to_string(),
+                    "NFT6".to_string(),
+                ];
+                assert_eq!(tokens, expected);
+            }
+            _ => panic!("unexpected"),
+        }
+        let xfer6 = Tx {
+            tx_id: 11,
+            block_height: 12345,
+            block_time: 1571797419,
+            token_id: "NFT6".to_string(),
+            memo: None,
+            action: TxAction::Transfer {
                 from: HumanAddr("charlie".to_string()),
-                sender: None,
+                sender: Some(HumanAddr("bob".to_string())),
                 recipient: HumanAddr("alice".to_string()),
+            },
+        };
+        let xfer3 = Tx {
+            tx_id: 7,
+            block_height: 12345,
+            block_time: 1571797419,
+            token_id: "NFT3".to_string(),
+            memo: Some("test memo".to_string()),
+            action: TxAction::Transfer {
+                from: HumanAddr("alice".to_string()),
+                sender: Some(HumanAddr("bob".to_string())),
+                recipient: HumanAddr("charlie".to_string()),
+            },
+        };
+        let query_msg = QueryMsg::TransactionHistory {
+            address: HumanAddr("alice".to_string()),
+            viewing_key: "akey".to_string(),
+            page: None,
+            page_size: None,
+            tx_type: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(total, 8);
+                assert_eq!(txs[3], xfer3);
+                assert_eq!(txs[0], xfer6);
             }
-        );
-        assert_eq!(txs[0].memo, None);
-        let (tx2, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
-        assert_eq!(total, 3);
-        assert_eq!(txs, tx2);
-        // confirm both owner lists are correct
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 0);
-        assert!(!inventory.contains(&deps.storage, 0).unwrap());
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        // confirm charlie's AuthList was removed because the only token was xferred
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
-        // confirm charlie did not inherit any AuthLists from the xfer
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
-        assert!(auth_list.is_none());
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::Tokens {
+            owner: HumanAddr("charlie".to_string()),
+            viewer: None,
+            viewing_key: Some("ckey".to_string()),
+            start_after: None,
+            limit: Some(30),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens, .. } => {
+                let expected = vec!["NFT2".to_string()];
+                assert_eq!(tokens, expected);
+            }
+            _ => panic!("unexpected"),
+        }
     }
 
-    // test batch transfer
+    // test register receive_nft
     #[test]
-    fn test_batch_transfer() {
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+    fn test_register_receive_nft() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
 
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: Some("metadata".to_string()),
-                    image: Some("uri".to_string()),
-                    ..Extension::default()
-                }),
-            }),
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test transfer when status prevents it
+        // test register when status prevents it
         let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+            level: ContractStatus::StopAll,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let transfers = vec![Transfer {
-            recipient: HumanAddr("bob".to_string()),
-            token_ids: vec!["MyNFT".to_string()],
-            memo: None,
-        }];
-        let handle_msg = HandleMsg::BatchTransferNft {
-            transfers,
+        let handle_msg = HandleMsg::RegisterReceiveNft {
+            code_hash: "alice code hash".to_string(),
+            also_implements_batch_receive_nft: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
         assert!(error.contains("The contract admin has temporarily disabled this action"));
 
+        // you can still register when transactions are stopped
         let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::Normal,
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        let (init_result, mut deps) =
-            init_helper_with_config(true, false, false, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
-        let transfers = vec![Transfer {
-            recipient: HumanAddr("bob".to_string()),
-            token_ids: vec!["MyNFT".to_string()],
-            memo: None,
-        }];
-
-        // test token not found when supply is public
-        let handle_msg = HandleMsg::BatchTransferNft {
-            transfers,
+        // sanity check
+        let handle_msg = HandleMsg::RegisterReceiveNft {
+            code_hash: "alice code hash".to_string(),
+            also_implements_batch_receive_nft: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Token ID: MyNFT not found"));
-
-        let tok_key = 0u32.to_le_bytes();
-        let tok5_key = 4u32.to_le_bytes();
-        let tok3_key = 2u32.to_le_bytes();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let bob_key = bob_raw.as_slice();
-        let charlie_raw = deps
-            .api
-            .canonical_address(&HumanAddr("charlie".to_string()))
-            .unwrap();
-        let charlie_key = charlie_raw.as_slice();
-        let david_raw = deps
-            .api
-            .canonical_address(&HumanAddr("david".to_string()))
-            .unwrap();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let store = ReadonlyPrefixedStorage::new(PREFIX_RECEIVERS, &deps.storage);
+        let hash: String = load(
+            &store,
+            deps.api
+                .canonical_address(&HumanAddr("alice".to_string()))
+                .unwrap()
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(&hash, "alice code hash");
+    }
 
-        // set up for batch transfer test
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+    // test create viewing key
+    #[test]
+    fn test_create_viewing_key() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::BatchMintNft {
-            mints: vec![
-                Mint {
-                    token_id: Some("NFT1".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT2".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT3".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT4".to_string()),
-                    owner: Some(HumanAddr("bob".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT5".to_string()),
-                    owner: Some(HumanAddr("bob".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT6".to_string()),
-                    owner: Some(HumanAddr("charlie".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-            ],
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+
+        // test creating a key when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            transfer: None,
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::CreateViewingKey {
+            entropy: "blah".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        // you can still create a key when transactions are stopped
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        let handle_msg = HandleMsg::CreateViewingKey {
+            entropy: "blah".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
-            padding: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(
+            handle_result.is_ok(),
+            "handle() failed: {}",
+            handle_result.err().unwrap()
+        );
+        let answer: HandleAnswer = from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+
+        let key_str = match answer {
+            HandleAnswer::ViewingKey { key } => key,
+            _ => panic!("NOPE"),
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let key = ViewingKey(key_str);
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let key_store = ReadonlyPrefixedStorage::new(PREFIX_VIEW_KEY, &deps.storage);
+        let saved_vk: [u8; VIEWING_KEY_SIZE] = load(&key_store, alice_raw.as_slice()).unwrap();
+        assert!(key.check_viewing_key(&saved_vk));
+    }
+
+    // test set viewing key
+    #[test]
+    fn test_set_viewing_key() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test setting a key when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT5".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            transfer: None,
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "blah".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT6".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        // you can still set a key when transactions are stopped
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "blah".to_string(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let key = ViewingKey("blah".to_string());
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let key_store = ReadonlyPrefixedStorage::new(PREFIX_VIEW_KEY, &deps.storage);
+        let saved_vk: [u8; VIEWING_KEY_SIZE] = load(&key_store, alice_raw.as_slice()).unwrap();
+        assert!(key.check_viewing_key(&saved_vk));
+    }
+
+    // test add minters
+    #[test]
+    fn test_add_minters() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test adding minters when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let minters = vec![
+            HumanAddr("alice".to_string()),
+            HumanAddr("bob".to_string()),
+            HumanAddr("bob".to_string()),
+            HumanAddr("alice".to_string()),
+        ];
+        let handle_msg = HandleMsg::AddMinters {
+            minters: minters.clone(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let transfers = vec![
-            Transfer {
-                recipient: HumanAddr("charlie".to_string()),
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-            Transfer {
-                recipient: HumanAddr("alice".to_string()),
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-            Transfer {
-                recipient: HumanAddr("bob".to_string()),
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-            Transfer {
-                recipient: HumanAddr("david".to_string()),
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-        ];
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
 
-        // test transferring the same token among address the sender has ALL permission,
-        // but then breaks when it gets to an address he does not have authority for
-        let handle_msg = HandleMsg::BatchTransferNft {
-            transfers,
+        // you can still add minters when transactions are stopped
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test non admin trying to add minters
+        let handle_msg = HandleMsg::AddMinters {
+            minters: minters.clone(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
-        // confirm it didn't die until david tried to transfer itaway from bob
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, bob_raw);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // sanity check
+        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let admin_raw = deps
+            .api
+            .canonical_address(&HumanAddr("admin".to_string()))
+            .unwrap();
+        // verify the minters we will add are not already in the list
+        assert!(!cur_minter.contains(&alice_raw));
+        assert!(!cur_minter.contains(&bob_raw));
+        let handle_msg = HandleMsg::AddMinters {
+            minters,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify the new minters were added
+        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
+        assert_eq!(cur_minter.len(), 3);
+        assert!(cur_minter.contains(&alice_raw));
+        assert!(cur_minter.contains(&bob_raw));
+        assert!(cur_minter.contains(&admin_raw));
+
+        // let's try an empty list to see if it breaks
+        let minters = vec![];
+        let handle_msg = HandleMsg::AddMinters {
+            minters,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify it's the same list
+        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
+        assert_eq!(cur_minter.len(), 3);
+        assert!(cur_minter.contains(&alice_raw));
+        assert!(cur_minter.contains(&bob_raw));
+        assert!(cur_minter.contains(&admin_raw));
+    }
 
-        // set up for batch transfer test
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+    // test remove minters
+    #[test]
+    fn test_remove_minters() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        // test removing minters when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT3".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        let minters = vec![
+            HumanAddr("alice".to_string()),
+            HumanAddr("bob".to_string()),
+            HumanAddr("charlie".to_string()),
+            HumanAddr("bob".to_string()),
+        ];
+        let handle_msg = HandleMsg::RemoveMinters {
+            minters: minters.clone(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT4".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        // you can still remove minters when transactions are stopped
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT5".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+
+        // test non admin trying to remove minters
+        let handle_msg = HandleMsg::RemoveMinters {
+            minters: minters.clone(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT6".to_string()),
-            owner: Some(HumanAddr("charlie".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // sanity check
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let admin_raw = deps
+            .api
+            .canonical_address(&HumanAddr("admin".to_string()))
+            .unwrap();
+        let handle_msg = HandleMsg::AddMinters {
+            minters: minters.clone(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        // verify the new minters were added
+        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
+        assert_eq!(cur_minter.len(), 4);
+        assert!(cur_minter.contains(&alice_raw));
+        assert!(cur_minter.contains(&bob_raw));
+        assert!(cur_minter.contains(&charlie_raw));
+        assert!(cur_minter.contains(&admin_raw));
+
+        // let's give it an empty list to see if it breaks
+        let minters = vec![];
+        let handle_msg = HandleMsg::RemoveMinters {
+            minters,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT5".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            transfer: None,
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify it is the same list
+        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
+        assert_eq!(cur_minter.len(), 4);
+        assert!(cur_minter.contains(&alice_raw));
+        assert!(cur_minter.contains(&bob_raw));
+        assert!(cur_minter.contains(&charlie_raw));
+        assert!(cur_minter.contains(&admin_raw));
+
+        // let's throw some repeats to see if it breaks
+        let minters = vec![
+            HumanAddr("alice".to_string()),
+            HumanAddr("bob".to_string()),
+            HumanAddr("alice".to_string()),
+            HumanAddr("charlie".to_string()),
+        ];
+        let handle_msg = HandleMsg::RemoveMinters {
+            minters,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT6".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify the minters were removed
+        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
+        assert_eq!(cur_minter.len(), 1);
+        assert!(!cur_minter.contains(&alice_raw));
+        assert!(!cur_minter.contains(&bob_raw));
+        assert!(!cur_minter.contains(&charlie_raw));
+        assert!(cur_minter.contains(&admin_raw));
+
+        // let's remove the last one
+        let handle_msg = HandleMsg::RemoveMinters {
+            minters: vec![HumanAddr("admin".to_string())],
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify the minters were removed
+        let cur_minter: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, MINTERS_KEY).unwrap();
+        assert!(cur_minter.is_none());
+    }
+
+    // test set minters
+    #[test]
+    fn test_set_minters() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test setting minters when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let minters = vec![
+            HumanAddr("alice".to_string()),
+            HumanAddr("bob".to_string()),
+            HumanAddr("charlie".to_string()),
+            HumanAddr("bob".to_string()),
+            HumanAddr("alice".to_string()),
+        ];
+        let handle_msg = HandleMsg::SetMinters {
+            minters: minters.clone(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        // you can still set minters when transactions are stopped
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let transfers = vec![
-            Transfer {
-                recipient: HumanAddr("charlie".to_string()),
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-            Transfer {
-                recipient: HumanAddr("alice".to_string()),
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-            Transfer {
-                recipient: HumanAddr("bob".to_string()),
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-        ];
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test transferring the same token among address the sender has ALL permission
-        // and verify the AuthLists are correct after all the transfers
-        let handle_msg = HandleMsg::BatchTransferNft {
-            transfers,
+        // test non admin trying to set minters
+        let handle_msg = HandleMsg::SetMinters {
+            minters: minters.clone(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
-        // confirm token was not removed from the maps
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "NFT1".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("NFT1".to_string(), id);
-        // confirm token has the correct owner and the permissions were cleared
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, bob_raw);
-        assert!(token.permissions.is_empty());
-        assert!(token.unwrapped);
-        // confirm transfer txs were logged
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 10).unwrap();
-        assert_eq!(total, 6);
-        assert_eq!(txs.len(), 6);
-        assert_eq!(
-            txs[2].action,
-            TxAction::Transfer {
-                from: HumanAddr("alice".to_string()),
-                sender: Some(HumanAddr("david".to_string())),
-                recipient: HumanAddr("charlie".to_string()),
-            }
-        );
-        assert_eq!(
-            txs[1].action,
-            TxAction::Transfer {
-                from: HumanAddr("charlie".to_string()),
-                sender: Some(HumanAddr("david".to_string())),
-                recipient: HumanAddr("alice".to_string()),
-            }
-        );
-        assert_eq!(
-            txs[0].action,
-            TxAction::Transfer {
-                from: HumanAddr("alice".to_string()),
-                sender: Some(HumanAddr("david".to_string())),
-                recipient: HumanAddr("bob".to_string()),
-            }
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
         );
-        // confirm the owner list is correct
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(!inventory.contains(&deps.storage, 0).unwrap());
-        assert!(inventory.contains(&deps.storage, 1).unwrap());
-        assert!(inventory.contains(&deps.storage, 2).unwrap());
-        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 3);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        assert!(inventory.contains(&deps.storage, 3).unwrap());
-        assert!(inventory.contains(&deps.storage, 4).unwrap());
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 5).unwrap());
-        // confirm authLists were updated correctly
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(alice_list.len(), 2);
-        let david_auth = alice_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[view_meta_idx].len(), 1);
-        assert!(david_auth.tokens[view_meta_idx].contains(&2u32));
-        assert!(david_auth.tokens[transfer_idx].is_empty());
-        assert!(david_auth.tokens[view_owner_idx].is_empty());
-        let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[view_owner_idx].len(), 1);
-        assert!(bob_auth.tokens[view_owner_idx].contains(&2u32));
-        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 2);
-        assert!(bob_auth.tokens[view_meta_idx].contains(&1u32));
-        assert!(bob_auth.tokens[view_meta_idx].contains(&2u32));
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
-        assert_eq!(bob_list.len(), 1);
-        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
-        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 2);
-        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
-        assert!(alice_auth.tokens[view_owner_idx].contains(&4u32));
-        assert_eq!(alice_auth.tokens[view_meta_idx].len(), 1);
-        assert!(alice_auth.tokens[view_meta_idx].contains(&3u32));
-        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
-        assert!(alice_auth.tokens[transfer_idx].contains(&3u32));
-        let charlie_list: Vec<AuthList> = load(&auth_store, charlie_key).unwrap();
-        assert_eq!(charlie_list.len(), 1);
-        let bob_auth = charlie_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
-        assert!(bob_auth.tokens[view_meta_idx].contains(&5u32));
-        assert!(bob_auth.tokens[transfer_idx].is_empty());
-
-        let transfers = vec![
-            Transfer {
-                recipient: HumanAddr("charlie".to_string()),
-                token_ids: vec!["NFT1".to_string()],
-                memo: None,
-            },
-            Transfer {
-                recipient: HumanAddr("alice".to_string()),
-                token_ids: vec!["NFT5".to_string()],
-                memo: None,
-            },
-            Transfer {
-                recipient: HumanAddr("bob".to_string()),
-                token_ids: vec!["NFT3".to_string()],
-                memo: None,
-            },
-        ];
 
-        // test bobs trnsfer two of his tokens and one of alice's
-        let handle_msg = HandleMsg::BatchTransferNft {
-            transfers,
+        // sanity check
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let handle_msg = HandleMsg::SetMinters {
+            minters: minters.clone(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        // confirm tokens have the correct owner and the permissions were cleared
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, charlie_raw);
-        assert!(token.permissions.is_empty());
-        let token: Token = json_load(&info_store, &tok3_key).unwrap();
-        assert_eq!(token.owner, bob_raw);
-        assert!(token.permissions.is_empty());
-        let token: Token = json_load(&info_store, &tok5_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.permissions.is_empty());
-        // confirm the owner list is correct
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(inventory.contains(&deps.storage, 1).unwrap());
-        assert!(inventory.contains(&deps.storage, 4).unwrap());
-        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(inventory.contains(&deps.storage, 2).unwrap());
-        assert!(inventory.contains(&deps.storage, 3).unwrap());
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        assert!(inventory.contains(&deps.storage, 5).unwrap());
-        // confirm authLists were updated correctly
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(alice_list.len(), 1);
-        let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
-        assert!(bob_auth.tokens[view_meta_idx].contains(&1u32));
-        assert!(bob_auth.tokens[transfer_idx].is_empty());
-        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
-        assert_eq!(bob_list.len(), 1);
-        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
-        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 1);
-        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
-        assert_eq!(alice_auth.tokens[view_meta_idx].len(), 1);
-        assert!(alice_auth.tokens[view_meta_idx].contains(&3u32));
-        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
-        assert!(alice_auth.tokens[transfer_idx].contains(&3u32));
-        let charlie_list: Vec<AuthList> = load(&auth_store, charlie_key).unwrap();
-        assert_eq!(charlie_list.len(), 1);
-        let bob_auth = charlie_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
-        assert!(bob_auth.tokens[view_meta_idx].contains(&5u32));
-        assert!(bob_auth.tokens[transfer_idx].is_empty());
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify the new minters were added
+        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
+        assert_eq!(cur_minter.len(), 3);
+        assert!(cur_minter.contains(&alice_raw));
+        assert!(cur_minter.contains(&bob_raw));
+        assert!(cur_minter.contains(&charlie_raw));
+        // let's try an empty list
+        let minters = vec![];
+        let handle_msg = HandleMsg::SetMinters {
+            minters,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify the minters were removed
+        let cur_minter: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, MINTERS_KEY).unwrap();
+        assert!(cur_minter.is_none());
+    }
 
-        // set up for batch transfer test
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+    // test change admin
+    #[test]
+    fn test_change_admin() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::BatchMintNft {
-            mints: vec![
-                Mint {
-                    token_id: Some("NFT1".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT2".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT3".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT4".to_string()),
-                    owner: Some(HumanAddr("bob".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT5".to_string()),
-                    owner: Some(HumanAddr("bob".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT6".to_string()),
-                    owner: Some(HumanAddr("charlie".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-            ],
+
+        // test changing admin when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let handle_msg = HandleMsg::ChangeAdmin {
+            address: HumanAddr("alice".to_string()),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+
+        // you can still change admin when transactions are stopped
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        let handle_msg = HandleMsg::BatchTransferNft {
-            transfers: vec![
-                Transfer {
-                    recipient: HumanAddr("charlie".to_string()),
-                    token_ids: vec!["NFT2".to_string(), "NFT3".to_string(), "NFT4".to_string()],
-                    memo: Some("test memo".to_string()),
-                },
-                Transfer {
-                    recipient: HumanAddr("charlie".to_string()),
-                    token_ids: vec!["NFT1".to_string(), "NFT5".to_string()],
-                    memo: None,
-                },
-            ],
+        // test non admin trying to change admin
+        let handle_msg = HandleMsg::ChangeAdmin {
+            address: HumanAddr("alice".to_string()),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetViewingKey {
-            key: "ckey".to_string(),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // sanity check
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let admin_raw = deps
+            .api
+            .canonical_address(&HumanAddr("admin".to_string()))
+            .unwrap();
+        // verify admin is the current admin
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.admin, admin_raw);
+        // change it to alice
+        let handle_msg = HandleMsg::ChangeAdmin {
+            address: HumanAddr("alice".to_string()),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        // confirm charlie's tokens
-        let query_msg = QueryMsg::Tokens {
-            owner: HumanAddr("charlie".to_string()),
-            viewer: None,
-            viewing_key: Some("ckey".to_string()),
-            start_after: None,
-            limit: Some(30),
-        };
-        let query_result = query(&deps, query_msg);
-        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
-        match query_answer {
-            QueryAnswer::TokenList { tokens } => {
-                let expected = vec![
-                    "NFT6".to_string(),
-                    "NFT2".to_string(),
-                    "NFT3".to_string(),
-                    "NFT4".to_string(),
-                    "NFT1".to_string(),
-                    "NFT5".to_string(),
-                ];
-                assert_eq!(tokens, expected);
-            }
-            _ => panic!("unexpected"),
-        }
-        let xfer4 = Tx {
-            tx_id: 8,
-            block_height: 12345,
-            block_time: 1571797419,
-            token_id: "NFT4".to_string(),
-            memo: Some("test memo".to_string()),
-            action: TxAction::Transfer {
-                from: HumanAddr("bob".to_string()),
-                sender: None,
-                recipient: HumanAddr("charlie".to_string()),
-            },
-        };
-        let xfer1 = Tx {
-            tx_id: 9,
-            block_height: 12345,
-            block_time: 1571797419,
-            token_id: "NFT1".to_string(),
-            memo: None,
-            action: TxAction::Transfer {
-                from: HumanAddr("alice".to_string()),
-                sender: Some(HumanAddr("bob".to_string())),
-                recipient: HumanAddr("charlie".to_string()),
-            },
-        };
-        let query_msg = QueryMsg::TransactionHistory {
-            address: HumanAddr("charlie".to_string()),
-            viewing_key: "ckey".to_string(),
-            page: None,
-            page_size: None,
-        };
-        let query_result = query(&deps, query_msg);
-        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
-        match query_answer {
-            QueryAnswer::TransactionHistory { total, txs } => {
-                assert_eq!(total, 6);
-                assert_eq!(txs[1], xfer1);
-                assert_eq!(txs[2], xfer4);
-            }
-            _ => panic!("unexpected"),
-        }
-        let handle_msg = HandleMsg::SetViewingKey {
-            key: "akey".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify admin was changed
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.admin, alice_raw);
+    }
+
+    // test set contract status
+    #[test]
+    fn test_set_contract_status() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test non admin trying to change status
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let query_msg = QueryMsg::Tokens {
-            owner: HumanAddr("alice".to_string()),
-            viewer: None,
-            viewing_key: Some("akey".to_string()),
-            start_after: None,
-            limit: Some(30),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // sanity check
+        // verify current status is normal
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.status, ContractStatus::Normal.to_u8());
+        // change it to StopAll
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
+            padding: None,
         };
-        let query_result = query(&deps, query_msg);
-        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
-        match query_answer {
-            QueryAnswer::TokenList { tokens } => {
-                assert!(tokens.is_empty());
-            }
-            _ => panic!("unexpected"),
-        }
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        // verify status was changed
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.status, ContractStatus::StopAll.to_u8());
     }
 
-    // test send
+    // test approve_all from the cw721 spec
     #[test]
-    fn test_send() {
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+    fn test_cw721_approve_all() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
@@ -7306,582 +10266,472 @@ mod tests {
         );
 
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("NFT1".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: Some("metadata".to_string()),
-                    image: Some("uri".to_string()),
-                    ..Extension::default()
-                }),
-            }),
             public_metadata: None,
+            private_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test send when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("bob".to_string()),
-            receiver_info: None,
-            token_id: "MyNFT".to_string(),
-            msg: None,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg); // test burn when status prevents it
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT3".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
+        // test trying to ApproveAll when status does not allow
         let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::Normal,
+            level: ContractStatus::StopAll,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        let (init_result, mut deps) =
-            init_helper_with_config(true, false, false, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
-
-        // test token not found when supply is public
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("bob".to_string()),
-            receiver_info: None,
-            token_id: "MyNFT".to_string(),
-            msg: None,
-            memo: None,
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Token ID: MyNFT not found"));
-
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
-
-        // test token not found when supply is private
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("bob".to_string()),
-            receiver_info: None,
-            token_id: "MyNFT".to_string(),
-            msg: None,
-            memo: None,
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("bob".to_string()),
+            expires: None,
+            max_uses: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
-
-        let priv1 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT".to_string()),
-                description: Some("privmetadata".to_string()),
-                image: Some("privuri".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let pub1 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("MyNFT".to_string()),
-                description: Some("pubmetadata".to_string()),
-                image: Some("puburi".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: priv1.clone(),
-            public_metadata: pub1.clone(),
-            royalty_info: None,
-            serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        // setting approval is ok even during StopTransactions status
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test unauthorized sender (but we'll give him view owner access)
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        let nft1_key = 0u32.to_le_bytes();
+        let nft2_key = 1u32.to_le_bytes();
+        let nft3_key = 2u32.to_le_bytes();
+
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
-            token_id: Some("MyNFT".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
             view_private_metadata: None,
-            transfer: None,
+            transfer: Some(AccessLevel::ApproveToken),
             expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("bob".to_string()),
-            receiver_info: None,
-            token_id: "MyNFT".to_string(),
-            msg: None,
-            memo: None,
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("You are not authorized to perform this action on token MyNFT"));
-
-        // test expired token approval
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            expires: Some(Expiration::AtHeight(10)),
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("bob".to_string()),
-            receiver_info: None,
-            token_id: "MyNFT".to_string(),
-            msg: None,
-            memo: None,
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
             padding: None,
         };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("charlie".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // confirm bob has transfer token permissions but not transfer all permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permission has bob
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // confirm NFT2 permission has bob
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // confirm NFT3 permission has bob
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
         );
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Access to token MyNFT has expired"));
+        // confirm AuthLists has bob
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 3);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
 
-        // test expired ALL approval
+        // test that ApproveAll will remove all the token permissions
         let handle_msg = HandleMsg::ApproveAll {
             operator: HumanAddr("bob".to_string()),
-            expires: Some(Expiration::AtHeight(10)),
+            expires: None,
+            max_uses: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("charlie".to_string()),
-            receiver_info: None,
-            token_id: "MyNFT".to_string(),
-            msg: None,
-            memo: None,
-            padding: None,
-        };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 100,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("Access to all tokens of alice has expired"));
-
-        let tok_key = 0u32.to_le_bytes();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let charlie_raw = deps
-            .api
-            .canonical_address(&HumanAddr("charlie".to_string()))
-            .unwrap();
-        let charlie_key = charlie_raw.as_slice();
-        let david_raw = deps
-            .api
-            .canonical_address(&HumanAddr("david".to_string()))
-            .unwrap();
-        let david_key = david_raw.as_slice();
-
-        // confirm that sending to the same address that owns the token throws an error
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("alice".to_string()),
-            receiver_info: None,
-            token_id: "MyNFT".to_string(),
-            msg: None,
-            memo: Some("Xfer it".to_string()),
-            padding: None,
-        };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 1,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains(
-            "Attempting to transfer token ID: MyNFT to the address that already owns it"
-        ));
-
-        // sanity check: operator sends
-        // msg to go with ReceiveNft
-        let send_msg = Some(
-            to_binary(&HandleMsg::RevokeAll {
-                operator: HumanAddr("zoe".to_string()),
-                padding: None,
-            })
-            .unwrap(),
+        // confirm bob has transfer all permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::Never)
         );
-        // register david's ReceiveNft
-        let handle_msg = HandleMsg::RegisterReceiveNft {
-            code_hash: "david code hash".to_string(),
-            also_implements_batch_receive_nft: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("david".to_string()),
-            receiver_info: None,
-            token_id: "MyNFT".to_string(),
-            msg: send_msg.clone(),
-            memo: Some("Xfer it".to_string()),
-            padding: None,
-        };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 1,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("bob".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_oper_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
         );
-        // confirm the receive nft msg was created
-        let handle_resp = handle_result.unwrap();
-        let messages = handle_resp.messages;
-        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::ReceiveNft {
-            sender: HumanAddr("alice".to_string()),
-            token_id: "MyNFT".to_string(),
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
-        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("david".to_string()),
-            callback_code_hash: "david code hash".to_string(),
-            msg: Binary(msg_fr_al.to_vec()),
-            send: vec![],
-        });
-        assert_eq!(messages[0], msg_fr_al);
-        // confirm token was not removed from the maps
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
-        // confirm token belongs to david now and permissions have been cleared
+        // confirm bob's NFT1 permission is gone
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, david_raw);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
         assert!(token.permissions.is_empty());
-        assert!(token.unwrapped);
-        // confirm the metadata is intact
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv1.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &tok_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
-        // confirm the tx was logged to all involved parties
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
-        assert_eq!(total, 2);
-        assert_eq!(txs.len(), 1);
-        assert_eq!(txs[0].token_id, "MyNFT".to_string());
-        assert_eq!(
-            txs[0].action,
-            TxAction::Transfer {
-                from: HumanAddr("alice".to_string()),
-                sender: Some(HumanAddr("bob".to_string())),
-                recipient: HumanAddr("david".to_string()),
-            }
-        );
-        assert_eq!(txs[0].memo, Some("Xfer it".to_string()));
-        let (tx2, total) = get_txs(&deps.api, &deps.storage, &bob_raw, 0, 1).unwrap();
-        assert_eq!(total, 1);
-        let (tx3, total) = get_txs(&deps.api, &deps.storage, &david_raw, 0, 1).unwrap();
-        assert_eq!(total, 1);
-        assert_eq!(txs, tx2);
-        assert_eq!(tx2, tx3);
-        // confirm both owner lists are correct
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 0);
-        assert!(!inventory.contains(&deps.storage, 0).unwrap());
-        let inventory = Inventory::new(&deps.storage, david_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        // confirm charlie's and bob's AuthList were removed because the only token was xferred
+        // confirm bob's NFT2 permission is gone
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm bob's NFT3 permission is gone
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm AuthLists no longer have bob
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
         assert!(auth_list.is_none());
-        // confirm david did not inherit any AuthLists from the xfer
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, david_key).unwrap();
-        assert!(auth_list.is_none());
+    }
 
-        // sanity check: address with token permission xfers it to itself and specifies its
-        // code hash and that it implements BatchReceiveNft in the SendNft msg
-        let handle_msg = HandleMsg::Approve {
-            spender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            expires: Some(Expiration::AtHeight(10)),
+    // test revoke_all from the cw721 spec
+    #[test]
+    fn test_cw721_revoke_all() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("charlie".to_string()),
-            receiver_info: Some(ReceiverInfo {
-                recipient_code_hash: "supplied in the send".to_string(),
-                also_implements_batch_receive_nft: Some(true),
-            }),
-            token_id: "MyNFT".to_string(),
-            msg: send_msg.clone(),
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
-        let handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 1,
-                    time: 100,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("charlie".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg); // test burn when status prevents it
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT3".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test trying to RevokeAll when status does not allow
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::RevokeAll {
+            operator: HumanAddr("bob".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        // setting approval is ok even during StopTransactions status
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        let nft1_key = 0u32.to_le_bytes();
+        let nft2_key = 1u32.to_le_bytes();
+        let nft3_key = 2u32.to_le_bytes();
+
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT2".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT3".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // confirm bob has transfer token permissions but not transfer all permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::Never)
         );
-        // confirm the batch receive nft msg was created
-        let handle_resp = handle_result.unwrap();
-        let messages = handle_resp.messages;
-        let mut msg_fr_dv = to_binary(&Snip721ReceiveMsg::BatchReceiveNft {
-            sender: HumanAddr("charlie".to_string()),
-            from: HumanAddr("david".to_string()),
-            token_ids: vec!["MyNFT".to_string()],
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_dv = space_pad(&mut msg_fr_dv.0, 256usize);
-        let msg_fr_dv = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("charlie".to_string()),
-            callback_code_hash: "supplied in the send".to_string(),
-            msg: Binary(msg_fr_dv.to_vec()),
-            send: vec![],
-        });
-        assert_eq!(messages[0], msg_fr_dv);
-        // confirm token was not removed from the list
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
-        // confirm token belongs to charlie now and permissions have been cleared
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permission has bob
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, charlie_raw);
-        assert!(token.permissions.is_empty());
-        assert!(token.unwrapped);
-        // confirm the metadata is intact
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Metadata = load(&priv_store, &tok_key).unwrap();
-        assert_eq!(priv_meta, priv1.clone().unwrap());
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &tok_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
-        // confirm the tx was logged to all involved parties
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 10).unwrap();
-        assert_eq!(total, 1);
-        assert_eq!(txs.len(), 1);
-        assert_eq!(txs[0].token_id, "MyNFT".to_string());
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
         assert_eq!(
-            txs[0].action,
-            TxAction::Transfer {
-                from: HumanAddr("david".to_string()),
-                sender: Some(HumanAddr("charlie".to_string())),
-                recipient: HumanAddr("charlie".to_string()),
-            }
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
         );
-        assert_eq!(txs[0].memo, None);
-        let (tx2, total) = get_txs(&deps.api, &deps.storage, &david_raw, 0, 1).unwrap();
-        assert_eq!(total, 2);
-        assert_eq!(txs, tx2);
-        // confirm both owner lists are correct
-        let inventory = Inventory::new(&deps.storage, david_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 0);
-        assert!(!inventory.contains(&deps.storage, 0).unwrap());
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        // confirm charlie's AuthList was removed because the only token was xferred
+        // confirm NFT2 permission has bob
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // confirm NFT3 permission has bob
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // confirm AuthLists has bob
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, david_key).unwrap();
-        assert!(auth_list.is_none());
-        // confirm charlie did not inherit any AuthLists from the xfer
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
-        assert!(auth_list.is_none());
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 3);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&1u32));
+        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
 
-        // sanity check: owner sends and specifies a code hash in the send
-        let handle_msg = HandleMsg::SendNft {
-            contract: HumanAddr("alice".to_string()),
-            receiver_info: Some(ReceiverInfo {
-                recipient_code_hash: "supplied in the send".to_string(),
-                also_implements_batch_receive_nft: None,
-            }),
-            token_id: "MyNFT".to_string(),
-            msg: send_msg.clone(),
-            memo: None,
+        // test that RevokeAll will remove all the token permissions
+        let handle_msg = HandleMsg::RevokeAll {
+            operator: HumanAddr("bob".to_string()),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        // confirm the receive nft msg was created
-        let handle_resp = handle_result.unwrap();
-        let messages = handle_resp.messages;
-        let mut msg_fr_ch = to_binary(&Snip721ReceiveMsg::ReceiveNft {
-            sender: HumanAddr("charlie".to_string()),
-            token_id: "MyNFT".to_string(),
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_ch = space_pad(&mut msg_fr_ch.0, 256usize);
-        let msg_fr_ch = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("alice".to_string()),
-            callback_code_hash: "supplied in the send".to_string(),
-            msg: Binary(msg_fr_ch.to_vec()),
-            send: vec![],
-        });
-        assert_eq!(messages[0], msg_fr_ch);
-        // confirm token was not removed from the maps
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "MyNFT".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("MyNFT".to_string(), id);
-        // confirm token belongs to alice now and permissions have been cleared
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.permissions.is_empty());
-        assert!(token.unwrapped);
-        // confirm the tx was logged to all involved parties
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
-        assert_eq!(total, 2);
-        assert_eq!(txs[0].token_id, "MyNFT".to_string());
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm bob does not have transfer all permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
         assert_eq!(
-            txs[0].action,
-            TxAction::Transfer {
-                from: HumanAddr("charlie".to_string()),
-                sender: None,
-                recipient: HumanAddr("alice".to_string()),
-            }
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::Never)
         );
-        assert_eq!(txs[0].memo, None);
-        let (tx2, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
-        assert_eq!(total, 3);
-        assert_eq!(txs, tx2);
-        // confirm both owner lists are correct
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 0);
-        assert!(!inventory.contains(&deps.storage, 0).unwrap());
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        // confirm charlie's AuthList was removed because the only token was xferred
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        // confirm bob's NFT1 permission is gone
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm bob's NFT2 permission is gone
+        let token: Token = json_load(&info_store, &nft2_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm bob's NFT3 permission is gone
+        let token: Token = json_load(&info_store, &nft3_key).unwrap();
+        assert!(token.permissions.is_empty());
+        // confirm AuthLists no longer have bob
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
         let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
         assert!(auth_list.is_none());
-        // confirm charlie did not inherit any AuthLists from the xfer
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, charlie_key).unwrap();
-        assert!(auth_list.is_none());
+
+        // grant bob transfer all permission to test if revoke all removes it
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("bob".to_string()),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm bob has transfer all permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(
+            bob_oper_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        // now get rid of it
+        let handle_msg = HandleMsg::RevokeAll {
+            operator: HumanAddr("bob".to_string()),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // confirm bob no longer has transfer all permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::Never)
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
     }
 
-    // test batch send
+    // test that revoke_all only removes the named operator
     #[test]
-    fn test_batch_send() {
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+    fn test_revoke_all_single_operator() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
@@ -7889,52 +10739,204 @@ mod tests {
         );
 
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("MyNFT".to_string()),
+            token_id: Some("NFT1".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: Some(Metadata {
-                token_uri: None,
-                extension: Some(Extension {
-                    name: Some("MyNFT".to_string()),
-                    description: Some("metadata".to_string()),
-                    image: Some("uri".to_string()),
-                    ..Extension::default()
-                }),
-            }),
             public_metadata: None,
+            private_metadata: None,
             royalty_info: None,
             serial_number: None,
-            memo: Some("Mint it baby!".to_string()),
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test send when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let sends = vec![Send {
-            contract: HumanAddr("bob".to_string()),
-            receiver_info: None,
-            token_ids: vec!["MyNFT".to_string()],
-            msg: None,
+
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let david_raw = deps
+            .api
+            .canonical_address(&HumanAddr("david".to_string()))
+            .unwrap();
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        let nft1_key = 0u32.to_le_bytes();
+
+        // bob gets an operator permission that is still live, charlie one that never expires
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("bob".to_string()),
+            expires: Some(Expiration::AtHeight(2000000)),
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("charlie".to_string()),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // david gets a token approval
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("david".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // revoke only charlie
+        let handle_msg = HandleMsg::RevokeAll {
+            operator: HumanAddr("charlie".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // confirm bob still has his live operator permission and charlie has none
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(2000000))
+        );
+        assert!(all_perm
+            .iter()
+            .find(|p| p.address == charlie_raw)
+            .map(|p| p.expirations[transfer_idx].is_none())
+            .unwrap_or(true));
+        // confirm david's token permission is untouched
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let david_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == david_raw)
+            .unwrap();
+        assert_eq!(
+            david_tok_perm.expirations[transfer_idx],
+            Some(Expiration::Never)
+        );
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        let david_auth = auth_list.iter().find(|a| a.address == david_raw).unwrap();
+        assert_eq!(david_auth.tokens[transfer_idx], vec![0u32]);
+
+        // charlie can no longer transfer
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "NFT2".to_string(),
             memo: None,
-        }];
-        let handle_msg = HandleMsg::BatchSendNft {
-            sends,
             padding: None,
         };
+        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+        // david can still transfer his approved token
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("david".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        // bob can still transfer with his unexpired operator permission
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+    }
+
+    // test making ownership private
+    #[test]
+    fn test_make_ownership_private() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test setting privacy when status prevents it
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MakeOwnershipPrivate { padding: None };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
         assert!(error.contains("The contract admin has temporarily disabled this action"));
 
+        // you can still set privacy when transactions are stopped
         let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::Normal,
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
+        // sanity check when contract default is private
+        let handle_msg = HandleMsg::MakeOwnershipPrivate { padding: None };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
+        let owner_priv: Option<bool> = may_load(&store, alice_key).unwrap();
+        assert!(owner_priv.is_none());
+
+        // test when contract default is public
+        let (init_result, mut deps) =
+            init_helper_with_config(false, true, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MakeOwnershipPrivate { padding: None };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
+        let owner_priv: bool = load(&store, alice_key).unwrap();
+        assert!(!owner_priv);
+    }
+
+    // test owner setting global approvals
+    #[test]
+    fn test_set_global_approval() {
         let (init_result, mut deps) =
             init_helper_with_config(true, false, false, false, false, false, false);
         assert!(
@@ -7942,50 +10944,19 @@ mod tests {
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let sends = vec![Send {
-            contract: HumanAddr("bob".to_string()),
-            receiver_info: None,
-            token_ids: vec!["MyNFT".to_string()],
-            msg: None,
-            memo: None,
-        }];
 
-        // test token not found when supply is public
-        let handle_msg = HandleMsg::BatchSendNft {
-            sends,
+        // test token does not exist when supply is public
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            expires: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Token ID: MyNFT not found"));
-
-        let tok_key = 0u32.to_le_bytes();
-        let tok5_key = 4u32.to_le_bytes();
-        let tok3_key = 2u32.to_le_bytes();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let bob_key = bob_raw.as_slice();
-        let charlie_raw = deps
-            .api
-            .canonical_address(&HumanAddr("charlie".to_string()))
-            .unwrap();
-        let charlie_key = charlie_raw.as_slice();
-        let david_raw = deps
-            .api
-            .canonical_address(&HumanAddr("david".to_string()))
-            .unwrap();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        assert!(error.contains("Token ID: NFT1 not found"));
 
-        // set up for batch send test
         let (init_result, mut deps) =
             init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
@@ -7993,1181 +10964,1376 @@ mod tests {
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::MintNft {
+
+        // test token does not exist when supply is private
+        let handle_msg = HandleMsg::SetGlobalApproval {
             token_id: Some("NFT1".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not own token NFT1"));
 
+        let pub1 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("My1".to_string()),
+                description: Some("Pub 1".to_string()),
+                image: Some("https://URI 1".to_string()),
+                ..Extension::default()
+            }),
+        });
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT3".to_string()),
+            token_id: Some("NFT1".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub1.clone(),
             private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT4".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT5".to_string()),
-            owner: Some(HumanAddr("bob".to_string())),
-            private_metadata: None,
-            public_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT6".to_string()),
-            owner: Some(HumanAddr("charlie".to_string())),
-            private_metadata: None,
-            public_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+
+        // test trying to set approval when status does not allow
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
+        let handle_msg = HandleMsg::SetGlobalApproval {
             token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
+            view_owner: Some(AccessLevel::All),
             view_private_metadata: None,
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
             expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT4".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        // setting approval is ok even during StopTransactions status
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("alice".to_string()),
-            token_id: Some("NFT5".to_string()),
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // only allow the owner to use SetGlobalApproval
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
             view_owner: Some(AccessLevel::ApproveToken),
             view_private_metadata: None,
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT6".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
             expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("david".to_string()),
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not own token NFT1"));
+
+        // try approving a token without specifying which token
+        let handle_msg = HandleMsg::SetGlobalApproval {
             token_id: None,
-            view_owner: None,
+            view_owner: Some(AccessLevel::ApproveToken),
             view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
             expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "Attempted to grant/revoke permission for a token, but did not specify a token ID"
+        ));
+
+        // try revoking a token approval without specifying which token
+        let handle_msg = HandleMsg::SetGlobalApproval {
             token_id: None,
-            view_owner: None,
+            view_owner: Some(AccessLevel::RevokeToken),
             view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
             expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::RegisterReceiveNft {
-            code_hash: "charlie code hash".to_string(),
-            also_implements_batch_receive_nft: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "Attempted to grant/revoke permission for a token, but did not specify a token ID"
+        ));
+
+        // sanity check
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(1000000)),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        // msg to go with ReceiveNft
-        let send_msg = Some(
-            to_binary(&HandleMsg::RevokeAll {
-                operator: HumanAddr("zoe".to_string()),
-                padding: None,
-            })
-            .unwrap(),
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let global_raw = CanonicalAddr(Binary::from(b"public"));
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let alice_key = alice_raw.as_slice();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        // confirm ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 1);
+        let global_perm = all_perm.iter().find(|p| p.address == global_raw).unwrap();
+        assert_eq!(
+            global_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
         );
-        let sends = vec![
-            Send {
-                contract: HumanAddr("charlie".to_string()),
-                receiver_info: None,
-                token_ids: vec!["NFT1".to_string()],
-                msg: send_msg.clone(),
-                memo: None,
-            },
-            Send {
-                contract: HumanAddr("alice".to_string()),
-                receiver_info: None,
-                token_ids: vec!["NFT1".to_string()],
-                msg: send_msg.clone(),
-                memo: None,
-            },
-            Send {
-                contract: HumanAddr("bob".to_string()),
-                receiver_info: Some(ReceiverInfo {
-                    recipient_code_hash: "supplied in the send".to_string(),
-                    also_implements_batch_receive_nft: None,
-                }),
-                token_ids: vec!["NFT1".to_string()],
-                msg: send_msg.clone(),
-                memo: None,
-            },
-        ];
+        assert_eq!(global_perm.expirations[view_meta_idx], None);
+        assert_eq!(global_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permissions and that the token data did not get modified
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let nft1_key = 0u32.to_le_bytes();
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.owner, alice_raw);
+        assert!(token.unwrapped);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
+        assert_eq!(pub_meta, pub1.clone().unwrap());
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
+        assert!(priv_meta.is_none());
+        assert_eq!(token.permissions.len(), 1);
+        let global_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == global_raw)
+            .unwrap();
+        assert_eq!(
+            global_tok_perm.expirations[view_meta_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(global_tok_perm.expirations[transfer_idx], None);
+        assert_eq!(global_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists has public with NFT1 permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let global_auth = auth_list.iter().find(|a| a.address == global_raw).unwrap();
+        assert_eq!(global_auth.tokens[view_meta_idx].len(), 1);
+        assert!(global_auth.tokens[view_meta_idx].contains(&0u32));
+        assert!(global_auth.tokens[transfer_idx].is_empty());
+        assert!(global_auth.tokens[view_owner_idx].is_empty());
 
-        // test sending the same token among address the sender has ALL permission
-        // and verify the AuthLists are correct after all the transfers
-        let handle_msg = HandleMsg::BatchSendNft {
-            sends,
+        // bob approvals to make sure whitelisted addresses don't break
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(1000000)),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("david", &[]), handle_msg);
-        // confirm the receive nft msgs were created
-        let handle_resp = handle_result.unwrap();
-        let messages = handle_resp.messages;
-        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::ReceiveNft {
-            sender: HumanAddr("alice".to_string()),
-            token_id: "NFT1".to_string(),
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
-        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("charlie".to_string()),
-            callback_code_hash: "charlie code hash".to_string(),
-            msg: Binary(msg_fr_al.to_vec()),
-            send: vec![],
-        });
-        assert_eq!(messages[0], msg_fr_al);
-        assert_eq!(messages.len(), 2);
-        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::ReceiveNft {
-            sender: HumanAddr("alice".to_string()),
-            token_id: "NFT1".to_string(),
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
-        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("bob".to_string()),
-            callback_code_hash: "supplied in the send".to_string(),
-            msg: Binary(msg_fr_al.to_vec()),
-            send: vec![],
-        });
-        assert_eq!(messages[1], msg_fr_al);
-        // confirm token was not removed from the maps
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let index: u32 = load(&map2idx, "NFT1".as_bytes()).unwrap();
-        let token_key = index.to_le_bytes();
-        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-        let id: String = load(&map2id, &token_key).unwrap();
-        assert_eq!("NFT1".to_string(), id);
-        // confirm token has the correct owner and the permissions were cleared
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 100, 1000), handle_msg);
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        // confirm ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 2);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        let global_perm = all_perm.iter().find(|p| p.address == global_raw).unwrap();
+        assert_eq!(
+            global_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(global_perm.expirations[view_meta_idx], None);
+        assert_eq!(global_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permissions
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, bob_raw);
-        assert!(token.permissions.is_empty());
-        assert!(token.unwrapped);
-        // confirm transfer txs were logged
-        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 10).unwrap();
-        assert_eq!(total, 6);
-        assert_eq!(txs.len(), 6);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
         assert_eq!(
-            txs[2].action,
-            TxAction::Transfer {
-                from: HumanAddr("alice".to_string()),
-                sender: Some(HumanAddr("david".to_string())),
-                recipient: HumanAddr("charlie".to_string()),
-            }
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        assert_eq!(
+            global_tok_perm.expirations[view_meta_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(global_tok_perm.expirations[transfer_idx], None);
+        assert_eq!(global_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists has bob with NFT1 permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 2);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let global_auth = auth_list.iter().find(|a| a.address == global_raw).unwrap();
+        assert_eq!(global_auth.tokens[view_meta_idx].len(), 1);
+        assert!(global_auth.tokens[view_meta_idx].contains(&0u32));
+        assert!(global_auth.tokens[transfer_idx].is_empty());
+        assert!(global_auth.tokens[view_owner_idx].is_empty());
+
+        // confirm ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 2);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        let global_perm = all_perm.iter().find(|p| p.address == global_raw).unwrap();
+        assert_eq!(
+            global_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
         );
+        assert_eq!(global_perm.expirations[view_meta_idx], None);
+        assert_eq!(global_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permissions
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
         assert_eq!(
-            txs[1].action,
-            TxAction::Transfer {
-                from: HumanAddr("charlie".to_string()),
-                sender: Some(HumanAddr("david".to_string())),
-                recipient: HumanAddr("alice".to_string()),
-            }
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
         );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
         assert_eq!(
-            txs[0].action,
-            TxAction::Transfer {
-                from: HumanAddr("alice".to_string()),
-                sender: Some(HumanAddr("david".to_string())),
-                recipient: HumanAddr("bob".to_string()),
-            }
+            global_tok_perm.expirations[view_meta_idx],
+            Some(Expiration::AtTime(1000000))
         );
-        // confirm the owner list is correct
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(!inventory.contains(&deps.storage, 0).unwrap());
-        assert!(inventory.contains(&deps.storage, 1).unwrap());
-        assert!(inventory.contains(&deps.storage, 2).unwrap());
-        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 3);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        assert!(inventory.contains(&deps.storage, 3).unwrap());
-        assert!(inventory.contains(&deps.storage, 4).unwrap());
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 1);
-        assert!(inventory.contains(&deps.storage, 5).unwrap());
-        // confirm authLists were updated correctly
+        assert_eq!(global_tok_perm.expirations[transfer_idx], None);
+        assert_eq!(global_tok_perm.expirations[view_owner_idx], None);
+        // confirm AuthLists has bob with NFT1 permission
         let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(alice_list.len(), 2);
-        let david_auth = alice_list.iter().find(|a| a.address == david_raw).unwrap();
-        assert_eq!(david_auth.tokens[view_meta_idx].len(), 1);
-        assert!(david_auth.tokens[view_meta_idx].contains(&2u32));
-        assert!(david_auth.tokens[transfer_idx].is_empty());
-        assert!(david_auth.tokens[view_owner_idx].is_empty());
-        let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[view_owner_idx].len(), 1);
-        assert!(bob_auth.tokens[view_owner_idx].contains(&2u32));
-        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 2);
-        assert!(bob_auth.tokens[view_meta_idx].contains(&1u32));
-        assert!(bob_auth.tokens[view_meta_idx].contains(&2u32));
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 2);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
         assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
-        assert_eq!(bob_list.len(), 1);
-        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
-        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 2);
-        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
-        assert!(alice_auth.tokens[view_owner_idx].contains(&4u32));
-        assert_eq!(alice_auth.tokens[view_meta_idx].len(), 1);
-        assert!(alice_auth.tokens[view_meta_idx].contains(&3u32));
-        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
-        assert!(alice_auth.tokens[transfer_idx].contains(&3u32));
-        let charlie_list: Vec<AuthList> = load(&auth_store, charlie_key).unwrap();
-        assert_eq!(charlie_list.len(), 1);
-        let bob_auth = charlie_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
         assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
-        assert!(bob_auth.tokens[view_meta_idx].contains(&5u32));
-        assert!(bob_auth.tokens[transfer_idx].is_empty());
-
-        let sends = vec![
-            Send {
-                contract: HumanAddr("charlie".to_string()),
-                receiver_info: None,
-                token_ids: vec!["NFT1".to_string()],
-                msg: send_msg.clone(),
-                memo: None,
-            },
-            Send {
-                contract: HumanAddr("alice".to_string()),
-                receiver_info: None,
-                token_ids: vec!["NFT5".to_string()],
-                msg: send_msg.clone(),
-                memo: None,
-            },
-            Send {
-                contract: HumanAddr("bob".to_string()),
-                receiver_info: Some(ReceiverInfo {
-                    recipient_code_hash: "supplied in the send".to_string(),
-                    also_implements_batch_receive_nft: Some(true),
-                }),
-                token_ids: vec!["NFT3".to_string()],
-                msg: send_msg.clone(),
-                memo: None,
-            },
-        ];
+        let global_auth = auth_list.iter().find(|a| a.address == global_raw).unwrap();
+        assert_eq!(global_auth.tokens[view_meta_idx].len(), 1);
+        assert!(global_auth.tokens[view_meta_idx].contains(&0u32));
+        assert!(global_auth.tokens[transfer_idx].is_empty());
+        assert!(global_auth.tokens[view_owner_idx].is_empty());
 
-        // test bob transfers two of his tokens and one of alice's
-        let handle_msg = HandleMsg::BatchSendNft {
-            sends,
+        // test revoking global approval
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: Some(AccessLevel::None),
+            expires: Some(Expiration::AtTime(1000000)),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        // confirm the receive nft msgs were created
-        let handle_resp = handle_result.unwrap();
-        let messages = handle_resp.messages;
-        let mut msg_fr_b = to_binary(&Snip721ReceiveMsg::ReceiveNft {
-            sender: HumanAddr("bob".to_string()),
-            token_id: "NFT1".to_string(),
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_b = space_pad(&mut msg_fr_b.0, 256usize);
-        let msg_fr_b = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("charlie".to_string()),
-            callback_code_hash: "charlie code hash".to_string(),
-            msg: Binary(msg_fr_b.to_vec()),
-            send: vec![],
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 100, 1000), handle_msg);
+        // confirm ALL permission
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        assert_eq!(all_perm.len(), 2);
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(
+            bob_oper_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
+        let global_perm = all_perm.iter().find(|p| p.address == global_raw).unwrap();
+        assert_eq!(
+            global_perm.expirations[view_owner_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(global_perm.expirations[view_meta_idx], None);
+        assert_eq!(global_perm.expirations[transfer_idx], None);
+        // confirm NFT1 permissions
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &nft1_key).unwrap();
+        assert_eq!(token.permissions.len(), 1);
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(
+            bob_tok_perm.expirations[transfer_idx],
+            Some(Expiration::AtTime(1000000))
+        );
+        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
+        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
+        let global_tok_perm = token.permissions.iter().find(|p| p.address == global_raw);
+        assert!(global_tok_perm.is_none());
+        // confirm AuthLists has bob with NFT1 permission
+        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
+        assert_eq!(auth_list.len(), 1);
+        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
+        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
+        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
+        assert!(bob_auth.tokens[view_meta_idx].is_empty());
+        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let global_auth = auth_list.iter().find(|a| a.address == global_raw);
+        assert!(global_auth.is_none());
+    }
+
+    // test permissioning works
+    #[test]
+    fn test_check_permission() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let block = BlockInfo {
+            height: 1,
+            time: 1,
+            chain_id: "secret-2".to_string(),
+        };
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
+            .unwrap();
+        let charlie_raw = deps
+            .api
+            .canonical_address(&HumanAddr("charlie".to_string()))
+            .unwrap();
+        let nft1_key = 0u32.to_le_bytes();
+        let nft2_key = 1u32.to_le_bytes();
+        let pub1 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("My1".to_string()),
+                description: Some("Pub 1".to_string()),
+                image: Some("https://URI 1".to_string()),
+                ..Extension::default()
+            }),
         });
-        assert_eq!(messages[0], msg_fr_b);
-        assert_eq!(messages.len(), 2);
-        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::BatchReceiveNft {
-            sender: HumanAddr("bob".to_string()),
-            from: HumanAddr("alice".to_string()),
-            token_ids: vec!["NFT3".to_string()],
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
-        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("bob".to_string()),
-            callback_code_hash: "supplied in the send".to_string(),
-            msg: Binary(msg_fr_al.to_vec()),
-            send: vec![],
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub1.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let pub2 = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("My2".to_string()),
+                description: Some("Pub 2".to_string()),
+                image: Some("https://URI 2".to_string()),
+                ..Extension::default()
+            }),
         });
-        assert_eq!(messages[1], msg_fr_al);
-        // confirm tokens have the correct owner and the permissions were cleared
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub2.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test not approved
         let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &tok_key).unwrap();
-        assert_eq!(token.owner, charlie_raw);
-        assert!(token.permissions.is_empty());
-        let token: Token = json_load(&info_store, &tok3_key).unwrap();
-        assert_eq!(token.owner, bob_raw);
-        assert!(token.permissions.is_empty());
-        let token: Token = json_load(&info_store, &tok5_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.permissions.is_empty());
-        // confirm the owner list is correct
-        let inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(inventory.contains(&deps.storage, 1).unwrap());
-        assert!(inventory.contains(&deps.storage, 4).unwrap());
-        let inventory = Inventory::new(&deps.storage, bob_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(inventory.contains(&deps.storage, 2).unwrap());
-        assert!(inventory.contains(&deps.storage, 3).unwrap());
-        let inventory = Inventory::new(&deps.storage, charlie_raw.clone()).unwrap();
-        assert_eq!(inventory.info.count, 2);
-        assert!(inventory.contains(&deps.storage, 0).unwrap());
-        assert!(inventory.contains(&deps.storage, 5).unwrap());
-        // confirm authLists were updated correctly
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let alice_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(alice_list.len(), 1);
-        let bob_auth = alice_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
-        assert!(bob_auth.tokens[view_meta_idx].contains(&1u32));
-        assert!(bob_auth.tokens[transfer_idx].is_empty());
-        let bob_list: Vec<AuthList> = load(&auth_store, bob_key).unwrap();
-        assert_eq!(bob_list.len(), 1);
-        let alice_auth = bob_list.iter().find(|a| a.address == alice_raw).unwrap();
-        assert_eq!(alice_auth.tokens[view_owner_idx].len(), 1);
-        assert!(alice_auth.tokens[view_owner_idx].contains(&3u32));
-        assert_eq!(alice_auth.tokens[view_meta_idx].len(), 1);
-        assert!(alice_auth.tokens[view_meta_idx].contains(&3u32));
-        assert_eq!(alice_auth.tokens[transfer_idx].len(), 1);
-        assert!(alice_auth.tokens[transfer_idx].contains(&3u32));
-        let charlie_list: Vec<AuthList> = load(&auth_store, charlie_key).unwrap();
-        assert_eq!(charlie_list.len(), 1);
-        let bob_auth = charlie_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        assert_eq!(bob_auth.tokens[view_meta_idx].len(), 1);
-        assert!(bob_auth.tokens[view_meta_idx].contains(&5u32));
-        assert!(bob_auth.tokens[transfer_idx].is_empty());
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("not approved"));
 
-        // set up for batch send test
+        // test owner is public for the contract
         let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
+            init_helper_with_config(true, true, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::BatchMintNft {
-            mints: vec![
-                Mint {
-                    token_id: Some("NFT1".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT2".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT3".to_string()),
-                    owner: Some(HumanAddr("alice".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT4".to_string()),
-                    owner: Some(HumanAddr("bob".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT5".to_string()),
-                    owner: Some(HumanAddr("bob".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-                Mint {
-                    token_id: Some("NFT6".to_string()),
-                    owner: Some(HumanAddr("charlie".to_string())),
-                    private_metadata: None,
-                    public_metadata: None,
-                    royalty_info: None,
-                    serial_number: None,
-                    memo: None,
-                },
-            ],
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub1.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub2.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            "not approved",
+            true,
+        );
+        assert!(check_perm.is_ok());
+
+        // test owner makes their tokens private when the contract has public ownership
+        let handle_msg = HandleMsg::MakeOwnershipPrivate { padding: None };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            "not approved",
+            true,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("not approved"));
+
+        // test owner later makes ownership of a single token public
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            expires: Some(Expiration::AtTime(1000000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+        // test public approval when no address is given
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            None,
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+
+        // test global approval for all tokens
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token2: Token = json_load(&info_store, &nft2_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token2,
+            "NFT2",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("not approved"));
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(1000000)),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token2: Token = json_load(&info_store, &nft2_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token2,
+            "NFT2",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+        // test public approval when no address is given
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token2,
+            "NFT2",
+            None,
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+
+        // test those global permissions having expired
+        let block = BlockInfo {
+            height: 1,
+            time: 2000000,
+            chain_id: "secret-2".to_string(),
+        };
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("not approved"));
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token2,
+            "NFT2",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("not approved"));
+
+        let block = BlockInfo {
+            height: 1,
+            time: 1,
+            chain_id: "secret-2".to_string(),
+        };
+
+        // test whitelisted approval on a token
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
-            token_id: None,
+            token_id: Some("NFT2".to_string()),
             view_owner: None,
             view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(5)),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token2: Token = json_load(&info_store, &nft2_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token2,
+            "NFT2",
+            Some(&bob_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token2,
+            "NFT2",
+            Some(&charlie_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("not approved"));
+
+        // test approval expired
+        let block = BlockInfo {
+            height: 1,
+            time: 6,
+            chain_id: "secret-2".to_string(),
+        };
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token2,
+            "NFT2",
+            Some(&bob_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("Access to token NFT2 has expired"));
+
+        // test owner access
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token2,
+            "NFT2",
+            Some(&alice_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+
+        // test whitelisted approval on all tokens
         let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
+            address: HumanAddr("charlie".to_string()),
             token_id: None,
             view_owner: None,
             view_private_metadata: None,
             transfer: Some(AccessLevel::All),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::RegisterReceiveNft {
-            code_hash: "alice code hash".to_string(),
-            also_implements_batch_receive_nft: None,
+            expires: Some(Expiration::AtTime(7)),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::RegisterReceiveNft {
-            code_hash: "charlie code hash".to_string(),
-            also_implements_batch_receive_nft: Some(true),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let send_msg = Some(
-            to_binary(&HandleMsg::RevokeAll {
-                operator: HumanAddr("zoe".to_string()),
-                padding: None,
-            })
-            .unwrap(),
-        );
-        let handle_msg = HandleMsg::BatchSendNft {
-            sends: vec![
-                Send {
-                    contract: HumanAddr("charlie".to_string()),
-                    receiver_info: None,
-                    token_ids: vec!["NFT2".to_string(), "NFT3".to_string(), "NFT4".to_string()],
-                    msg: send_msg.clone(),
-                    memo: Some("test memo".to_string()),
-                },
-                Send {
-                    contract: HumanAddr("alice".to_string()),
-                    receiver_info: None,
-                    token_ids: vec!["NFT3".to_string(), "NFT4".to_string(), "NFT6".to_string()],
-                    msg: None,
-                    memo: None,
-                },
-            ],
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let handle_resp = handle_result.unwrap();
-        let messages = handle_resp.messages;
-        let mut msg_fr_al = to_binary(&Snip721ReceiveMsg::BatchReceiveNft {
-            sender: HumanAddr("bob".to_string()),
-            from: HumanAddr("alice".to_string()),
-            token_ids: vec!["NFT2".to_string(), "NFT3".to_string()],
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_al = space_pad(&mut msg_fr_al.0, 256usize);
-        let msg_fr_al = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("charlie".to_string()),
-            callback_code_hash: "charlie code hash".to_string(),
-            msg: Binary(msg_fr_al.to_vec()),
-            send: vec![],
-        });
-        let mut msf_fr_b = to_binary(&Snip721ReceiveMsg::BatchReceiveNft {
-            sender: HumanAddr("bob".to_string()),
-            from: HumanAddr("bob".to_string()),
-            token_ids: vec!["NFT4".to_string()],
-            msg: send_msg.clone(),
-        })
-        .unwrap();
-        let msg_fr_b = space_pad(&mut msf_fr_b.0, 256usize);
-        let msg_fr_b = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("charlie".to_string()),
-            callback_code_hash: "charlie code hash".to_string(),
-            msg: Binary(msg_fr_b.to_vec()),
-            send: vec![],
-        });
-        let mut msg_fr_c3 = to_binary(&Snip721ReceiveMsg::ReceiveNft {
-            sender: HumanAddr("charlie".to_string()),
-            token_id: "NFT3".to_string(),
-            msg: None,
-        })
-        .unwrap();
-        let msg_fr_c3 = space_pad(&mut msg_fr_c3.0, 256usize);
-        let msg_fr_c3 = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("alice".to_string()),
-            callback_code_hash: "alice code hash".to_string(),
-            msg: Binary(msg_fr_c3.to_vec()),
-            send: vec![],
-        });
-        let mut msg_fr_c4 = to_binary(&Snip721ReceiveMsg::ReceiveNft {
-            sender: HumanAddr("charlie".to_string()),
-            token_id: "NFT4".to_string(),
-            msg: None,
-        })
-        .unwrap();
-        let msg_fr_c4 = space_pad(&mut msg_fr_c4.0, 256usize);
-        let msg_fr_c4 = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("alice".to_string()),
-            callback_code_hash: "alice code hash".to_string(),
-            msg: Binary(msg_fr_c4.to_vec()),
-            send: vec![],
-        });
-        let mut msg_fr_c6 = to_binary(&Snip721ReceiveMsg::ReceiveNft {
-            sender: HumanAddr("charlie".to_string()),
-            token_id: "NFT6".to_string(),
-            msg: None,
-        })
-        .unwrap();
-        let msg_fr_c6 = space_pad(&mut msg_fr_c6.0, 256usize);
-        let msg_fr_c6 = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: HumanAddr("alice".to_string()),
-            callback_code_hash: "alice code hash".to_string(),
-            msg: Binary(msg_fr_c6.to_vec()),
-            send: vec![],
-        });
-        let expected_msgs = vec![msg_fr_al, msg_fr_b, msg_fr_c3, msg_fr_c4, msg_fr_c6];
-        assert_eq!(messages, expected_msgs);
-        let handle_msg = HandleMsg::SetViewingKey {
-            key: "ckey".to_string(),
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("not approved"));
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&charlie_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+
+        // test whitelisted ALL permission has expired
+        let block = BlockInfo {
+            height: 1,
+            time: 7,
+            chain_id: "secret-2".to_string(),
+        };
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&charlie_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("Access to all tokens of alice has expired"));
+
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub1.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetViewingKey {
-            key: "akey".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub2.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test whitelist approval expired, but global is good on a token
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: Some(Expiration::AtTime(10)),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm alice's tokens
-        let query_msg = QueryMsg::Tokens {
-            owner: HumanAddr("alice".to_string()),
-            viewer: None,
-            viewing_key: Some("akey".to_string()),
-            start_after: None,
-            limit: Some(30),
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(1000)),
+            padding: None,
         };
-        let query_result = query(&deps, query_msg);
-        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
-        match query_answer {
-            QueryAnswer::TokenList { tokens } => {
-                let expected = vec![
-                    "NFT1".to_string(),
-                    "NFT4".to_string(),
-                    "NFT3".to_string(),
-                    "NFT6".to_string(),
-                ];
-                assert_eq!(tokens, expected);
-            }
-            _ => panic!("unexpected"),
-        }
-        let xfer6 = Tx {
-            tx_id: 11,
-            block_height: 12345,
-            block_time: 1571797419,
-            token_id: "NFT6".to_string(),
-            memo: None,
-            action: TxAction::Transfer {
-                from: HumanAddr("charlie".to_string()),
-                sender: Some(HumanAddr("bob".to_string())),
-                recipient: HumanAddr("alice".to_string()),
-            },
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let block = BlockInfo {
+            height: 1,
+            time: 100,
+            chain_id: "secret-2".to_string(),
         };
-        let xfer3 = Tx {
-            tx_id: 7,
-            block_height: 12345,
-            block_time: 1571797419,
-            token_id: "NFT3".to_string(),
-            memo: Some("test memo".to_string()),
-            action: TxAction::Transfer {
-                from: HumanAddr("alice".to_string()),
-                sender: Some(HumanAddr("bob".to_string())),
-                recipient: HumanAddr("charlie".to_string()),
-            },
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+
+        // test whitelist approval expired, but global is good on ALL tokens
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::All),
+            transfer: None,
+            expires: Some(Expiration::AtTime(10)),
+            padding: None,
         };
-        let query_msg = QueryMsg::TransactionHistory {
-            address: HumanAddr("alice".to_string()),
-            viewing_key: "akey".to_string(),
-            page: None,
-            page_size: None,
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(1000)),
+            padding: None,
         };
-        let query_result = query(&deps, query_msg);
-        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
-        match query_answer {
-            QueryAnswer::TransactionHistory { total, txs } => {
-                assert_eq!(total, 8);
-                assert_eq!(txs[3], xfer3);
-                assert_eq!(txs[0], xfer6);
-            }
-            _ => panic!("unexpected"),
-        }
-        let query_msg = QueryMsg::Tokens {
-            owner: HumanAddr("charlie".to_string()),
-            viewer: None,
-            viewing_key: Some("ckey".to_string()),
-            start_after: None,
-            limit: Some(30),
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let block = BlockInfo {
+            height: 1,
+            time: 100,
+            chain_id: "secret-2".to_string(),
         };
-        let query_result = query(&deps, query_msg);
-        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
-        match query_answer {
-            QueryAnswer::TokenList { tokens } => {
-                let expected = vec!["NFT2".to_string()];
-                assert_eq!(tokens, expected);
-            }
-            _ => panic!("unexpected"),
-        }
-    }
-
-    // test register receive_nft
-    #[test]
-    fn test_register_receive_nft() {
-        let (init_result, mut deps) = init_helper_default();
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
         );
+        assert!(check_perm.is_ok());
 
-        // test register when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
+        // test whitelist approval is good, but global expired on a token
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: Some(Expiration::AtTime(1000)),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::RegisterReceiveNft {
-            code_hash: "alice code hash".to_string(),
-            also_implements_batch_receive_nft: None,
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 1, 50), handle_msg);
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(10)),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 1, 50), handle_msg);
+        let block = BlockInfo {
+            height: 1,
+            time: 100,
+            chain_id: "secret-2".to_string(),
+        };
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
 
-        // you can still register when transactions are stopped
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        // test whitelist approval is good, but global expired on ALL tokens
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::All),
+            transfer: None,
+            expires: Some(Expiration::AtTime(10)),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // sanity check
-        let handle_msg = HandleMsg::RegisterReceiveNft {
-            code_hash: "alice code hash".to_string(),
-            also_implements_batch_receive_nft: None,
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(1000)),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let store = ReadonlyPrefixedStorage::new(PREFIX_RECEIVERS, &deps.storage);
-        let hash: String = load(
-            &store,
-            deps.api
-                .canonical_address(&HumanAddr("alice".to_string()))
-                .unwrap()
-                .as_slice(),
-        )
-        .unwrap();
-        assert_eq!(&hash, "alice code hash");
-    }
+        let block = BlockInfo {
+            height: 1,
+            time: 100,
+            chain_id: "secret-2".to_string(),
+        };
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
 
-    // test create viewing key
-    #[test]
-    fn test_create_viewing_key() {
         let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        // test creating a key when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub1.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::CreateViewingKey {
-            entropy: "blah".to_string(),
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: pub2.clone(),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // you can still create a key when transactions are stopped
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        // test bob has view owner approval on NFT1 and view metadata approval on ALL
+        // while there is global view owner approval on ALL tokens and global view metadata
+        // approval on NFT1
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::All),
+            transfer: None,
+            expires: Some(Expiration::AtTime(100)),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        let handle_msg = HandleMsg::CreateViewingKey {
-            entropy: "blah".to_string(),
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 1, 1), handle_msg);
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(10)),
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        assert!(
-            handle_result.is_ok(),
-            "handle() failed: {}",
-            handle_result.err().unwrap()
-        );
-        let answer: HandleAnswer = from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
-
-        let key_str = match answer {
-            HandleAnswer::ViewingKey { key } => key,
-            _ => panic!("NOPE"),
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 1, 1), handle_msg);
+        let block = BlockInfo {
+            height: 1,
+            time: 1,
+            chain_id: "secret-2".to_string(),
         };
-        let key = ViewingKey(key_str);
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let key_store = ReadonlyPrefixedStorage::new(PREFIX_VIEW_KEY, &deps.storage);
-        let saved_vk: [u8; VIEWING_KEY_SIZE] = load(&key_store, alice_raw.as_slice()).unwrap();
-        assert!(key.check_viewing_key(&saved_vk));
-    }
-
-    // test set viewing key
-    #[test]
-    fn test_set_viewing_key() {
-        let (init_result, mut deps) = init_helper_default();
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        let error = extract_error_msg(check_perm);
+        assert!(error.contains("not approved"));
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
         );
+        assert!(check_perm.is_ok());
 
-        // test setting a key when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetViewingKey {
-            key: "blah".to_string(),
-            padding: None,
+        // now check where the global approvals expired
+        let block = BlockInfo {
+            height: 1,
+            time: 50,
+            chain_id: "secret-2".to_string(),
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&bob_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
 
-        // you can still set a key when transactions are stopped
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        // throw a charlie transfer approval and a view meta token approval in the mix
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtTime(100)),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        let handle_msg = HandleMsg::SetViewingKey {
-            key: "blah".to_string(),
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: Some(Expiration::AtTime(100)),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let key = ViewingKey("blah".to_string());
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let key_store = ReadonlyPrefixedStorage::new(PREFIX_VIEW_KEY, &deps.storage);
-        let saved_vk: [u8; VIEWING_KEY_SIZE] = load(&key_store, alice_raw.as_slice()).unwrap();
-        assert!(key.check_viewing_key(&saved_vk));
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&charlie_raw),
+            PermissionType::Transfer,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
+        let check_perm = check_permission(
+            &deps,
+            &block,
+            &token1,
+            "NFT1",
+            Some(&charlie_raw),
+            PermissionType::ViewMetadata,
+            &mut Vec::new(),
+            "not approved",
+            false,
+        );
+        assert!(check_perm.is_ok());
     }
 
-    // test add minters
+    // test locking a token against transfers
     #[test]
-    fn test_add_minters() {
-        let (init_result, mut deps) = init_helper_default();
+    fn test_set_transfer_lock() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, true, true);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        // test adding minters when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let minters = vec![
-            HumanAddr("alice".to_string()),
-            HumanAddr("bob".to_string()),
-            HumanAddr("bob".to_string()),
-            HumanAddr("alice".to_string()),
-        ];
-        let handle_msg = HandleMsg::AddMinters {
-            minters: minters.clone(),
-            padding: None,
-        };
-        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
-
-        // you can still add minters when transactions are stopped
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test non admin trying to add minters
-        let handle_msg = HandleMsg::AddMinters {
-            minters: minters.clone(),
+        // test non-owner trying to lock
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "MyNFT".to_string(),
+            locked: true,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(
-            error.contains("This is an admin command and can only be run from the admin address")
-        );
+        assert!(error.contains("You do not own token MyNFT"));
 
         // sanity check
-        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let admin_raw = deps
-            .api
-            .canonical_address(&HumanAddr("admin".to_string()))
-            .unwrap();
-        // verify the minters we will add are not already in the list
-        assert!(!cur_minter.contains(&alice_raw));
-        assert!(!cur_minter.contains(&bob_raw));
-        let handle_msg = HandleMsg::AddMinters {
-            minters,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify the new minters were added
-        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
-        assert_eq!(cur_minter.len(), 3);
-        assert!(cur_minter.contains(&alice_raw));
-        assert!(cur_minter.contains(&bob_raw));
-        assert!(cur_minter.contains(&admin_raw));
-
-        // let's try an empty list to see if it breaks
-        let minters = vec![];
-        let handle_msg = HandleMsg::AddMinters {
-            minters,
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "MyNFT".to_string(),
+            locked: true,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify it's the same list
-        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
-        assert_eq!(cur_minter.len(), 3);
-        assert!(cur_minter.contains(&alice_raw));
-        assert!(cur_minter.contains(&bob_raw));
-        assert!(cur_minter.contains(&admin_raw));
-    }
-
-    // test remove minters
-    #[test]
-    fn test_remove_minters() {
-        let (init_result, mut deps) = init_helper_default();
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::SetTransferLock { status } => assert_eq!(status, ResponseStatus::Success),
+            _ => panic!("unexpected"),
+        }
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &0u32.to_le_bytes()).unwrap();
+        assert!(token.transfer_locked);
 
-        // test removing minters when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
+        // test approvals and metadata updates are still allowed
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
+            expires: None,
+            max_uses: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let minters = vec![
-            HumanAddr("alice".to_string()),
-            HumanAddr("bob".to_string()),
-            HumanAddr("charlie".to_string()),
-            HumanAddr("bob".to_string()),
-        ];
-        let handle_msg = HandleMsg::RemoveMinters {
-            minters: minters.clone(),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: Some(Metadata {
+                token_uri: Some("https://new_uri".to_string()),
+                extension: None,
+            }),
+            private_metadata: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
 
-        // you can still remove minters when transactions are stopped
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        // test transfer, send, and burn are blocked, even for an approved address
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test non admin trying to remove minters
-        let handle_msg = HandleMsg::RemoveMinters {
-            minters: minters.clone(),
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT is locked against transfers"));
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers: vec![Transfer {
+                recipient: HumanAddr("charlie".to_string()),
+                token_ids: vec!["MyNFT".to_string()],
+                memo: None,
+            }],
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(
-            error.contains("This is an admin command and can only be run from the admin address")
-        );
-
-        // sanity check
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let charlie_raw = deps
-            .api
-            .canonical_address(&HumanAddr("charlie".to_string()))
-            .unwrap();
-        let admin_raw = deps
-            .api
-            .canonical_address(&HumanAddr("admin".to_string()))
-            .unwrap();
-        let handle_msg = HandleMsg::AddMinters {
-            minters: minters.clone(),
+        assert!(error.contains("Token ID: MyNFT is locked against transfers"));
+        let handle_msg = HandleMsg::SendNft {
+            contract: HumanAddr("charlie".to_string()),
+            receiver_info: None,
+            token_id: "MyNFT".to_string(),
+            msg: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify the new minters were added
-        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
-        assert_eq!(cur_minter.len(), 4);
-        assert!(cur_minter.contains(&alice_raw));
-        assert!(cur_minter.contains(&bob_raw));
-        assert!(cur_minter.contains(&charlie_raw));
-        assert!(cur_minter.contains(&admin_raw));
-
-        // let's give it an empty list to see if it breaks
-        let minters = vec![];
-        let handle_msg = HandleMsg::RemoveMinters {
-            minters,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT is locked against transfers"));
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "MyNFT".to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify it is the same list
-        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
-        assert_eq!(cur_minter.len(), 4);
-        assert!(cur_minter.contains(&alice_raw));
-        assert!(cur_minter.contains(&bob_raw));
-        assert!(cur_minter.contains(&charlie_raw));
-        assert!(cur_minter.contains(&admin_raw));
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT is locked against transfers"));
 
-        // let's throw some repeats to see if it breaks
-        let minters = vec![
-            HumanAddr("alice".to_string()),
-            HumanAddr("bob".to_string()),
-            HumanAddr("alice".to_string()),
-            HumanAddr("charlie".to_string()),
-        ];
-        let handle_msg = HandleMsg::RemoveMinters {
-            minters,
+        // sanity check, unlock and transfer
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "MyNFT".to_string(),
+            locked: false,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify the minters were removed
-        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
-        assert_eq!(cur_minter.len(), 1);
-        assert!(!cur_minter.contains(&alice_raw));
-        assert!(!cur_minter.contains(&bob_raw));
-        assert!(!cur_minter.contains(&charlie_raw));
-        assert!(cur_minter.contains(&admin_raw));
-
-        // let's remove the last one
-        let handle_msg = HandleMsg::RemoveMinters {
-            minters: vec![HumanAddr("admin".to_string())],
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "MyNFT".to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify the minters were removed
-        let cur_minter: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, MINTERS_KEY).unwrap();
-        assert!(cur_minter.is_none());
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &0u32.to_le_bytes()).unwrap();
+        assert!(!token.transfer_locked);
+        assert_eq!(
+            token.owner,
+            deps.api
+                .canonical_address(&HumanAddr("charlie".to_string()))
+                .unwrap()
+        );
     }
 
-    // test set minters
+    // test reserving token ids
     #[test]
-    fn test_set_minters() {
+    fn test_reserve_token_ids() {
         let (init_result, mut deps) = init_helper_default();
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        // test setting minters when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
+        let handle_msg = HandleMsg::AddMinters {
+            minters: vec![HumanAddr("bob".to_string())],
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let minters = vec![
-            HumanAddr("alice".to_string()),
-            HumanAddr("bob".to_string()),
-            HumanAddr("charlie".to_string()),
-            HumanAddr("bob".to_string()),
-            HumanAddr("alice".to_string()),
-        ];
-        let handle_msg = HandleMsg::SetMinters {
-            minters: minters.clone(),
+        let mint_msg = |id: &str| HandleMsg::MintNft {
+            token_id: Some(id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("Minted"));
+
+        // test non-minter trying to reserve
+        let handle_msg = HandleMsg::ReserveTokenIds {
+            token_ids: vec!["Res1".to_string()],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters are allowed to reserve token ids"));
+
+        // test reserving an id that has already been minted
+        let handle_msg = HandleMsg::ReserveTokenIds {
+            token_ids: vec!["Res1".to_string(), "Minted".to_string()],
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        assert!(error.contains("Token ID Minted is already in use"));
 
-        // you can still set minters when transactions are stopped
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        // sanity check
+        let handle_msg = HandleMsg::ReserveTokenIds {
+            token_ids: vec!["Res2".to_string(), "Res3".to_string()],
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::ReserveTokenIds { status } => assert_eq!(status, ResponseStatus::Success),
+            _ => panic!("unexpected"),
+        }
+        let reserved_cnt: u32 = load(&deps.storage, RESERVED_CNT_KEY).unwrap();
+        assert_eq!(reserved_cnt, 2);
+        let query_msg = QueryMsg::IsReserved {
+            token_id: "Res2".to_string(),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsReserved { is_reserved } => assert!(is_reserved),
+            _ => panic!("unexpected"),
+        }
 
-        // test non admin trying to set minters
-        let handle_msg = HandleMsg::SetMinters {
-            minters: minters.clone(),
+        // test reserving an id that is already reserved
+        let handle_msg = HandleMsg::ReserveTokenIds {
+            token_ids: vec!["Res3".to_string()],
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID Res3 is already in use"));
+
+        // test another minter trying to mint a reserved id
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), mint_msg("Res2"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID Res2 is reserved by another minter"));
+
+        // test non-minter trying to mint a reserved id
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), mint_msg("Res2"));
         let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters are allowed to mint"));
+
+        // sanity check, minting over the reservation clears it
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("Res2"));
+        assert!(handle_result.is_ok());
+        let reserved_cnt: u32 = load(&deps.storage, RESERVED_CNT_KEY).unwrap();
+        assert_eq!(reserved_cnt, 1);
+        let query_msg = QueryMsg::IsReserved {
+            token_id: "Res2".to_string(),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsReserved { is_reserved } => assert!(!is_reserved),
+            _ => panic!("unexpected"),
+        }
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: Option<u32> = may_load(&map2idx, "Res2".as_bytes()).unwrap();
+        assert!(index.is_some());
+    }
+
+    // test expired permissions are pruned the next time the token is written
+    #[test]
+    fn test_prune_expired_permissions() {
+        let (init_result, mut deps) = init_helper_default();
         assert!(
-            error.contains("This is an admin command and can only be run from the admin address")
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-
-        // sanity check
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
         let bob_raw = deps
             .api
             .canonical_address(&HumanAddr("bob".to_string()))
@@ -9176,107 +12342,234 @@ mod tests {
             .api
             .canonical_address(&HumanAddr("charlie".to_string()))
             .unwrap();
-        let handle_msg = HandleMsg::SetMinters {
-            minters: minters.clone(),
+        let david_raw = deps
+            .api
+            .canonical_address(&HumanAddr("david".to_string()))
+            .unwrap();
+        let view_owner_idx = PermissionType::ViewOwner.to_usize();
+        let transfer_idx = PermissionType::Transfer.to_usize();
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify the new minters were added
-        let cur_minter: Vec<CanonicalAddr> = load(&deps.storage, MINTERS_KEY).unwrap();
-        assert_eq!(cur_minter.len(), 3);
-        assert!(cur_minter.contains(&alice_raw));
-        assert!(cur_minter.contains(&bob_raw));
-        assert!(cur_minter.contains(&charlie_raw));
-        // let's try an empty list
-        let minters = vec![];
-        let handle_msg = HandleMsg::SetMinters {
-            minters,
+        let approvals = vec![
+            // bob has an expired view_owner and a live transfer permission
+            (
+                "bob",
+                Some(AccessLevel::ApproveToken),
+                None,
+                Expiration::AtHeight(100),
+            ),
+            (
+                "bob",
+                None,
+                Some(AccessLevel::ApproveToken),
+                Expiration::AtHeight(20000),
+            ),
+            // charlie only has an expired permission
+            (
+                "charlie",
+                None,
+                Some(AccessLevel::ApproveToken),
+                Expiration::AtTime(1000),
+            ),
+            // david's permission never expires
+            (
+                "david",
+                Some(AccessLevel::ApproveToken),
+                None,
+                Expiration::Never,
+            ),
+        ];
+        for (address, view_owner, transfer, expires) in approvals.into_iter() {
+            let handle_msg = HandleMsg::SetWhitelistedApproval {
+                address: HumanAddr(address.to_string()),
+                token_id: Some("MyNFT".to_string()),
+                view_owner,
+                view_private_metadata: None,
+                transfer,
+                expires: Some(expires),
+                padding: None,
+            };
+            // grant them before any expire, so the grants themselves do not prune
+            let _handle_result = handle(&mut deps, mock_env_at("alice", 1, 1), handle_msg);
+        }
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(token.permissions.len(), 3);
+
+        // writing the token prunes the expired permissions
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "MyNFT".to_string(),
+            locked: false,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify the minters were removed
-        let cur_minter: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, MINTERS_KEY).unwrap();
-        assert!(cur_minter.is_none());
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(token.permissions.len(), 2);
+        let bob_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_perm.expirations[view_owner_idx], None);
+        assert_eq!(
+            bob_perm.expirations[transfer_idx],
+            Some(Expiration::AtHeight(20000))
+        );
+        let david_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == david_raw)
+            .unwrap();
+        assert_eq!(
+            david_perm.expirations[view_owner_idx],
+            Some(Expiration::Never)
+        );
+
+        // test an approval write prunes the other addresses' expired permissions, but keeps
+        // the new permission even if it has already expired
+        let edmund_raw = deps
+            .api
+            .canonical_address(&HumanAddr("edmund".to_string()))
+            .unwrap();
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("charlie".to_string()),
+            token_id: Some("MyNFT".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtTime(1000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 1, 1), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("edmund".to_string()),
+            token_id: Some("MyNFT".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: Some(Expiration::AtHeight(10)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(token.permissions.len(), 3);
+        assert!(token.permissions.iter().any(|p| p.address == edmund_raw));
+        assert!(token.permissions.iter().all(|p| p.address != charlie_raw));
+        let mut token = token;
+
+        // test pruning directly reports whether anything changed
+        let block = BlockInfo {
+            height: 12345,
+            time: 1571797419,
+            chain_id: "cosmos-testnet-14002".to_string(),
+        };
+        assert!(token.prune_expired(&block));
+        assert_eq!(token.permissions.len(), 2);
+        assert!(!token.prune_expired(&block));
+        let block = BlockInfo {
+            height: 20000,
+            time: 1571797419,
+            chain_id: "cosmos-testnet-14002".to_string(),
+        };
+        assert!(token.prune_expired(&block));
+        assert_eq!(token.permissions.len(), 1);
+        assert_eq!(token.permissions[0].address, david_raw);
     }
 
-    // test change admin
+    // test the maximum attribute count
     #[test]
-    fn test_change_admin() {
-        let (init_result, mut deps) = init_helper_default();
+    fn test_max_attributes() {
+        let (init_result, mut deps) = init_helper_with_init_config(InitConfig {
+            max_attributes: Some(2),
+            ..InitConfig::default()
+        });
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        // test changing admin when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::ChangeAdmin {
-            address: HumanAddr("alice".to_string()),
-            padding: None,
+        let traits = |cnt: usize| {
+            extension_meta(Extension {
+                attributes: Some(
+                    (0..cnt)
+                        .map(|i| Trait {
+                            display_type: None,
+                            trait_type: Some(format!("Trait{}", i)),
+                            value: "Value".to_string(),
+                            max_value: None,
+                        })
+                        .collect(),
+                ),
+                ..Extension::default()
+            })
         };
-        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test exactly at the limit passes
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT1", traits(2)),
+        );
+        assert!(handle_result.is_ok());
+
+        // test one over the limit fails on mint
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT2", traits(3)),
+        );
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        assert!(error.contains("Metadata has 3 attributes, but the maximum allowed is 2"));
 
-        // you can still change admin when transactions are stopped
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        // test one over the limit fails on SetMetadata
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(traits(3)),
+            private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata has 3 attributes, but the maximum allowed is 2"));
 
-        // test non admin trying to change admin
-        let handle_msg = HandleMsg::ChangeAdmin {
-            address: HumanAddr("alice".to_string()),
+        // test one over the limit fails on PatchMetadata
+        let handle_msg = HandleMsg::PatchMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(MetadataPatch {
+                attributes: traits(3).extension.unwrap().attributes,
+                ..MetadataPatch::default()
+            }),
+            private_metadata: None,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(
-            error.contains("This is an admin command and can only be run from the admin address")
-        );
+        assert!(error.contains("Metadata has 3 attributes, but the maximum allowed is 2"));
 
-        // sanity check
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let admin_raw = deps
-            .api
-            .canonical_address(&HumanAddr("admin".to_string()))
-            .unwrap();
-        // verify admin is the current admin
-        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
-        assert_eq!(config.admin, admin_raw);
-        // change it to alice
-        let handle_msg = HandleMsg::ChangeAdmin {
-            address: HumanAddr("alice".to_string()),
+        // test one over the limit fails on SetDefaultExtension
+        let handle_msg = HandleMsg::SetDefaultExtension {
+            extension: traits(3).extension,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify admin was changed
-        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
-        assert_eq!(config.admin, alice_raw);
-    }
-
-    // test set contract status
-    #[test]
-    fn test_set_contract_status() {
-        let (init_result, mut deps) = init_helper_default();
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata has 3 attributes, but the maximum allowed is 2"));
 
-        // test non admin trying to change status
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
+        // test non-admin can not change the limit
+        let handle_msg = HandleMsg::SetMaxAttributes {
+            max_attributes: None,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
@@ -9285,1675 +12578,1609 @@ mod tests {
             error.contains("This is an admin command and can only be run from the admin address")
         );
 
-        // sanity check
-        // verify current status is normal
-        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
-        assert_eq!(config.status, ContractStatus::Normal.to_u8());
-        // change it to StopAll
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
+        // test removing the limit
+        let handle_msg = HandleMsg::SetMaxAttributes {
+            max_attributes: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        // verify status was changed
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
         let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
-        assert_eq!(config.status, ContractStatus::StopAll.to_u8());
+        assert_eq!(config.max_attributes, None);
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT3", traits(3)),
+        );
+        assert!(handle_result.is_ok());
     }
 
-    // test approve_all from the cw721 spec
+    // test restricting which trait_types may be modified after minting
     #[test]
-    fn test_cw721_approve_all() {
-        let (init_result, mut deps) = init_helper_default();
+    fn test_mutable_trait_types() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, true, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: None,
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: None,
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
-            padding: None,
+        let traits = |level: &str, class: &str| {
+            extension_meta(Extension {
+                attributes: Some(vec![
+                    Trait {
+                        display_type: None,
+                        trait_type: Some("Level".to_string()),
+                        value: level.to_string(),
+                        max_value: None,
+                    },
+                    Trait {
+                        display_type: None,
+                        trait_type: Some("Class".to_string()),
+                        value: class.to_string(),
+                        max_value: None,
+                    },
+                ]),
+                ..Extension::default()
+            })
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg); // test burn when status prevents it
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT3".to_string()),
+            token_id: Some("NFT1".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: None,
+            public_metadata: Some(traits("1", "Mage")),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test trying to ApproveAll when status does not allow
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::ApproveAll {
-            operator: HumanAddr("bob".to_string()),
-            expires: None,
+        // test non-admin can not set the mutable trait_types
+        let handle_msg = HandleMsg::SetMutableTraitTypes {
+            trait_types: Some(vec!["Level".to_string()]),
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
-        // setting approval is ok even during StopTransactions status
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let handle_msg = HandleMsg::SetMutableTraitTypes {
+            trait_types: Some(vec!["Level".to_string()]),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-        let nft1_key = 0u32.to_le_bytes();
-        let nft2_key = 1u32.to_le_bytes();
-        let nft3_key = 2u32.to_le_bytes();
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.mutable_trait_types, Some(vec!["Level".to_string()]));
 
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        // test changing an allowed trait succeeds
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(traits("2", "Mage")),
+            private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test changing a disallowed trait fails and leaves the metadata unchanged
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(traits("3", "Rogue")),
+            private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Attribute Class may not be modified after minting"));
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(pub_meta, traits("2", "Mage"));
+
+        // test removing a disallowed trait with a patch fails
+        let handle_msg = HandleMsg::PatchMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(MetadataPatch {
+                attributes: Some(vec![Trait {
+                    display_type: None,
+                    trait_type: Some("Level".to_string()),
+                    value: "3".to_string(),
+                    max_value: None,
+                }]),
+                ..MetadataPatch::default()
+            }),
+            private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-
-        // confirm bob has transfer token permissions but not transfer all permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permission has bob
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm NFT2 permission has bob
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm NFT3 permission has bob
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm AuthLists has bob
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 3);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Attribute Class may not be modified after minting"));
 
-        // test that ApproveAll will remove all the token permissions
-        let handle_msg = HandleMsg::ApproveAll {
-            operator: HumanAddr("bob".to_string()),
-            expires: None,
+        // test adding a disallowed trait to metadata that had none fails
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: None,
+            private_metadata: Some(traits("1", "Mage")),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm bob has transfer all permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_oper_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm bob's NFT1 permission is gone
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm bob's NFT2 permission is gone
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm bob's NFT3 permission is gone
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm AuthLists no longer have bob
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
-    }
-
-    // test revoke_all from the cw721 spec
-    #[test]
-    fn test_cw721_revoke_all() {
-        let (init_result, mut deps) = init_helper_default();
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Attribute Class may not be modified after minting"));
 
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: None,
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        // test an empty list makes every trait mutable again
+        let handle_msg = HandleMsg::SetMutableTraitTypes {
+            trait_types: Some(Vec::new()),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: None,
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.mutable_trait_types, None);
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(traits("3", "Rogue")),
+            private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg); // test burn when status prevents it
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+    }
+
+    // test transfer memos are length capped and recorded in the history of the parties
+    #[test]
+    fn test_transfer_memo() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT3".to_string()),
+            token_id: Some("NFT1".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
             public_metadata: None,
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // test trying to RevokeAll when status does not allow
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
+        // test a memo that is too long
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: Some("m".repeat(257)),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::RevokeAll {
-            operator: HumanAddr("bob".to_string()),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Memo for token ID: NFT1 exceeds the maximum length of 256 bytes"));
+        // test the cap also applies to batch transfers
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers: vec![Transfer {
+                recipient: HumanAddr("bob".to_string()),
+                token_ids: vec!["NFT1".to_string()],
+                memo: Some("m".repeat(257)),
+            }],
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
-        // setting approval is ok even during StopTransactions status
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        assert!(error.contains("Memo for token ID: NFT1 exceeds the maximum length of 256 bytes"));
+
+        // test a memo at the limit is stored
+        let memo = "m".repeat(256);
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: Some(memo.clone()),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
+            .unwrap();
         let bob_raw = deps
             .api
             .canonical_address(&HumanAddr("bob".to_string()))
             .unwrap();
-        let alice_raw = deps
+        let charlie_raw = deps
             .api
-            .canonical_address(&HumanAddr("alice".to_string()))
+            .canonical_address(&HumanAddr("charlie".to_string()))
             .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-        let nft1_key = 0u32.to_le_bytes();
-        let nft2_key = 1u32.to_le_bytes();
-        let nft3_key = 2u32.to_le_bytes();
+        let (txs, _) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 1).unwrap();
+        assert_eq!(txs[0].memo, Some(memo.clone()));
+        let (txs, _) = get_txs(&deps.api, &deps.storage, &bob_raw, 0, 1).unwrap();
+        assert_eq!(txs[0].memo, Some(memo));
+        // an address that was not part of the transfer does not see it
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &charlie_raw, 0, 1).unwrap();
+        assert!(txs.is_empty());
+        assert_eq!(total, 0);
+    }
 
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT3".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: None,
+    // test operator approvals that are limited to a number of uses
+    #[test]
+    fn test_approve_all_max_uses() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for id in vec!["NFT1", "NFT2", "NFT3", "NFT4", "NFT5", "NFT6"].into_iter() {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let transfer = |ids: Vec<&str>| HandleMsg::BatchTransferNft {
+            transfers: vec![Transfer {
+                recipient: HumanAddr("charlie".to_string()),
+                token_ids: ids.into_iter().map(|i| i.to_string()).collect(),
+                memo: None,
+            }],
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-
-        // confirm bob has transfer token permissions but not transfer all permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permission has bob
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm NFT2 permission has bob
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
+        let bob_raw = deps
+            .api
+            .canonical_address(&HumanAddr("bob".to_string()))
             .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm NFT3 permission has bob
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
+        let alice_raw = deps
+            .api
+            .canonical_address(&HumanAddr("alice".to_string()))
             .unwrap();
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
-        );
-        // confirm AuthLists has bob
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 3);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&1u32));
-        assert!(bob_auth.tokens[transfer_idx].contains(&2u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
+        let alice_key = alice_raw.as_slice();
 
-        // test that RevokeAll will remove all the token permissions
-        let handle_msg = HandleMsg::RevokeAll {
+        // test a 1-use approval is consumed by one transfer
+        let handle_msg = HandleMsg::ApproveAll {
             operator: HumanAddr("bob".to_string()),
+            expires: None,
+            max_uses: Some(1),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm bob does not have transfer all permission
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), transfer(vec!["NFT1"]));
+        assert!(handle_result.is_ok());
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        // confirm bob's NFT1 permission is gone
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm bob's NFT2 permission is gone
-        let token: Token = json_load(&info_store, &nft2_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm bob's NFT3 permission is gone
-        let token: Token = json_load(&info_store, &nft3_key).unwrap();
-        assert!(token.permissions.is_empty());
-        // confirm AuthLists no longer have bob
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Option<Vec<AuthList>> = may_load(&auth_store, alice_key).unwrap();
-        assert!(auth_list.is_none());
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), transfer(vec!["NFT2"]));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
 
-        // grant bob transfer all permission to test if revoke all removes it
+        // test a 3-use approval across multiple transfers
         let handle_msg = HandleMsg::ApproveAll {
             operator: HumanAddr("bob".to_string()),
-            expires: None,
+            expires: Some(Expiration::AtHeight(2000000)),
+            max_uses: Some(3),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm bob has transfer all permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(
-            bob_oper_perm.expirations[transfer_idx],
-            Some(Expiration::Never)
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &[]),
+            transfer(vec!["NFT2", "NFT3"]),
         );
-        // now get rid of it
-        let handle_msg = HandleMsg::RevokeAll {
-            operator: HumanAddr("bob".to_string()),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm bob no longer has transfer all permission
+        assert!(handle_result.is_ok());
         let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::Never)
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-    }
-
-    // test making ownership private
-    #[test]
-    fn test_make_ownership_private() {
-        let (init_result, mut deps) = init_helper_default();
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
-
-        // test setting privacy when status prevents it
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MakeOwnershipPrivate { padding: None };
-        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
+        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
+        assert_eq!(bob_oper_perm.max_uses, Some(3));
+        assert_eq!(bob_oper_perm.uses, 2);
+        // the last use is consumed part way through a batch
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &[]),
+            transfer(vec!["NFT4", "NFT5"]),
+        );
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        assert!(error.contains("You are not authorized to perform this action on token NFT5"));
 
-        // you can still set privacy when transactions are stopped
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        // test the expiration still applies before the uses run out
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("bob".to_string()),
+            expires: Some(Expiration::AtHeight(2000000)),
+            max_uses: Some(5),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // sanity check when contract default is private
-        let handle_msg = HandleMsg::MakeOwnershipPrivate { padding: None };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
-        let owner_priv: Option<bool> = may_load(&store, alice_key).unwrap();
-        assert!(owner_priv.is_none());
+        let mut env = mock_env("bob", &[]);
+        env.block.height = 3000000;
+        let handle_result = handle(&mut deps, env, transfer(vec!["NFT6"]));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Access to all tokens of alice has expired"));
 
-        // test when contract default is public
-        let (init_result, mut deps) =
-            init_helper_with_config(false, true, false, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
-        let handle_msg = HandleMsg::MakeOwnershipPrivate { padding: None };
+        // test revoking clears the use count
+        let handle_msg = HandleMsg::RevokeAll {
+            operator: HumanAddr("bob".to_string()),
+            padding: None,
+        };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
-        let owner_priv: bool = load(&store, alice_key).unwrap();
-        assert!(!owner_priv);
-    }
-
-    // test owner setting global approvals
-    #[test]
-    fn test_set_global_approval() {
-        let (init_result, mut deps) =
-            init_helper_with_config(true, false, false, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
 
-        // test token does not exist when supply is public
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
+        // test a 0-use approval is rejected
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("bob".to_string()),
             expires: None,
+            max_uses: Some(0),
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("Token ID: NFT1 not found"));
-
-        let (init_result, mut deps) =
-            init_helper_with_config(false, false, false, false, false, false, false);
-        assert!(
-            init_result.is_ok(),
-            "Init failed: {}",
-            init_result.err().unwrap()
-        );
-
-        // test token does not exist when supply is private
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
+        assert!(error.contains("max_uses must be at least 1"));
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let all_perm: Option<Vec<Permission>> = json_may_load(&all_store, alice_key).unwrap();
+        assert!(all_perm.is_none());
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "NFT6".to_string(),
             expires: None,
+            max_uses: Some(0),
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("You do not own token NFT1"));
+        assert!(error.contains("max_uses must be at least 1"));
 
-        let pub1 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("My1".to_string()),
-                description: Some("Pub 1".to_string()),
-                image: Some("URI 1".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub1.clone(),
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        // test a limited token approval is stored and consumed by a transfer
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "NFT6".to_string(),
+            expires: None,
+            max_uses: Some(1),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &5u32.to_le_bytes()).unwrap();
+        let bob_tok_perm = token
+            .permissions
+            .iter()
+            .find(|p| p.address == bob_raw)
+            .unwrap();
+        assert_eq!(bob_tok_perm.max_uses, Some(1));
+        assert_eq!(bob_tok_perm.uses, 0);
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), transfer(vec!["NFT6"]));
+        assert!(handle_result.is_ok());
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &5u32.to_le_bytes()).unwrap();
+        assert!(token.permissions.is_empty());
+    }
 
-        // test trying to set approval when status does not allow
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopAll,
-            padding: None,
+    // test locking a batch of tokens against transfers
+    #[test]
+    fn test_batch_set_transfer_lock() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, true, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for (id, owner) in &[("NFT1", "alice"), ("NFT2", "alice"), ("NFT3", "bob")] {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr(owner.to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let is_locked = |deps: &Extern<MockStorage, MockApi, MockQuerier>, idx: u32| {
+            let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+            let token: Token = json_load(&info_store, &idx.to_le_bytes()).unwrap();
+            token.transfer_locked
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            expires: None,
+
+        // test nothing is locked if the sender does not own every token
+        let handle_msg = HandleMsg::BatchSetTransferLock {
+            token_ids: vec!["NFT1".to_string(), "NFT2".to_string(), "NFT3".to_string()],
+            locked: true,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let error = extract_error_msg(handle_result);
-        assert!(error.contains("The contract admin has temporarily disabled this action"));
-        // setting approval is ok even during StopTransactions status
-        let handle_msg = HandleMsg::SetContractStatus {
-            level: ContractStatus::StopTransactions,
+        assert!(error.contains("You do not own token NFT3"));
+        assert!(!is_locked(&deps, 0));
+        assert!(!is_locked(&deps, 1));
+        let handle_msg = HandleMsg::BatchSetTransferLock {
+            token_ids: vec!["NFT1".to_string(), "NFT4".to_string()],
+            locked: true,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT4 not found"));
+        assert!(!is_locked(&deps, 0));
 
-        // only allow the owner to use SetGlobalApproval
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            expires: None,
+        // test locking counts each changed token once
+        let handle_msg = HandleMsg::BatchSetTransferLock {
+            token_ids: vec!["NFT1".to_string(), "NFT2".to_string(), "NFT1".to_string()],
+            locked: true,
             padding: None,
         };
-        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains("You do not own token NFT1"));
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::BatchSetTransferLock { updated } => assert_eq!(updated, 2),
+            _ => panic!("unexpected"),
+        }
+        assert!(is_locked(&deps, 0));
+        assert!(is_locked(&deps, 1));
+        assert!(!is_locked(&deps, 2));
 
-        // try approving a token without specifying which token
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: None,
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            expires: None,
+        // test already locked tokens are not counted
+        let handle_msg = HandleMsg::BatchSetTransferLock {
+            token_ids: vec!["NFT2".to_string()],
+            locked: true,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains(
-            "Attempted to grant/revoke permission for a token, but did not specify a token ID"
-        ));
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::BatchSetTransferLock { updated } => assert_eq!(updated, 0),
+            _ => panic!("unexpected"),
+        }
 
-        // try revoking a token approval without specifying which token
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: None,
-            view_owner: Some(AccessLevel::RevokeToken),
-            view_private_metadata: None,
-            expires: None,
+        // test unlocking
+        let handle_msg = HandleMsg::BatchSetTransferLock {
+            token_ids: vec!["NFT1".to_string(), "NFT2".to_string()],
+            locked: false,
             padding: None,
         };
         let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let error = extract_error_msg(handle_result);
-        assert!(error.contains(
-            "Attempted to grant/revoke permission for a token, but did not specify a token ID"
-        ));
+        assert!(handle_result.is_ok());
+        assert!(!is_locked(&deps, 0));
+        assert!(!is_locked(&deps, 1));
+    }
 
-        // sanity check
-        let handle_msg = HandleMsg::SetGlobalApproval {
+    // test recording private metadata views
+    #[test]
+    fn test_record_view() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
             token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtTime(1000000)),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let global_raw = CanonicalAddr(Binary::from(b"public"));
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let alice_key = alice_raw.as_slice();
-        let view_owner_idx = PermissionType::ViewOwner.to_usize();
-        let view_meta_idx = PermissionType::ViewMetadata.to_usize();
-        let transfer_idx = PermissionType::Transfer.to_usize();
-        // confirm ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 1);
-        let global_perm = all_perm.iter().find(|p| p.address == global_raw).unwrap();
-        assert_eq!(
-            global_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(global_perm.expirations[view_meta_idx], None);
-        assert_eq!(global_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permissions and that the token data did not get modified
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let nft1_key = 0u32.to_le_bytes();
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.owner, alice_raw);
-        assert!(token.unwrapped);
-        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-        let pub_meta: Metadata = load(&pub_store, &nft1_key).unwrap();
-        assert_eq!(pub_meta, pub1.clone().unwrap());
-        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-        let priv_meta: Option<Metadata> = may_load(&priv_store, &nft1_key).unwrap();
-        assert!(priv_meta.is_none());
-        assert_eq!(token.permissions.len(), 1);
-        let global_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == global_raw)
-            .unwrap();
-        assert_eq!(
-            global_tok_perm.expirations[view_meta_idx],
-            Some(Expiration::AtTime(1000000))
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test recording when tracking is disabled
+        let handle_msg = HandleMsg::RecordView {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("View tracking is not enabled for this contract"));
+
+        let (init_result, mut deps) = init_helper_with_init_config(InitConfig {
+            track_views: Some(true),
+            ..InitConfig::default()
+        });
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        assert_eq!(global_tok_perm.expirations[transfer_idx], None);
-        assert_eq!(global_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists has public with NFT1 permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let global_auth = auth_list.iter().find(|a| a.address == global_raw).unwrap();
-        assert_eq!(global_auth.tokens[view_meta_idx].len(), 1);
-        assert!(global_auth.tokens[view_meta_idx].contains(&0u32));
-        assert!(global_auth.tokens[transfer_idx].is_empty());
-        assert!(global_auth.tokens[view_owner_idx].is_empty());
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        for user in &["alice", "bob"] {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env(*user, &[]), handle_msg);
+        }
+        let view_stats = |deps: &Extern<MockStorage, MockApi, MockQuerier>, user: &str| {
+            let query_msg = QueryMsg::ViewStats {
+                token_id: "NFT1".to_string(),
+                viewer: Some(ViewerInfo {
+                    address: HumanAddr(user.to_string()),
+                    viewing_key: "key".to_string(),
+                }),
+            };
+            query(deps, query_msg)
+        };
 
-        // bob approvals to make sure whitelisted addresses don't break
+        // test a failed authorization does not count as a view
+        let handle_msg = HandleMsg::RecordView {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test the owner and a whitelisted viewer both count
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: HumanAddr("bob".to_string()),
             token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtTime(1000000)),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        // confirm ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 2);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        let global_perm = all_perm.iter().find(|p| p.address == global_raw).unwrap();
-        assert_eq!(
-            global_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(global_perm.expirations[view_meta_idx], None);
-        assert_eq!(global_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permissions
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(
-            global_tok_perm.expirations[view_meta_idx],
-            Some(Expiration::AtTime(1000000))
+        for user in &["alice", "bob", "bob"] {
+            let handle_msg = HandleMsg::RecordView {
+                token_id: "NFT1".to_string(),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env(*user, &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+        let query_answer: QueryAnswer = from_binary(&view_stats(&deps, "alice").unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ViewStats { private_view_count } => assert_eq!(private_view_count, 3),
+            _ => panic!("unexpected"),
+        }
+
+        // test only the owner may view the stats
+        let error = extract_error_msg(view_stats(&deps, "bob"));
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+    }
+
+    // test media file types and extensions are validated on mint and SetMetadata
+    #[test]
+    fn test_media_type_validation() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        assert_eq!(global_tok_perm.expirations[transfer_idx], None);
-        assert_eq!(global_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists has bob with NFT1 permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 2);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let global_auth = auth_list.iter().find(|a| a.address == global_raw).unwrap();
-        assert_eq!(global_auth.tokens[view_meta_idx].len(), 1);
-        assert!(global_auth.tokens[view_meta_idx].contains(&0u32));
-        assert!(global_auth.tokens[transfer_idx].is_empty());
-        assert!(global_auth.tokens[view_owner_idx].is_empty());
+        let media_meta = |file_type: Option<&str>, extension: &str| {
+            extension_meta(Extension {
+                media: Some(vec![MediaFile {
+                    file_type: file_type.map(|t| t.to_string()),
+                    extension: Some(extension.to_string()),
+                    authentication: None,
+                    url: "https://media".to_string(),
+                    sha256: None,
+                }]),
+                ..Extension::default()
+            })
+        };
 
-        // confirm ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 2);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
+        // test a valid video file
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT1", media_meta(Some("video"), "mp4")),
         );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        let global_perm = all_perm.iter().find(|p| p.address == global_raw).unwrap();
-        assert_eq!(
-            global_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
+        assert!(handle_result.is_ok());
+
+        // test an extension that does not match the file type
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT2", media_meta(Some("video"), "png")),
         );
-        assert_eq!(global_perm.expirations[view_meta_idx], None);
-        assert_eq!(global_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permissions
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 2);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Media extension png is not valid for file_type video"));
+
+        // test an unknown file type
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT3", media_meta(Some("hologram"), "mp4")),
         );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        assert_eq!(
-            global_tok_perm.expirations[view_meta_idx],
-            Some(Expiration::AtTime(1000000))
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Invalid media file_type: hologram"));
+
+        // test no file type is not checked
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT4", media_meta(None, "anything")),
         );
-        assert_eq!(global_tok_perm.expirations[transfer_idx], None);
-        assert_eq!(global_tok_perm.expirations[view_owner_idx], None);
-        // confirm AuthLists has bob with NFT1 permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 2);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let global_auth = auth_list.iter().find(|a| a.address == global_raw).unwrap();
-        assert_eq!(global_auth.tokens[view_meta_idx].len(), 1);
-        assert!(global_auth.tokens[view_meta_idx].contains(&0u32));
-        assert!(global_auth.tokens[transfer_idx].is_empty());
-        assert!(global_auth.tokens[view_owner_idx].is_empty());
+        assert!(handle_result.is_ok());
 
-        // test revoking global approval
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: Some(AccessLevel::None),
-            expires: Some(Expiration::AtTime(1000000)),
+        // test SetMetadata is also checked
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: None,
+            private_metadata: Some(media_meta(Some("audio"), "webm")),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        // confirm ALL permission
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let all_perm: Vec<Permission> = json_load(&all_store, alice_key).unwrap();
-        assert_eq!(all_perm.len(), 2);
-        let bob_oper_perm = all_perm.iter().find(|p| p.address == bob_raw).unwrap();
-        assert_eq!(
-            bob_oper_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(bob_oper_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_oper_perm.expirations[transfer_idx], None);
-        let global_perm = all_perm.iter().find(|p| p.address == global_raw).unwrap();
-        assert_eq!(
-            global_perm.expirations[view_owner_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(global_perm.expirations[view_meta_idx], None);
-        assert_eq!(global_perm.expirations[transfer_idx], None);
-        // confirm NFT1 permissions
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token: Token = json_load(&info_store, &nft1_key).unwrap();
-        assert_eq!(token.permissions.len(), 1);
-        let bob_tok_perm = token
-            .permissions
-            .iter()
-            .find(|p| p.address == bob_raw)
-            .unwrap();
-        assert_eq!(
-            bob_tok_perm.expirations[transfer_idx],
-            Some(Expiration::AtTime(1000000))
-        );
-        assert_eq!(bob_tok_perm.expirations[view_meta_idx], None);
-        assert_eq!(bob_tok_perm.expirations[view_owner_idx], None);
-        let global_tok_perm = token.permissions.iter().find(|p| p.address == global_raw);
-        assert!(global_tok_perm.is_none());
-        // confirm AuthLists has bob with NFT1 permission
-        let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-        let auth_list: Vec<AuthList> = load(&auth_store, alice_key).unwrap();
-        assert_eq!(auth_list.len(), 1);
-        let bob_auth = auth_list.iter().find(|a| a.address == bob_raw).unwrap();
-        assert_eq!(bob_auth.tokens[transfer_idx].len(), 1);
-        assert!(bob_auth.tokens[transfer_idx].contains(&0u32));
-        assert!(bob_auth.tokens[view_meta_idx].is_empty());
-        assert!(bob_auth.tokens[view_owner_idx].is_empty());
-        let global_auth = auth_list.iter().find(|a| a.address == global_raw);
-        assert!(global_auth.is_none());
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Media extension webm is not valid for file_type audio"));
     }
 
-    // test permissioning works
-    #[test]
-    fn test_check_permission() {
+    // test a batch transfer to several recipients with individual memos
+    #[test]
+    fn test_batch_transfer_multiple_recipients() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for (id, owner) in &[
+            ("NFT1", "alice"),
+            ("NFT2", "alice"),
+            ("NFT3", "alice"),
+            ("NFT4", "alice"),
+            ("NFT5", "edmund"),
+        ] {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr(owner.to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let transfers = |last: &str| {
+            vec![
+                Transfer {
+                    recipient: HumanAddr("bob".to_string()),
+                    token_ids: vec!["NFT1".to_string(), "NFT2".to_string()],
+                    memo: Some("for bob".to_string()),
+                },
+                Transfer {
+                    recipient: HumanAddr("charlie".to_string()),
+                    token_ids: vec!["NFT3".to_string()],
+                    memo: Some("for charlie".to_string()),
+                },
+                Transfer {
+                    recipient: HumanAddr("david".to_string()),
+                    token_ids: vec![last.to_string()],
+                    memo: None,
+                },
+            ]
+        };
+
+        // test the whole batch fails if the sender is not approved for one token
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers: transfers("NFT5"),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT5"));
+
         let (init_result, mut deps) =
-            init_helper_with_config(true, false, false, false, false, false, false);
+            init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let block = BlockInfo {
-            height: 1,
-            time: 1,
-            chain_id: "secret-2".to_string(),
-        };
-        let alice_raw = deps
-            .api
-            .canonical_address(&HumanAddr("alice".to_string()))
-            .unwrap();
-        let bob_raw = deps
-            .api
-            .canonical_address(&HumanAddr("bob".to_string()))
-            .unwrap();
-        let charlie_raw = deps
-            .api
-            .canonical_address(&HumanAddr("charlie".to_string()))
-            .unwrap();
-        let nft1_key = 0u32.to_le_bytes();
-        let nft2_key = 1u32.to_le_bytes();
-        let pub1 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("My1".to_string()),
-                description: Some("Pub 1".to_string()),
-                image: Some("URI 1".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub1.clone(),
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        for id in &["NFT1", "NFT2", "NFT3", "NFT4"] {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let handle_msg = HandleMsg::BatchTransferNft {
+            transfers: transfers("NFT4"),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let pub2 = Some(Metadata {
-            token_uri: None,
-            extension: Some(Extension {
-                name: Some("My2".to_string()),
-                description: Some("Pub 2".to_string()),
-                image: Some("URI 2".to_string()),
-                ..Extension::default()
-            }),
-        });
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub2.clone(),
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        for (idx, owner) in [(0u32, "bob"), (1, "bob"), (2, "charlie"), (3, "david")].iter() {
+            let token: Token = json_load(&info_store, &idx.to_le_bytes()).unwrap();
+            assert_eq!(
+                token.owner,
+                deps.api
+                    .canonical_address(&HumanAddr(owner.to_string()))
+                    .unwrap()
+            );
+        }
+
+        // test each token has its own history entry with its transfer's memo
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        // test not approved
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewOwner,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("not approved"));
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::TransactionHistory {
+            address: HumanAddr("alice".to_string()),
+            viewing_key: "akey".to_string(),
+            page: None,
+            page_size: None,
+            tx_type: Some(TxType::Transfer),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(total, 4);
+                let mut entries: Vec<(String, Option<String>, String)> = txs
+                    .into_iter()
+                    .map(|tx| match tx.action {
+                        TxAction::Transfer { recipient, .. } => (tx.token_id, tx.memo, recipient.0),
+                        _ => panic!("unexpected"),
+                    })
+                    .collect();
+                entries.sort();
+                assert_eq!(
+                    entries,
+                    vec![
+                        (
+                            "NFT1".to_string(),
+                            Some("for bob".to_string()),
+                            "bob".to_string()
+                        ),
+                        (
+                            "NFT2".to_string(),
+                            Some("for bob".to_string()),
+                            "bob".to_string()
+                        ),
+                        (
+                            "NFT3".to_string(),
+                            Some("for charlie".to_string()),
+                            "charlie".to_string()
+                        ),
+                        ("NFT4".to_string(), None, "david".to_string()),
+                    ]
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+    }
 
-        // test owner is public for the contract
+    // test metadata editors may update metadata but not mint
+    #[test]
+    fn test_metadata_editors() {
         let (init_result, mut deps) =
-            init_helper_with_config(true, true, false, false, false, false, false);
+            init_helper_with_config(false, false, false, false, false, false, false);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
+            token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub1.clone(),
+            public_metadata: None,
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub2.clone(),
+        let patch = || HandleMsg::PatchMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: Some(MetadataPatch {
+                description: Some("Curated".to_string()),
+                ..MetadataPatch::default()
+            }),
             private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewOwner,
-            &mut Vec::new(),
-            "not approved",
-            true,
-        );
-        assert!(check_perm.is_ok());
-
-        // test owner makes their tokens private when the contract has public ownership
-        let handle_msg = HandleMsg::MakeOwnershipPrivate { padding: None };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewOwner,
-            &mut Vec::new(),
-            "not approved",
-            true,
-        );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("not approved"));
-
-        // test owner later makes ownership of a single token public
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: None,
-            expires: Some(Expiration::AtTime(1000000)),
-            padding: None,
+        let editors = |deps: &Extern<MockStorage, MockApi, MockQuerier>| {
+            let query_result = query(deps, QueryMsg::MetadataEditors {});
+            match from_binary(&query_result.unwrap()).unwrap() {
+                QueryAnswer::MetadataEditors { editors } => editors,
+                _ => panic!("unexpected"),
+            }
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewOwner,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
-        // test public approval when no address is given
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            None,
-            PermissionType::ViewOwner,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
 
-        // test global approval for all tokens
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token2: Token = json_load(&info_store, &nft2_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token2,
-            "NFT2",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("not approved"));
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(1000000)),
+        // test non-admin can not add editors
+        let handle_msg = HandleMsg::AddMetadataEditors {
+            editors: vec![HumanAddr("edith".to_string())],
             padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token2: Token = json_load(&info_store, &nft2_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token2,
-            "NFT2",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
-        // test public approval when no address is given
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token2,
-            "NFT2",
-            None,
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        };
+        let handle_result = handle(&mut deps, mock_env("edith", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
         );
-        assert!(check_perm.is_ok());
 
-        // test those global permissions having expired
-        let block = BlockInfo {
-            height: 1,
-            time: 2000000,
-            chain_id: "secret-2".to_string(),
+        // test a non-editor can not update metadata
+        let handle_result = handle(&mut deps, mock_env("edith", &[]), patch());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Not authorized to update metadata of token MyNFT"));
+
+        // test an editor can update metadata even when minters may not
+        let handle_msg = HandleMsg::AddMetadataEditors {
+            editors: vec![
+                HumanAddr("edith".to_string()),
+                HumanAddr("frank".to_string()),
+                HumanAddr("edith".to_string()),
+            ],
+            padding: None,
         };
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewOwner,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(
+            editors(&deps),
+            vec![
+                HumanAddr("edith".to_string()),
+                HumanAddr("frank".to_string())
+            ]
         );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("not approved"));
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token2,
-            "NFT2",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let handle_result = handle(&mut deps, mock_env("edith", &[]), patch());
+        assert!(handle_result.is_ok());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(
+            pub_meta.extension.unwrap().description,
+            Some("Curated".to_string())
         );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("not approved"));
 
-        let block = BlockInfo {
-            height: 1,
-            time: 1,
-            chain_id: "secret-2".to_string(),
+        // test an editor can not mint
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("EditorNFT".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
         };
+        let handle_result = handle(&mut deps, mock_env("edith", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters are allowed to mint"));
 
-        // test whitelisted approval on a token
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT2".to_string()),
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtTime(5)),
+        // test removing and setting editors
+        let handle_msg = HandleMsg::RemoveMetadataEditors {
+            editors: vec![HumanAddr("edith".to_string())],
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token2: Token = json_load(&info_store, &nft2_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token2,
-            "NFT2",
-            Some(&bob_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert_eq!(editors(&deps), vec![HumanAddr("frank".to_string())]);
+        let handle_result = handle(&mut deps, mock_env("edith", &[]), patch());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Not authorized to update metadata of token MyNFT"));
+        let handle_msg = HandleMsg::SetMetadataEditors {
+            editors: vec![],
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(editors(&deps).is_empty());
+    }
+
+    // test image_data size and content validation
+    #[test]
+    fn test_image_data_validation() {
+        let (init_result, mut deps) = init_helper_with_init_config(InitConfig {
+            max_image_data_size: Some(128),
+            ..InitConfig::default()
+        });
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        assert!(check_perm.is_ok());
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token2,
-            "NFT2",
-            Some(&charlie_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let svg_meta = |svg: &str| {
+            extension_meta(Extension {
+                image_data: Some(svg.to_string()),
+                ..Extension::default()
+            })
+        };
+        let rect = "<svg><rect width=\"10\" height=\"10\" fill=\"red\"/></svg>";
+
+        // test a plain svg passes
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT1", svg_meta(rect)),
         );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("not approved"));
+        assert!(handle_result.is_ok());
 
-        // test approval expired
-        let block = BlockInfo {
-            height: 1,
-            time: 6,
-            chain_id: "secret-2".to_string(),
-        };
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token2,
-            "NFT2",
-            Some(&bob_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        // test an oversized svg
+        let big = format!("<svg>{}</svg>", "<g/>".repeat(40));
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT2", svg_meta(&big)),
         );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("Access to token NFT2 has expired"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("image_data is 171 bytes, but the maximum allowed is 128"));
 
-        // test owner access
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token2,
-            "NFT2",
-            Some(&alice_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        // test a script tag, regardless of case
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT3", svg_meta("<svg><SCRIPT>alert(1)</SCRIPT></svg>")),
         );
-        assert!(check_perm.is_ok());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("image_data may not contain <script"));
 
-        // test whitelisted approval on all tokens
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(7)),
+        // test a javascript url is rejected when updating metadata
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(svg_meta("<svg><a href=\"javascript:alert(1)\"/></svg>")),
+            private_metadata: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("image_data may not contain javascript:"));
+    }
+
+    // test background_color validation when minting and setting metadata
+    #[test]
+    fn test_background_color_validation() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, true, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("not approved"));
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&charlie_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let color_meta = |color: &str| {
+            extension_meta(Extension {
+                background_color: Some(color.to_string()),
+                ..Extension::default()
+            })
+        };
+
+        // test a six-character hex color passes
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT1", color_meta("A0B1C2")),
         );
-        assert!(check_perm.is_ok());
+        assert!(handle_result.is_ok());
 
-        // test whitelisted ALL permission has expired
-        let block = BlockInfo {
-            height: 1,
-            time: 7,
-            chain_id: "secret-2".to_string(),
-        };
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&charlie_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        // test a #-prefixed color
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint_msg("NFT2", color_meta("#a0b1c2")),
         );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("Access to all tokens of alice has expired"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Invalid background_color: #a0b1c2."));
 
-        let (init_result, mut deps) = init_helper_default();
+        // test shorthand is rejected when updating metadata
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(color_meta("abc")),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Invalid background_color: abc."));
+
+        // test valid colors, in either case
+        for color in &["ffffff", "000000", "1A2b3C"] {
+            assert!(color_meta(color).validate_background_color().is_ok());
+        }
+        // test a missing color is valid
+        assert!(extension_meta(Extension::default())
+            .validate_background_color()
+            .is_ok());
+
+        // test invalid colors
+        for color in &["#ffffff", "fff", "ffffff00", "gggggg", "+12345", ""] {
+            let error = color_meta(color).validate_background_color().unwrap_err();
+            assert!(error
+                .to_string()
+                .contains(&format!("Invalid background_color: {}.", color)));
+        }
+    }
+
+    // test the StopMutations contract status
+    #[test]
+    fn test_stop_mutations() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, true, false, true);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
+        let mint = |id: &str| HandleMsg::MintNft {
+            token_id: Some(id.to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub1.clone(),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        for id in &["NFT1", "NFT2"] {
+            let handle_result = handle(&mut deps, mock_env("admin", &[]), mint(id));
+            assert!(handle_result.is_ok());
+        }
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopMutations,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.status, ContractStatus::StopMutations.to_u8());
+        let blocked = "The contract admin has temporarily disabled this action";
+
+        // test mints are blocked
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint("NFT3"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
+        let handle_msg = HandleMsg::ReserveTokenIds {
+            token_ids: vec!["NFT4".to_string()],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
+
+        // test metadata and royalty changes are blocked
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(Metadata {
+                token_uri: Some("https://new.uri".to_string()),
+                extension: None,
+            }),
             private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
-            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub2.clone(),
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
+        let handle_msg = HandleMsg::PatchMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(MetadataPatch::default()),
             private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
+        let handle_msg = HandleMsg::SetRoyaltyInfo {
+            token_id: None,
             royalty_info: None,
-            serial_number: None,
-            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
+        let handle_msg = HandleMsg::SetDefaultExtension {
+            extension: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
+        let handle_msg = HandleMsg::SetMaxRoyalty {
+            max_royalty_bps: Some(500),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
 
-        // test whitelist approval expired, but global is good on a token
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: Some(Expiration::AtTime(10)),
+        // test approvals, transfers, and burns are still allowed
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("bob".to_string()),
+            token_id: "NFT1".to_string(),
+            expires: None,
+            max_uses: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtTime(1000)),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let block = BlockInfo {
-            height: 1,
-            time: 100,
-            chain_id: "secret-2".to_string(),
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
         };
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
 
-        // test whitelist approval expired, but global is good on ALL tokens
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::All),
-            transfer: None,
-            expires: Some(Expiration::AtTime(10)),
+        // test the more restrictive StopTransactions blocks both transfers and metadata changes
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(1000)),
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("alice".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let block = BlockInfo {
-            height: 1,
-            time: 100,
-            chain_id: "secret-2".to_string(),
+        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(Metadata {
+                token_uri: Some("https://new.uri".to_string()),
+                extension: None,
+            }),
+            private_metadata: None,
+            padding: None,
         };
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(blocked));
+    }
+
+    // test reveals log the hash of the revealed metadata
+    #[test]
+    fn test_reveal_metadata_hash() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
         );
-        assert!(check_perm.is_ok());
+        let sealed_meta = |name: &str| {
+            extension_meta(Extension {
+                name: Some(name.to_string()),
+                description: Some("Sealed".to_string()),
+                ..Extension::default()
+            })
+        };
+        let mint =
+            |id: &str, private_metadata: Option<Metadata>, reveal_commitment| HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                private_metadata,
+                public_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment,
+                memo: None,
+                padding: None,
+            };
+        let hash_of = |meta: &Metadata| hex::encode(sha_256(&to_vec(meta).unwrap()));
+        // logs are padded, so trim them before comparing
+        let trimmed = |logs: Vec<LogAttribute>| {
+            logs.into_iter()
+                .map(|l| (l.key.trim().to_string(), l.value.trim().to_string()))
+                .collect::<Vec<(String, String)>>()
+        };
+        let commitment = hash_of(&sealed_meta("Committed"));
+        for (id, meta, commit) in vec![
+            ("NFT1", Some(sealed_meta("NFT1")), None),
+            ("NFT2", Some(sealed_meta("NFT2")), None),
+            ("NFT3", None, None),
+            (
+                "Committed",
+                Some(sealed_meta("Committed")),
+                Some(commitment),
+            ),
+        ]
+        .into_iter()
+        {
+            let handle_result = handle(&mut deps, mock_env("admin", &[]), mint(id, meta, commit));
+            assert!(handle_result.is_ok());
+        }
 
-        // test whitelist approval is good, but global expired on a token
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: Some(Expiration::AtTime(1000)),
+        // test Reveal logs the hash of the now public metadata
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "NFT1".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtTime(10)),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let logs = trimmed(handle_result.unwrap().log);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let revealed: Metadata = load(&pub_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(
+            logs,
+            vec![
+                ("revealed_token_id".to_string(), "NFT1".to_string()),
+                ("revealed_metadata_hash".to_string(), hash_of(&revealed)),
+            ]
+        );
+
+        // test RevealWithProof logs the hash matching the commitment
+        let handle_msg = HandleMsg::RevealWithProof {
+            token_id: "Committed".to_string(),
+            private_metadata: sealed_meta("Committed"),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let block = BlockInfo {
-            height: 1,
-            time: 100,
-            chain_id: "secret-2".to_string(),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let logs = trimmed(handle_result.unwrap().log);
+        assert_eq!(logs[1].1, hash_of(&sealed_meta("Committed")));
+
+        // test BatchReveal logs a hash for each token with metadata
+        let handle_msg = HandleMsg::BatchReveal {
+            token_ids: vec!["NFT2".to_string(), "NFT3".to_string()],
+            padding: None,
         };
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let logs = trimmed(handle_result.unwrap().log);
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let revealed: Metadata = load(&pub_store, &1u32.to_le_bytes()).unwrap();
+        assert_eq!(
+            logs,
+            vec![
+                ("revealed_token_id".to_string(), "NFT2".to_string()),
+                ("revealed_metadata_hash".to_string(), hash_of(&revealed)),
+            ]
         );
-        assert!(check_perm.is_ok());
+    }
 
-        // test whitelist approval is good, but global expired on ALL tokens
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::All),
-            transfer: None,
-            expires: Some(Expiration::AtTime(10)),
+    // test approvals limited to token ids with a prefix
+    #[test]
+    fn test_approve_all_with_prefix() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for id in vec!["season2-1", "season2-2", "season2-3", "season1-1"].into_iter() {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let transfer = |id: &str| HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: id.to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(1000)),
+        let approve = |prefix: &str, expires: Option<Expiration>| HandleMsg::ApproveAllWithPrefix {
+            operator: HumanAddr("bob".to_string()),
+            prefix: prefix.to_string(),
+            expires,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let block = BlockInfo {
-            height: 1,
-            time: 100,
-            chain_id: "secret-2".to_string(),
+
+        // test an empty prefix is rejected
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), approve("", None));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The prefix can not be empty"));
+
+        // test a prefixed approval authorizes matching ids and rejects non-matching ones
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), approve("season2-", None));
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::ApproveAllWithPrefix { status } => {
+                assert_eq!(status, ResponseStatus::Success)
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), transfer("season2-1"));
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), transfer("season1-1"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token season1-1"));
+        // the approval does not extend to tokens of other owners
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &[]),
+            HandleMsg::TransferNft {
+                recipient: HumanAddr("david".to_string()),
+                token_id: "season2-1".to_string(),
+                memo: None,
+                padding: None,
+            },
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token season2-1"));
+
+        // test only the owner's viewing key may query the approvals
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
         };
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_approvals =
+            |key: &str, include_expired: Option<bool>| QueryMsg::PrefixApprovals {
+                owner: HumanAddr("alice".to_string()),
+                viewing_key: key.to_string(),
+                include_expired,
+            };
+        let query_result = query(&deps, query_approvals("wrong", None));
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Wrong viewing key"));
+        let query_result = query(&deps, query_approvals("akey", None));
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PrefixApprovals { approvals } => {
+                assert_eq!(
+                    approvals,
+                    vec![PrefixApproval {
+                        operator: HumanAddr("bob".to_string()),
+                        prefix: "season2-".to_string(),
+                        expires: Expiration::Never,
+                    }]
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test an expired prefixed approval no longer authorizes transfers
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &[]),
+            approve("season2-", Some(Expiration::AtHeight(20000))),
         );
-        assert!(check_perm.is_ok());
+        assert!(handle_result.is_ok());
+        let mut env = mock_env("bob", &[]);
+        env.block.height = 20001;
+        let handle_result = handle(&mut deps, env, transfer("season2-2"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token season2-2"));
+        let query_result = query(&deps, query_approvals("akey", Some(true)));
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PrefixApprovals { approvals } => {
+                assert_eq!(approvals.len(), 1);
+                assert_eq!(approvals[0].expires, Expiration::AtHeight(20000));
+            }
+            _ => panic!("unexpected"),
+        }
 
-        let (init_result, mut deps) = init_helper_default();
+        // test revoking the approval
+        let handle_msg = HandleMsg::RevokeAllWithPrefix {
+            operator: HumanAddr("bob".to_string()),
+            prefix: "season2-".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_result = query(&deps, query_approvals("akey", Some(true)));
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PrefixApprovals { approvals } => assert!(approvals.is_empty()),
+            _ => panic!("unexpected"),
+        }
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), transfer("season2-3"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token season2-3"));
+    }
+
+    // test freezing and unfreezing tokens
+    #[test]
+    fn test_freeze_token() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, true);
         assert!(
             init_result.is_ok(),
             "Init failed: {}",
             init_result.err().unwrap()
         );
         let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT1".to_string()),
+            token_id: Some("MyNFT".to_string()),
             owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub1.clone(),
+            public_metadata: None,
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
-        let handle_msg = HandleMsg::MintNft {
-            token_id: Some("NFT2".to_string()),
-            owner: Some(HumanAddr("alice".to_string())),
-            public_metadata: pub2.clone(),
-            private_metadata: None,
-            royalty_info: None,
-            serial_number: None,
+        let freeze = || HandleMsg::FreezeToken {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let transfer = || HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "MyNFT".to_string(),
             memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let is_frozen = |deps: &Extern<MockStorage, MockApi, MockQuerier>| {
+            let query_msg = QueryMsg::IsFrozen {
+                token_id: "MyNFT".to_string(),
+            };
+            match from_binary(&query(deps, query_msg).unwrap()).unwrap() {
+                QueryAnswer::IsFrozen { is_frozen } => is_frozen,
+                _ => panic!("unexpected"),
+            }
+        };
 
-        // test bob has view owner approval on NFT1 and view metadata approval on ALL
-        // while there is global view owner approval on ALL tokens and global view metadata
-        // approval on NFT1
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("bob".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::All),
-            transfer: None,
-            expires: Some(Expiration::AtTime(100)),
+        // test only the admin may freeze, even if the caller is the owner
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), freeze());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("This is an admin command"));
+        assert!(!is_frozen(&deps));
+        // test freezing a token that does not exist
+        let handle_msg = HandleMsg::FreezeToken {
+            token_id: "NFT2".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT2 not found"));
+
+        // test a frozen token can not be transferred or burned by its owner
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), freeze());
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::FreezeToken { status } => assert_eq!(status, ResponseStatus::Success),
+            _ => panic!("unexpected"),
+        }
+        assert!(is_frozen(&deps));
+
+        // test the queries do not show a frozen token as transferable by its approved addresses
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("bob".to_string()),
+            expires: None,
+            max_uses: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetGlobalApproval {
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::All),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            expires: Some(Expiration::AtTime(10)),
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let block = BlockInfo {
-            height: 1,
-            time: 1,
-            chain_id: "secret-2".to_string(),
+        let alice_viewer = || {
+            Some(ViewerInfo {
+                address: HumanAddr("alice".to_string()),
+                viewing_key: "akey".to_string(),
+            })
         };
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        let error = extract_error_msg(check_perm);
-        assert!(error.contains("not approved"));
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewOwner,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
+        let query_msg = QueryMsg::OperatorTokens {
+            owner: HumanAddr("alice".to_string()),
+            operator: HumanAddr("bob".to_string()),
+            viewer: alice_viewer(),
+            start_after: None,
+            limit: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens, .. } => assert!(tokens.is_empty()),
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::WhoCanTransfer {
+            token_id: "MyNFT".to_string(),
+            viewer: alice_viewer(),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::WhoCanTransfer {
+                frozen, operators, ..
+            } => {
+                assert!(frozen);
+                assert_eq!(operators.len(), 1);
+            }
+            _ => panic!("unexpected"),
+        }
 
-        // now check where the global approvals expired
-        let block = BlockInfo {
-            height: 1,
-            time: 50,
-            chain_id: "secret-2".to_string(),
+        // test the admin may not unfreeze while the contract is stopped
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopAll,
+            padding: None,
         };
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewOwner,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&bob_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::UnfreezeToken {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The contract admin has temporarily disabled this action"));
+        assert!(is_frozen(&deps));
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
 
-        // throw a charlie transfer approval and a view meta token approval in the mix
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: None,
-            view_owner: None,
-            view_private_metadata: None,
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtTime(100)),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), transfer());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT has been frozen by the admin"));
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "MyNFT".to_string(),
+            memo: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: HumanAddr("charlie".to_string()),
-            token_id: Some("NFT1".to_string()),
-            view_owner: None,
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: None,
-            expires: Some(Expiration::AtTime(100)),
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: MyNFT has been frozen by the admin"));
+        let query_msg = QueryMsg::IsTransferable {
+            token_id: "MyNFT".to_string(),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsTransferable {
+                transferable,
+                reason,
+            } => {
+                assert!(!transferable);
+                assert_eq!(reason, Some("frozen".to_string()));
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test only the admin may unfreeze, and unfreezing restores transfers
+        let unfreeze = || HandleMsg::UnfreezeToken {
+            token_id: "MyNFT".to_string(),
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-        let token1: Token = json_load(&info_store, &nft1_key).unwrap();
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&charlie_raw),
-            PermissionType::Transfer,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
-        let check_perm = check_permission(
-            &deps,
-            &block,
-            &token1,
-            "NFT1",
-            Some(&charlie_raw),
-            PermissionType::ViewMetadata,
-            &mut Vec::new(),
-            "not approved",
-            false,
-        );
-        assert!(check_perm.is_ok());
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), unfreeze());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("This is an admin command"));
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), unfreeze());
+        assert!(handle_result.is_ok());
+        assert!(!is_frozen(&deps));
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), transfer());
+        assert!(handle_result.is_ok());
     }
 }
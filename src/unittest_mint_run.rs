@@ -1,10 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use crate::contract::{handle, init, query};
+    use crate::contract::{generate_clone_ids, handle, init, query};
     use crate::mint_run::MintRunInfo;
-    use crate::msg::{HandleMsg, InitMsg, QueryAnswer, QueryMsg};
+    use crate::msg::{
+        HandleAnswer, HandleMsg, InitMsg, NumericTrait, QueryAnswer, QueryMsg, WeightedTrait,
+    };
+    use crate::rand::Prng;
+    use crate::token::{Extension, Metadata, Trait};
     use cosmwasm_std::testing::*;
-    use cosmwasm_std::{from_binary, Extern, HumanAddr, InitResponse, StdError, StdResult};
+    use cosmwasm_std::{
+        from_binary, Extern, HandleResult, HumanAddr, InitResponse, StdError, StdResult,
+    };
     use std::any::Any;
 
     // Helper functions
@@ -53,8 +59,13 @@ mod tests {
         let handle_msg = HandleMsg::MintNftClones {
             mint_run_id: None,
             quantity: 1,
+            id_prefix: None,
             owner: None,
             public_metadata: None,
+            use_default_extension: None,
+            trait_pool: None,
+            rarity_tiers: None,
+            numeric_traits: None,
             private_metadata: None,
             royalty_info: None,
             memo: None,
@@ -68,8 +79,13 @@ mod tests {
         let handle_msg = HandleMsg::MintNftClones {
             mint_run_id: None,
             quantity: 0,
+            id_prefix: None,
             owner: None,
             public_metadata: None,
+            use_default_extension: None,
+            trait_pool: None,
+            rarity_tiers: None,
+            numeric_traits: None,
             private_metadata: None,
             royalty_info: None,
             memo: None,
@@ -83,8 +99,13 @@ mod tests {
         let handle_msg = HandleMsg::MintNftClones {
             mint_run_id: None,
             quantity: 3,
+            id_prefix: None,
             owner: None,
             public_metadata: None,
+            use_default_extension: None,
+            trait_pool: None,
+            rarity_tiers: None,
+            numeric_traits: None,
             private_metadata: None,
             royalty_info: None,
             memo: None,
@@ -122,6 +143,8 @@ mod tests {
             token_id: "0".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -135,6 +158,8 @@ mod tests {
             token_id: "1".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -148,6 +173,8 @@ mod tests {
             token_id: "2".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -162,8 +189,13 @@ mod tests {
         let handle_msg = HandleMsg::MintNftClones {
             mint_run_id: Some("Starry Night".to_string()),
             quantity: 1,
+            id_prefix: None,
             owner: None,
             public_metadata: None,
+            use_default_extension: None,
+            trait_pool: None,
+            rarity_tiers: None,
+            numeric_traits: None,
             private_metadata: None,
             royalty_info: None,
             memo: None,
@@ -184,6 +216,8 @@ mod tests {
             token_id: "3".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -198,8 +232,13 @@ mod tests {
         let handle_msg = HandleMsg::MintNftClones {
             mint_run_id: Some("Starry Night".to_string()),
             quantity: 2,
+            id_prefix: None,
             owner: None,
             public_metadata: None,
+            use_default_extension: None,
+            trait_pool: None,
+            rarity_tiers: None,
+            numeric_traits: None,
             private_metadata: None,
             royalty_info: None,
             memo: None,
@@ -220,6 +259,8 @@ mod tests {
             token_id: "4".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -241,6 +282,8 @@ mod tests {
             token_id: "5".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -251,4 +294,563 @@ mod tests {
             _ => panic!("unexpected"),
         }
     }
+
+    // test distributing a trait pool across clones
+    #[test]
+    fn test_mint_clones_trait_pool() {
+        let (init_result, mut deps) = init_helper();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let pool: Vec<Trait> = (0..5)
+            .map(|i| Trait {
+                display_type: None,
+                trait_type: Some("edition color".to_string()),
+                value: format!("color {}", i),
+                max_value: None,
+            })
+            .collect();
+        let public_metadata = Some(Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("Clone".to_string()),
+                attributes: Some(vec![Trait {
+                    display_type: None,
+                    trait_type: Some("shared".to_string()),
+                    value: "yes".to_string(),
+                    max_value: None,
+                }]),
+                ..Extension::default()
+            }),
+        });
+
+        // test pool size does not match quantity
+        let handle_msg = HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 4,
+            id_prefix: None,
+            owner: None,
+            public_metadata: public_metadata.clone(),
+            use_default_extension: None,
+            trait_pool: Some(pool.clone()),
+            rarity_tiers: None,
+            numeric_traits: None,
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The trait pool must contain exactly one trait per clone"));
+
+        let clone_msg = || HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 5,
+            id_prefix: None,
+            owner: None,
+            public_metadata: public_metadata.clone(),
+            use_default_extension: None,
+            trait_pool: Some(pool.clone()),
+            rarity_tiers: None,
+            numeric_traits: None,
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg());
+        assert!(handle_result.is_ok());
+        let mut assigned: Vec<Trait> = Vec::new();
+        for i in 0..5 {
+            let query_msg = QueryMsg::NftInfo {
+                token_id: format!("{}", i),
+                ipfs_gateway: None,
+                locale: None,
+            };
+            let query_result = query(&deps, query_msg);
+            let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::NftInfo { extension, .. } => {
+                    let ext = extension.unwrap();
+                    assert_eq!(ext.name, Some("Clone".to_string()));
+                    let attributes = ext.attributes.unwrap();
+                    assert_eq!(attributes.len(), 2);
+                    assert_eq!(attributes[0].value, "yes".to_string());
+                    assigned.push(attributes[1].clone());
+                }
+                _ => panic!("unexpected"),
+            }
+        }
+        // every trait in the pool was assigned exactly once
+        assert_ne!(assigned, pool);
+        let mut sorted = assigned.clone();
+        sorted.sort_by(|a, b| a.value.cmp(&b.value));
+        assert_eq!(sorted, pool);
+
+        // test the same seed and inputs yield the same permutation
+        let (_init_result, mut deps) = init_helper();
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg());
+        for (i, expected) in assigned.iter().enumerate() {
+            let query_msg = QueryMsg::NftInfo {
+                token_id: format!("{}", i),
+                ipfs_gateway: None,
+                locale: None,
+            };
+            let query_result = query(&deps, query_msg);
+            let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::NftInfo { extension, .. } => {
+                    let attributes = extension.unwrap().attributes.unwrap();
+                    assert_eq!(&attributes[1], expected);
+                }
+                _ => panic!("unexpected"),
+            }
+        }
+
+        // test a pool can be used without any other public metadata
+        let handle_msg = HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 2,
+            id_prefix: None,
+            owner: None,
+            public_metadata: None,
+            use_default_extension: None,
+            trait_pool: Some(pool[..2].to_vec()),
+            rarity_tiers: None,
+            numeric_traits: None,
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::NftInfo {
+            token_id: "5".to_string(),
+            ipfs_gateway: None,
+            locale: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftInfo {
+                token_uri,
+                extension,
+            } => {
+                assert!(token_uri.is_none());
+                assert_eq!(extension.unwrap().attributes.unwrap().len(), 1);
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test minting clones from the default extension template
+    #[test]
+    fn test_mint_clones_default_extension() {
+        let (init_result, mut deps) = init_helper();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let template = Extension {
+            name: Some("Base".to_string()),
+            description: Some("Template description".to_string()),
+            image: Some("https://example.com/base.png".to_string()),
+            attributes: Some(vec![Trait {
+                display_type: None,
+                trait_type: Some("shared".to_string()),
+                value: "yes".to_string(),
+                max_value: None,
+            }]),
+            ..Extension::default()
+        };
+        let clone_msg =
+            |public_metadata: Option<Metadata>, use_default: bool| HandleMsg::MintNftClones {
+                mint_run_id: None,
+                quantity: 1,
+                id_prefix: None,
+                owner: None,
+                public_metadata,
+                use_default_extension: Some(use_default),
+                trait_pool: None,
+                rarity_tiers: None,
+                numeric_traits: None,
+                private_metadata: None,
+                royalty_info: None,
+                memo: None,
+                padding: None,
+            };
+        let nft_ext = |deps: &Extern<MockStorage, MockApi, MockQuerier>, id: &str| {
+            let query_msg = QueryMsg::NftInfo {
+                token_id: id.to_string(),
+                ipfs_gateway: None,
+                locale: None,
+            };
+            let query_result = query(deps, query_msg);
+            match from_binary(&query_result.unwrap()).unwrap() {
+                QueryAnswer::NftInfo { extension, .. } => extension,
+                _ => panic!("unexpected"),
+            }
+        };
+
+        // test only minters may set the template
+        let handle_msg = HandleMsg::SetDefaultExtension {
+            extension: Some(template.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters can set the default extension"));
+        let handle_msg = HandleMsg::SetDefaultExtension {
+            extension: Some(template.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_result = query(&deps, QueryMsg::DefaultExtension {});
+        match from_binary(&query_result.unwrap()).unwrap() {
+            QueryAnswer::DefaultExtension { extension } => {
+                assert_eq!(extension, Some(template.clone()));
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test the per-token fields take precedence over the template
+        let overrides = Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("Override".to_string()),
+                ..Extension::default()
+            }),
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            clone_msg(Some(overrides.clone()), true),
+        );
+        assert!(handle_result.is_ok());
+        let ext = nft_ext(&deps, "0").unwrap();
+        assert_eq!(ext.name, Some("Override".to_string()));
+        assert_eq!(ext.description, template.description);
+        assert_eq!(ext.image, template.image);
+        assert_eq!(ext.attributes, template.attributes);
+
+        // test the template is ignored unless requested
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            clone_msg(Some(overrides.clone()), false),
+        );
+        assert!(handle_result.is_ok());
+        assert_eq!(nft_ext(&deps, "1"), overrides.extension);
+
+        // test clearing the template reverts to an empty extension
+        let handle_msg = HandleMsg::SetDefaultExtension {
+            extension: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let query_result = query(&deps, QueryMsg::DefaultExtension {});
+        match from_binary(&query_result.unwrap()).unwrap() {
+            QueryAnswer::DefaultExtension { extension } => assert!(extension.is_none()),
+            _ => panic!("unexpected"),
+        }
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg(None, true));
+        assert!(handle_result.is_ok());
+        assert_eq!(nft_ext(&deps, "2"), Some(Extension::default()));
+
+        // test a token_uri can not be combined with the template
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            clone_msg(
+                Some(Metadata {
+                    token_uri: Some("https://example.com/token.json".to_string()),
+                    extension: None,
+                }),
+                true,
+            ),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata can not have BOTH token_uri AND extension"));
+    }
+
+    // test generating clone token ids from a prefix
+    #[test]
+    fn test_mint_clones_id_prefix() {
+        // test the indices are always padded to the same width
+        assert_eq!(
+            generate_clone_ids("card", 1, 3),
+            vec!["card-0000000001", "card-0000000002", "card-0000000003"]
+        );
+        let mut ids = generate_clone_ids("x", 1, 9);
+        ids.extend(generate_clone_ids("x", 10, 3));
+        assert_eq!(ids[8], "x-0000000009");
+        assert_eq!(ids[9], "x-0000000010");
+        let mut sorted = ids.clone();
+        sorted.sort();
+        assert_eq!(sorted, ids);
+        assert_eq!(
+            generate_clone_ids("x", u32::MAX, 1),
+            vec![format!("x-{}", u32::MAX)]
+        );
+
+        let (init_result, mut deps) = init_helper();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let clone_msg = |prefix: &str| HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 10,
+            id_prefix: Some(prefix.to_string()),
+            owner: None,
+            public_metadata: None,
+            use_default_extension: None,
+            trait_pool: None,
+            rarity_tiers: None,
+            numeric_traits: None,
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let first_last = |handle_result: HandleResult| -> (String, String) {
+            let answer: HandleAnswer = from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+            match answer {
+                HandleAnswer::MintNftClones {
+                    first_minted,
+                    last_minted,
+                } => (first_minted, last_minted),
+                _ => panic!("unexpected"),
+            }
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg("gold"));
+        assert_eq!(
+            first_last(handle_result),
+            ("gold-0000000001".to_string(), "gold-0000000010".to_string())
+        );
+
+        // test cloning the same prefix again continues the numbering with the same width
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg("gold"));
+        assert_eq!(
+            first_last(handle_result),
+            ("gold-0000000011".to_string(), "gold-0000000020".to_string())
+        );
+
+        // test a collision with an existing id is rejected before anything is minted
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("silver-0000000007".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg("silver"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Generated token ID silver-0000000007 is already in use"));
+    }
+
+    // test drawing weighted rarity tiers for clones
+    #[test]
+    fn test_mint_clones_rarity_tiers() {
+        let (init_result, mut deps) = init_helper();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let tier = |value: &str, weight: u32| WeightedTrait {
+            attribute: Trait {
+                display_type: None,
+                trait_type: Some("rarity".to_string()),
+                value: value.to_string(),
+                max_value: None,
+            },
+            weight,
+        };
+        let clone_msg = |tiers: Vec<WeightedTrait>| HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 40,
+            id_prefix: None,
+            owner: None,
+            public_metadata: None,
+            use_default_extension: None,
+            trait_pool: None,
+            rarity_tiers: Some(tiers),
+            numeric_traits: None,
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let tiers = || vec![tier("common", 3), tier("never", 0), tier("rare", 1)];
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg(tiers()));
+        assert!(handle_result.is_ok());
+        let drawn = |deps: &Extern<MockStorage, MockApi, MockQuerier>| -> Vec<String> {
+            (0..40)
+                .map(|i| {
+                    let query_msg = QueryMsg::NftInfo {
+                        token_id: format!("{}", i),
+                        ipfs_gateway: None,
+                        locale: None,
+                    };
+                    let query_result = query(deps, query_msg);
+                    match from_binary(&query_result.unwrap()).unwrap() {
+                        QueryAnswer::NftInfo { extension, .. } => {
+                            let attributes = extension.unwrap().attributes.unwrap();
+                            assert_eq!(attributes.len(), 1);
+                            attributes[0].value.clone()
+                        }
+                        _ => panic!("unexpected"),
+                    }
+                })
+                .collect()
+        };
+        let values = drawn(&deps);
+        // test a zero weight is never drawn, and both other tiers are
+        assert!(!values.contains(&"never".to_string()));
+        let common = values.iter().filter(|v| *v == "common").count();
+        assert!(common > 20 && common < 40);
+        assert!(values.contains(&"rare".to_string()));
+
+        // test the same seed and inputs yield the same draws
+        let (_init_result, mut deps) = init_helper();
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg(tiers()));
+        assert_eq!(drawn(&deps), values);
+
+        // test all zero weights are rejected
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            clone_msg(vec![tier("common", 0), tier("rare", 0)]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("At least one weight must be greater than zero"));
+    }
+
+    // test generating random numeric traits
+    #[test]
+    fn test_new_numeric() {
+        // test the same seed always yields the same traits
+        let mut rng1 = Prng::new(b"foo", b"bar!");
+        let mut rng2 = Prng::new(b"foo", b"bar!");
+        for _ in 0..10 {
+            assert_eq!(
+                Trait::new_numeric(&mut rng1, "Strength", 18),
+                Trait::new_numeric(&mut rng2, "Strength", 18)
+            );
+        }
+
+        // test the value never exceeds max and every value is reachable
+        let mut seen = [false; 7];
+        for _ in 0..500 {
+            let stat = Trait::new_numeric(&mut rng1, "Dexterity", 6);
+            assert_eq!(stat.display_type, Some("number".to_string()));
+            assert_eq!(stat.trait_type, Some("Dexterity".to_string()));
+            assert_eq!(stat.max_value, Some("6".to_string()));
+            let value: usize = stat.value.parse().unwrap();
+            assert!(value <= 6);
+            seen[value] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+
+        // test a max of 0 always yields 0
+        let stat = Trait::new_numeric(&mut rng1, "Luck", 0);
+        assert_eq!(stat.value, "0".to_string());
+        assert_eq!(stat.max_value, Some("0".to_string()));
+
+        // test the largest max does not overflow
+        let stat = Trait::new_numeric(&mut rng1, "Gold", u32::MAX);
+        assert!(stat.value.parse::<u64>().unwrap() <= u32::MAX as u64);
+    }
+
+    // test giving clones random numeric traits
+    #[test]
+    fn test_mint_clones_numeric_traits() {
+        let (init_result, mut deps) = init_helper();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let numerics = || {
+            vec![
+                NumericTrait {
+                    trait_type: "Strength".to_string(),
+                    max: 18,
+                },
+                NumericTrait {
+                    trait_type: "Luck".to_string(),
+                    max: 0,
+                },
+            ]
+        };
+        let clone_msg = || HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 10,
+            id_prefix: None,
+            owner: None,
+            public_metadata: None,
+            use_default_extension: None,
+            trait_pool: None,
+            rarity_tiers: None,
+            numeric_traits: Some(numerics()),
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg());
+        assert!(handle_result.is_ok());
+        let drawn = |deps: &Extern<MockStorage, MockApi, MockQuerier>| -> Vec<Vec<Trait>> {
+            (0..10)
+                .map(|i| {
+                    let query_msg = QueryMsg::NftInfo {
+                        token_id: format!("{}", i),
+                        ipfs_gateway: None,
+                        locale: None,
+                    };
+                    let query_result = query(deps, query_msg);
+                    match from_binary(&query_result.unwrap()).unwrap() {
+                        QueryAnswer::NftInfo { extension, .. } => {
+                            extension.unwrap().attributes.unwrap()
+                        }
+                        _ => panic!("unexpected"),
+                    }
+                })
+                .collect()
+        };
+        let attributes = drawn(&deps);
+        for attrs in attributes.iter() {
+            // test every clone gets each numeric trait in order
+            assert_eq!(attrs.len(), 2);
+            assert_eq!(attrs[0].trait_type, Some("Strength".to_string()));
+            assert_eq!(attrs[0].display_type, Some("number".to_string()));
+            assert_eq!(attrs[0].max_value, Some("18".to_string()));
+            assert!(attrs[0].value.parse::<u32>().unwrap() <= 18);
+            assert_eq!(attrs[1].trait_type, Some("Luck".to_string()));
+            assert_eq!(attrs[1].value, "0".to_string());
+        }
+        // test the clones do not all get the same value
+        assert!(attributes
+            .iter()
+            .any(|a| a[0].value != attributes[0][0].value));
+
+        // test the same seed and inputs yield the same values
+        let (_init_result, mut deps) = init_helper();
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), clone_msg());
+        assert_eq!(drawn(&deps), attributes);
+    }
 }
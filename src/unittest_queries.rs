@@ -2,14 +2,18 @@
 mod tests {
     use crate::contract::{handle, init, query};
     use crate::expiration::Expiration;
+    use crate::mint_run::SerialNumber;
     use crate::msg::{
-        AccessLevel, Cw721Approval, HandleMsg, InitConfig, InitMsg, QueryAnswer, QueryMsg,
-        Snip721Approval, Tx, TxAction, ViewerInfo,
+        AccessLevel, Burn, ContractStatus, Cw721Approval, HandleMsg, InitConfig, InitMsg,
+        MediaHash, MetadataType, Mint, PrefixApproval, PrngAlgorithm, QueryAnswer, QueryMsg,
+        Snip721Approval, Tx, TxAction, TxType, UnwrappedStatus, ViewerInfo,
     };
-    use crate::token::{Extension, Metadata};
+    use crate::rand::sha_256;
+    use crate::royalties::{Royalty, RoyaltyInfo};
+    use crate::token::{Extension, Localization, MediaFile, Metadata, MetadataPatch, Trait};
     use cosmwasm_std::testing::*;
     use cosmwasm_std::{
-        from_binary, Binary, BlockInfo, Env, Extern, HumanAddr, InitResponse, MessageInfo,
+        from_binary, to_vec, Binary, BlockInfo, Env, Extern, HumanAddr, InitResponse, MessageInfo,
         StdError, StdResult,
     };
     use std::any::Any;
@@ -84,6 +88,15 @@ mod tests {
         (init(&mut deps, env, init_msg), deps)
     }
 
+    // returns a mock Env at an earlier block, so that a handle does not prune approvals that
+    // have expired at the default mock block
+    fn mock_env_at(sender: &str, height: u64, time: u64) -> Env {
+        let mut env = mock_env(sender, &[]);
+        env.block.height = height;
+        env.block.time = time;
+        env
+    }
+
     fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
         match error {
             Ok(_response) => panic!("Expected error, but had Ok response"),
@@ -112,9 +125,46 @@ mod tests {
         );
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::ContractInfo { name, symbol } => {
+            QueryAnswer::ContractInfo {
+                name,
+                symbol,
+                features,
+            } => {
                 assert_eq!(name, "sec721".to_string());
                 assert_eq!(symbol, "S721".to_string());
+                assert_eq!(
+                    features,
+                    vec![
+                        "royalties".to_string(),
+                        "transfer_lock".to_string(),
+                        "metadata_updates".to_string(),
+                    ]
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test the feature list reflects the config
+        let (init_result, deps) =
+            init_helper_with_config(false, false, true, false, false, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_result = query(&deps, QueryMsg::ContractInfo {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ContractInfo { features, .. } => {
+                assert_eq!(
+                    features,
+                    vec![
+                        "royalties".to_string(),
+                        "transfer_lock".to_string(),
+                        "sealed_metadata".to_string(),
+                        "burn".to_string(),
+                    ]
+                );
             }
             _ => panic!("unexpected"),
         }
@@ -147,6 +197,12 @@ mod tests {
                 minter_may_update_metadata,
                 owner_may_update_metadata,
                 burn_is_enabled,
+                max_attributes,
+                mutable_trait_types,
+                track_views,
+                max_image_data_size,
+                max_royalty_bps,
+                prng_algorithm,
             } => {
                 assert_eq!(token_supply_is_public, false);
                 assert_eq!(owner_is_public, true);
@@ -155,6 +211,12 @@ mod tests {
                 assert_eq!(minter_may_update_metadata, true);
                 assert_eq!(owner_may_update_metadata, false);
                 assert_eq!(burn_is_enabled, true);
+                assert_eq!(max_attributes, None);
+                assert_eq!(mutable_trait_types, None);
+                assert!(!track_views);
+                assert_eq!(max_image_data_size, 65536);
+                assert_eq!(max_royalty_bps, None);
+                assert_eq!(prng_algorithm, PrngAlgorithm::Chacha20);
             }
             _ => panic!("unexpected"),
         }
@@ -224,13 +286,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My1".to_string()),
                     description: Some("Public 1".to_string()),
-                    image: Some("URI 1".to_string()),
+                    image: Some("https://URI 1".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -243,13 +306,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My2".to_string()),
                     description: Some("Public 2".to_string()),
-                    image: Some("URI 2".to_string()),
+                    image: Some("https://URI 2".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -330,13 +394,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My1".to_string()),
                     description: Some("Public 1".to_string()),
-                    image: Some("URI 1".to_string()),
+                    image: Some("https://URI 1".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -349,13 +414,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My2".to_string()),
                     description: Some("Public 2".to_string()),
-                    image: Some("URI 2".to_string()),
+                    image: Some("https://URI 2".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -401,13 +467,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My1".to_string()),
                     description: Some("Public 1".to_string()),
-                    image: Some("URI 1".to_string()),
+                    image: Some("https://URI 1".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -420,13 +487,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My2".to_string()),
                     description: Some("Public 2".to_string()),
-                    image: Some("URI 2".to_string()),
+                    image: Some("https://URI 2".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -439,13 +507,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My3".to_string()),
                     description: Some("Public 3".to_string()),
-                    image: Some("URI 3".to_string()),
+                    image: Some("https://URI 3".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -511,7 +580,7 @@ mod tests {
         );
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT1".to_string(), "NFT2".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -535,13 +604,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My1".to_string()),
                     description: Some("Public 1".to_string()),
-                    image: Some("URI 1".to_string()),
+                    image: Some("https://URI 1".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -554,13 +624,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My2".to_string()),
                     description: Some("Public 2".to_string()),
-                    image: Some("URI 2".to_string()),
+                    image: Some("https://URI 2".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -573,13 +644,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My3".to_string()),
                     description: Some("Public 3".to_string()),
-                    image: Some("URI 3".to_string()),
+                    image: Some("https://URI 3".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -592,13 +664,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My5".to_string()),
                     description: Some("Public 5".to_string()),
-                    image: Some("URI 5".to_string()),
+                    image: Some("https://URI 5".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -611,13 +684,14 @@ mod tests {
                 extension: Some(Extension {
                     name: Some("My4".to_string()),
                     description: Some("Public 4".to_string()),
-                    image: Some("URI 4".to_string()),
+                    image: Some("https://URI 4".to_string()),
                     ..Extension::default()
                 }),
             }),
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -639,7 +713,7 @@ mod tests {
         );
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT3".to_string(), "NFT5".to_string(), "NFT4".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -677,7 +751,7 @@ mod tests {
         );
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec![
                     "NFT1".to_string(),
                     "NFT2".to_string(),
@@ -706,6 +780,8 @@ mod tests {
             token_id: "NFT1".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let error = extract_error_msg(query_result);
@@ -724,6 +800,8 @@ mod tests {
             token_id: "NFT1".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let error = extract_error_msg(query_result);
@@ -734,7 +812,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("Name1".to_string()),
                 description: Some("PubDesc1".to_string()),
-                image: Some("PubUri1".to_string()),
+                image: Some("https://PubUri1".to_string()),
                 ..Extension::default()
             }),
         };
@@ -743,7 +821,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("PrivName1".to_string()),
                 description: Some("PrivDesc1".to_string()),
-                image: Some("PrivUri1".to_string()),
+                image: Some("https://PrivUri1".to_string()),
                 ..Extension::default()
             }),
         };
@@ -758,6 +836,7 @@ mod tests {
             private_metadata: Some(private_meta.clone()),
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -808,6 +887,8 @@ mod tests {
             token_id: "NFT1".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -860,6 +941,7 @@ mod tests {
             private_metadata: Some(private_meta.clone()),
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -907,6 +989,8 @@ mod tests {
             token_id: "NFT1".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -1004,6 +1088,8 @@ mod tests {
             token_id: "NFT1".to_string(),
             viewer: None,
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -1039,16 +1125,9 @@ mod tests {
             _ => panic!("unexpected"),
         }
 
-        // test owner is the viewer including expired
-        let handle_msg = HandleMsg::SetViewingKey {
-            key: "key".to_string(),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
-        let viewer = ViewerInfo {
-            address: alice.clone(),
-            viewing_key: "key".to_string(),
-        };
+        // test owner is the viewer including expired.  The global approval is written before
+        // the other approvals expire, so the write does not prune them, and then the block
+        // advances past their expirations without writing the token again
         let handle_msg = HandleMsg::SetGlobalApproval {
             token_id: Some("NFT1".to_string()),
             view_owner: Some(AccessLevel::ApproveToken),
@@ -1056,6 +1135,11 @@ mod tests {
             expires: Some(Expiration::AtHeight(10)),
             padding: None,
         };
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 1, 100), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
         let _handle_result = handle(
             &mut deps,
             Env {
@@ -1076,6 +1160,10 @@ mod tests {
             },
             handle_msg,
         );
+        let viewer = ViewerInfo {
+            address: alice.clone(),
+            viewing_key: "key".to_string(),
+        };
         let bob_tok_app = Snip721Approval {
             address: bob.clone(),
             view_owner_expiration: None,
@@ -1104,6 +1192,8 @@ mod tests {
             token_id: "NFT1".to_string(),
             viewer: Some(viewer.clone()),
             include_expired: Some(true),
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -1146,6 +1236,8 @@ mod tests {
             token_id: "NFT1".to_string(),
             viewer: Some(viewer.clone()),
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -1193,6 +1285,8 @@ mod tests {
             token_id: "NFT1".to_string(),
             viewer: Some(viewer.clone()),
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let error = extract_error_msg(query_result);
@@ -1212,6 +1306,7 @@ mod tests {
             private_metadata: Some(private_meta.clone()),
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1226,16 +1321,6 @@ mod tests {
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
-        let handle_msg = HandleMsg::SetWhitelistedApproval {
-            address: charlie.clone(),
-            token_id: Some("NFT1".to_string()),
-            view_owner: Some(AccessLevel::ApproveToken),
-            view_private_metadata: Some(AccessLevel::ApproveToken),
-            transfer: Some(AccessLevel::All),
-            expires: Some(Expiration::AtHeight(5)),
-            padding: None,
-        };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: bob.clone(),
             token_id: Some("NFT1".to_string()),
@@ -1274,6 +1359,8 @@ mod tests {
                 viewing_key: "key".to_string(),
             }),
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -1316,6 +1403,16 @@ mod tests {
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: charlie.clone(),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtHeight(5)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
 
         // test expired view private meta approval
         let query_msg = QueryMsg::NftDossier {
@@ -1325,6 +1422,8 @@ mod tests {
                 viewing_key: "ckey".to_string(),
             }),
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -1391,6 +1490,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1402,6 +1502,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1413,6 +1514,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1424,6 +1526,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1435,6 +1538,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1446,6 +1550,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1457,6 +1562,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1468,6 +1574,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1484,7 +1591,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec![
                     "NFT1".to_string(),
                     "NFT2".to_string(),
@@ -1533,7 +1640,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 assert!(tokens.is_empty());
             }
             _ => panic!("unexpected"),
@@ -1550,7 +1657,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT4".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -1597,7 +1704,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT1".to_string(), "NFT3".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -1615,7 +1722,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT1".to_string(), "NFT3".to_string(), "NFT5".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -1633,7 +1740,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT1".to_string(), "NFT2".to_string(), "NFT3".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -1669,7 +1776,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec![
                     "NFT1".to_string(),
                     "NFT2".to_string(),
@@ -1714,6 +1821,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1725,6 +1833,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1736,6 +1845,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1747,6 +1857,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1758,6 +1869,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1769,6 +1881,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1780,6 +1893,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1791,6 +1905,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1802,6 +1917,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -1875,7 +1991,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 assert!(tokens.is_empty());
             }
             _ => panic!("unexpected"),
@@ -1892,7 +2008,7 @@ mod tests {
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT9".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -2004,6 +2120,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2060,6 +2177,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2132,6 +2250,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2241,7 +2360,9 @@ mod tests {
         let error = extract_error_msg(query_result);
         assert!(error.contains("You are not authorized to view the owner of token NFT1"));
 
-        // test owner can see approvals including expired
+        // test owner can see approvals including expired.  The approvals are written before
+        // bob's expires, so these writes do not prune it, and then the block advances past its
+        // expiration without writing the token again
         let handle_msg = HandleMsg::SetGlobalApproval {
             token_id: Some("NFT1".to_string()),
             view_owner: None,
@@ -2249,7 +2370,17 @@ mod tests {
             expires: None,
             padding: None,
         };
-        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 50, 1000000), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: bob.clone(),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: Some(Expiration::AtHeight(100)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 50, 1000000), handle_msg);
         let handle_msg = HandleMsg::SetWhitelistedApproval {
             address: charlie.clone(),
             token_id: Some("NFT1".to_string()),
@@ -2259,26 +2390,12 @@ mod tests {
             expires: Some(Expiration::AtHeight(1000)),
             padding: None,
         };
-        let _handle_result = handle(
-            &mut deps,
-            Env {
-                block: BlockInfo {
-                    height: 500,
-                    time: 1000000,
-                    chain_id: "cosmos-testnet-14002".to_string(),
-                },
-                message: MessageInfo {
-                    sender: HumanAddr("alice".to_string()),
-                    sent_funds: vec![],
-                },
-                contract: cosmwasm_std::ContractInfo {
-                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
-                },
-                contract_key: Some("".to_string()),
-                contract_code_hash: "".to_string(),
-            },
-            handle_msg,
-        );
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 50, 1000000), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env_at("alice", 500, 1000000), handle_msg);
         let bob_approv = Cw721Approval {
             spender: bob.clone(),
             expires: Expiration::AtHeight(100),
@@ -2341,6 +2458,8 @@ mod tests {
         // test token not found when supply is public
         let query_msg = QueryMsg::NftInfo {
             token_id: "NFT1".to_string(),
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let error = extract_error_msg(query_result);
@@ -2357,6 +2476,8 @@ mod tests {
         // test token not found when supply is public
         let query_msg = QueryMsg::NftInfo {
             token_id: "NFT1".to_string(),
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -2372,7 +2493,7 @@ mod tests {
         }
         let alice = HumanAddr("alice".to_string());
         let public_meta = Metadata {
-            token_uri: Some("uri".to_string()),
+            token_uri: Some("https://uri".to_string()),
             extension: None,
         };
         let handle_msg = HandleMsg::MintNft {
@@ -2382,6 +2503,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2390,6 +2512,8 @@ mod tests {
         // sanity check
         let query_msg = QueryMsg::NftInfo {
             token_id: "NFT1".to_string(),
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -2424,11 +2548,11 @@ mod tests {
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
 
         let meta_for_fail = Metadata {
-            token_uri: Some("uri".to_string()),
+            token_uri: Some("https://uri".to_string()),
             extension: Some(Extension {
                 name: Some("Name1".to_string()),
                 description: Some("PubDesc1".to_string()),
-                image: Some("PubUri1".to_string()),
+                image: Some("https://PubUri1".to_string()),
                 ..Extension::default()
             }),
         };
@@ -2438,7 +2562,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("Name1".to_string()),
                 description: Some("PubDesc1".to_string()),
-                image: Some("PubUri1".to_string()),
+                image: Some("https://PubUri1".to_string()),
                 ..Extension::default()
             }),
         };
@@ -2451,6 +2575,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2465,6 +2590,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2505,6 +2631,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2563,7 +2690,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("Name1".to_string()),
                 description: Some("PrivDesc1".to_string()),
-                image: Some("PrivUri1".to_string()),
+                image: Some("https://PrivUri1".to_string()),
                 ..Extension::default()
             }),
         };
@@ -2574,6 +2701,7 @@ mod tests {
             private_metadata: Some(private_meta.clone()),
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2592,6 +2720,8 @@ mod tests {
         let query_msg = QueryMsg::PrivateMetadata {
             token_id: "NFT1".to_string(),
             viewer: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -2619,6 +2749,8 @@ mod tests {
         let query_msg = QueryMsg::PrivateMetadata {
             token_id: "NFT1".to_string(),
             viewer: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -2658,7 +2790,7 @@ mod tests {
             extension: Some(Extension {
                 name: Some("Name1".to_string()),
                 description: Some("PrivDesc1".to_string()),
-                image: Some("PrivUri1".to_string()),
+                image: Some("https://PrivUri1".to_string()),
                 ..Extension::default()
             }),
         };
@@ -2669,6 +2801,7 @@ mod tests {
             private_metadata: Some(private_meta.clone()),
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -2681,6 +2814,8 @@ mod tests {
                 address: alice.clone(),
                 viewing_key: "akey".to_string(),
             }),
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let error = extract_error_msg(query_result);
@@ -2700,6 +2835,8 @@ mod tests {
                 address: alice.clone(),
                 viewing_key: "akey".to_string(),
             }),
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -2721,6 +2858,8 @@ mod tests {
                 address: bob.clone(),
                 viewing_key: "bkey".to_string(),
             }),
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let error = extract_error_msg(query_result);
@@ -2749,12 +2888,14 @@ mod tests {
         let handle_msg = HandleMsg::ApproveAll {
             operator: bob.clone(),
             expires: None,
+            max_uses: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
         let handle_msg = HandleMsg::ApproveAll {
             operator: charlie.clone(),
             expires: None,
+            max_uses: None,
             padding: None,
         };
         let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
@@ -2853,6 +2994,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -3271,6 +3413,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -3282,6 +3425,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -3293,6 +3437,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -3304,6 +3449,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -3315,6 +3461,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -3443,6 +3590,7 @@ mod tests {
             viewing_key: "key".to_string(),
             page: None,
             page_size: None,
+            tx_type: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -3460,6 +3608,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -3471,6 +3620,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: Some("Mint 2".to_string()),
             padding: None,
         };
@@ -3541,6 +3691,7 @@ mod tests {
             viewing_key: "key".to_string(),
             page: None,
             page_size: None,
+            tx_type: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -3561,6 +3712,7 @@ mod tests {
             viewing_key: "key".to_string(),
             page: None,
             page_size: Some(2),
+            tx_type: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -3578,6 +3730,7 @@ mod tests {
             viewing_key: "key".to_string(),
             page: Some(2),
             page_size: Some(1),
+            tx_type: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -3595,6 +3748,7 @@ mod tests {
             viewing_key: "akey".to_string(),
             page: None,
             page_size: None,
+            tx_type: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -3605,6 +3759,76 @@ mod tests {
             }
             _ => panic!("unexpected"),
         }
+
+        // test filtering by type
+        let query_msg = QueryMsg::TransactionHistory {
+            address: admin.clone(),
+            viewing_key: "key".to_string(),
+            page: None,
+            page_size: None,
+            tx_type: Some(TxType::Mint),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(txs, vec![mint2.clone(), mint1.clone()]);
+                assert_eq!(total, 2);
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::TransactionHistory {
+            address: admin.clone(),
+            viewing_key: "key".to_string(),
+            page: None,
+            page_size: None,
+            tx_type: Some(TxType::Burn),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(txs, vec![burn2]);
+                assert_eq!(total, 1);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test paginating a filtered history
+        let query_msg = QueryMsg::TransactionHistory {
+            address: admin,
+            viewing_key: "key".to_string(),
+            page: Some(1),
+            page_size: Some(1),
+            tx_type: Some(TxType::Mint),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(txs, vec![mint1]);
+                assert_eq!(total, 2);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test a type with no txs
+        let query_msg = QueryMsg::TransactionHistory {
+            address: alice,
+            viewing_key: "akey".to_string(),
+            page: None,
+            page_size: None,
+            tx_type: Some(TxType::Mint),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert!(txs.is_empty());
+                assert_eq!(total, 0);
+            }
+            _ => panic!("unexpected"),
+        }
     }
 
     // test RegisteredCodeHash query
@@ -3683,4 +3907,2710 @@ mod tests {
             _ => panic!("unexpected"),
         }
     }
+
+    // test TokenTrait query
+    #[test]
+    fn test_token_trait() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let color = Trait {
+            display_type: None,
+            trait_type: Some("color".to_string()),
+            value: "red".to_string(),
+            max_value: None,
+        };
+        let color2 = Trait {
+            display_type: None,
+            trait_type: Some("color".to_string()),
+            value: "blue".to_string(),
+            max_value: None,
+        };
+        let size = Trait {
+            display_type: None,
+            trait_type: Some("size".to_string()),
+            value: "large".to_string(),
+            max_value: None,
+        };
+        let secret = Trait {
+            display_type: None,
+            trait_type: Some("secret".to_string()),
+            value: "shhh".to_string(),
+            max_value: None,
+        };
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    attributes: Some(vec![color.clone(), size.clone(), color2.clone()]),
+                    protected_attributes: Some(vec!["secret".to_string()]),
+                    ..Extension::default()
+                }),
+            }),
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    attributes: Some(vec![secret.clone()]),
+                    ..Extension::default()
+                }),
+            }),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // test token does not exist when supply is private
+        let query_msg = QueryMsg::TokenTrait {
+            token_id: "NFT2".to_string(),
+            trait_type: "color".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+
+        // test multiple matches without a viewer
+        let query_msg = QueryMsg::TokenTrait {
+            token_id: "NFT1".to_string(),
+            trait_type: "color".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenTrait { traits } => {
+                assert_eq!(traits, vec![color.clone(), color2.clone()]);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test no matches
+        let query_msg = QueryMsg::TokenTrait {
+            token_id: "NFT1".to_string(),
+            trait_type: "weight".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenTrait { traits } => {
+                assert!(traits.is_empty());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test protected attribute without a viewer
+        let query_msg = QueryMsg::TokenTrait {
+            token_id: "NFT1".to_string(),
+            trait_type: "secret".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test protected attribute when the owner views sealed metadata
+        let query_msg = QueryMsg::TokenTrait {
+            token_id: "NFT1".to_string(),
+            trait_type: "secret".to_string(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains(
+            "Sealed metadata must be unwrapped by calling Reveal before it can be viewed"
+        ));
+
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // test protected attribute when the owner views unwrapped metadata
+        let query_msg = QueryMsg::TokenTrait {
+            token_id: "NFT1".to_string(),
+            trait_type: "secret".to_string(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenTrait { traits } => {
+                assert_eq!(traits, vec![secret.clone()]);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test protected attribute after private metadata is made public
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::TokenTrait {
+            token_id: "NFT1".to_string(),
+            trait_type: "secret".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenTrait { traits } => {
+                assert_eq!(traits, vec![secret]);
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test InventoryCounts query
+    #[test]
+    fn test_inventory_counts() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let bob = HumanAddr("bob".to_string());
+        for id in &["NFT1", "NFT2", "NFT3"] {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(alice.clone()),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        for id in &["NFT1", "NFT2"] {
+            let handle_msg = HandleMsg::SetTransferLock {
+                token_id: id.to_string(),
+                locked: true,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        }
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "bkey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+
+        // test owner viewing their counts
+        let query_msg = QueryMsg::InventoryCounts {
+            owner: alice.clone(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::InventoryCounts {
+                total,
+                transfer_locked,
+                sealed,
+            } => {
+                assert_eq!(total, 3);
+                assert_eq!(transfer_locked, 2);
+                assert_eq!(sealed, 2);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test wrong viewing key
+        let query_msg = QueryMsg::InventoryCounts {
+            owner: alice.clone(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "bkey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Wrong viewing key"));
+
+        // test unauthorized viewer and no viewer
+        let query_msg = QueryMsg::InventoryCounts {
+            owner: alice.clone(),
+            viewer: Some(ViewerInfo {
+                address: bob.clone(),
+                viewing_key: "bkey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to view the inventory of alice"));
+        let query_msg = QueryMsg::InventoryCounts {
+            owner: alice.clone(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to view the inventory of alice"));
+
+        // test viewer with view_owner permission for only one token is still not authorized
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: bob.clone(),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::InventoryCounts {
+            owner: alice.clone(),
+            viewer: Some(ViewerInfo {
+                address: bob.clone(),
+                viewing_key: "bkey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to view the inventory of alice"));
+
+        // sanity check with view_owner permission for all tokens
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: bob.clone(),
+            token_id: None,
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::InventoryCounts {
+            owner: alice.clone(),
+            viewer: Some(ViewerInfo {
+                address: bob.clone(),
+                viewing_key: "bkey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::InventoryCounts { total, .. } => assert_eq!(total, 3),
+            _ => panic!("unexpected"),
+        }
+
+        // test public ownership lets anyone view the counts
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: None,
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::InventoryCounts {
+            owner: alice,
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::InventoryCounts {
+                total,
+                transfer_locked,
+                sealed,
+            } => {
+                assert_eq!(total, 3);
+                assert_eq!(transfer_locked, 2);
+                assert_eq!(sealed, 2);
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test ProtectedAttributeTypes query
+    #[test]
+    fn test_protected_attribute_types() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    protected_attributes: Some(vec!["secret".to_string()]),
+                    ..Extension::default()
+                }),
+            }),
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    attributes: Some(vec![Trait {
+                        display_type: None,
+                        trait_type: Some("secret".to_string()),
+                        value: "shhh".to_string(),
+                        max_value: None,
+                    }]),
+                    ..Extension::default()
+                }),
+            }),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(Metadata {
+                token_uri: Some("https://uri".to_string()),
+                extension: None,
+            }),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test token does not exist when supply is public
+        let query_msg = QueryMsg::ProtectedAttributeTypes {
+            token_id: "NFT3".to_string(),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Token ID: NFT3 not found"));
+
+        // test protected trait_types are displayed without a viewer
+        let query_msg = QueryMsg::ProtectedAttributeTypes {
+            token_id: "NFT1".to_string(),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ProtectedAttributeTypes {
+                protected_attributes,
+            } => assert_eq!(protected_attributes, vec!["secret".to_string()]),
+            _ => panic!("unexpected"),
+        }
+        // but the values are still private
+        let query_msg = QueryMsg::TokenTrait {
+            token_id: "NFT1".to_string(),
+            trait_type: "secret".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+        let query_msg = QueryMsg::PrivateMetadata {
+            token_id: "NFT1".to_string(),
+            viewer: None,
+            ipfs_gateway: None,
+            locale: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test token without a public extension
+        let query_msg = QueryMsg::ProtectedAttributeTypes {
+            token_id: "NFT2".to_string(),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ProtectedAttributeTypes {
+                protected_attributes,
+            } => assert!(protected_attributes.is_empty()),
+            _ => panic!("unexpected"),
+        }
+
+        // test token does not exist when supply is private
+        let (init_result, deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_msg = QueryMsg::ProtectedAttributeTypes {
+            token_id: "NFT1".to_string(),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ProtectedAttributeTypes {
+                protected_attributes,
+            } => assert!(protected_attributes.is_empty()),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test MediaHashes query
+    #[test]
+    fn test_media_hashes() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let bob = HumanAddr("bob".to_string());
+        let pub_hash = MediaHash {
+            url: "https://public".to_string(),
+            sha256: Some("a".repeat(64)),
+        };
+        let priv_hash = MediaHash {
+            url: "https://private".to_string(),
+            sha256: Some("b".repeat(64)),
+        };
+        let media_meta = |hash: &MediaHash| Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                media: Some(vec![MediaFile {
+                    file_type: None,
+                    extension: None,
+                    authentication: None,
+                    url: hash.url.clone(),
+                    sha256: hash.sha256.clone(),
+                }]),
+                ..Extension::default()
+            }),
+        };
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: Some(media_meta(&pub_hash)),
+            private_metadata: Some(media_meta(&priv_hash)),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "bkey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+
+        // test token does not exist when supply is private
+        let query_msg = QueryMsg::MediaHashes {
+            token_id: "NFT2".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+
+        // test no viewer only sees public media
+        let query_msg = QueryMsg::MediaHashes {
+            token_id: "NFT1".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MediaHashes {
+                public_media,
+                private_media,
+            } => {
+                assert_eq!(public_media, vec![pub_hash.clone()]);
+                assert!(private_media.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test unauthorized viewer only sees public media
+        let query_msg = QueryMsg::MediaHashes {
+            token_id: "NFT1".to_string(),
+            viewer: Some(ViewerInfo {
+                address: bob.clone(),
+                viewing_key: "bkey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MediaHashes { private_media, .. } => assert!(private_media.is_none()),
+            _ => panic!("unexpected"),
+        }
+
+        // test owner sees private media
+        let query_msg = QueryMsg::MediaHashes {
+            token_id: "NFT1".to_string(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MediaHashes {
+                public_media,
+                private_media,
+            } => {
+                assert_eq!(public_media, vec![pub_hash]);
+                assert_eq!(private_media, Some(vec![priv_hash.clone()]));
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test whitelisted viewer sees private media
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: bob.clone(),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::MediaHashes {
+            token_id: "NFT1".to_string(),
+            viewer: Some(ViewerInfo {
+                address: bob,
+                viewing_key: "bkey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MediaHashes { private_media, .. } => {
+                assert_eq!(private_media, Some(vec![priv_hash]))
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test batch public dossier matches the public subset of the full dossier
+    #[test]
+    fn test_batch_public_dossier() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let public_meta = Metadata {
+            token_uri: Some("https://public.info".to_string()),
+            extension: None,
+        };
+        let private_meta = Metadata {
+            token_uri: Some("https://private.info".to_string()),
+            extension: None,
+        };
+        for id in ["NFT1", "NFT2"].iter() {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(alice.clone()),
+                public_metadata: Some(public_meta.clone()),
+                private_metadata: Some(private_meta.clone()),
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // test token does not exist when supply is private
+        let query_msg = QueryMsg::BatchPublicDossier {
+            token_ids: vec!["NFT1".to_string(), "NFT3".to_string()],
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT3"));
+
+        let query_msg = QueryMsg::BatchPublicDossier {
+            token_ids: vec!["NFT1".to_string(), "NFT2".to_string()],
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        let dossiers = match query_answer {
+            QueryAnswer::BatchPublicDossier { dossiers } => dossiers,
+            _ => panic!("unexpected"),
+        };
+        assert_eq!(dossiers.len(), 2);
+        assert_eq!(dossiers[0].owner, Some(alice));
+        assert!(dossiers[0].private_metadata_is_public);
+        assert_eq!(dossiers[1].owner, None);
+        assert!(!dossiers[1].owner_is_public);
+        for dossier in dossiers.iter() {
+            let query_msg = QueryMsg::NftDossier {
+                token_id: dossier.token_id.clone(),
+                viewer: None,
+                include_expired: None,
+                ipfs_gateway: None,
+                locale: None,
+            };
+            let query_result = query(&deps, query_msg);
+            let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::NftDossier {
+                    owner,
+                    public_metadata,
+                    royalty_info,
+                    mint_run_info,
+                    owner_is_public,
+                    public_ownership_expiration,
+                    private_metadata_is_public,
+                    private_metadata_is_public_expiration,
+                    ..
+                } => {
+                    assert_eq!(dossier.owner, owner);
+                    assert_eq!(dossier.public_metadata, public_metadata);
+                    assert_eq!(dossier.royalty_info, royalty_info);
+                    assert_eq!(dossier.mint_run_info, mint_run_info);
+                    assert_eq!(dossier.owner_is_public, owner_is_public);
+                    assert_eq!(
+                        dossier.public_ownership_expiration,
+                        public_ownership_expiration
+                    );
+                    assert_eq!(
+                        dossier.private_metadata_is_public,
+                        private_metadata_is_public
+                    );
+                    assert_eq!(
+                        dossier.private_metadata_is_public_expiration,
+                        private_metadata_is_public_expiration
+                    );
+                }
+                _ => panic!("unexpected"),
+            }
+        }
+    }
+
+    // test displaying ipfs urls with a gateway
+    #[test]
+    fn test_ipfs_gateway() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let public_meta = Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                image: Some("ipfs://QmImage".to_string()),
+                external_url: Some("https://mysite.com".to_string()),
+                media: Some(vec![MediaFile {
+                    file_type: None,
+                    extension: None,
+                    authentication: None,
+                    url: "ipfs://QmMedia".to_string(),
+                    sha256: None,
+                }]),
+                ..Extension::default()
+            }),
+        };
+        let private_meta = Metadata {
+            token_uri: Some("ipfs://QmPrivate".to_string()),
+            extension: None,
+        };
+
+        // test an ipfs url without a CID is rejected
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: Some(Metadata {
+                token_uri: Some("ipfs://".to_string()),
+                extension: None,
+            }),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Invalid token_uri: ipfs://.  ipfs urls must include a CID"));
+
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: Some(public_meta.clone()),
+            private_metadata: Some(private_meta.clone()),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // test a gateway that is not https
+        let query_msg = QueryMsg::NftInfo {
+            token_id: "NFT2".to_string(),
+            ipfs_gateway: Some("http://gateway.io".to_string()),
+            locale: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains(
+            "Invalid ipfs gateway: http://gateway.io.  Gateways must be prefixed with https://"
+        ));
+
+        // test no gateway leaves urls unchanged
+        let query_msg = QueryMsg::NftInfo {
+            token_id: "NFT2".to_string(),
+            ipfs_gateway: None,
+            locale: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftInfo { extension, .. } => {
+                assert_eq!(extension, public_meta.extension)
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test ipfs urls are rewritten and other urls are left alone
+        let query_msg = QueryMsg::NftInfo {
+            token_id: "NFT2".to_string(),
+            ipfs_gateway: Some("https://gateway.io/ipfs/".to_string()),
+            locale: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftInfo { extension, .. } => {
+                let ext = extension.unwrap();
+                assert_eq!(
+                    ext.image,
+                    Some("https://gateway.io/ipfs/QmImage".to_string())
+                );
+                assert_eq!(ext.external_url, Some("https://mysite.com".to_string()));
+                assert_eq!(
+                    ext.media.unwrap()[0].url,
+                    "https://gateway.io/ipfs/QmMedia".to_string()
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::PrivateMetadata {
+            token_id: "NFT2".to_string(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            }),
+            ipfs_gateway: Some("https://gateway.io/ipfs".to_string()),
+            locale: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PrivateMetadata { token_uri, .. } => {
+                assert_eq!(
+                    token_uri,
+                    Some("https://gateway.io/ipfs/QmPrivate".to_string())
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "NFT2".to_string(),
+            viewer: Some(ViewerInfo {
+                address: alice,
+                viewing_key: "akey".to_string(),
+            }),
+            include_expired: None,
+            ipfs_gateway: Some("https://gateway.io/ipfs".to_string()),
+            locale: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftDossier {
+                public_metadata,
+                private_metadata,
+                ..
+            } => {
+                assert_eq!(
+                    public_metadata.unwrap().extension.unwrap().image,
+                    Some("https://gateway.io/ipfs/QmImage".to_string())
+                );
+                assert_eq!(
+                    private_metadata.unwrap().token_uri,
+                    Some("https://gateway.io/ipfs/QmPrivate".to_string())
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test VerifyOwnership query
+    #[test]
+    fn test_verify_ownership() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let bob = HumanAddr("bob".to_string());
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        for addr in &["alice", "bob"] {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env(*addr, &[]), handle_msg);
+        }
+        let viewer = |address: &HumanAddr| {
+            Some(ViewerInfo {
+                address: address.clone(),
+                viewing_key: "key".to_string(),
+            })
+        };
+        let verify = |token_id: &str, claimed_owner: &HumanAddr, viewer: Option<ViewerInfo>| {
+            QueryMsg::VerifyOwnership {
+                token_id: token_id.to_string(),
+                claimed_owner: claimed_owner.clone(),
+                viewer,
+            }
+        };
+
+        // test an unauthenticated query can not probe private ownership
+        let query_result = query(&deps, verify("NFT1", &alice, None));
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test a wrong viewing key is rejected
+        let query_msg = QueryMsg::VerifyOwnership {
+            token_id: "NFT1".to_string(),
+            claimed_owner: alice.clone(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "wrong".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Wrong viewing key"));
+
+        // test the claimed owner can verify they own the token
+        let query_result = query(&deps, verify("NFT1", &alice, viewer(&alice)));
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::VerifyOwnership { is_owner } => assert!(is_owner),
+            _ => panic!("unexpected"),
+        }
+
+        // test the claimed owner can verify they do not own the token
+        let query_result = query(&deps, verify("NFT1", &bob, viewer(&bob)));
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::VerifyOwnership { is_owner } => assert!(!is_owner),
+            _ => panic!("unexpected"),
+        }
+
+        // test someone else without permission to view the owner can not verify a claim
+        let query_result = query(&deps, verify("NFT1", &alice, viewer(&bob)));
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test someone permitted to view the owner can verify a claim
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: bob.clone(),
+            token_id: Some("NFT1".to_string()),
+            view_owner: Some(AccessLevel::ApproveToken),
+            view_private_metadata: None,
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_result = query(&deps, verify("NFT1", &alice, viewer(&bob)));
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::VerifyOwnership { is_owner } => assert!(is_owner),
+            _ => panic!("unexpected"),
+        }
+
+        // test nonexistent token when supply is private does not leak that it does not exist
+        let query_result = query(&deps, verify("NFT2", &alice, viewer(&alice)));
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+
+        // test anyone can verify a claim when ownership is public
+        let (init_result, mut deps) =
+            init_helper_with_config(true, true, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let query_result = query(&deps, verify("NFT1", &bob, None));
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::VerifyOwnership { is_owner } => assert!(!is_owner),
+            _ => panic!("unexpected"),
+        }
+
+        // test nonexistent token when supply is public
+        let query_result = query(&deps, verify("NFT2", &alice, None));
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Token ID: NFT2 not found"));
+    }
+
+    // test paginating AllTokens with the next cursor
+    #[test]
+    fn test_all_tokens_cursor() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        // token ids that do not sort in minting order
+        let mints: Vec<Mint> = (0..255)
+            .map(|i| Mint {
+                token_id: Some(format!("Token{}", 254 - i)),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                serial_number: None,
+                royalty_info: None,
+                reveal_commitment: None,
+                memo: None,
+            })
+            .collect();
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        // burn some tokens to leave gaps, including the last one minted
+        let handle_msg = HandleMsg::BatchBurnNft {
+            burns: vec![Burn {
+                token_ids: vec![
+                    "Token200".to_string(),
+                    "Token150".to_string(),
+                    "Token101".to_string(),
+                    "Token100".to_string(),
+                    "Token0".to_string(),
+                ],
+                memo: None,
+            }],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        let mut all: Vec<String> = Vec::new();
+        let mut start_after: Option<String> = None;
+        let mut pages = 0;
+        loop {
+            let query_msg = QueryMsg::AllTokens {
+                viewer: None,
+                start_after: start_after.clone(),
+                limit: Some(100),
+            };
+            let query_result = query(&deps, query_msg);
+            let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+            let (tokens, next) = match query_answer {
+                QueryAnswer::TokenList { tokens, next } => (tokens, next),
+                _ => panic!("unexpected"),
+            };
+            pages += 1;
+            // test the cursor is stable across calls
+            let query_msg = QueryMsg::AllTokens {
+                viewer: None,
+                start_after: start_after.clone(),
+                limit: Some(100),
+            };
+            let query_result = query(&deps, query_msg);
+            let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::TokenList {
+                    tokens: again,
+                    next: next_again,
+                } => {
+                    assert_eq!(again, tokens);
+                    assert_eq!(next_again, next);
+                }
+                _ => panic!("unexpected"),
+            }
+            all.extend(tokens);
+            if next.is_none() {
+                break;
+            }
+            start_after = next;
+        }
+        assert_eq!(pages, 3);
+        assert_eq!(all.len(), 250);
+        let expected: Vec<String> = (1..255)
+            .rev()
+            .filter(|i| ![200, 150, 101, 100].contains(i))
+            .map(|i| format!("Token{}", i))
+            .collect();
+        assert_eq!(all, expected);
+
+        // test no cursor when the last page is exactly full
+        let query_msg = QueryMsg::AllTokens {
+            viewer: None,
+            start_after: Some(all[199].clone()),
+            limit: Some(50),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens, next } => {
+                assert_eq!(tokens.len(), 50);
+                assert_eq!(next, None);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test the Tokens query cursor
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::Tokens {
+            owner: HumanAddr("alice".to_string()),
+            viewer: None,
+            viewing_key: Some("akey".to_string()),
+            start_after: None,
+            limit: Some(249),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        let cursor = match query_answer {
+            QueryAnswer::TokenList { tokens, next } => {
+                assert_eq!(tokens.len(), 249);
+                assert_eq!(next, tokens.last().cloned());
+                next
+            }
+            _ => panic!("unexpected"),
+        };
+        let query_msg = QueryMsg::Tokens {
+            owner: HumanAddr("alice".to_string()),
+            viewer: None,
+            viewing_key: Some("akey".to_string()),
+            start_after: cursor,
+            limit: Some(249),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens, next } => {
+                assert_eq!(tokens.len(), 1);
+                assert_eq!(next, None);
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test ExportTokens query
+    #[test]
+    fn test_export_tokens() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, true, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let royalty_info = RoyaltyInfo {
+            decimal_places_in_rates: 2,
+            royalties: vec![Royalty {
+                recipient: HumanAddr("artist".to_string()),
+                rate: 5,
+                flat_fee: None,
+            }],
+        };
+        let public_meta = Metadata {
+            token_uri: Some("https://public.info".to_string()),
+            extension: None,
+        };
+        let private_meta = Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("Secret".to_string()),
+                ..Extension::default()
+            }),
+        };
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: Some(public_meta.clone()),
+            private_metadata: Some(private_meta.clone()),
+            royalty_info: Some(royalty_info.clone()),
+            serial_number: Some(SerialNumber {
+                mint_run: Some(2),
+                serial_number: 7,
+                quantity_minted_this_run: Some(10),
+            }),
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "NFT1".to_string(),
+            locked: true,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        for (addr, key) in [("alice", "akey"), ("admin", "key")].iter() {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: key.to_string(),
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env(*addr, &[]), handle_msg);
+        }
+
+        // test a non-minter can not export, even if they own the tokens
+        let query_msg = QueryMsg::ExportTokens {
+            viewer: ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            },
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Only the admin and designated minters may export tokens"));
+
+        // test paginating the export
+        let query_msg = QueryMsg::ExportTokens {
+            viewer: ViewerInfo {
+                address: HumanAddr("admin".to_string()),
+                viewing_key: "key".to_string(),
+            },
+            start_after: None,
+            limit: Some(1),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        let exported = match query_answer {
+            QueryAnswer::ExportTokens { mut tokens, next } => {
+                assert_eq!(tokens.len(), 1);
+                assert_eq!(next, Some("NFT1".to_string()));
+                tokens.pop().unwrap()
+            }
+            _ => panic!("unexpected"),
+        };
+        let query_msg = QueryMsg::ExportTokens {
+            viewer: ViewerInfo {
+                address: HumanAddr("admin".to_string()),
+                viewing_key: "key".to_string(),
+            },
+            start_after: Some("NFT1".to_string()),
+            limit: Some(1),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ExportTokens { tokens, next } => {
+                assert_eq!(tokens.len(), 1);
+                assert_eq!(tokens[0].token_id, "NFT2".to_string());
+                assert_eq!(next, None);
+            }
+            _ => panic!("unexpected"),
+        }
+        assert_eq!(exported.token_id, "NFT1".to_string());
+        assert_eq!(exported.owner, alice);
+        assert_eq!(exported.public_metadata, Some(public_meta));
+        assert_eq!(exported.private_metadata, Some(private_meta));
+        assert!(exported.token_is_unwrapped);
+        assert!(exported.transfer_locked);
+
+        // test re-minting the exported token in a new contract yields the same export
+        let (init_result, mut new_deps) =
+            init_helper_with_config(false, false, false, false, true, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let mint_run = exported.mint_run_info.clone().unwrap();
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some(exported.token_id.clone()),
+            owner: Some(exported.owner.clone()),
+            public_metadata: exported.public_metadata.clone(),
+            private_metadata: exported.private_metadata.clone(),
+            royalty_info: exported.royalty_info.clone().map(|r| RoyaltyInfo {
+                decimal_places_in_rates: r.decimal_places_in_rates,
+                royalties: r
+                    .royalties
+                    .into_iter()
+                    .map(|d| Royalty {
+                        recipient: d.recipient.unwrap(),
+                        rate: d.rate,
+                        flat_fee: None,
+                    })
+                    .collect(),
+            }),
+            serial_number: Some(SerialNumber {
+                mint_run: mint_run.mint_run,
+                serial_number: mint_run.serial_number.unwrap(),
+                quantity_minted_this_run: mint_run.quantity_minted_this_run,
+            }),
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut new_deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "NFT1".to_string(),
+            locked: exported.transfer_locked,
+            padding: None,
+        };
+        let _handle_result = handle(&mut new_deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut new_deps, mock_env("admin", &[]), handle_msg);
+        let query_msg = QueryMsg::ExportTokens {
+            viewer: ViewerInfo {
+                address: HumanAddr("admin".to_string()),
+                viewing_key: "key".to_string(),
+            },
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(&new_deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ExportTokens { tokens, next } => {
+                assert_eq!(tokens, vec![exported]);
+                assert_eq!(next, None);
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test RarityScore query
+    #[test]
+    fn test_rarity_score() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let traits = |eyes: &str| Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                attributes: Some(vec![
+                    Trait {
+                        display_type: None,
+                        trait_type: Some("Eyes".to_string()),
+                        value: eyes.to_string(),
+                        max_value: None,
+                    },
+                    Trait {
+                        display_type: None,
+                        trait_type: Some("Hat".to_string()),
+                        value: "Cap".to_string(),
+                        max_value: None,
+                    },
+                ]),
+                ..Extension::default()
+            }),
+        };
+        for (id, eyes) in vec![("NFT1", "Blue"), ("NFT2", "Blue"), ("NFT3", "Red")].into_iter() {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: Some(traits(eyes)),
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let score = |deps: &Extern<MockStorage, MockApi, MockQuerier>, id: &str| {
+            let query_msg = QueryMsg::RarityScore {
+                token_id: id.to_string(),
+            };
+            let query_result = query(deps, query_msg);
+            match from_binary(&query_result.unwrap()).unwrap() {
+                QueryAnswer::RarityScore { score } => score,
+                _ => panic!("unexpected"),
+            }
+        };
+
+        // Blue is held by 2 tokens, Red by 1, and Cap by 3
+        assert_eq!(score(&deps, "NFT1"), 500000 + 333333);
+        assert_eq!(score(&deps, "NFT2"), 500000 + 333333);
+        assert_eq!(score(&deps, "NFT3"), 1000000 + 333333);
+        assert!(score(&deps, "NFT3") > score(&deps, "NFT1"));
+
+        // test burning updates the frequencies
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(score(&deps, "NFT1"), 1000000 + 500000);
+
+        // test changing the public metadata updates the frequencies
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(traits("Red")),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(score(&deps, "NFT1"), 500000 + 500000);
+        assert_eq!(score(&deps, "NFT3"), 500000 + 500000);
+
+        // test a nonexistent token when the supply is public
+        let query_msg = QueryMsg::RarityScore {
+            token_id: "NFT2".to_string(),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Token ID: NFT2 not found"));
+    }
+
+    // test querying whether a token can be transferred
+    #[test]
+    fn test_is_transferable() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let check =
+            |deps: &Extern<MockStorage, MockApi, MockQuerier>, id: &str, expected: Option<&str>| {
+                let query_msg = QueryMsg::IsTransferable {
+                    token_id: id.to_string(),
+                };
+                let query_result = query(deps, query_msg);
+                let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+                match query_answer {
+                    QueryAnswer::IsTransferable {
+                        transferable,
+                        reason,
+                    } => {
+                        assert_eq!(transferable, expected.is_none());
+                        assert_eq!(reason.as_deref(), expected);
+                    }
+                    _ => panic!("unexpected"),
+                }
+            };
+
+        // test a transferable token
+        check(&deps, "NFT1", None);
+        // test nonexistent token when supply is private
+        check(&deps, "NFT2", None);
+
+        // test a locked token
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "NFT1".to_string(),
+            locked: true,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        check(&deps, "NFT1", Some("locked"));
+
+        // test the contract status takes precedence over the lock
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        check(&deps, "NFT1", Some("contract_paused"));
+        let handle_msg = HandleMsg::SetContractStatus {
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "NFT1".to_string(),
+            locked: false,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        check(&deps, "NFT1", None);
+
+        // test nonexistent token when supply is public
+        let (init_result, deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_msg = QueryMsg::IsTransferable {
+            token_id: "NFT2".to_string(),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Token ID: NFT2 not found"));
+    }
+
+    // test BatchIsUnwrapped query
+    #[test]
+    fn test_batch_is_unwrapped() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, true, true, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for id in &["NFT1", "NFT2"] {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "NFT2".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+
+        // test the statuses are in the requested order, and a nonexistent token is
+        // reported as newly minted when supply is private
+        let query_msg = QueryMsg::BatchIsUnwrapped {
+            token_ids: vec!["NFT2".to_string(), "NFT1".to_string(), "NFT9".to_string()],
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::BatchIsUnwrapped { statuses } => {
+                let expected = vec![
+                    UnwrappedStatus {
+                        token_id: "NFT2".to_string(),
+                        unwrapped: true,
+                    },
+                    UnwrappedStatus {
+                        token_id: "NFT1".to_string(),
+                        unwrapped: false,
+                    },
+                    UnwrappedStatus {
+                        token_id: "NFT9".to_string(),
+                        unwrapped: false,
+                    },
+                ];
+                assert_eq!(statuses, expected);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test a nonexistent token is an error when supply is public
+        let (init_result, deps) =
+            init_helper_with_config(true, true, true, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_msg = QueryMsg::BatchIsUnwrapped {
+            token_ids: vec!["NFT9".to_string()],
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Token ID: NFT9 not found"));
+    }
+
+    // test TokensByTrait query
+    #[test]
+    fn test_tokens_by_trait() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let colored = |color: &str, protected: bool| Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                attributes: Some(vec![Trait {
+                    display_type: None,
+                    trait_type: Some("color".to_string()),
+                    value: color.to_string(),
+                    max_value: None,
+                }]),
+                protected_attributes: if protected {
+                    Some(vec!["color".to_string()])
+                } else {
+                    None
+                },
+                ..Extension::default()
+            }),
+        };
+        for (id, color, protected) in &[
+            ("NFT1", "red", false),
+            ("NFT2", "blue", false),
+            ("NFT3", "red", false),
+            ("NFT4", "red", true),
+        ] {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: Some(colored(color, *protected)),
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let search = |deps: &Extern<MockStorage, MockApi, MockQuerier>,
+                      value: &str,
+                      start_after: Option<&str>,
+                      limit: Option<u32>| {
+            let query_msg = QueryMsg::TokensByTrait {
+                trait_type: "color".to_string(),
+                value: value.to_string(),
+                viewer: None,
+                start_after: start_after.map(|s| s.to_string()),
+                limit,
+            };
+            let query_result = query(deps, query_msg);
+            match from_binary(&query_result.unwrap()).unwrap() {
+                QueryAnswer::TokenList { tokens, next } => (tokens, next),
+                _ => panic!("unexpected"),
+            }
+        };
+
+        // test a trait shared by several tokens, skipping the protected one
+        assert_eq!(
+            search(&deps, "red", None, None),
+            (vec!["NFT1".to_string(), "NFT3".to_string()], None)
+        );
+        assert_eq!(
+            search(&deps, "blue", None, None),
+            (vec!["NFT2".to_string()], None)
+        );
+        assert_eq!(search(&deps, "green", None, None), (vec![], None));
+
+        // test pagination
+        assert_eq!(
+            search(&deps, "red", None, Some(1)),
+            (vec!["NFT1".to_string()], Some("NFT1".to_string()))
+        );
+        assert_eq!(
+            search(&deps, "red", Some("NFT1"), Some(1)),
+            (vec!["NFT3".to_string()], None)
+        );
+
+        // test a patch that removes the trait updates the index
+        let handle_msg = HandleMsg::PatchMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(MetadataPatch {
+                attributes: Some(vec![]),
+                ..MetadataPatch::default()
+            }),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(
+            search(&deps, "red", None, None),
+            (vec!["NFT3".to_string()], None)
+        );
+
+        // test changing a trait value and burning update the index
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT2".to_string(),
+            public_metadata: Some(colored("red", false)),
+            private_metadata: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert_eq!(search(&deps, "blue", None, None), (vec![], None));
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT3".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert_eq!(
+            search(&deps, "red", None, None),
+            (vec!["NFT2".to_string()], None)
+        );
+
+        // test protected attributes are left out of the counts as well as the index
+        let query_msg = QueryMsg::RarityScore {
+            token_id: "NFT4".to_string(),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::RarityScore { score } => assert_eq!(score, 0),
+            _ => panic!("unexpected"),
+        }
+
+        // test searching across index shards
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: (0..70)
+                .map(|i| Mint {
+                    token_id: Some(format!("Green{}", i)),
+                    owner: Some(HumanAddr("alice".to_string())),
+                    public_metadata: Some(colored("green", false)),
+                    private_metadata: None,
+                    royalty_info: None,
+                    serial_number: None,
+                    reveal_commitment: None,
+                    memo: None,
+                })
+                .collect(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let (tokens, next) = search(&deps, "green", None, Some(65));
+        assert_eq!(tokens.len(), 65);
+        assert_eq!(next, Some("Green64".to_string()));
+        assert_eq!(
+            search(&deps, "green", Some("Green64"), None),
+            ((65..70).map(|i| format!("Green{}", i)).collect(), None)
+        );
+        assert_eq!(
+            search(&deps, "green", Some("Green58"), Some(2)),
+            (
+                vec!["Green59".to_string(), "Green60".to_string()],
+                Some("Green60".to_string())
+            )
+        );
+
+        // test private token supply requires a minter
+        let (init_result, deps) =
+            init_helper_with_config(false, false, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_msg = QueryMsg::TokensByTrait {
+            trait_type: "color".to_string(),
+            value: "red".to_string(),
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("The token supply of this contract is private"));
+    }
+
+    // test CollectionStats query
+    #[test]
+    fn test_query_collection_stats() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let check = |deps: &Extern<MockStorage, MockApi, MockQuerier>,
+                     tokens: u32,
+                     burned: u32,
+                     owners: u32| {
+            let query_msg = QueryMsg::CollectionStats { viewer: None };
+            let query_result = query(deps, query_msg);
+            let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::CollectionStats {
+                    token_count,
+                    burned_count,
+                    owner_count,
+                } => {
+                    assert_eq!(token_count, tokens);
+                    assert_eq!(burned_count, burned);
+                    assert_eq!(owner_count, owners);
+                }
+                _ => panic!("unexpected"),
+            }
+        };
+
+        // test an empty collection
+        check(&deps, 0, 0, 0);
+
+        for (id, owner) in &[("NFT1", "alice"), ("NFT2", "alice"), ("NFT3", "bob")] {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr(owner.to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        check(&deps, 3, 0, 2);
+
+        // test bob leaving the owner count after transferring his only token
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("alice".to_string()),
+            token_id: "NFT3".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        check(&deps, 3, 0, 1);
+
+        // test a transfer to a new owner
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        check(&deps, 3, 0, 2);
+
+        // test burns
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        check(&deps, 2, 1, 2);
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        check(&deps, 1, 2, 1);
+
+        // test a private supply requires a minter
+        let (init_result, deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_msg = QueryMsg::CollectionStats { viewer: None };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("The token supply of this contract is private"));
+    }
+
+    // test RevokedPermits query
+    #[test]
+    fn test_query_revoked_permits() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let check = |deps: &Extern<MockStorage, MockApi, MockQuerier>, expected: &[&str]| {
+            let query_msg = QueryMsg::RevokedPermits {
+                address: HumanAddr("alice".to_string()),
+                viewing_key: "akey".to_string(),
+            };
+            let query_result = query(deps, query_msg);
+            let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::RevokedPermits { permit_names } => {
+                    assert_eq!(permit_names, expected);
+                }
+                _ => panic!("unexpected"),
+            }
+        };
+
+        // test nothing revoked
+        check(&deps, &[]);
+
+        // test revocations are listed once each, and only for the signer
+        for (name, sender) in &[
+            ("leaked", "alice"),
+            ("old", "alice"),
+            ("leaked", "alice"),
+            ("bobs", "bob"),
+        ] {
+            let handle_msg = HandleMsg::RevokePermit {
+                permit_name: name.to_string(),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env(*sender, &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+        check(&deps, &["leaked", "old"]);
+
+        // test a wrong viewing key
+        let query_msg = QueryMsg::RevokedPermits {
+            address: HumanAddr("alice".to_string()),
+            viewing_key: "wrong".to_string(),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Wrong viewing key"));
+    }
+
+    // test OperatorTokens query
+    #[test]
+    fn test_query_operator_tokens() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for id in &["NFT1", "NFT2", "NFT3", "NFT4"] {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        for (user, key) in &[("alice", "akey"), ("bob", "bkey"), ("charlie", "ckey")] {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: key.to_string(),
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env(*user, &[]), handle_msg);
+        }
+        // bob may transfer NFT1 and NFT3, and NFT2 until height 20000
+        for (id, expires) in &[
+            ("NFT1", None),
+            ("NFT2", Some(Expiration::AtHeight(20000))),
+            ("NFT3", None),
+        ] {
+            let handle_msg = HandleMsg::Approve {
+                spender: HumanAddr("bob".to_string()),
+                token_id: id.to_string(),
+                expires: *expires,
+                max_uses: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        }
+        // charlie may transfer the whole inventory
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: HumanAddr("charlie".to_string()),
+            expires: None,
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // NFT3 is locked
+        let handle_msg = HandleMsg::SetTransferLock {
+            token_id: "NFT3".to_string(),
+            locked: true,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let list = |deps: &Extern<MockStorage, MockApi, MockQuerier>,
+                    operator: &str,
+                    viewer: &str,
+                    key: &str,
+                    start_after: Option<&str>,
+                    limit: Option<u32>| {
+            let query_msg = QueryMsg::OperatorTokens {
+                owner: HumanAddr("alice".to_string()),
+                operator: HumanAddr(operator.to_string()),
+                viewer: Some(ViewerInfo {
+                    address: HumanAddr(viewer.to_string()),
+                    viewing_key: key.to_string(),
+                }),
+                start_after: start_after.map(|s| s.to_string()),
+                limit,
+            };
+            query(deps, query_msg).map(|b| match from_binary(&b).unwrap() {
+                QueryAnswer::TokenList { tokens, next } => (tokens, next),
+                _ => panic!("unexpected"),
+            })
+        };
+
+        // test per-token approvals exclude locked tokens
+        let (tokens, next) = list(&deps, "bob", "bob", "bkey", None, None).unwrap();
+        assert_eq!(tokens, vec!["NFT1".to_string(), "NFT2".to_string()]);
+        assert_eq!(next, None);
+        // test the owner may ask about an operator
+        let (tokens, _) = list(&deps, "bob", "alice", "akey", None, None).unwrap();
+        assert_eq!(tokens, vec!["NFT1".to_string(), "NFT2".to_string()]);
+
+        // test an inventory-wide approval, with pagination
+        let (tokens, next) = list(&deps, "charlie", "charlie", "ckey", None, Some(2)).unwrap();
+        assert_eq!(tokens, vec!["NFT1".to_string(), "NFT2".to_string()]);
+        assert_eq!(next, Some("NFT2".to_string()));
+        let (tokens, next) =
+            list(&deps, "charlie", "charlie", "ckey", Some("NFT2"), Some(2)).unwrap();
+        assert_eq!(tokens, vec!["NFT4".to_string()]);
+        assert_eq!(next, None);
+
+        // test a third party may not query
+        let error = extract_error_msg(list(&deps, "bob", "charlie", "ckey", None, None));
+        assert!(error.contains("Only the owner or the operator may view"));
+        // test a cursor the operator may not transfer
+        let error = extract_error_msg(list(&deps, "bob", "bob", "bkey", Some("NFT4"), None));
+        assert!(error.contains("Token ID: NFT4 is not one of the operator's tokens"));
+
+        // test an expired approval is excluded
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "bkey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 30000,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: HumanAddr("bob".to_string()),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+        let (tokens, _) = list(&deps, "bob", "bob", "bkey", None, None).unwrap();
+        assert_eq!(tokens, vec!["NFT1".to_string()]);
+    }
+
+    // test TokenUri query
+    #[test]
+    fn test_query_token_uri() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let bob = HumanAddr("bob".to_string());
+        let uri_meta = |uri: &str| Metadata {
+            token_uri: Some(uri.to_string()),
+            extension: None,
+        };
+        let ext_meta = Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some("Name1".to_string()),
+                ..Extension::default()
+            }),
+        };
+        let mint =
+            |id: &str, public: Option<Metadata>, private: Option<Metadata>| HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(alice.clone()),
+                public_metadata: public,
+                private_metadata: private,
+                royalty_info: None,
+                serial_number: None,
+                reveal_commitment: None,
+                memo: None,
+                padding: None,
+            };
+        let _handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint("URI", Some(uri_meta("https://public")), None),
+        );
+        let _handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint("EXT", Some(ext_meta.clone()), Some(ext_meta)),
+        );
+        let _handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint("PRIV", None, Some(uri_meta("https://private"))),
+        );
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "bkey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let token_uri = |deps: &Extern<MockStorage, MockApi, MockQuerier>,
+                         id: &str,
+                         viewer: Option<ViewerInfo>| {
+            let query_msg = QueryMsg::TokenUri {
+                token_id: id.to_string(),
+                viewer,
+            };
+            let query_answer: QueryAnswer = from_binary(&query(deps, query_msg).unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::TokenUri { token_uri } => token_uri,
+                _ => panic!("unexpected"),
+            }
+        };
+        let alice_viewer = || {
+            Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            })
+        };
+        let bob_viewer = || {
+            Some(ViewerInfo {
+                address: bob.clone(),
+                viewing_key: "bkey".to_string(),
+            })
+        };
+
+        // test token does not exist when supply is private
+        let query_msg = QueryMsg::TokenUri {
+            token_id: "NFT2".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+
+        // test public uri-mode token is visible to anyone
+        assert_eq!(
+            token_uri(&deps, "URI", None),
+            Some("https://public".to_string())
+        );
+        // test extension-mode token has no uri, even for the owner
+        assert_eq!(token_uri(&deps, "EXT", None), None);
+        assert_eq!(token_uri(&deps, "EXT", alice_viewer()), None);
+        // test private uri is only displayed to permitted viewers
+        assert_eq!(token_uri(&deps, "PRIV", None), None);
+        assert_eq!(token_uri(&deps, "PRIV", bob_viewer()), None);
+        assert_eq!(
+            token_uri(&deps, "PRIV", alice_viewer()),
+            Some("https://private".to_string())
+        );
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: bob.clone(),
+            token_id: Some("PRIV".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert_eq!(
+            token_uri(&deps, "PRIV", bob_viewer()),
+            Some("https://private".to_string())
+        );
+    }
+
+    // test TokenCreator query
+    #[test]
+    fn test_query_token_creator() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let minter = HumanAddr("minter".to_string());
+        let handle_msg = HandleMsg::AddMinters {
+            minters: vec![minter.clone()],
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let mint = |id: &str| HandleMsg::MintNft {
+            token_id: Some(id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), mint("NFT1"));
+        let _handle_result = handle(&mut deps, mock_env("minter", &[]), mint("NFT2"));
+        let creator = |deps: &Extern<MockStorage, MockApi, MockQuerier>, id: &str| {
+            let query_msg = QueryMsg::TokenCreator {
+                token_id: id.to_string(),
+            };
+            let query_answer: QueryAnswer = from_binary(&query(deps, query_msg).unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::TokenCreator { creator } => creator,
+                _ => panic!("unexpected"),
+            }
+        };
+
+        // test the creator is the minter, not the owner
+        assert_eq!(creator(&deps, "NFT1"), Some(HumanAddr("admin".to_string())));
+        assert_eq!(creator(&deps, "NFT2"), Some(minter.clone()));
+
+        // test the creator does not change when the token is transferred
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(creator(&deps, "NFT2"), Some(minter));
+
+        // test a token that does not exist when supply is private
+        assert_eq!(creator(&deps, "NFT3"), None);
+    }
+
+    // test WhoCanTransfer query
+    #[test]
+    fn test_query_who_can_transfer() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let bob = HumanAddr("bob".to_string());
+        let charlie = HumanAddr("charlie".to_string());
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: bob.clone(),
+            token_id: Some("NFT1".to_string()),
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::ApproveToken),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::Approve {
+            spender: HumanAddr("dave".to_string()),
+            token_id: "NFT1".to_string(),
+            expires: Some(Expiration::AtHeight(5)),
+            max_uses: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: charlie.clone(),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: None,
+            transfer: Some(AccessLevel::All),
+            expires: Some(Expiration::AtHeight(1000000)),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::ApproveAllWithPrefix {
+            operator: HumanAddr("eve".to_string()),
+            prefix: "NFT".to_string(),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_msg = HandleMsg::ApproveAllWithPrefix {
+            operator: HumanAddr("frank".to_string()),
+            prefix: "NFT2".to_string(),
+            expires: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        // expire dave's approval
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "bkey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(
+            &mut deps,
+            Env {
+                block: BlockInfo {
+                    height: 10,
+                    time: 100,
+                    chain_id: "cosmos-testnet-14002".to_string(),
+                },
+                message: MessageInfo {
+                    sender: bob.clone(),
+                    sent_funds: vec![],
+                },
+                contract: cosmwasm_std::ContractInfo {
+                    address: HumanAddr::from(MOCK_CONTRACT_ADDR),
+                },
+                contract_key: Some("".to_string()),
+                contract_code_hash: "".to_string(),
+            },
+            handle_msg,
+        );
+
+        // test an approved address that is not the owner can not view the list
+        let query_msg = QueryMsg::WhoCanTransfer {
+            token_id: "NFT1".to_string(),
+            viewer: Some(ViewerInfo {
+                address: bob.clone(),
+                viewing_key: "bkey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test the owner sees live token and inventory approvals, but not expired ones
+        let query_msg = QueryMsg::WhoCanTransfer {
+            token_id: "NFT1".to_string(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            }),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::WhoCanTransfer {
+                owner,
+                transfer_locked,
+                frozen,
+                token_approvals,
+                operators,
+                prefix_approvals,
+            } => {
+                assert_eq!(owner, alice);
+                assert!(!transfer_locked);
+                assert!(!frozen);
+                assert_eq!(
+                    token_approvals,
+                    vec![Cw721Approval {
+                        spender: bob,
+                        expires: Expiration::Never,
+                    }]
+                );
+                assert_eq!(
+                    operators,
+                    vec![Cw721Approval {
+                        spender: charlie,
+                        expires: Expiration::AtHeight(1000000),
+                    }]
+                );
+                // only prefix approvals that match this token's id are listed
+                assert_eq!(
+                    prefix_approvals,
+                    vec![PrefixApproval {
+                        operator: HumanAddr("eve".to_string()),
+                        prefix: "NFT".to_string(),
+                        expires: Expiration::Never,
+                    }]
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test localized names and descriptions
+    #[test]
+    fn test_localized_metadata() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let localized = |name: &str| Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some(name.to_string()),
+                description: Some("A sword".to_string()),
+                localizations: Some(vec![
+                    Localization {
+                        locale: "fr".to_string(),
+                        name: Some("Épée".to_string()),
+                        description: Some("Une épée".to_string()),
+                    },
+                    Localization {
+                        locale: "ja-JP".to_string(),
+                        name: Some("剣".to_string()),
+                        description: None,
+                    },
+                ]),
+                ..Extension::default()
+            }),
+        };
+        let mint = |id: &str, public: Metadata, private: Option<Metadata>| HandleMsg::MintNft {
+            token_id: Some(id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(public),
+            private_metadata: private,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint("NFT1", localized("Sword"), Some(localized("Secret Sword"))),
+        );
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let nft_info = |deps: &Extern<MockStorage, MockApi, MockQuerier>, locale: Option<&str>| {
+            let query_msg = QueryMsg::NftInfo {
+                token_id: "NFT1".to_string(),
+                ipfs_gateway: None,
+                locale: locale.map(|l| l.to_string()),
+            };
+            let query_answer: QueryAnswer = from_binary(&query(deps, query_msg).unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::NftInfo { extension, .. } => extension.unwrap(),
+                _ => panic!("unexpected"),
+            }
+        };
+
+        // test the localizations round-trip through storage unchanged
+        assert_eq!(Some(nft_info(&deps, None)), localized("Sword").extension);
+
+        // test a matched locale, regardless of case
+        for locale in &["fr", "FR"] {
+            let ext = nft_info(&deps, Some(locale));
+            assert_eq!(ext.name, Some("Épée".to_string()));
+            assert_eq!(ext.description, Some("Une épée".to_string()));
+        }
+        // test fields the localization does not provide fall back to the base fields
+        let ext = nft_info(&deps, Some("ja-jp"));
+        assert_eq!(ext.name, Some("剣".to_string()));
+        assert_eq!(ext.description, Some("A sword".to_string()));
+        // test an unmatched locale falls back to the base fields
+        let ext = nft_info(&deps, Some("de"));
+        assert_eq!(ext.name, Some("Sword".to_string()));
+        assert_eq!(ext.description, Some("A sword".to_string()));
+
+        // test private metadata is localized too
+        let query_msg = QueryMsg::PrivateMetadata {
+            token_id: "NFT1".to_string(),
+            viewer: Some(ViewerInfo {
+                address: HumanAddr("alice".to_string()),
+                viewing_key: "akey".to_string(),
+            }),
+            ipfs_gateway: None,
+            locale: Some("fr".to_string()),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PrivateMetadata { extension, .. } => {
+                assert_eq!(extension.unwrap().name, Some("Épée".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test an invalid query locale
+        let query_msg = QueryMsg::NftInfo {
+            token_id: "NFT1".to_string(),
+            ipfs_gateway: None,
+            locale: Some("en_US".to_string()),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Invalid locale: en_US."));
+
+        // test invalid locales are rejected when minting
+        for (i, locale) in ["", "e", "en-", "pt-BRAZILIAN", "12"].iter().enumerate() {
+            let mut meta = localized("Sword");
+            meta.extension.as_mut().unwrap().localizations = Some(vec![Localization {
+                locale: locale.to_string(),
+                name: Some("Sword".to_string()),
+                description: None,
+            }]);
+            let id = format!("Bad{}", i);
+            let handle_result = handle(&mut deps, mock_env("admin", &[]), mint(&id, meta, None));
+            let error = extract_error_msg(handle_result);
+            assert!(error.contains(&format!("Invalid locale: {}.", locale)));
+        }
+    }
+
+    // test the canonical metadata bytes hash to the reveal commitment
+    #[test]
+    fn test_query_metadata_canonical_bytes() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, true, true, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let meta = |name: &str| Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some(name.to_string()),
+                description: Some("Description".to_string()),
+                ..Extension::default()
+            }),
+        };
+        // the client serializes the final metadata off-chain to build the commitment
+        let final_meta = meta("Final");
+        let commitment = sha_256(&final_meta.canonical_bytes().unwrap());
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(meta("Public")),
+            private_metadata: Some(meta("Placeholder")),
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: Some(hex::encode(commitment)),
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_bytes = |deps: &Extern<MockStorage, MockApi, MockQuerier>,
+                           id: &str,
+                           which: MetadataType,
+                           viewer: Option<ViewerInfo>| {
+            let query_msg = QueryMsg::MetadataCanonicalBytes {
+                token_id: id.to_string(),
+                which,
+                viewer,
+            };
+            query(deps, query_msg).map(|b| match from_binary(&b).unwrap() {
+                QueryAnswer::MetadataCanonicalBytes { bytes } => bytes,
+                _ => panic!("unexpected"),
+            })
+        };
+        let alice_viewer = || {
+            Some(ViewerInfo {
+                address: HumanAddr("alice".to_string()),
+                viewing_key: "akey".to_string(),
+            })
+        };
+
+        // test public metadata bytes are visible to anyone
+        let bytes = query_bytes(&deps, "NFT1", MetadataType::Public, None).unwrap();
+        assert_eq!(bytes, Some(Binary::from(to_vec(&meta("Public")).unwrap())));
+        // test a token without metadata has no bytes
+        let bytes = query_bytes(&deps, "NFT2", MetadataType::Public, None).unwrap();
+        assert_eq!(bytes, None);
+        // test sealed private metadata can not be viewed
+        let query_result = query_bytes(&deps, "NFT1", MetadataType::Private, alice_viewer());
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Sealed metadata must be unwrapped by calling Reveal"));
+
+        // test the revealed bytes hash to the commitment and the logged reveal hash
+        let handle_msg = HandleMsg::RevealWithProof {
+            token_id: "NFT1".to_string(),
+            private_metadata: final_meta,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let logged_hash = handle_result.unwrap().log[1].value.trim().to_string();
+        let bytes = query_bytes(&deps, "NFT1", MetadataType::Private, alice_viewer())
+            .unwrap()
+            .unwrap();
+        assert_eq!(sha_256(bytes.as_slice()), commitment);
+        assert_eq!(hex::encode(sha_256(bytes.as_slice())), logged_hash);
+
+        // test private metadata bytes are not visible without permission
+        let query_result = query_bytes(&deps, "NFT1", MetadataType::Private, None);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+    }
 }
@@ -3,14 +3,14 @@ mod tests {
     use crate::contract::{handle, init, query};
     use crate::msg::{
         AccessLevel, ContractStatus, HandleMsg, InitConfig, InitMsg, PostInitCallback, QueryAnswer,
-        QueryMsg, ViewerInfo,
+        QueryMsg, Transfer, ViewerInfo,
     };
     use crate::royalties::{DisplayRoyalty, DisplayRoyaltyInfo, Royalty, RoyaltyInfo};
     use crate::state::{load, Config, CONFIG_KEY};
     use cosmwasm_std::testing::*;
     use cosmwasm_std::{
-        from_binary, to_binary, Api, Binary, Coin, CosmosMsg, Extern, HumanAddr, InitResponse,
-        StdError, StdResult, Uint128, WasmMsg,
+        from_binary, to_binary, Api, BankMsg, Binary, Coin, CosmosMsg, Extern, HumanAddr,
+        InitResponse, StdError, StdResult, Uint128, WasmMsg,
     };
     use std::any::Any;
 
@@ -97,6 +97,20 @@ mod tests {
         }
     }
 
+    fn display_info(decimal_places_in_rates: u8, rates: &[u16]) -> DisplayRoyaltyInfo {
+        DisplayRoyaltyInfo {
+            decimal_places_in_rates,
+            royalties: rates
+                .iter()
+                .map(|rate| DisplayRoyalty {
+                    recipient: None,
+                    rate: *rate,
+                    flat_fee: None,
+                })
+                .collect(),
+        }
+    }
+
     // Init tests
 
     #[test]
@@ -107,10 +121,12 @@ mod tests {
                 Royalty {
                     recipient: HumanAddr("alice".to_string()),
                     rate: 10,
+                    flat_fee: None,
                 },
                 Royalty {
                     recipient: HumanAddr("bob".to_string()),
                     rate: 5,
+                    flat_fee: None,
                 },
             ],
         };
@@ -144,10 +160,12 @@ mod tests {
                 DisplayRoyalty {
                     recipient: Some(HumanAddr("alice".to_string())),
                     rate: 10,
+                    flat_fee: None,
                 },
                 DisplayRoyalty {
                     recipient: Some(HumanAddr("bob".to_string())),
                     rate: 5,
+                    flat_fee: None,
                 },
             ],
         };
@@ -158,10 +176,12 @@ mod tests {
                 DisplayRoyalty {
                     recipient: None,
                     rate: 10,
+                    flat_fee: None,
                 },
                 DisplayRoyalty {
                     recipient: None,
                     rate: 5,
+                    flat_fee: None,
                 },
             ],
         };
@@ -317,10 +337,12 @@ mod tests {
                 Royalty {
                     recipient: HumanAddr("steven".to_string()),
                     rate: 10,
+                    flat_fee: None,
                 },
                 Royalty {
                     recipient: HumanAddr("thomas".to_string()),
                     rate: 5,
+                    flat_fee: None,
                 },
             ],
         };
@@ -344,14 +366,17 @@ mod tests {
                 Royalty {
                     recipient: HumanAddr("steven".to_string()),
                     rate: 80,
+                    flat_fee: None,
                 },
                 Royalty {
                     recipient: HumanAddr("thomas".to_string()),
                     rate: 20,
+                    flat_fee: None,
                 },
                 Royalty {
                     recipient: HumanAddr("uriel".to_string()),
                     rate: 1,
+                    flat_fee: None,
                 },
             ],
         };
@@ -389,10 +414,12 @@ mod tests {
                 DisplayRoyalty {
                     recipient: None,
                     rate: 10,
+                    flat_fee: None,
                 },
                 DisplayRoyalty {
                     recipient: None,
                     rate: 5,
+                    flat_fee: None,
                 },
             ],
         };
@@ -403,10 +430,12 @@ mod tests {
                 DisplayRoyalty {
                     recipient: Some(HumanAddr("steven".to_string())),
                     rate: 10,
+                    flat_fee: None,
                 },
                 DisplayRoyalty {
                     recipient: Some(HumanAddr("thomas".to_string())),
                     rate: 5,
+                    flat_fee: None,
                 },
             ],
         };
@@ -554,6 +583,7 @@ mod tests {
             royalties: vec![Royalty {
                 recipient: HumanAddr("default".to_string()),
                 rate: 10,
+                flat_fee: None,
             }],
         };
         let individual = RoyaltyInfo {
@@ -561,6 +591,7 @@ mod tests {
             royalties: vec![Royalty {
                 recipient: HumanAddr("individual".to_string()),
                 rate: 10,
+                flat_fee: None,
             }],
         };
         let default_hide = DisplayRoyaltyInfo {
@@ -568,6 +599,7 @@ mod tests {
             royalties: vec![DisplayRoyalty {
                 recipient: None,
                 rate: 10,
+                flat_fee: None,
             }],
         };
         let default_see = DisplayRoyaltyInfo {
@@ -575,6 +607,7 @@ mod tests {
             royalties: vec![DisplayRoyalty {
                 recipient: Some(HumanAddr("default".to_string())),
                 rate: 10,
+                flat_fee: None,
             }],
         };
         let individual_hide = DisplayRoyaltyInfo {
@@ -582,6 +615,7 @@ mod tests {
             royalties: vec![DisplayRoyalty {
                 recipient: None,
                 rate: 10,
+                flat_fee: None,
             }],
         };
         let individual_see = DisplayRoyaltyInfo {
@@ -589,6 +623,7 @@ mod tests {
             royalties: vec![DisplayRoyalty {
                 recipient: Some(HumanAddr("individual".to_string())),
                 rate: 10,
+                flat_fee: None,
             }],
         };
         let handle_msg = HandleMsg::SetRoyaltyInfo {
@@ -606,6 +641,7 @@ mod tests {
             private_metadata: None,
             royalty_info: None,
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -653,6 +689,7 @@ mod tests {
             private_metadata: None,
             royalty_info: Some(individual.clone()),
             serial_number: None,
+            reveal_commitment: None,
             memo: None,
             padding: None,
         };
@@ -697,6 +734,8 @@ mod tests {
                 viewing_key: bob_key.clone(),
             }),
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -727,6 +766,8 @@ mod tests {
                 viewing_key: bob_key.clone(),
             }),
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -789,6 +830,8 @@ mod tests {
                 viewing_key: bob_key.clone(),
             }),
             include_expired: None,
+            ipfs_gateway: None,
+            locale: None,
         };
         let query_result = query(&deps, query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
@@ -859,4 +902,512 @@ mod tests {
         let error = extract_error_msg(query_result);
         assert!(error.contains("Token ID: NFT not found"));
     }
+
+    // test a token's royalties take precedence over the contract default
+    #[test]
+    fn test_royalty_override_precedence() {
+        let first_default = RoyaltyInfo {
+            decimal_places_in_rates: 2,
+            royalties: vec![Royalty {
+                recipient: HumanAddr("default".to_string()),
+                rate: 10,
+                flat_fee: None,
+            }],
+        };
+        let (init_result, mut deps) = init_helper_royalties(Some(first_default));
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let override_roy = RoyaltyInfo {
+            decimal_places_in_rates: 2,
+            royalties: vec![Royalty {
+                recipient: HumanAddr("override".to_string()),
+                rate: 5,
+                flat_fee: None,
+            }],
+        };
+        let new_default = RoyaltyInfo {
+            decimal_places_in_rates: 2,
+            royalties: vec![Royalty {
+                recipient: HumanAddr("newdefault".to_string()),
+                rate: 7,
+                flat_fee: None,
+            }],
+        };
+        let display = |roy: &RoyaltyInfo| DisplayRoyaltyInfo {
+            decimal_places_in_rates: roy.decimal_places_in_rates,
+            royalties: roy
+                .royalties
+                .iter()
+                .map(|r| DisplayRoyalty {
+                    recipient: None,
+                    rate: r.rate,
+                    flat_fee: None,
+                })
+                .collect(),
+        };
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // set an override for the token and then change the contract default
+        let handle_msg = HandleMsg::SetRoyaltyInfo {
+            token_id: Some("NFT1".to_string()),
+            royalty_info: Some(override_roy.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::SetRoyaltyInfo {
+            token_id: None,
+            royalty_info: Some(new_default.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test the override is still used
+        let query_msg = QueryMsg::RoyaltyInfo {
+            token_id: Some("NFT1".to_string()),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::RoyaltyInfo { royalty_info } => {
+                assert_eq!(royalty_info, Some(display(&override_roy)));
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test clearing the override falls back to the current default
+        let handle_msg = HandleMsg::SetRoyaltyInfo {
+            token_id: Some("NFT1".to_string()),
+            royalty_info: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::RoyaltyInfo {
+            token_id: Some("NFT1".to_string()),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::RoyaltyInfo { royalty_info } => {
+                assert_eq!(royalty_info, Some(display(&new_default)));
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test the royalty cap
+    #[test]
+    fn test_max_royalty() {
+        let roy = |decimals: u8, rates: &[u16]| RoyaltyInfo {
+            decimal_places_in_rates: decimals,
+            royalties: rates
+                .iter()
+                .enumerate()
+                .map(|(i, r)| Royalty {
+                    recipient: HumanAddr(format!("payee{}", i)),
+                    rate: *r,
+                    flat_fee: None,
+                })
+                .collect(),
+        };
+        // mint a token with a high royalty before any cap is set
+        let (init_result, mut deps) = init_helper_royalties(None);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: Some(roy(2, &[50])),
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test only the admin may set the cap
+        let set_max = || HandleMsg::SetMaxRoyalty {
+            max_royalty_bps: Some(1000),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), set_max());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("This is an admin command"));
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), set_max());
+        assert!(handle_result.is_ok());
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.max_royalty_bps, Some(1000));
+
+        // test the existing royalty is kept, but an update over the cap fails
+        let query_msg = QueryMsg::RoyaltyInfo {
+            token_id: Some("NFT1".to_string()),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::RoyaltyInfo { royalty_info } => {
+                assert_eq!(royalty_info.unwrap().royalties[0].rate, 50);
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::SetRoyaltyInfo {
+            token_id: Some("NFT1".to_string()),
+            royalty_info: Some(roy(2, &[6, 5])),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error
+            .contains("Royalty rates total 1100 basis points, which exceeds the maximum of 1000"));
+
+        // test a fractional excess is rounded up
+        let handle_msg = HandleMsg::SetRoyaltyInfo {
+            token_id: None,
+            royalty_info: Some(roy(5, &[10001])),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Royalty rates total 1001 basis points"));
+
+        // test royalties exactly at the cap
+        let handle_msg = HandleMsg::SetRoyaltyInfo {
+            token_id: Some("NFT1".to_string()),
+            royalty_info: Some(roy(2, &[5, 5])),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT2".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: Some(roy(4, &[400, 600])),
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test a cap set at init applies to mints and the init default
+        let mut deps = mock_dependencies(20, &[]);
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: Some(roy(2, &[20])),
+            config: Some(InitConfig {
+                max_royalty_bps: Some(500),
+                ..InitConfig::default()
+            }),
+            post_init_callback: None,
+        };
+        let init_result = init(&mut deps, mock_env("instantiator", &[]), init_msg);
+        let error = extract_error_msg(init_result);
+        assert!(error
+            .contains("Royalty rates total 2000 basis points, which exceeds the maximum of 500"));
+    }
+
+    // test flat fee royalties
+    #[test]
+    fn test_flat_fee_royalties() {
+        let (init_result, mut deps) = init_helper_royalties(None);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let fee = |recipient: &str, amount: u128, denom: &str| Royalty {
+            recipient: HumanAddr(recipient.to_string()),
+            rate: 0,
+            flat_fee: Some(Coin {
+                denom: denom.to_string(),
+                amount: Uint128(amount),
+            }),
+        };
+        let mint = |id: &str, royalties: Vec<Royalty>| HandleMsg::MintNft {
+            token_id: Some(id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: Some(RoyaltyInfo {
+                decimal_places_in_rates: 2,
+                royalties,
+            }),
+            serial_number: None,
+            reveal_commitment: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint(
+                "NFT1",
+                vec![
+                    fee("bob", 100, "uscrt"),
+                    Royalty {
+                        recipient: HumanAddr("charlie".to_string()),
+                        rate: 5,
+                        flat_fee: None,
+                    },
+                ],
+            ),
+        );
+        assert!(handle_result.is_ok());
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint(
+                "NFT2",
+                vec![fee("bob", 50, "uscrt"), fee("david", 7, "usdc")],
+            ),
+        );
+        assert!(handle_result.is_ok());
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint("NFT3", vec![fee("bob", 100, "uscrt")]),
+        );
+        assert!(handle_result.is_ok());
+
+        // test the flat fee is displayed
+        let query_msg = QueryMsg::RoyaltyInfo {
+            token_id: Some("NFT1".to_string()),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::RoyaltyInfo { royalty_info } => {
+                let royalties = royalty_info.unwrap().royalties;
+                assert_eq!(royalties[0].flat_fee, Some(Coin::new(100, "uscrt")));
+                assert_eq!(royalties[1].flat_fee, None);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test a flat fee royalty can not also have a rate, and must not be zero
+        let mut with_rate = fee("bob", 100, "uscrt");
+        with_rate.rate = 1;
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint("Bad1", vec![with_rate]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("A flat fee royalty must have a rate of 0"));
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint("Bad2", vec![fee("bob", 0, "uscrt")]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("A flat fee royalty must have a non-zero amount"));
+
+        let transfers = || HandleMsg::BatchTransferNft {
+            transfers: vec![Transfer {
+                recipient: HumanAddr("edmund".to_string()),
+                token_ids: vec!["NFT1".to_string(), "NFT2".to_string()],
+                memo: None,
+            }],
+            padding: None,
+        };
+        // test the fees are summed per recipient and paid
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &[Coin::new(150, "uscrt"), Coin::new(7, "usdc")]),
+            transfers(),
+        );
+        let handle_response = handle_result.unwrap();
+        // test the logs list exactly the recipient and amount of each royalty paid
+        let logs: Vec<(&str, &str)> = handle_response
+            .log
+            .iter()
+            .map(|l| (l.key.trim(), l.value.trim()))
+            .collect();
+        assert_eq!(
+            logs,
+            vec![
+                ("royalty_recipient_1", "bob"),
+                ("royalty_amount_1", "150uscrt"),
+                ("royalty_recipient_2", "david"),
+                ("royalty_amount_2", "7usdc"),
+            ]
+        );
+        let messages = handle_response.messages;
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                    to_address: HumanAddr("bob".to_string()),
+                    amount: vec![Coin::new(150, "uscrt")],
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                    to_address: HumanAddr("david".to_string()),
+                    amount: vec![Coin::new(7, "usdc")],
+                }),
+            ]
+        );
+
+        // test overpayment and denoms no royalty uses are refunded to the sender
+        let handle_result = handle(
+            &mut deps,
+            mock_env("edmund", &[Coin::new(130, "uscrt"), Coin::new(5, "uatom")]),
+            HandleMsg::TransferNft {
+                recipient: HumanAddr("frank".to_string()),
+                token_id: "NFT1".to_string(),
+                memo: None,
+                padding: None,
+            },
+        );
+        let messages = handle_result.unwrap().messages;
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                    to_address: HumanAddr("bob".to_string()),
+                    amount: vec![Coin::new(100, "uscrt")],
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                    to_address: HumanAddr("edmund".to_string()),
+                    amount: vec![Coin::new(30, "uscrt"), Coin::new(5, "uatom")],
+                }),
+            ]
+        );
+
+        // test funds sent with a transfer that owes no flat fee are refunded
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            mint(
+                "NFT4",
+                vec![Royalty {
+                    recipient: HumanAddr("charlie".to_string()),
+                    rate: 5,
+                    flat_fee: None,
+                }],
+            ),
+        );
+        assert!(handle_result.is_ok());
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &[Coin::new(20, "uscrt")]),
+            HandleMsg::TransferNft {
+                recipient: HumanAddr("frank".to_string()),
+                token_id: "NFT4".to_string(),
+                memo: None,
+                padding: None,
+            },
+        );
+        let handle_response = handle_result.unwrap();
+        // test no royalty logs are added when no royalties are paid
+        assert!(handle_response.log.is_empty());
+        let messages = handle_response.messages;
+        assert_eq!(
+            messages,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                to_address: HumanAddr("alice".to_string()),
+                amount: vec![Coin::new(20, "uscrt")],
+            })]
+        );
+
+        // test insufficient funds are rejected
+        let transfer = |id: &str| HandleMsg::TransferNft {
+            recipient: HumanAddr("frank".to_string()),
+            token_id: id.to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("edmund", &[Coin::new(50, "uscrt"), Coin::new(6, "usdc")]),
+            transfer("NFT2"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "Flat fee royalties of 7usdc are owed for these transfers, but only 6usdc was sent"
+        ));
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), transfer("NFT3"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "Flat fee royalties of 100uscrt are owed for these transfers, but only 0uscrt was sent"
+        ));
+    }
+
+    // test splitting a sale price among the royalty recipients
+    #[test]
+    fn test_payouts_remainder() {
+        // 10% total royalty of 1099 is 109.9, rounded down to 109, but the individually rounded
+        // shares of 3%, 5%, and 2% only sum to 32 + 54 + 21 = 107, so the largest gets 2 more
+        let info = display_info(2, &[3, 5, 2]);
+        let payouts = info.payouts(Uint128(1099)).unwrap();
+        assert_eq!(payouts, vec![Uint128(32), Uint128(56), Uint128(21)]);
+
+        // shares of 33.33%, 33.33%, and 33.34% of 100 round down to 33 + 33 + 33 = 99, with the
+        // remainder going to the largest rate
+        let info = display_info(4, &[3333, 3333, 3334]);
+        let payouts = info.payouts(Uint128(100)).unwrap();
+        assert_eq!(payouts, vec![Uint128(33), Uint128(33), Uint128(34)]);
+        let total: u128 = payouts.iter().map(|p| p.u128()).sum();
+        assert_eq!(total, 100);
+
+        // remainder goes to the first recipient when the largest rates are tied
+        let info = display_info(1, &[1, 1, 1]);
+        let payouts = info.payouts(Uint128(97)).unwrap();
+        // 30% of 97 is 29.1, rounded down to 29, and each 10% share is 9.7, rounded down to 9
+        assert_eq!(payouts, vec![Uint128(11), Uint128(9), Uint128(9)]);
+
+        // the sum always equals the total royalty owed over many sale amounts
+        let info = display_info(3, &[125, 73, 41]);
+        for sale in 0..5000u128 {
+            let payouts = info.payouts(Uint128(sale)).unwrap();
+            let total: u128 = payouts.iter().map(|p| p.u128()).sum();
+            assert_eq!(total, sale * 239 / 1000);
+        }
+
+        // no royalties means no payouts
+        let info = display_info(2, &[]);
+        assert!(info.payouts(Uint128(1000)).unwrap().is_empty());
+
+        // test a payout that does not fit in a Uint128 is an error instead of being truncated
+        let info = display_info(0, &[2]);
+        let error = extract_error_msg(info.payouts(Uint128(u128::MAX)));
+        assert!(error.contains("Royalty payout is larger than the supported maximum"));
+    }
 }